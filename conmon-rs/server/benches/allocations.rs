@@ -0,0 +1,164 @@
+//! Benchmarks of the heap allocations per line written by the JSON log driver, which are counted
+//! by a wrapping global allocator. Run them by `cargo bench -p conmonrs --bench allocations`.
+
+use conmon_common::conmon_capnp::conmon::{create_container_request, log_driver::Type};
+use conmonrs::bench::{ContainerLog, ContainerLogOptions, Pipe, SharedContainerLog};
+use criterion::{
+    criterion_group, criterion_main,
+    measurement::{Measurement, ValueFormatter},
+    Criterion, Throughput,
+};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    path::Path,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use tempfile::TempDir;
+use tokio::runtime::{Builder, Runtime};
+
+/// The global allocator counting every allocation.
+struct CountingAllocator;
+
+/// The amount of allocations since the start of the process.
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Measurement of the allocations instead of the wall time.
+struct Allocations;
+
+impl Measurement for Allocations {
+    type Intermediate = u64;
+    type Value = u64;
+
+    fn start(&self) -> Self::Intermediate {
+        ALLOCATIONS.load(Ordering::SeqCst)
+    }
+
+    fn end(&self, start: Self::Intermediate) -> Self::Value {
+        ALLOCATIONS.load(Ordering::SeqCst) - start
+    }
+
+    fn add(&self, v1: &Self::Value, v2: &Self::Value) -> Self::Value {
+        v1 + v2
+    }
+
+    fn zero(&self) -> Self::Value {
+        0
+    }
+
+    fn to_f64(&self, value: &Self::Value) -> f64 {
+        *value as f64
+    }
+
+    fn formatter(&self) -> &dyn ValueFormatter {
+        &AllocationsFormatter
+    }
+}
+
+/// Formatter of the allocation counts, which are never scaled.
+struct AllocationsFormatter;
+
+impl ValueFormatter for AllocationsFormatter {
+    fn scale_values(&self, _typical_value: f64, _values: &mut [f64]) -> &'static str {
+        "allocs"
+    }
+
+    fn scale_throughputs(
+        &self,
+        _typical_value: f64,
+        throughput: &Throughput,
+        values: &mut [f64],
+    ) -> &'static str {
+        let elements = match throughput {
+            Throughput::Elements(x) | Throughput::Bytes(x) | Throughput::BytesDecimal(x) => *x,
+        };
+        for value in values {
+            *value /= elements as f64;
+        }
+        "allocs/line"
+    }
+
+    fn scale_for_machines(&self, _values: &mut [f64]) -> &'static str {
+        "allocs"
+    }
+}
+
+/// Create an initialized container log writing by the JSON log driver into `dir`.
+fn container_log(rt: &Runtime, dir: &Path) -> SharedContainerLog {
+    let mut message = capnp::message::Builder::new_default();
+    let mut driver = message
+        .init_root::<create_container_request::Builder>()
+        .init_log_drivers(1)
+        .get(0);
+    driver.set_type(Type::Json);
+    driver.set_path(dir.join("0.log").to_str().expect("valid path"));
+    // Truncating the log file bounds the disk usage of long runs.
+    driver.set_max_size(64 * 1024 * 1024);
+    let request = message
+        .get_root_as_reader::<create_container_request::Reader>()
+        .expect("read request");
+    let log = ContainerLog::from(
+        request.get_log_drivers().expect("get log drivers"),
+        ContainerLogOptions {
+            container_id: "id".into(),
+            ..Default::default()
+        },
+    )
+    .expect("create container log");
+    rt.block_on(async { log.write().await.init().await })
+        .expect("initialize container log");
+    log
+}
+
+/// Allocations of writing a single line, where ASCII and valid UTF-8 lines get borrowed instead
+/// of copied and invalid UTF-8 lines get base64 encoded.
+fn line(c: &mut Criterion<Allocations>) {
+    // A single thread keeps the allocations of other workers out of the measurement.
+    let rt = Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("create runtime");
+    let mut group = c.benchmark_group("allocations");
+    group.throughput(Throughput::Elements(1));
+    for (name, line) in [
+        ("ascii", "x".repeat(127).into_bytes()),
+        ("utf8", "ü".repeat(63).into_bytes()),
+        ("invalid_utf8", vec![0xff; 127]),
+    ] {
+        let mut line = line;
+        line.push(b'\n');
+        let dir = TempDir::new().expect("create temp dir");
+        let log = container_log(&rt, dir.path());
+        group.bench_function(name, |b| {
+            b.to_async(&rt)
+                .iter(|| async { log.write().await.write(Pipe::StdOut, &line[..]).await })
+        });
+    }
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    // The plots fail on allocation counts without any variance.
+    config = Criterion::default().with_measurement(Allocations).without_plots();
+    targets = line
+}
+criterion_main!(benches);
@@ -0,0 +1,142 @@
+//! Benchmarks of the container logging hot path, which writes the container output to the log
+//! drivers. Run them by `cargo bench -p conmonrs`.
+
+use conmon_common::conmon_capnp::conmon::{create_container_request, log_driver::Type};
+use conmonrs::bench::{ContainerLog, ContainerLogOptions, Pipe, SharedContainerLog};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::{cell::Cell, path::Path};
+use tempfile::TempDir;
+use tokio::runtime::Runtime;
+
+/// The size after which the log files get truncated, which bounds the disk usage of long runs.
+const MAX_SIZE: u64 = 64 * 1024 * 1024;
+
+/// The benchmarked combinations of log drivers.
+const DRIVERS: [(&str, &[Type]); 3] = [
+    ("json", &[Type::Json]),
+    ("cri", &[Type::ContainerRuntimeInterface]),
+    ("both", &[Type::Json, Type::ContainerRuntimeInterface]),
+];
+
+/// Create an initialized container log writing by the `drivers` into `dir`.
+fn container_log(rt: &Runtime, dir: &Path, drivers: &[Type]) -> SharedContainerLog {
+    let mut message = capnp::message::Builder::new_default();
+    let mut list = message
+        .init_root::<create_container_request::Builder>()
+        .init_log_drivers(drivers.len() as u32);
+    for (i, driver_type) in drivers.iter().enumerate() {
+        let mut driver = list.reborrow().get(i as u32);
+        let path = dir.join(format!("{i}.log"));
+        driver.set_type(*driver_type);
+        driver.set_path(path.to_str().expect("valid path"));
+        driver.set_max_size(MAX_SIZE);
+    }
+    let request = message
+        .get_root_as_reader::<create_container_request::Reader>()
+        .expect("read request");
+    let log = ContainerLog::from(
+        request.get_log_drivers().expect("get log drivers"),
+        ContainerLogOptions {
+            container_id: "id".into(),
+            ..Default::default()
+        },
+    )
+    .expect("create container log");
+    rt.block_on(async { log.write().await.init().await })
+        .expect("initialize container log");
+    log
+}
+
+/// Lines of `line_size` bytes including the newline, which add up to about `total` bytes.
+fn lines(line_size: usize, total: usize) -> Vec<u8> {
+    let mut line = "x".repeat(line_size - 1);
+    line.push('\n');
+    line.repeat((total / line_size).max(1)).into_bytes()
+}
+
+/// Throughput of writes containing many lines of common sizes.
+fn throughput(c: &mut Criterion) {
+    let rt = Runtime::new().expect("create runtime");
+    let mut group = c.benchmark_group("throughput");
+    for line_size in [64, 512, 4096] {
+        let bytes = lines(line_size, 256 * 1024);
+        group.throughput(Throughput::Bytes(bytes.len() as u64));
+        for (name, drivers) in DRIVERS {
+            let dir = TempDir::new().expect("create temp dir");
+            let log = container_log(&rt, dir.path(), drivers);
+            group.bench_with_input(BenchmarkId::new(name, line_size), &bytes, |b, bytes| {
+                b.to_async(&rt)
+                    .iter(|| async { log.write().await.write(Pipe::StdOut, &bytes[..]).await })
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Latency of writing a single line, like an interactive container does. The `write_line` fast
+/// path skips splitting the already complete line.
+fn latency(c: &mut Criterion) {
+    let rt = Runtime::new().expect("create runtime");
+    let mut group = c.benchmark_group("latency");
+    let line = lines(128, 128);
+    group.throughput(Throughput::Elements(1));
+    for (name, drivers) in DRIVERS {
+        let dir = TempDir::new().expect("create temp dir");
+        let log = container_log(&rt, dir.path(), drivers);
+        group.bench_function(name, |b| {
+            b.to_async(&rt)
+                .iter(|| async { log.write().await.write(Pipe::StdOut, &line[..]).await })
+        });
+        group.bench_function(format!("{name}_write_line"), |b| {
+            b.to_async(&rt).iter(|| async {
+                let line = &line[..line.len() - 1];
+                log.write().await.write_line(Pipe::StdOut, line).await
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Throughput of multi-megabyte bursts, like a container dumping a file.
+fn burst(c: &mut Criterion) {
+    let rt = Runtime::new().expect("create runtime");
+    let mut group = c.benchmark_group("burst");
+    group.sample_size(10);
+    let bytes = lines(200, 4 * 1024 * 1024);
+    group.throughput(Throughput::Bytes(bytes.len() as u64));
+    for (name, drivers) in DRIVERS {
+        let dir = TempDir::new().expect("create temp dir");
+        let log = container_log(&rt, dir.path(), drivers);
+        group.bench_function(name, |b| {
+            b.to_async(&rt)
+                .iter(|| async { log.write().await.write(Pipe::StdOut, &bytes[..]).await })
+        });
+    }
+    group.finish();
+}
+
+/// Throughput of single byte writes, like an unbuffered container output, where every 64th byte
+/// terminates the line.
+fn single_byte(c: &mut Criterion) {
+    let rt = Runtime::new().expect("create runtime");
+    let mut group = c.benchmark_group("single_byte");
+    let line = lines(64, 64);
+    group.throughput(Throughput::Bytes(1));
+    for (name, drivers) in DRIVERS {
+        let dir = TempDir::new().expect("create temp dir");
+        let log = container_log(&rt, dir.path(), drivers);
+        let pos = Cell::new(0);
+        group.bench_function(name, |b| {
+            b.to_async(&rt).iter(|| {
+                let i = pos.replace((pos.get() + 1) % line.len());
+                let log = &log;
+                let byte = &line[i..=i];
+                async move { log.write().await.write(Pipe::StdOut, byte).await }
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, throughput, latency, burst, single_byte);
+criterion_main!(benches);
@@ -301,6 +301,7 @@ impl Attach {
                         .map(|x| {
                             let mut y = x.to_vec();
                             let p = match pipe {
+                                Pipe::StdIn => 1,
                                 Pipe::StdOut => 2,
                                 Pipe::StdErr => 3,
                             };
@@ -55,6 +55,14 @@ impl ChildReaper {
         Ok(r)
     }
 
+    /// Retrieve the first child of every container identifier together with the identifier.
+    pub fn children(&self) -> Result<Vec<(String, ReapableChild)>> {
+        Ok(lock!(self.grandchildren)
+            .iter()
+            .map(|(id, child)| (id.clone(), child.clone()))
+            .collect())
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn create_child<P, I, S>(
         &self,
@@ -71,6 +79,11 @@ impl ChildReaper {
         I: IntoIterator<Item = S>,
         S: AsRef<OsStr>,
     {
+        // The output of the child gets written right away, which would fail every write.
+        if !container_io.logger().read().await.is_initialized() {
+            bail!("log drivers are not initialized")
+        }
+
         let mut cmd = Command::new(cmd);
 
         if stdin {
@@ -100,20 +113,19 @@ impl ChildReaper {
 
         let token = CancellationToken::new();
 
-        match container_io.typ_mut() {
-            ContainerIOType::Terminal(ref mut terminal) => {
-                terminal
-                    .wait_connected(stdin, token.clone())
-                    .await
-                    .context("wait for terminal socket connection")?;
-            }
+        let readers = match container_io.typ_mut() {
+            ContainerIOType::Terminal(ref mut terminal) => vec![terminal
+                .wait_connected(stdin, token.clone())
+                .await
+                .context("wait for terminal socket connection")?],
             ContainerIOType::Streams(streams) => {
                 let stdout = child.stdout.take();
                 let stderr = child.stderr.take();
                 let stdin = child.stdin.take();
-                streams.handle_stdio_receive(stdin, stdout, stderr, token.clone());
+                streams.handle_stdio_receive(stdin, stdout, stderr, token.clone())
             }
         };
+        container_io.track_readers(readers);
 
         let output = child.wait_with_output().await?;
 
@@ -306,6 +318,7 @@ impl ReapableChild {
         let timeout = *self.timeout();
         let stop_token = self.token().clone();
         let cleanup_cmd_raw = self.cleanup_cmd().clone();
+        let io = self.io().clone();
 
         let task = task::spawn(
             async move {
@@ -342,6 +355,10 @@ impl ReapableChild {
                 }
                 oom_watcher.stop().await;
 
+                if let Err(e) = io.close_logs(exit_code).await {
+                    error!(pid, "Could not close container logs: {:#}", e);
+                }
+
                 let exit_channel_data = ExitChannelData {
                     exit_code,
                     oomed,
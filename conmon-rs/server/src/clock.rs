@@ -0,0 +1,140 @@
+//! Clock functionalities for log timestamps.
+
+use anyhow::{Context, Result};
+use std::{
+    fmt::Debug,
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+use tz::{DateTime, TimeZoneRef, UtcDateTime};
+
+/// A source of wall-clock time for log timestamps.
+pub trait Clock: Debug + Send + Sync {
+    /// Retrieve the current time.
+    fn now(&self) -> SystemTime;
+
+    /// Retrieve the current time as UTC RFC3339 timestamp.
+    fn timestamp(&self) -> Result<String> {
+        let (secs, nanos) = self.unix_time()?;
+        Ok(UtcDateTime::from_timespec(secs, nanos)
+            .context("get UTC datetime")?
+            .to_string())
+    }
+
+    /// Retrieve the current time as RFC3339 timestamp within the provided time zone.
+    fn local_timestamp(&self, time_zone: TimeZoneRef) -> Result<String> {
+        let (secs, nanos) = self.unix_time()?;
+        Ok(DateTime::from_timespec(secs, nanos, time_zone)
+            .context("get local datetime")?
+            .to_string())
+    }
+
+    /// Retrieve the seconds and subsecond nanoseconds since the UNIX epoch.
+    fn unix_time(&self) -> Result<(i64, u32)> {
+        let duration = self
+            .now()
+            .duration_since(UNIX_EPOCH)
+            .context("get duration since UNIX epoch")?;
+        Ok((duration.as_secs().try_into()?, duration.subsec_nanos()))
+    }
+}
+
+#[derive(Debug, Default)]
+/// The system wall clock, which may jump if the time gets adjusted.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+#[derive(Debug)]
+/// A wall clock anchored to the monotonic clock on creation, which never jumps backwards on
+/// time adjustments like NTP steps.
+pub struct MonotonicClock {
+    system_time: SystemTime,
+    instant: Instant,
+}
+
+impl MonotonicClock {
+    /// Create a new monotonic clock anchored to the current system time.
+    pub fn new() -> Self {
+        Self {
+            system_time: SystemTime::now(),
+            instant: Instant::now(),
+        }
+    }
+}
+
+impl Default for MonotonicClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MonotonicClock {
+    fn now(&self) -> SystemTime {
+        self.system_time + self.instant.elapsed()
+    }
+}
+
+#[cfg(test)]
+#[derive(Debug)]
+/// A clock always returning the same time.
+pub struct FixedClock(pub SystemTime);
+
+#[cfg(test)]
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
+
+#[cfg(test)]
+#[derive(Debug)]
+/// A clock which only moves forward when advanced manually.
+pub struct ManualClock(std::sync::Mutex<SystemTime>);
+
+#[cfg(test)]
+impl ManualClock {
+    pub fn new(time: SystemTime) -> Self {
+        Self(time.into())
+    }
+
+    pub fn advance(&self, duration: std::time::Duration) {
+        *self.0.lock().unwrap() += duration;
+    }
+}
+
+#[cfg(test)]
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        *self.0.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn timestamp_success() -> Result<()> {
+        let sut = FixedClock(UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789));
+        assert_eq!(sut.timestamp()?, "2023-11-14T22:13:20.123456789Z");
+        assert_eq!(
+            sut.local_timestamp(TimeZoneRef::utc())?,
+            "2023-11-14T22:13:20.123456789Z"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn monotonic_now() -> Result<()> {
+        let sut = MonotonicClock::new();
+        let first = sut.now();
+        assert!(sut.now() >= first);
+        assert!(SystemTime::now().duration_since(first)? < Duration::from_secs(1));
+        Ok(())
+    }
+}
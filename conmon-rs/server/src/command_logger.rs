@@ -0,0 +1,290 @@
+//! Command logging functionalities.
+
+use crate::{container_io::Pipe, container_log::Logger};
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use getset::{CopyGetters, Getters};
+use std::{process::Stdio, time::Duration};
+use tokio::{
+    io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader},
+    process::{Child, Command},
+    select,
+    sync::mpsc::{self, error::TrySendError, Receiver, Sender},
+    task, time,
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, debug_span, error, warn, Instrument};
+use tz::UtcDateTime;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Available strategies if the command does not keep up with the container output.
+pub enum Backpressure {
+    #[default]
+    /// Block the container output until the command accepted the line.
+    Block,
+
+    /// Drop lines which do not fit into the internal buffer.
+    Drop,
+}
+
+#[derive(Debug, CopyGetters, Getters)]
+/// Logger forwarding container output to the stdin of a child process.
+pub struct CommandLogger {
+    #[getset(get)]
+    /// Program to be executed.
+    program: String,
+
+    #[getset(get)]
+    /// Arguments passed to the program.
+    args: Vec<String>,
+
+    #[getset(get_copy)]
+    /// Strategy used if the internal buffer is full.
+    backpressure: Backpressure,
+
+    /// Sending side of the buffered lines, consumed by the supervisor task.
+    tx: Option<Sender<Vec<u8>>>,
+
+    /// Token for stopping the supervisor task.
+    token: CancellationToken,
+}
+
+impl CommandLogger {
+    const ERR_UNINITIALIZED: &'static str = "logger not initialized";
+
+    /// The amount of lines buffered before applying backpressure.
+    const CHANNEL_CAPACITY: usize = 1024;
+
+    /// The initial delay before restarting a failed command.
+    const MIN_BACKOFF: Duration = Duration::from_millis(100);
+
+    /// The maximum delay before restarting a failed command.
+    const MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+    /// Create a new command logger instance.
+    pub fn new(program: &str, args: Vec<String>, backpressure: Backpressure) -> Result<Self> {
+        if program.is_empty() {
+            bail!("no log command provided")
+        }
+        Ok(Self {
+            program: program.into(),
+            args,
+            backpressure,
+            tx: None,
+            token: CancellationToken::new(),
+        })
+    }
+
+    /// Write the contents of the provided reader line by line into the stdin of the command.
+    pub async fn write<T>(&mut self, pipe: Pipe, bytes: T) -> Result<usize>
+    where
+        T: AsyncBufRead + Unpin,
+    {
+        let tx = self.tx.as_ref().context(Self::ERR_UNINITIALIZED)?;
+        let mut reader = BufReader::new(bytes);
+        let mut line_buf = Vec::new();
+        let mut read = 0;
+
+        loop {
+            let n = reader.read_until(b'\n', &mut line_buf).await?;
+            if n == 0 {
+                break;
+            }
+            read += n;
+
+            let line = Self::format(pipe, &line_buf)?;
+            match self.backpressure() {
+                Backpressure::Block => tx
+                    .send(line)
+                    .await
+                    .context("send log line to command task")?,
+                Backpressure::Drop => match tx.try_send(line) {
+                    Ok(()) => {}
+                    Err(TrySendError::Full(_)) => {
+                        warn!("Dropping log line because the log command does not keep up")
+                    }
+                    Err(TrySendError::Closed(_)) => bail!("log command task stopped"),
+                },
+            }
+            line_buf.clear();
+        }
+
+        Ok(read)
+    }
+
+    /// Format a single line as `<TIMESTAMP> <PIPE> <MESSAGE>`.
+    fn format(pipe: Pipe, line: &[u8]) -> Result<Vec<u8>> {
+        let timestamp = UtcDateTime::now()
+            .context("get current UTC datetime")?
+            .to_string();
+        let mut res = format!("{timestamp} {pipe} ").into_bytes();
+        res.extend_from_slice(line);
+        if !line.ends_with(b"\n") {
+            res.push(b'\n');
+        }
+        Ok(res)
+    }
+
+    fn spawn(program: &str, args: &[String]) -> Result<Child> {
+        Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .kill_on_drop(true)
+            .spawn()
+            .context(format!("spawn log command '{program}'"))
+    }
+
+    /// Forward all received lines to the command, restarting it with backoff if it dies.
+    async fn supervise(
+        program: String,
+        args: Vec<String>,
+        mut child: Child,
+        mut rx: Receiver<Vec<u8>>,
+        token: CancellationToken,
+    ) {
+        let mut backoff = Self::MIN_BACKOFF;
+
+        while let Some(line) = select! {
+            line = rx.recv() => line,
+            _ = token.cancelled() => None,
+        } {
+            while let Some(stdin) = child.stdin.as_mut() {
+                let Err(e) = stdin.write_all(&line).await else {
+                    backoff = Self::MIN_BACKOFF;
+                    break;
+                };
+
+                warn!(
+                    "Log command {} failed, restarting in {:?}: {:#}",
+                    program, backoff, e
+                );
+                select! {
+                    _ = time::sleep(backoff) => {}
+                    _ = token.cancelled() => return,
+                }
+                backoff = (backoff * 2).min(Self::MAX_BACKOFF);
+
+                match Self::spawn(&program, &args) {
+                    Ok(new_child) => child = new_child,
+                    Err(e) => error!("Unable to restart log command: {:#}", e),
+                }
+            }
+        }
+
+        // Closing stdin signals the command that no more lines will arrive.
+        drop(child.stdin.take());
+        if let Err(e) = child.wait().await {
+            error!("Unable to wait for log command {}: {:#}", program, e);
+        }
+    }
+}
+
+#[async_trait]
+impl Logger for CommandLogger {
+    fn name(&self) -> &'static str {
+        "command"
+    }
+
+    /// Spawn the command and the task supervising it.
+    async fn init(&mut self) -> Result<()> {
+        debug!("Initializing command logger for program {}", self.program());
+        let child = Self::spawn(self.program(), self.args())?;
+        let (tx, rx) = mpsc::channel(Self::CHANNEL_CAPACITY);
+
+        // Stop a previously running supervisor task.
+        self.token.cancel();
+        self.token = CancellationToken::new();
+
+        task::spawn(
+            Self::supervise(
+                self.program().clone(),
+                self.args().clone(),
+                child,
+                rx,
+                self.token.clone(),
+            )
+            .instrument(debug_span!("command_logger")),
+        );
+        self.tx = Some(tx);
+        Ok(())
+    }
+
+    async fn write(
+        &mut self,
+        pipe: Pipe,
+        bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> Result<usize> {
+        CommandLogger::write(self, pipe, bytes).await
+    }
+
+    /// The command keeps running on reopen, which means that this only verifies the logger state.
+    async fn reopen(&mut self) -> Result<()> {
+        debug!("Reopen command logger for program {}", self.program());
+        self.tx.as_ref().context(Self::ERR_UNINITIALIZED)?;
+        Ok(())
+    }
+
+    /// Lines are handed over to the supervisor task, which means there is nothing to flush.
+    async fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Stop the supervisor task, which closes the stdin of the command.
+    async fn close(&mut self) -> Result<()> {
+        self.token.cancel();
+        self.tx = None;
+        Ok(())
+    }
+}
+
+impl Drop for CommandLogger {
+    fn drop(&mut self) {
+        self.token.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn write_success() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("out.log");
+        let mut sut = CommandLogger::new(
+            "sh",
+            vec!["-c".into(), format!("cat > {}", path.display())],
+            Backpressure::Block,
+        )?;
+        sut.init().await?;
+
+        sut.write(Pipe::StdOut, "hello\nworld\n".as_bytes()).await?;
+
+        let mut contents = String::new();
+        for _ in 0..100 {
+            contents = fs::read_to_string(&path).unwrap_or_default();
+            if contents.lines().count() == 2 {
+                break;
+            }
+            time::sleep(Duration::from_millis(50)).await;
+        }
+        let lines = contents.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with(" stdout hello"));
+        assert!(lines[1].ends_with(" stdout world"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn init_failure() -> Result<()> {
+        assert!(CommandLogger::new("", vec![], Backpressure::Drop).is_err());
+        let mut sut = CommandLogger::new("/file/does/not/exist", vec![], Backpressure::Drop)?;
+        assert!(sut.init().await.is_err());
+        assert!(sut.write(Pipe::StdOut, "hello\n".as_bytes()).await.is_err());
+        Ok(())
+    }
+}
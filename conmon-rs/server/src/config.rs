@@ -125,6 +125,30 @@ pub struct Config {
     )]
     /// OpenTelemetry GRPC endpoint to be used for tracing.
     tracing_endpoint: String,
+
+    #[get_copy = "pub"]
+    #[arg(
+        default_value("0"),
+        env(concat!(prefix!(), "LOG_DISK_BUDGET")),
+        long("log-disk-budget"),
+        value_name("SIZE"),
+        value_parser(crate::size::parse_size)
+    )]
+    /// Maximum total size of all container log files including their backups, like `10GiB`. The
+    /// oldest backups get removed when exceeding it. 0 means unlimited.
+    log_disk_budget: usize,
+
+    #[get_copy = "pub"]
+    #[arg(
+        default_value("0"),
+        env(concat!(prefix!(), "MAX_LOG_SIZE")),
+        long("max-log-size"),
+        value_name("SIZE"),
+        value_parser(crate::size::parse_size)
+    )]
+    /// Maximum size of the log files of the log drivers without a configured one, like `10MiB`,
+    /// for deployments which do not set it in the request. 0 means unset.
+    max_log_size: usize,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, Subcommand)]
@@ -371,3 +395,23 @@ impl Config {
         self.runtime_dir().join(FD_SOCKET)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn max_log_size_env() -> Result<()> {
+        let var = concat!(prefix!(), "MAX_LOG_SIZE");
+        env::set_var(var, "10MiB");
+        let from_env = Config::try_parse_from([clap::crate_name!()]);
+        let from_flag = Config::try_parse_from([clap::crate_name!(), "--max-log-size", "1KiB"]);
+        env::remove_var(var);
+
+        assert_eq!(from_env?.max_log_size(), 10 << 20);
+        // The flag takes precedence over the environment.
+        assert_eq!(from_flag?.max_log_size(), 1024);
+        Ok(())
+    }
+}
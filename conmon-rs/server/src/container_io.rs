@@ -1,16 +1,20 @@
 use crate::{
-    attach::SharedContainerAttach, container_log::SharedContainerLog, streams::Streams,
+    attach::SharedContainerAttach,
+    container_log::{LogEvent, SharedContainerLog},
+    streams::Streams,
     terminal::Terminal,
 };
 use anyhow::{bail, Context, Result};
+use futures::future::join_all;
 use getset::{Getters, MutGetters};
 use nix::errno::Errno;
 use std::{
-    fmt,
+    fmt, mem,
     path::{Path, PathBuf},
     sync::Arc,
+    time::Duration,
 };
-use strum::AsRefStr;
+use strum::{AsRefStr, IntoStaticStr};
 use tempfile::Builder;
 use tokio::{
     io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
@@ -19,10 +23,11 @@ use tokio::{
         mpsc::{UnboundedReceiver, UnboundedSender},
         RwLock,
     },
-    time::{self, Instant},
+    task::JoinHandle,
+    time::{self, Instant, MissedTickBehavior},
 };
 use tokio_util::sync::CancellationToken;
-use tracing::{debug, error};
+use tracing::{debug, error, warn};
 
 /// A shared container IO abstraction.
 #[derive(Debug, Clone)]
@@ -59,6 +64,34 @@ impl SharedContainerIO {
     pub async fn attach(&self) -> SharedContainerAttach {
         self.0.read().await.attach().clone()
     }
+
+    /// Wait for the output read loops to reach EOF, write the exit event and close the logs.
+    /// Processes which forked into the background may keep the pipes open, which is why the
+    /// logs get closed anyway after the `ContainerIO::READERS_TIMEOUT`.
+    pub async fn close_logs(&self, exit_code: i32) -> Result<()> {
+        let readers = mem::take(&mut self.0.write().await.readers);
+        match time::timeout(ContainerIO::READERS_TIMEOUT, join_all(readers)).await {
+            Ok(results) => {
+                for e in results.into_iter().filter_map(Result::err) {
+                    warn!("Unable to wait for read loop: {:#}", e)
+                }
+            }
+            Err(_) => warn!("Container output did not reach EOF, closing the logs anyway"),
+        }
+
+        let logger = self.logger().await;
+        let mut logger = logger.write().await;
+        let event = if logger.exit_event() {
+            logger
+                .emit_event(LogEvent::ContainerExit { exit_code })
+                .await
+                .context("write container exit event")
+        } else {
+            Ok(())
+        };
+        logger.close().await.context("close container logs")?;
+        event
+    }
 }
 
 #[derive(Debug, Getters, MutGetters)]
@@ -71,6 +104,8 @@ pub struct ContainerIO {
 
     #[getset(get = "pub")]
     attach: SharedContainerAttach,
+
+    readers: Vec<JoinHandle<()>>,
 }
 
 #[derive(Debug)]
@@ -87,10 +122,14 @@ pub enum Message {
     Done,
 }
 
-#[derive(AsRefStr, Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(AsRefStr, IntoStaticStr, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 #[strum(serialize_all = "lowercase")]
+#[allow(clippy::enum_variant_names)]
 /// Available pipe types.
 pub enum Pipe {
+    /// Standard input, only logged if explicitly enabled.
+    StdIn,
+
     /// Standard output.
     StdOut,
 
@@ -104,6 +143,41 @@ impl fmt::Display for Pipe {
     }
 }
 
+impl Pipe {
+    /// Retrieve the label of the pipe, like `stdout`.
+    pub fn as_str(&self) -> &'static str {
+        self.into()
+    }
+
+    /// Map the pipe to the severity of its output, which treats stdin like stdout.
+    pub fn severity(&self) -> Severity {
+        match self {
+            Pipe::StdIn | Pipe::StdOut => Severity::Info,
+            Pipe::StdErr => Severity::Error,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Severity of the container output, which the log drivers map to their levels or priorities.
+pub enum Severity {
+    /// Regular output, like stdout.
+    Info,
+
+    /// Error output, like stderr.
+    Error,
+}
+
+impl Severity {
+    /// Retrieve the numerical severity of RFC5424, which GELF uses as level as well.
+    pub fn code(self) -> u8 {
+        match self {
+            Severity::Info => 6,
+            Severity::Error => 3,
+        }
+    }
+}
+
 impl From<Terminal> for ContainerIOType {
     fn from(c: Terminal) -> Self {
         Self::Terminal(c)
@@ -118,6 +192,7 @@ impl From<Streams> for ContainerIOType {
 
 impl ContainerIO {
     const MAX_STDIO_STREAM_SIZE: usize = 16 * 1024 * 1024;
+    const READERS_TIMEOUT: Duration = Duration::from_secs(10);
 
     /// Create a new container IO instance.
     pub fn new(terminal: bool, logger: SharedContainerLog) -> Result<Self> {
@@ -137,9 +212,15 @@ impl ContainerIO {
             typ,
             logger,
             attach,
+            readers: vec![],
         })
     }
 
+    /// Keep track of the output read loops, which have to finish before closing the logs.
+    pub fn track_readers(&mut self, readers: impl IntoIterator<Item = JoinHandle<()>>) {
+        self.readers.extend(readers)
+    }
+
     /// Generate a the temp file name without creating the file.
     pub fn temp_file_name(directory: Option<&Path>, prefix: &str, suffix: &str) -> Result<PathBuf> {
         let mut file = Builder::new();
@@ -237,12 +318,41 @@ impl ContainerIO {
         T: AsyncRead + Unpin,
     {
         let mut buf = vec![0; 1024];
+        // Retained entries like joined multiline ones get written once they expired, even if no
+        // further output arrives.
+        let mut expiry = logger.read().await.expiry_interval().map(|period| {
+            let mut interval = time::interval_at(Instant::now() + period, period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval
+        });
 
         loop {
-            match reader.read(&mut buf).await {
+            let res = match expiry.as_mut() {
+                Some(interval) => select! {
+                    res = reader.read(&mut buf) => res,
+                    _ = interval.tick() => {
+                        logger
+                            .write()
+                            .await
+                            .flush_expired()
+                            .await
+                            .context("flush expired log entries")?;
+                        continue;
+                    }
+                },
+                None => reader.read(&mut buf).await,
+            };
+            match res {
                 Ok(0) => {
                     debug!("Nothing more to read");
 
+                    logger
+                        .write()
+                        .await
+                        .flush_partial(pipe)
+                        .await
+                        .context("flush partial log line")?;
+
                     attach
                         .write(Message::Done)
                         .await
@@ -282,6 +392,13 @@ impl ContainerIO {
                 Err(e) => match Errno::from_raw(e.raw_os_error().context("get OS error")?) {
                     Errno::EIO => {
                         debug!("Stopping read loop");
+                        logger
+                            .write()
+                            .await
+                            .flush_partial(pipe)
+                            .await
+                            .context("flush partial log line")?;
+
                         attach
                             .write(Message::Done)
                             .await
@@ -308,6 +425,7 @@ impl ContainerIO {
 
     pub async fn read_loop_stdin(
         mut writer: impl AsyncWrite + Unpin,
+        logger: SharedContainerLog,
         mut attach: SharedContainerAttach,
         token: CancellationToken,
     ) -> Result<()> {
@@ -322,7 +440,7 @@ impl ContainerIO {
                 res = attach.read() => {
                     match res {
                         Ok(data) => {
-                            Self::handle_stdin_data(&data, &mut writer).await?;
+                            Self::handle_stdin_data(&data, &mut writer, &logger).await?;
                         }
                         Err(e) => {
                             return Err(e).context("read from stdin attach endpoints");
@@ -333,7 +451,7 @@ impl ContainerIO {
                     // Closing immediately may race with outstanding data on stdin for short lived
                     // containers. This means we try to read once again.
                     if let Ok(data) = attach.try_read() {
-                        Self::handle_stdin_data(&data, &mut writer).await?;
+                        Self::handle_stdin_data(&data, &mut writer, &logger).await?;
                     }
                     return Ok(());
                 }
@@ -341,12 +459,146 @@ impl ContainerIO {
         }
     }
 
-    async fn handle_stdin_data(data: &[u8], mut writer: impl AsyncWrite + Unpin) -> Result<()> {
+    async fn handle_stdin_data(
+        data: &[u8],
+        mut writer: impl AsyncWrite + Unpin,
+        logger: &SharedContainerLog,
+    ) -> Result<()> {
         debug!("Got {} attach bytes", data.len());
 
+        // Logging stdin is best effort, which should not break the attach session.
+        if logger.read().await.logs_stdin() {
+            if let Err(e) = logger.write().await.write(Pipe::StdIn, data).await {
+                warn!("Unable to write stdin to log file: {:#}", e);
+            }
+        }
+
         writer
             .write_all(data)
             .await
             .context("write attach stdin to stream")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container_log::ContainerLog;
+    use conmon_common::conmon_capnp::conmon::log_driver::Type;
+    use std::{fs, process::Stdio};
+    use tokio::process::Command;
+
+    #[tokio::test]
+    async fn read_loop_flush_expired() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("json.log");
+        let logger = ContainerLog::from_drivers(
+            "id",
+            false,
+            &[(Type::Json, path.clone())],
+            |_, mut driver| {
+                driver.set_multiline(true);
+                driver.set_multiline_timeout(10);
+            },
+        )?;
+        logger.write().await.init().await?;
+
+        let (reader, mut writer) = tokio::io::duplex(1024);
+        let (message_tx, _message_rx) = tokio::sync::mpsc::unbounded_channel();
+        let read_loop = tokio::spawn(ContainerIO::read_loop(
+            reader,
+            Pipe::StdErr,
+            logger,
+            message_tx,
+            SharedContainerAttach::default(),
+        ));
+        writer.write_all(b"error: boom\n\tat main\n").await?;
+
+        // The joined entry gets written without further output or the end of the stream.
+        let mut content = String::new();
+        for _ in 0..100 {
+            content = fs::read_to_string(&path)?;
+            if !content.is_empty() {
+                break;
+            }
+            time::sleep(Duration::from_millis(10)).await;
+        }
+        let entry: serde_json::Value = serde_json::from_str(content.trim())?;
+        assert_eq!(entry["message"], "error: boom\n\tat main");
+
+        drop(writer);
+        read_loop.await??;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stdin_log_failure_forwards_data() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("json.log");
+        // The logger is not initialized, which makes every write fail.
+        let logger = ContainerLog::from_drivers("id", true, &[(Type::Json, path)], |_, _| {})?;
+
+        let mut stream = vec![];
+        ContainerIO::handle_stdin_data(b"ls\n", &mut stream, &logger).await?;
+        assert_eq!(stream, b"ls\n");
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn close_logs_after_eof() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("json.log");
+        let logger =
+            ContainerLog::from_drivers("id", false, &[(Type::Json, path.clone())], |_, _| {})?;
+        logger.write().await.set_exit_event(true).init().await?;
+
+        let mut io = ContainerIO::new(false, logger)?;
+        let mut child = Command::new("sh")
+            .arg("-c")
+            .arg("(sleep 0.5; echo late) & echo early")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let ContainerIOType::Streams(streams) = io.typ() else {
+            bail!("no streams");
+        };
+        let readers = streams.handle_stdio_receive(
+            None,
+            child.stdout.take(),
+            child.stderr.take(),
+            CancellationToken::new(),
+        );
+        io.track_readers(readers);
+
+        // The process exits before the background one writes its output.
+        assert!(child.wait().await?.success());
+        SharedContainerIO::new(io).close_logs(0).await?;
+
+        let entries = fs::read_to_string(&path)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?;
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0]["message"], "early");
+        assert_eq!(entries[1]["message"], "late");
+        assert_eq!(entries[2]["event"], "container_exit");
+        Ok(())
+    }
+
+    #[test]
+    fn pipe_as_str() {
+        assert_eq!(Pipe::StdIn.as_str(), "stdin");
+        assert_eq!(Pipe::StdOut.as_str(), "stdout");
+        assert_eq!(Pipe::StdErr.as_str(), "stderr");
+        assert_eq!(Pipe::StdErr.as_str(), Pipe::StdErr.to_string());
+    }
+
+    #[test]
+    fn pipe_severity() {
+        assert_eq!(Pipe::StdIn.severity(), Severity::Info);
+        assert_eq!(Pipe::StdOut.severity(), Severity::Info);
+        assert_eq!(Pipe::StdErr.severity(), Severity::Error);
+        assert_eq!(Severity::Info.code(), 6);
+        assert_eq!(Severity::Error.code(), 3);
+    }
+}
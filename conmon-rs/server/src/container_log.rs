@@ -1,22 +1,481 @@
-use crate::{container_io::Pipe, cri_logger::CriLogger, json_logger::JsonLogger};
-use anyhow::Result;
+#[cfg(feature = "gelf")]
+use crate::gelf_logger::GelfLogger;
+#[cfg(feature = "journald")]
+use crate::journald_logger::JournaldLogger;
+#[cfg(feature = "s3")]
+use crate::log_upload::S3Upload;
+#[cfg(feature = "otlp")]
+use crate::otlp_logger::OtlpLogger;
+use crate::{
+    clock::{Clock, MonotonicClock, SystemClock},
+    command_logger::{Backpressure as CommandBackpressure, CommandLogger},
+    container_io::Pipe,
+    cri_logger::CriLogger,
+    json_logger::{JsonFraming, JsonKeys, JsonLogger, PipeLabel as JsonPipeLabel},
+    log_budget::LogDiskBudget,
+    log_durability::{DurabilityPolicy, FlushThreshold},
+    log_error::LoggerError,
+    log_metrics::{MetricsSink, NoopMetricsSink, PipeStats},
+    log_queue::{QueuePolicy as LogQueuePolicy, QueuedLogger},
+    log_reader::{CriTags, LogFormat, LogLine},
+    log_rotation::{BackupFormat, Compression, RotationMode, RotationNaming},
+    log_transform::{LogTransform, Redactor},
+    memory_logger::MemoryLogger,
+    null_logger::NullLogger,
+    optional_logger::OptionalLogger,
+    size,
+    syslog_logger::SyslogLogger,
+    timeout_logger::TimeoutLogger,
+};
+use anyhow::{bail, format_err, Context, Error, Result};
+use async_trait::async_trait;
 use capnp::struct_list::Reader;
-use conmon_common::conmon_capnp::conmon::log_driver::{Owned, Type};
-use futures::{future::join_all, FutureExt};
-use std::sync::Arc;
-use tokio::{io::AsyncBufRead, sync::RwLock};
+use conmon_common::conmon_capnp::conmon::log_driver::{
+    Backpressure, Compression as LogCompression, Durability, EmptyLines as LogEmptyLines, Framing,
+    MaxSizeMode, Owned, PipeLabel, QueuePolicy, Reader as LogDriverReader, Rotation, Stream,
+    TimestampSource, Type,
+};
+use futures::{future::join_all, Future};
+#[cfg(unix)]
+use nix::unistd::{access, fchown, gethostname, AccessFlags, Gid, Uid};
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::{self, Debug, Display},
+    fs, io, mem,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+use tokio::{
+    fs::{File, OpenOptions},
+    io::{AsyncBufRead, AsyncReadExt},
+    sync::{RwLock, Semaphore},
+};
+use tracing::{debug, warn};
 
 pub type SharedContainerLog = Arc<RwLock<ContainerLog>>;
 
-#[derive(Debug, Default)]
+/// The default capacity of the read and write buffers of the file based log drivers.
+pub const DEFAULT_BUFFER_SIZE: usize = 8 * 1024;
+
+#[derive(Debug)]
 pub struct ContainerLog {
-    drivers: Vec<LogDriver>,
+    drivers: Vec<Box<dyn Logger>>,
+    log_stdin: bool,
+    ordered: bool,
+    exit_event: bool,
+    metrics: Arc<dyn MetricsSink>,
+    writers: Option<Semaphore>,
+    paused: Option<PausedLog>,
+    options: DriverOptions,
+}
+
+impl Default for ContainerLog {
+    fn default() -> Self {
+        Self {
+            drivers: vec![],
+            log_stdin: false,
+            ordered: false,
+            exit_event: false,
+            metrics: Arc::new(NoopMetricsSink),
+            writers: None,
+            paused: None,
+            options: DriverOptions::default(),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+/// The options of a new container log, which apply to all of its log drivers.
+pub struct ContainerLogOptions {
+    /// The identifier of the container.
+    pub container_id: String,
+
+    /// The metadata identifying the container in structured log entries, where the container ID
+    /// is always included.
+    pub metadata: BTreeMap<String, String>,
+
+    /// Log the data written to stdin as well.
+    pub log_stdin: bool,
+
+    /// The node wide budget charged by the file based loggers, if any.
+    pub budget: Option<Arc<LogDiskBudget>>,
+
+    /// The maximum log size of the loggers inheriting it, if any.
+    pub default_max_size: Option<usize>,
+
+    /// The maximum log size of the node, which applies to the loggers without a configured one.
+    pub node_max_size: Option<usize>,
+}
+
+#[derive(Debug, Default)]
+/// The options of the container applying to all of its loggers, including the ones added later.
+struct DriverOptions {
+    /// The identifier of the container.
+    container_id: String,
+
+    /// The metadata identifying the container in structured log entries.
+    metadata: BTreeMap<String, String>,
+
+    /// The node wide budget charged by the file based loggers, if any.
+    budget: Option<Arc<LogDiskBudget>>,
+
+    /// The maximum log size of the loggers inheriting it, if any.
+    default_max_size: Option<usize>,
+
+    /// The maximum log size of the node, which applies to the loggers without a configured one.
+    node_max_size: Option<usize>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Statistics of a single log driver.
+pub struct LogStats {
+    /// The name of the logger.
+    pub name: &'static str,
+
+    /// The bytes written since the last rotation.
+    pub bytes_written: usize,
+
+    /// The maximum log size in bytes before rotating, if any.
+    pub max_log_size: Option<usize>,
+
+    /// The amount of rotations triggered by the log size.
+    pub rotations: usize,
+
+    /// The bytes dropped because the logger did not keep up.
+    pub dropped_bytes: usize,
+
+    /// The line statistics of every pipe, only provided by line based loggers.
+    pub lines: PipeStats,
+
+    /// The time of the last written line, only provided by file based loggers.
+    pub last_write: Option<SystemTime>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+/// A lifecycle event of the container written inline with its output, which lets log consumers
+/// see the lifecycle boundaries.
+pub enum LogEvent {
+    /// The container exited with the provided exit code.
+    ContainerExit { exit_code: i32 },
+
+    /// The logger switched to its fallback path after writing to the previous one failed.
+    LogPathFailover { previous_path: PathBuf },
+}
+
+impl Display for LogEvent {
+    /// Format the event as JSON object, like `{"event":"container_exit","exit_code":0}`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&serde_json::to_string(self).map_err(|_| fmt::Error)?)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The handling of the container output written while the logging is paused.
+pub enum PausePolicy {
+    /// Drop all output until resuming.
+    Drop,
+
+    /// Buffer the output up to the provided amount of bytes, which gets written on resume. Writes
+    /// exceeding the remaining capacity get dropped.
+    Buffer(usize),
+}
+
+#[derive(Debug)]
+/// A single write received while the logging is paused.
+enum PausedWrite {
+    /// Data to be split into lines, like passed to `write`.
+    Data(Pipe, Vec<u8>),
+
+    /// A single complete line, like passed to `write_line`.
+    Line(Pipe, Vec<u8>),
 }
 
 #[derive(Debug)]
-enum LogDriver {
-    ContainerRuntimeInterface(CriLogger),
-    Json(JsonLogger),
+/// The state of a paused container log.
+struct PausedLog {
+    /// The handling of the written output.
+    policy: PausePolicy,
+
+    /// The buffered writes in their original order.
+    writes: Vec<PausedWrite>,
+
+    /// The total bytes of the buffered writes.
+    size: usize,
+
+    /// The bytes dropped because they exceeded the buffer.
+    dropped_bytes: usize,
+}
+
+impl PausedLog {
+    /// Buffer the write if it fits into the remaining capacity of the policy, otherwise drop it.
+    fn push(&mut self, write: PausedWrite) {
+        let len = match &write {
+            PausedWrite::Data(_, data) | PausedWrite::Line(_, data) => data.len(),
+        };
+        match self.policy {
+            PausePolicy::Buffer(capacity) if self.size + len <= capacity => {
+                self.size += len;
+                self.writes.push(write);
+            }
+            _ => self.dropped_bytes += len,
+        }
+    }
+}
+
+#[async_trait]
+/// The common interface of all log drivers.
+pub trait Logger: Debug + Send + Sync {
+    /// The name of the logger used for error reporting.
+    fn name(&self) -> &'static str;
+
+    /// Asynchronously initialize the logger.
+    async fn init(&mut self) -> Result<()>;
+
+    /// Write the contents of the provided reader into the logger and return the amount of
+    /// consumed bytes.
+    async fn write(
+        &mut self,
+        pipe: Pipe,
+        bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> Result<usize>;
+
+    /// Write a single complete line as exactly one entry, where embedded newlines are part of
+    /// the message. Loggers with a line based format fall back to `write`, which splits the line.
+    async fn write_line(&mut self, pipe: Pipe, line: &[u8]) -> Result<()> {
+        self.write(pipe, &mut &line[..]).await?;
+        Ok(())
+    }
+
+    /// Reopen the logger, for example to rotate its file.
+    async fn reopen(&mut self) -> Result<()>;
+
+    /// Ensure that all buffered content is written.
+    async fn flush(&mut self) -> Result<()>;
+
+    /// Flush the logger including its retained partial lines and release its resources, which
+    /// requires another `init` before writing.
+    async fn close(&mut self) -> Result<()> {
+        self.flush().await
+    }
+
+    /// Retrieve the path and format of the log file, if the logger writes a readable one.
+    fn source(&self) -> Option<(PathBuf, LogFormat)> {
+        None
+    }
+
+    /// Indicates if the logger accepts the output of the provided pipe.
+    fn accepts(&self, _pipe: Pipe) -> bool {
+        true
+    }
+
+    /// Write a marker about `dropped_bytes` which got lost before reaching the logger. Loggers
+    /// without a dedicated marker format ignore it.
+    async fn write_marker(&mut self, _dropped_bytes: usize) -> Result<()> {
+        Ok(())
+    }
+
+    /// Write a lifecycle event of the container. Loggers without a dedicated event format write
+    /// it as JSON object line to stdout.
+    async fn write_event(&mut self, event: LogEvent) -> Result<()> {
+        self.write_line(Pipe::StdOut, format!("{event}\n").as_bytes())
+            .await
+    }
+
+    /// Write all retained partial lines of the provided pipe. Loggers which do not retain lines
+    /// have nothing to do.
+    async fn flush_partial(&mut self, _pipe: Pipe) -> Result<()> {
+        Ok(())
+    }
+
+    /// Retrieve the timeout of the entries retained by the logger, like a joined multiline entry
+    /// waiting for its next line. Loggers which do not retain entries have none.
+    fn expiry_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Write the retained entries whose timeout elapsed without another line arriving. Loggers
+    /// which do not retain entries have nothing to do.
+    async fn flush_expired(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Verify the configuration of the logger without creating any files. Loggers without
+    /// options to verify always succeed.
+    async fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Retrieve the lines kept in memory, if the logger keeps them instead of writing a file.
+    async fn lines(&self) -> Option<Vec<LogLine>> {
+        None
+    }
+
+    /// Indicates if `init` has been run, which is required before writing. Loggers without
+    /// resources to acquire are always initialized.
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    /// Retrieve the current statistics of the logger. Loggers without a size limit only provide
+    /// their name.
+    fn stats(&self) -> LogStats {
+        LogStats {
+            name: self.name(),
+            bytes_written: 0,
+            max_log_size: None,
+            rotations: 0,
+            dropped_bytes: 0,
+            lines: PipeStats::default(),
+            last_write: None,
+        }
+    }
+}
+
+/// Verify that the log file at `path` can be written without creating it, which requires either
+/// a writable file or a writable parent directory.
+pub async fn validate_path(path: &Path) -> Result<()> {
+    match tokio::fs::metadata(path).await {
+        Ok(metadata) if metadata.is_dir() => {
+            bail!("log path '{}' is a directory", path.display())
+        }
+        Ok(_) => check_writable(path, false)
+            .context(format!("log file '{}' is not writable", path.display())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            let dir = path
+                .parent()
+                .filter(|x| !x.as_os_str().is_empty())
+                .unwrap_or(Path::new("."));
+            let metadata = tokio::fs::metadata(dir)
+                .await
+                .context(format!("get log directory metadata of '{}'", dir.display()))?;
+            if !metadata.is_dir() {
+                bail!("log directory '{}' is not a directory", dir.display())
+            }
+            check_writable(dir, true)
+                .context(format!("log directory '{}' is not writable", dir.display()))
+        }
+        Err(e) => Err(e).context(format!("get log file metadata of '{}'", path.display())),
+    }
+}
+
+#[cfg(unix)]
+/// Verify that the file at `path` is writable, where a directory has to be searchable as well.
+fn check_writable(path: &Path, dir: bool) -> Result<()> {
+    let mut flags = AccessFlags::W_OK;
+    if dir {
+        flags |= AccessFlags::X_OK;
+    }
+    Ok(access(path, flags)?)
+}
+
+#[cfg(not(unix))]
+/// Verify that the file at `path` is writable, which is only indicated by the read-only attribute
+/// outside of Unix.
+fn check_writable(path: &Path, _: bool) -> Result<()> {
+    if fs::metadata(path)?.permissions().readonly() {
+        bail!("read-only")
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+/// Retrieve the hostname of the node.
+fn hostname() -> Result<String> {
+    Ok(gethostname()
+        .context("get hostname")?
+        .to_string_lossy()
+        .into_owned())
+}
+
+#[cfg(not(unix))]
+/// Retrieve the hostname of the node from the environment.
+fn hostname() -> Result<String> {
+    std::env::var("COMPUTERNAME").context("get hostname")
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Available policies for empty lines of the container output.
+pub enum EmptyLines {
+    #[default]
+    /// Write empty lines as they are.
+    Keep,
+
+    /// Skip empty lines, which includes the ones containing only whitespace if the logger trims
+    /// its messages.
+    Drop,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Permissions and ownership of the log files, where unset values keep the defaults.
+pub struct FileAccess {
+    /// The permission bits, which are applied regardless of the umask.
+    pub mode: Option<u32>,
+
+    /// The owning user ID.
+    pub uid: Option<u32>,
+
+    /// The owning group ID.
+    pub gid: Option<u32>,
+
+    /// Refuse to open the log files through a symbolic link, which could otherwise clobber the
+    /// file it points to. Symbolic links of the parent directories are still followed.
+    pub no_follow: bool,
+}
+
+impl FileAccess {
+    #[cfg(unix)]
+    /// The flags for opening the log files.
+    pub fn open_flags(self) -> i32 {
+        if self.no_follow {
+            libc::O_NOFOLLOW
+        } else {
+            0
+        }
+    }
+
+    /// Open the log file at `path` with the provided options, which include the `open_flags`.
+    pub async fn open(self, options: &OpenOptions, path: &Path) -> Result<File> {
+        options.open(path).await.map_err(|e| {
+            #[cfg(unix)]
+            if self.no_follow && e.raw_os_error() == Some(libc::ELOOP) {
+                return LoggerError::Symlink(path.into()).into();
+            }
+            Error::from(e).context(format!("open log file path '{}'", path.display()))
+        })
+    }
+
+    #[cfg(unix)]
+    /// Apply the permissions and ownership to the open log file.
+    pub async fn apply(self, file: &File) -> Result<()> {
+        use std::os::{fd::AsRawFd, unix::fs::PermissionsExt};
+
+        if let Some(mode) = self.mode {
+            file.set_permissions(fs::Permissions::from_mode(mode))
+                .await
+                .context(format!("set log file mode {mode:o}"))?;
+        }
+        if self.uid.is_some() || self.gid.is_some() {
+            fchown(
+                file.as_raw_fd(),
+                self.uid.map(Uid::from_raw),
+                self.gid.map(Gid::from_raw),
+            )
+            .context("change log file owner")?;
+        }
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    /// Permissions and ownership of the log files are only supported on Unix.
+    pub async fn apply(self, _: &File) -> Result<()> {
+        if self.mode.is_some() || self.uid.is_some() || self.gid.is_some() {
+            bail!("log file permissions and ownership are only supported on Unix")
+        }
+        Ok(())
+    }
 }
 
 impl ContainerLog {
@@ -25,47 +484,648 @@ impl ContainerLog {
         Arc::new(RwLock::new(Self::default()))
     }
 
-    pub fn from(reader: Reader<Owned>) -> Result<SharedContainerLog> {
+    /// Create a new SharedContainerLog from the provided log drivers and container `options`.
+    pub fn from(reader: Reader<Owned>, options: ContainerLogOptions) -> Result<SharedContainerLog> {
+        let ContainerLogOptions {
+            container_id,
+            mut metadata,
+            log_stdin,
+            budget,
+            default_max_size,
+            node_max_size,
+        } = options;
+        metadata
+            .entry("id".into())
+            .or_insert_with(|| container_id.clone());
+        let options = DriverOptions {
+            container_id,
+            metadata,
+            budget,
+            default_max_size,
+            node_max_size,
+        };
+        // Concurrent writes into the same file would interleave the entries of both drivers.
+        let mut paths = HashMap::new();
         let drivers = reader
             .iter()
-            .map(|x| -> Result<_> {
-                match x.get_type()? {
-                    Type::ContainerRuntimeInterface => {
-                        Ok(LogDriver::ContainerRuntimeInterface(CriLogger::new(
-                            x.get_path()?.to_str()?,
-                            if x.get_max_size() > 0 {
-                                Some(x.get_max_size() as usize)
-                            } else {
-                                None
-                            },
-                        )?))
+            .enumerate()
+            .map(|(i, x)| -> Result<Box<dyn Logger>> {
+                if matches!(x.get_type()?, Type::ContainerRuntimeInterface | Type::Json) {
+                    let path = Self::resolve_path(Path::new(x.get_path()?.to_str()?));
+                    if let Some(other) = paths.insert(path.clone(), i) {
+                        bail!(
+                            "log drivers {} and {} both write to path '{}'",
+                            other,
+                            i,
+                            path.display()
+                        )
                     }
-                    Type::Json => Ok(LogDriver::Json(JsonLogger::new(
-                        x.get_path()?.to_str()?,
-                        if x.get_max_size() > 0 {
-                            Some(x.get_max_size() as usize)
-                        } else {
-                            None
-                        },
-                    )?)),
                 }
+
+                Self::new_driver(&options, i, x)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Arc::new(RwLock::new(Self {
+            drivers,
+            log_stdin,
+            options,
+            ..Default::default()
+        })))
+    }
+
+    /// Create a single logger from its configuration, where `i` identifies it in errors.
+    fn new_driver(
+        options: &DriverOptions,
+        i: usize,
+        x: LogDriverReader,
+    ) -> Result<Box<dyn Logger>> {
+        let rotation = match x.get_rotation()? {
+            Rotation::Truncate => RotationMode::Truncate,
+            Rotation::Rename => RotationMode::Rename,
+            Rotation::Timestamp => RotationMode::Timestamp,
+        };
+        let naming = match x.get_backup_format()?.to_str()? {
+            "" => RotationNaming::Numbered,
+            format => RotationNaming::Format(BackupFormat::new(format)?),
+        };
+        let durability = match x.get_durability()? {
+            Durability::None => DurabilityPolicy::None,
+            Durability::EveryWrite => DurabilityPolicy::EveryWrite,
+            Durability::Dsync => DurabilityPolicy::Dsync,
+            Durability::Interval if x.get_sync_interval() == 0 => {
+                bail!(
+                    "log driver {} requires a sync interval greater than zero",
+                    i
+                )
+            }
+            Durability::Interval => {
+                DurabilityPolicy::Interval(Duration::from_millis(x.get_sync_interval()))
+            }
+        };
+        let max_files = if x.get_max_files() > 0 {
+            Some(x.get_max_files() as usize)
+        } else {
+            None
+        };
+        let compression = match x.get_compression()? {
+            // The legacy `compress` flag selects gzip.
+            LogCompression::None if x.get_compress() => Compression::Gzip,
+            LogCompression::None => Compression::None,
+            LogCompression::Gzip => Compression::Gzip,
+            LogCompression::Zstd => Compression::Zstd,
+        };
+        let buffer_size = match x.get_buffer_size() {
+            0 => DEFAULT_BUFFER_SIZE,
+            buffer_size => buffer_size.try_into()?,
+        };
+        let rotate_interval = if x.get_rotate_interval() > 0 {
+            Some(Duration::from_millis(x.get_rotate_interval()))
+        } else {
+            None
+        };
+        let reconcile_interval = Some(x.get_reconcile_interval())
+            .filter(|x| *x > 0)
+            .map(Duration::from_millis);
+        let pipe = match x.get_stream()? {
+            Stream::All => None,
+            Stream::Stdout => Some(Pipe::StdOut),
+            Stream::Stderr => Some(Pipe::StdErr),
+        };
+        if pipe.is_some() && !matches!(x.get_type()?, Type::ContainerRuntimeInterface | Type::Json)
+        {
+            bail!("log driver {} does not support selecting a stream", i)
+        }
+        let clock: Arc<dyn Clock> = match x.get_timestamp_source()? {
+            TimestampSource::WallClock => Arc::new(SystemClock),
+            TimestampSource::Monotonic => Arc::new(MonotonicClock::new()),
+        };
+        let max_size = Self::resolve_max_size(
+            x.get_max_size_mode()?,
+            Self::driver_max_size(
+                x.get_max_size(),
+                x.get_max_size_text()?.to_str()?,
+                options.node_max_size,
+            )?,
+            options.default_max_size,
+        )
+        .context(format!("log driver {i}"))?;
+        let max_line_size =
+            Self::max_size(x.get_max_line_size(), x.get_max_line_size_text()?.to_str()?)?;
+        let transforms = x
+            .get_redact_patterns()?
+            .iter()
+            .map(|pattern| {
+                let redactor = Redactor::new(pattern?.to_str()?, Redactor::DEFAULT_REPLACEMENT)?;
+                Ok(Arc::new(redactor) as Arc<dyn LogTransform>)
             })
             .collect::<Result<Vec<_>>>()?;
-        Ok(Arc::new(RwLock::new(Self { drivers })))
+        let empty_lines = match x.get_empty_lines()? {
+            LogEmptyLines::Keep => EmptyLines::Keep,
+            LogEmptyLines::Drop => EmptyLines::Drop,
+        };
+        let identity = [
+            ("name", x.get_container_name()?.to_str()?),
+            ("image", x.get_image()?.to_str()?),
+        ]
+        .into_iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(key, value)| (key.into(), value.into()))
+        .collect::<BTreeMap<String, String>>();
+        let fallback_path = Some(x.get_fallback_path()?.to_str()?)
+            .filter(|x| !x.is_empty())
+            .map(PathBuf::from);
+        let flush_threshold = FlushThreshold {
+            lines: Some(x.get_flush_every_lines().try_into()?).filter(|x| *x > 0),
+            bytes: Some(x.get_flush_every_bytes().try_into()?).filter(|x| *x > 0),
+        };
+        let file_access = FileAccess {
+            mode: Some(x.get_file_mode()).filter(|x| *x > 0),
+            uid: Self::file_owner(x.get_file_uid())?,
+            gid: Self::file_owner(x.get_file_gid())?,
+            no_follow: x.get_no_follow(),
+        };
+        if !transforms.is_empty()
+            && !matches!(x.get_type()?, Type::ContainerRuntimeInterface | Type::Json)
+        {
+            bail!("log driver {} does not support redacting messages", i)
+        }
+        if !x.get_upload_endpoint()?.is_empty()
+            && !matches!(x.get_type()?, Type::ContainerRuntimeInterface | Type::Json)
+        {
+            bail!("log driver {} does not support uploading backups", i)
+        }
+        #[cfg(feature = "s3")]
+        let upload = Self::upload(&x).context(format!("log driver {i}"))?;
+        #[cfg(not(feature = "s3"))]
+        if !x.get_upload_endpoint()?.is_empty() {
+            bail!("S3 upload support is not enabled")
+        }
+        let driver: Box<dyn Logger> = match x.get_type()? {
+            Type::ContainerRuntimeInterface => {
+                let mut cri_logger = CriLogger::new(x.get_path()?.to_str()?, max_size)?;
+                #[cfg(feature = "s3")]
+                cri_logger.set_upload(upload);
+                cri_logger
+                    .set_max_line_size(max_line_size)
+                    .set_durability(durability)
+                    .set_pipe(pipe)
+                    .set_rotation(rotation)
+                    .set_max_files(max_files)
+                    .set_compression(compression)
+                    .set_checksum(x.get_checksum())
+                    .set_naming(naming.clone())
+                    .set_buffer_size(buffer_size)
+                    .set_rotate_interval(rotate_interval)
+                    .set_reconcile_interval(reconcile_interval)
+                    .set_watch_path(x.get_watch_path())
+                    .set_clock(clock)
+                    .set_budget(options.budget.clone())
+                    .set_file_access(file_access)
+                    .set_disk_full_retries(x.get_disk_full_retries().try_into()?)
+                    .set_flush_threshold(flush_threshold)
+                    .set_empty_lines(empty_lines)
+                    .set_fallback_path(fallback_path.clone())
+                    .set_identity(identity)
+                    .set_link_path(
+                        Some(x.get_link_path()?.to_str()?)
+                            .filter(|x| !x.is_empty())
+                            .map(PathBuf::from),
+                    )
+                    .set_tags(CriTags::new(
+                        x.get_partial_tag()?.to_str()?,
+                        x.get_full_tag()?.to_str()?,
+                    )?)
+                    .set_transforms(transforms);
+                Box::new(cri_logger)
+            }
+            Type::Json => {
+                let framing = match x.get_framing()? {
+                    Framing::Ndjson => JsonFraming::Ndjson,
+                    Framing::JsonSeq => JsonFraming::JsonSeq,
+                    Framing::Array => JsonFraming::Array,
+                };
+                let pipe_label = match x.get_pipe_label()? {
+                    PipeLabel::Name => JsonPipeLabel::Name,
+                    PipeLabel::Short => JsonPipeLabel::Short,
+                    PipeLabel::Fd => JsonPipeLabel::Fd,
+                };
+                let mut json_logger = JsonLogger::new(x.get_path()?.to_str()?, max_size)?;
+                if x.get_trace_context() && cfg!(not(feature = "trace-context")) {
+                    bail!("trace context support is not enabled")
+                }
+                if x.get_add_hostname() {
+                    json_logger.set_hostname(Some(match x.get_hostname()?.to_str()? {
+                        "" => hostname()?,
+                        hostname => hostname.into(),
+                    }));
+                }
+                if x.get_parse_level() {
+                    json_logger.set_level_pattern(
+                        JsonLogger::level_regex(x.get_level_pattern()?.to_str()?)?.into(),
+                    );
+                }
+                if x.get_multiline() {
+                    json_logger.set_multiline_pattern(
+                        JsonLogger::multiline_regex(x.get_multiline_pattern()?.to_str()?)?.into(),
+                    );
+                }
+                if x.get_multiline_timeout() > 0 {
+                    json_logger
+                        .set_multiline_timeout(Duration::from_millis(x.get_multiline_timeout()));
+                }
+                #[cfg(feature = "s3")]
+                json_logger.set_upload(upload);
+                json_logger
+                    .set_max_line_size(max_line_size)
+                    .set_framing(framing)
+                    .set_append(x.get_append())
+                    .set_durability(durability)
+                    .set_pipe(pipe)
+                    .set_pipe_label(pipe_label)
+                    .set_metadata(
+                        options
+                            .metadata
+                            .clone()
+                            .into_iter()
+                            .chain(identity)
+                            .collect(),
+                    )
+                    .set_rotation(rotation)
+                    .set_max_files(max_files)
+                    .set_compression(compression)
+                    .set_checksum(x.get_checksum())
+                    .set_naming(naming.clone())
+                    .set_buffer_size(buffer_size)
+                    .set_rotate_interval(rotate_interval)
+                    .set_reconcile_interval(reconcile_interval)
+                    .set_watch_path(x.get_watch_path())
+                    .set_seq(x.get_sequence().then_some(0))
+                    .set_repeat_window(
+                        (x.get_repeat_window() > 0)
+                            .then(|| Duration::from_millis(x.get_repeat_window())),
+                    )
+                    .set_clock(clock)
+                    .set_budget(options.budget.clone())
+                    .set_file_access(file_access)
+                    .set_disk_full_retries(x.get_disk_full_retries().try_into()?)
+                    .set_flush_threshold(flush_threshold)
+                    .set_empty_lines(empty_lines)
+                    .set_trim(!x.get_preserve_raw())
+                    .set_fallback_path(fallback_path.clone())
+                    .set_trace_context(x.get_trace_context())
+                    .set_time_zone(match x.get_timezone()?.to_str()? {
+                        "" => None,
+                        name => Some(
+                            tzdb::tz_by_name(name)
+                                .context(format!("unknown timezone '{}'", name))?,
+                        ),
+                    })
+                    .set_keys(JsonKeys::new(
+                        x.get_timestamp_key()?.to_str()?,
+                        x.get_pipe_key()?.to_str()?,
+                        x.get_message_key()?.to_str()?,
+                    )?)
+                    .set_transforms(transforms)
+                    .set_docker_compat(x.get_docker_compat());
+                Box::new(json_logger)
+            }
+            Type::Syslog => Box::new(SyslogLogger::new(
+                x.get_path()?.to_str()?,
+                &options.container_id,
+            )?),
+            Type::Command => {
+                let backpressure = match x.get_backpressure()? {
+                    Backpressure::Block => CommandBackpressure::Block,
+                    Backpressure::Drop => CommandBackpressure::Drop,
+                };
+                let args = x
+                    .get_args()?
+                    .iter()
+                    .map(|arg| Ok(arg?.to_string()?))
+                    .collect::<Result<Vec<_>>>()?;
+                Box::new(CommandLogger::new(
+                    x.get_path()?.to_str()?,
+                    args,
+                    backpressure,
+                )?)
+            }
+            Type::Null => Box::new(NullLogger::new()),
+            Type::Memory => Box::new(MemoryLogger::new(
+                max_size.unwrap_or(MemoryLogger::DEFAULT_CAPACITY),
+            )),
+            #[cfg(feature = "journald")]
+            Type::Journald => Box::new(JournaldLogger::new(&options.container_id)?),
+            #[cfg(not(feature = "journald"))]
+            Type::Journald => anyhow::bail!("journald log driver support is not enabled"),
+            #[cfg(feature = "otlp")]
+            Type::Otlp => {
+                let mut otlp_logger = OtlpLogger::new(
+                    x.get_path()?.to_str()?,
+                    &options.container_id,
+                    &options.metadata,
+                )?;
+                otlp_logger
+                    .set_batch_size(match x.get_batch_size() {
+                        0 => OtlpLogger::DEFAULT_BATCH_SIZE,
+                        batch_size => batch_size.try_into()?,
+                    })
+                    .set_max_buffered(match x.get_max_buffered_lines() {
+                        0 => OtlpLogger::DEFAULT_MAX_BUFFERED,
+                        max_buffered => max_buffered.try_into()?,
+                    });
+                Box::new(otlp_logger)
+            }
+            #[cfg(not(feature = "otlp"))]
+            Type::Otlp => anyhow::bail!("otlp log driver support is not enabled"),
+            #[cfg(feature = "gelf")]
+            Type::Gelf => Box::new(GelfLogger::new(
+                x.get_path()?.to_str()?,
+                &options.container_id,
+            )?),
+            #[cfg(not(feature = "gelf"))]
+            Type::Gelf => anyhow::bail!("gelf log driver support is not enabled"),
+        };
+        let driver: Box<dyn Logger> = match x.get_write_timeout() {
+            0 => driver,
+            timeout => Box::new(TimeoutLogger::new(driver, Duration::from_millis(timeout))),
+        };
+        let driver: Box<dyn Logger> = if x.get_queue_depth() == 0 {
+            driver
+        } else {
+            let policy = match x.get_queue_policy()? {
+                QueuePolicy::Block => LogQueuePolicy::Block,
+                QueuePolicy::DropOldest => LogQueuePolicy::DropOldest,
+                QueuePolicy::DropNewest => LogQueuePolicy::DropNewest,
+            };
+            Box::new(QueuedLogger::new(
+                driver,
+                x.get_queue_depth() as usize,
+                policy,
+            )?)
+        };
+        if x.get_required() {
+            return Ok(driver);
+        }
+        Ok(Box::new(OptionalLogger::new(driver)))
+    }
+
+    /// Retrieve the maximum size from the human readable text, falling back to the numeric
+    /// value where 0 means unlimited.
+    fn max_size(value: u64, text: &str) -> Result<Option<usize>> {
+        let max_size = if text.is_empty() {
+            value.try_into()?
+        } else {
+            size::parse_size(text)?
+        };
+        Ok(Some(max_size).filter(|x| *x > 0))
+    }
+
+    /// Retrieve the maximum log size of a driver, where an unset one falls back to the maximum log
+    /// size of the node.
+    fn driver_max_size(value: u64, text: &str, node: Option<usize>) -> Result<Option<usize>> {
+        Ok(Self::max_size(value, text)?.or(node))
+    }
+
+    /// Apply the mode to the configured maximum log size of a driver, where an unconfigured one
+    /// inherits the default.
+    fn resolve_max_size(
+        mode: MaxSizeMode,
+        max_size: Option<usize>,
+        default: Option<usize>,
+    ) -> Result<Option<usize>> {
+        match mode {
+            MaxSizeMode::Inherit => Ok(max_size.or(default)),
+            MaxSizeMode::Unlimited => Ok(None),
+            MaxSizeMode::Limited => {
+                Ok(Some(max_size.context("limited max size must not be zero")?))
+            }
+        }
+    }
+
+    #[cfg(feature = "s3")]
+    /// Create the uploader of the rotated backups of a log driver, if an endpoint is set.
+    fn upload(
+        x: &conmon_common::conmon_capnp::conmon::log_driver::Reader,
+    ) -> Result<Option<Arc<S3Upload>>> {
+        let endpoint = x.get_upload_endpoint()?.to_str()?;
+        if endpoint.is_empty() {
+            return Ok(None);
+        }
+        let mut upload = S3Upload::new(endpoint, x.get_upload_bucket()?.to_str()?)?;
+        upload
+            .set_prefix(x.get_upload_prefix()?.to_str()?.into())
+            .set_delete(x.get_upload_delete())
+            .set_credentials(
+                x.get_upload_access_key_id()?.to_str()?,
+                x.get_upload_secret_access_key()?.to_str()?,
+            );
+        match x.get_upload_region()?.to_str()? {
+            "" => {}
+            region => {
+                upload.set_region(region.into());
+            }
+        }
+        Ok(Some(Arc::new(upload)))
+    }
+
+    /// Convert the owner ID of the log files, where a negative one means unchanged.
+    fn file_owner(id: i64) -> Result<Option<u32>> {
+        Ok(if id < 0 {
+            None
+        } else {
+            Some(id.try_into().context("convert log file owner")?)
+        })
+    }
+
+    /// Resolve the canonical path of a log file, which may not exist yet.
+    fn resolve_path(path: &Path) -> PathBuf {
+        fs::canonicalize(path)
+            .or_else(|_| {
+                let parent = path.parent().filter(|x| !x.as_os_str().is_empty());
+                Ok::<_, std::io::Error>(
+                    fs::canonicalize(parent.unwrap_or(Path::new(".")))?
+                        .join(path.file_name().unwrap_or_default()),
+                )
+            })
+            .unwrap_or_else(|_| path.into())
+    }
+
+    /// Verify the configuration of all loggers without creating any files, which allows
+    /// rejecting bad paths before spawning the container.
+    pub async fn validate(&self) -> Result<()> {
+        for driver in &self.drivers {
+            driver
+                .validate()
+                .await
+                .with_context(|| format!("validate {} log driver", driver.name()))?;
+        }
+        Ok(())
     }
 
     /// Asynchronously initialize all loggers.
     pub async fn init(&mut self) -> Result<()> {
+        join_all(self.drivers.iter_mut().map(|x| x.init()))
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+        Ok(())
+    }
+
+    /// Indicates if the data written to stdin gets logged.
+    pub fn logs_stdin(&self) -> bool {
+        self.log_stdin
+    }
+
+    /// Indicates if all loggers have been initialized.
+    pub fn is_initialized(&self) -> bool {
+        self.drivers.iter().all(|x| x.is_initialized())
+    }
+
+    /// Set the sink receiving the metrics of all loggers, which replaces the default no-op one.
+    pub fn set_metrics(&mut self, metrics: Arc<dyn MetricsSink>) -> &mut Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Write the loggers one after another instead of concurrently. The loggers writing a readable
+    /// file come first in their configured order, which keeps `kubectl logs` ahead of the others.
+    /// Failures of the others are only reported as warning, which makes them best effort.
+    pub fn set_ordered(&mut self, ordered: bool) -> &mut Self {
+        self.ordered = ordered;
+        self
+    }
+
+    /// Limit the amount of loggers written concurrently, which keeps many loggers from
+    /// overwhelming a slow shared resource. Zero means unlimited.
+    pub fn set_max_concurrent_writers(&mut self, max: usize) -> &mut Self {
+        self.writers = (max > 0).then(|| Semaphore::new(max));
+        self
+    }
+
+    /// Run the provided write of a logger once the concurrency limit allows it, if any.
+    async fn limit<F: Future>(writers: Option<&Semaphore>, write: F) -> F::Output {
+        // The semaphore is never closed, which means that acquiring it cannot fail.
+        let _permit = match writers {
+            Some(writers) => writers.acquire().await.ok(),
+            None => None,
+        };
+        write.await
+    }
+
+    /// Pause writing into the loggers, for example while a remote sink is known to be down. The
+    /// output written in the meantime gets handled by the `policy` without touching the loggers.
+    /// Pausing a paused log only replaces its policy. Events, reopens and partial line flushes are
+    /// not paused.
+    pub fn pause(&mut self, policy: PausePolicy) {
+        debug!("Pausing container log with policy {:?}", policy);
+        match &mut self.paused {
+            Some(paused) => paused.policy = policy,
+            None => {
+                self.paused = Some(PausedLog {
+                    policy,
+                    writes: vec![],
+                    size: 0,
+                    dropped_bytes: 0,
+                })
+            }
+        }
+    }
+
+    /// Indicates if writing into the loggers is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused.is_some()
+    }
+
+    /// Resume writing into the loggers, where the buffered output gets written first. Dropped
+    /// output is reported by a marker in every logger supporting it.
+    pub async fn resume(&mut self) -> Result<()> {
+        let Some(paused) = self.paused.take() else {
+            return Ok(());
+        };
+        debug!(
+            "Resuming container log with {} buffered and {} dropped bytes",
+            paused.size, paused.dropped_bytes
+        );
+        for write in paused.writes {
+            match write {
+                PausedWrite::Data(pipe, data) => {
+                    self.write(pipe, &data[..]).await?;
+                }
+                PausedWrite::Line(pipe, line) => self.write_line(pipe, &line).await?,
+            }
+        }
+        if paused.dropped_bytes > 0 {
+            self.metrics.on_dropped(paused.dropped_bytes);
+            join_all(
+                self.drivers
+                    .iter_mut()
+                    .map(|x| x.write_marker(paused.dropped_bytes)),
+            )
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+        }
+        Ok(())
+    }
+
+    /// Reopen the container logs.
+    ///
+    /// A failing logger does not prevent the others from being reopened. The returned error lists
+    /// every failed logger after all of them have been attempted.
+    pub async fn reopen(&mut self) -> Result<()> {
+        debug!("Reopen log drivers with stats: {:?}", self.stats());
+        let results = join_all(self.drivers.iter_mut().map(|x| x.reopen())).await;
+        let mut errors = vec![];
+        for (driver, res) in self.drivers.iter().zip(results) {
+            match res {
+                Ok(()) => {
+                    if let Some((path, _)) = driver.source() {
+                        self.metrics.on_rotation(&path);
+                    }
+                }
+                Err(e) => {
+                    self.metrics.on_error(&e);
+                    errors.push(format!("{}: {:#}", driver.name(), e));
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(format_err!(
+                "reopen {} of {} log driver(s): {}",
+                errors.len(),
+                self.drivers.len(),
+                errors.join("; ")
+            ));
+        }
+        Ok(())
+    }
+
+    /// Report the rotations and dropped bytes of the loggers since the `previous` statistics,
+    /// which are in the same order as the loggers.
+    fn report_stats(&self, previous: &[LogStats]) {
+        let mut dropped_bytes = 0;
+        for (driver, previous) in self.drivers.iter().zip(previous) {
+            let stats = driver.stats();
+            if stats.rotations > previous.rotations {
+                if let Some((path, _)) = driver.source() {
+                    self.metrics.on_rotation(&path);
+                }
+            }
+            dropped_bytes += stats.dropped_bytes.saturating_sub(previous.dropped_bytes);
+        }
+        if dropped_bytes > 0 {
+            self.metrics.on_dropped(dropped_bytes);
+        }
+    }
+
+    /// Write the lifecycle event into all loggers, regardless of the pipes they accept.
+    pub async fn emit_event(&mut self, event: LogEvent) -> Result<()> {
+        let writers = self.writers.as_ref();
         join_all(
             self.drivers
                 .iter_mut()
-                .map(|x| match x {
-                    LogDriver::ContainerRuntimeInterface(ref mut cri_logger) => {
-                        cri_logger.init().boxed()
-                    }
-                    LogDriver::Json(ref mut json_logger) => json_logger.init().boxed(),
-                })
-                .collect::<Vec<_>>(),
+                .map(|x| Self::limit(writers, x.write_event(event.clone()))),
         )
         .await
         .into_iter()
@@ -73,18 +1133,140 @@ impl ContainerLog {
         Ok(())
     }
 
-    /// Reopen the container logs.
-    pub async fn reopen(&mut self) -> Result<()> {
+    /// Indicates if the exit of the container gets written as event into the logs.
+    pub fn exit_event(&self) -> bool {
+        self.exit_event
+    }
+
+    /// Write a `container_exit` event with the exit code before closing the loggers, which the CRI
+    /// logger writes as stdout record.
+    pub fn set_exit_event(&mut self, exit_event: bool) -> &mut Self {
+        self.exit_event = exit_event;
+        self
+    }
+
+    /// Ensure that the buffered content of all loggers is written.
+    pub async fn flush(&mut self) -> Result<()> {
+        join_all(self.drivers.iter_mut().map(|x| x.flush()))
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+        Ok(())
+    }
+
+    /// Flush and close all loggers to release their file descriptors.
+    pub async fn close(&mut self) -> Result<()> {
+        join_all(self.drivers.iter_mut().map(|x| x.close()))
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+        Ok(())
+    }
+
+    /// Initialize the provided logger and add it, which makes it receive all subsequent writes.
+    /// Callers modify the loggers of a running container through the write lock of the
+    /// `SharedContainerLog`, which means that no write happens in between.
+    pub async fn add_driver(&mut self, mut driver: Box<dyn Logger>) -> Result<()> {
+        if let Some((path, _)) = driver.source() {
+            let path = Self::resolve_path(&path);
+            if self
+                .drivers
+                .iter()
+                .filter_map(|x| x.source())
+                .any(|(other, _)| Self::resolve_path(&other) == path)
+            {
+                bail!("a log driver already writes to path '{}'", path.display())
+            }
+        }
+        driver
+            .init()
+            .await
+            .with_context(|| format!("initialize {} log driver", driver.name()))?;
+        self.drivers.push(driver);
+        Ok(())
+    }
+
+    /// Create a logger from its configuration and add it like `add_driver`, where it inherits the
+    /// options of the container provided on creation.
+    pub async fn add_driver_from(&mut self, reader: LogDriverReader<'_>) -> Result<()> {
+        let driver = Self::new_driver(&self.options, self.drivers.len(), reader)?;
+        self.add_driver(driver).await
+    }
+
+    /// Remove all loggers matching the predicate, where their retained partial lines get written
+    /// before closing them. Returns the amount of removed loggers.
+    pub async fn remove_driver<F>(&mut self, predicate: F) -> Result<usize>
+    where
+        F: Fn(&dyn Logger) -> bool,
+    {
+        let (mut removed, drivers): (Vec<_>, Vec<_>) = mem::take(&mut self.drivers)
+            .into_iter()
+            .partition(|x| predicate(x.as_ref()));
+        self.drivers = drivers;
+
+        join_all(removed.iter_mut().map(|x| async move {
+            for pipe in [Pipe::StdIn, Pipe::StdOut, Pipe::StdErr] {
+                x.flush_partial(pipe).await?;
+            }
+            x.close().await
+        }))
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?;
+        Ok(removed.len())
+    }
+
+    /// Remove the loggers writing the file at `path` like `remove_driver`.
+    pub async fn remove_driver_by_path(&mut self, path: &Path) -> Result<usize> {
+        let path = Self::resolve_path(path);
+        self.remove_driver(|x| {
+            x.source()
+                .is_some_and(|(other, _)| Self::resolve_path(&other) == path)
+        })
+        .await
+    }
+
+    /// Retrieve the statistics of every logger.
+    pub fn stats(&self) -> Vec<LogStats> {
+        self.drivers.iter().map(|x| x.stats()).collect()
+    }
+
+    /// Retrieve the time of the latest write of any logger, which is `None` if none of them wrote
+    /// a line yet. A container without writes for a while is considered idle.
+    pub fn last_write(&self) -> Option<SystemTime> {
+        self.stats().into_iter().filter_map(|x| x.last_write).max()
+    }
+
+    /// Retrieve the shortest timeout of the entries retained by the loggers, which is the
+    /// interval for writing the expired ones.
+    pub fn expiry_interval(&self) -> Option<Duration> {
+        self.drivers
+            .iter()
+            .filter_map(|x| x.expiry_interval())
+            .min()
+    }
+
+    /// Write the retained entries of the loggers whose timeout elapsed.
+    pub async fn flush_expired(&mut self) -> Result<()> {
+        let writers = self.writers.as_ref();
         join_all(
             self.drivers
                 .iter_mut()
-                .map(|x| match x {
-                    LogDriver::ContainerRuntimeInterface(ref mut cri_logger) => {
-                        cri_logger.reopen().boxed()
-                    }
-                    LogDriver::Json(ref mut json_logger) => json_logger.reopen().boxed(),
-                })
-                .collect::<Vec<_>>(),
+                .map(|x| Self::limit(writers, x.flush_expired())),
+        )
+        .await
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?;
+        Ok(())
+    }
+
+    /// Write all retained partial lines of the provided pipe into the loggers.
+    pub async fn flush_partial(&mut self, pipe: Pipe) -> Result<()> {
+        let writers = self.writers.as_ref();
+        join_all(
+            self.drivers
+                .iter_mut()
+                .map(|x| Self::limit(writers, x.flush_partial(pipe))),
         )
         .await
         .into_iter()
@@ -92,36 +1274,1047 @@ impl ContainerLog {
         Ok(())
     }
 
-    /// Write the contents of the provided reader into all loggers.
-    pub async fn write<T>(&mut self, pipe: Pipe, bytes: T) -> Result<()>
+    /// Write the contents of the provided reader into all loggers. Returns the amount of input
+    /// bytes consumed, which is the most any logger consumed and independent of their count.
+    ///
+    /// A failing logger does not prevent the others from writing. The returned error lists every
+    /// failed logger after all of them have been attempted, except for the best effort ones of the
+    /// ordered mode.
+    pub async fn write<T>(&mut self, pipe: Pipe, bytes: T) -> Result<usize>
     where
-        T: AsyncBufRead + Unpin + Clone,
+        T: AsyncBufRead + Unpin + Clone + Send,
     {
-        let futures = self
+        if pipe == Pipe::StdIn && !self.log_stdin {
+            return Ok(0);
+        }
+        if let Some(paused) = &mut self.paused {
+            let mut data = vec![];
+            bytes
+                .clone()
+                .read_to_end(&mut data)
+                .await
+                .context("read paused data")?;
+            let len = data.len();
+            paused.push(PausedWrite::Data(pipe, data));
+            return Ok(len);
+        }
+
+        let previous = self.stats();
+        let results = if self.ordered {
+            let mut results = vec![];
+            for (x, required) in self.ordered_drivers(pipe) {
+                let mut bytes = bytes.clone();
+                results.push((x.name(), required, x.write(pipe, &mut bytes).await));
+            }
+            results
+        } else {
+            let writers = self.writers.as_ref();
+            let futures = self
+                .drivers
+                .iter_mut()
+                .filter(|x| x.accepts(pipe))
+                .map(|x| {
+                    let mut bytes = bytes.clone();
+                    async move {
+                        let res = Self::limit(writers, x.write(pipe, &mut bytes)).await;
+                        (x.name(), true, res)
+                    }
+                });
+            join_all(futures).await
+        };
+
+        let mut consumed = 0;
+        let mut errors = vec![];
+        for (name, required, res) in results {
+            match res {
+                Ok(n) => consumed = consumed.max(n),
+                Err(e) => {
+                    self.metrics.on_error(&e);
+                    if e.chain()
+                        .filter_map(|x| x.downcast_ref::<io::Error>())
+                        .any(|x| x.raw_os_error() == Some(libc::ENOSPC))
+                    {
+                        warn!("Log driver {} ran out of disk space", name);
+                    }
+                    if required {
+                        errors.push(format!("{name}: {e:#}"))
+                    } else {
+                        warn!("Ignoring failed write to {} log driver: {:#}", name, e)
+                    }
+                }
+            }
+        }
+        if consumed > 0 {
+            self.metrics.on_bytes_written(pipe, consumed);
+        }
+        self.report_stats(&previous);
+
+        if !errors.is_empty() {
+            return Err(format_err!(
+                "write to {} log driver(s): {}",
+                errors.len(),
+                errors.join("; ")
+            ));
+        }
+        Ok(consumed)
+    }
+
+    /// Retrieve the loggers accepting the provided pipe in the order of the ordered mode, where
+    /// the ones writing a readable file are first and required to succeed.
+    fn ordered_drivers(&mut self, pipe: Pipe) -> Vec<(&mut Box<dyn Logger>, bool)> {
+        let (mut local, remote): (Vec<_>, Vec<_>) = self
             .drivers
             .iter_mut()
+            .filter(|x| x.accepts(pipe))
             .map(|x| {
-                async fn box_future<'a, T: AsyncBufRead + Unpin + Clone>(
-                    logger: &mut LogDriver,
-                    pipe: Pipe,
-                    bytes: T,
-                ) -> Result<()> {
-                    match logger {
-                        LogDriver::ContainerRuntimeInterface(cri_logger) => {
-                            cri_logger.write(pipe, bytes).await
-                        }
-                        LogDriver::Json(json_logger) => json_logger.write(pipe, bytes).await,
+                let required = x.source().is_some();
+                (x, required)
+            })
+            .partition(|(_, required)| *required);
+        local.extend(remote);
+        local
+    }
+
+    /// Retrieve the path and format of the first log driver writing a readable file.
+    pub fn source(&self) -> Option<(PathBuf, LogFormat)> {
+        self.drivers.iter().find_map(|x| x.source())
+    }
+
+    /// Retrieve the lines of the first log driver keeping them in memory.
+    pub async fn lines(&self) -> Option<Vec<LogLine>> {
+        for x in &self.drivers {
+            if let Some(lines) = x.lines().await {
+                return Some(lines);
+            }
+        }
+        None
+    }
+
+    /// Write a single complete line into all loggers, which skips splitting the data into lines.
+    /// Failing loggers are reported the same way as for `write`.
+    pub async fn write_line(&mut self, pipe: Pipe, line: &[u8]) -> Result<()> {
+        if pipe == Pipe::StdIn && !self.log_stdin {
+            return Ok(());
+        }
+        if let Some(paused) = &mut self.paused {
+            paused.push(PausedWrite::Line(pipe, line.into()));
+            return Ok(());
+        }
+
+        let previous = self.stats();
+        let results = if self.ordered {
+            let mut results = vec![];
+            for (x, required) in self.ordered_drivers(pipe) {
+                results.push((x.name(), required, x.write_line(pipe, line).await));
+            }
+            results
+        } else {
+            let writers = self.writers.as_ref();
+            let futures = self
+                .drivers
+                .iter_mut()
+                .filter(|x| x.accepts(pipe))
+                .map(|x| async move {
+                    let res = Self::limit(writers, x.write_line(pipe, line)).await;
+                    (x.name(), true, res)
+                });
+            join_all(futures).await
+        };
+
+        let mut written = false;
+        let mut errors = vec![];
+        for (name, required, res) in results {
+            match res {
+                Ok(()) => written = true,
+                Err(e) => {
+                    self.metrics.on_error(&e);
+                    if required {
+                        errors.push(format!("{name}: {e:#}"))
+                    } else {
+                        warn!("Ignoring failed write line to {} log driver: {:#}", name, e)
                     }
                 }
+            }
+        }
+        if written {
+            self.metrics.on_bytes_written(pipe, line.len());
+        }
+        self.report_stats(&previous);
+        if !errors.is_empty() {
+            return Err(format_err!(
+                "write line to {} log driver(s): {}",
+                errors.len(),
+                errors.join("; ")
+            ));
+        }
+        Ok(())
+    }
+}
 
-                box_future(x, pipe, bytes.clone())
-            })
-            .collect::<Vec<_>>();
+#[cfg(test)]
+impl ContainerLog {
+    /// Create the container log `container_id` from drivers of the provided types and paths, where
+    /// `configure` sets the further options of every driver by its index.
+    pub fn from_drivers<F>(
+        container_id: &str,
+        log_stdin: bool,
+        drivers: &[(Type, PathBuf)],
+        mut configure: F,
+    ) -> Result<SharedContainerLog>
+    where
+        F: FnMut(usize, conmon_common::conmon_capnp::conmon::log_driver::Builder),
+    {
+        use conmon_common::conmon_capnp::conmon::create_container_request;
+
+        let mut message = capnp::message::Builder::new_default();
+        let mut list = message
+            .init_root::<create_container_request::Builder>()
+            .init_log_drivers(drivers.len().try_into()?);
+        for (i, (driver_type, path)) in drivers.iter().enumerate() {
+            let mut driver = list.reborrow().get(i.try_into()?);
+            driver.set_type(*driver_type);
+            driver.set_path(path.to_str().context("invalid path")?);
+            configure(i, driver);
+        }
+        let request = message.get_root_as_reader::<create_container_request::Reader>()?;
+        ContainerLog::from(
+            request.get_log_drivers()?,
+            ContainerLogOptions {
+                container_id: container_id.into(),
+                log_stdin,
+                ..Default::default()
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::ManualClock;
+    use anyhow::Context;
+    use conmon_common::conmon_capnp::conmon::log_driver;
+    use std::{
+        fs,
+        os::unix::fs::{MetadataExt, PermissionsExt},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Mutex,
+        },
+    };
+    use tempfile::NamedTempFile;
+
+    fn from_paths(drivers: &[(Type, PathBuf)]) -> Result<SharedContainerLog> {
+        ContainerLog::from_drivers("id", false, drivers, |_, _| {})
+    }
+
+    #[tokio::test]
+    async fn write_redacted() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let json_path = dir.path().join("json.log");
+        let cri_path = dir.path().join("cri.log");
+
+        let sut = ContainerLog::from_drivers(
+            "id",
+            false,
+            &[
+                (Type::Json, json_path.clone()),
+                (Type::ContainerRuntimeInterface, cri_path.clone()),
+            ],
+            |_, driver| driver.init_redact_patterns(1).set(0, r"api_key=\w+"),
+        )?;
+        let mut sut = sut.write().await;
+        sut.init().await?;
+        sut.write(Pipe::StdOut, "login api_key=f4k3s3cr3t ok\n".as_bytes())
+            .await?;
+
+        for path in [json_path, cri_path] {
+            let content = fs::read_to_string(path)?;
+            assert!(content.contains("login *** ok"));
+            assert!(!content.contains("f4k3s3cr3t"));
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_preserve_raw() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let sut = ContainerLog::from_drivers(
+            "id",
+            false,
+            &[
+                (Type::Json, dir.path().join("trimmed.json")),
+                (Type::Json, dir.path().join("raw.json")),
+            ],
+            |i, mut driver| driver.set_preserve_raw(i == 1),
+        )?;
+        let mut sut = sut.write().await;
+        sut.init().await?;
+        sut.write(Pipe::StdOut, "  50% \n".as_bytes()).await?;
+
+        let message = |name| -> Result<serde_json::Value> {
+            let entry: serde_json::Value =
+                serde_json::from_str(fs::read_to_string(dir.path().join(name))?.trim())?;
+            Ok(entry["message"].clone())
+        };
+        // Messages get trimmed by default.
+        assert_eq!(message("trimmed.json")?, "50%");
+        assert_eq!(message("raw.json")?, "  50% ");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_identity() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let drivers = [
+            (Type::Json, dir.path().join("named.json")),
+            (
+                Type::ContainerRuntimeInterface,
+                dir.path().join("named.log"),
+            ),
+            (Type::Json, dir.path().join("unnamed.json")),
+            (
+                Type::ContainerRuntimeInterface,
+                dir.path().join("unnamed.log"),
+            ),
+        ];
+        // Only the first two drivers are named.
+        let sut = ContainerLog::from_drivers("id", false, &drivers, |i, mut driver| {
+            if i < 2 {
+                driver.set_container_name("web");
+                driver.set_image("nginx:1.25");
+            }
+        })?;
+        let mut sut = sut.write().await;
+        sut.init().await?;
+        sut.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+
+        let container = |name| -> Result<serde_json::Value> {
+            let entry: serde_json::Value =
+                serde_json::from_str(fs::read_to_string(dir.path().join(name))?.trim())?;
+            Ok(entry["container"].clone())
+        };
+        assert_eq!(
+            container("named.json")?,
+            serde_json::json!({"id": "id", "name": "web", "image": "nginx:1.25"})
+        );
+        assert_eq!(container("unnamed.json")?, serde_json::json!({"id": "id"}));
+
+        let sidecar = fs::read_to_string(CriLogger::identity_path(dir.path().join("named.log")))?;
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&sidecar)?,
+            serde_json::json!({"name": "web", "image": "nginx:1.25"})
+        );
+        assert!(!CriLogger::identity_path(dir.path().join("unnamed.log")).exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reopen_moved_file() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("cri.log");
+        let moved = dir.path().join("cri.log.1");
+
+        let sut = from_paths(&[(Type::ContainerRuntimeInterface, path.clone())])?;
+        let mut sut = sut.write().await;
+        sut.init().await?;
+        sut.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+
+        // An external logrotate moves the file before requesting to reopen it.
+        fs::rename(&path, &moved)?;
+        sut.reopen().await?;
+        sut.write(Pipe::StdOut, "world\n".as_bytes()).await?;
+
+        assert_ne!(fs::metadata(&path)?.ino(), fs::metadata(&moved)?.ino());
+        assert!(fs::read_to_string(&moved)?.contains(" stdout F hello"));
+        assert!(fs::read_to_string(&path)?.contains(" stdout F world"));
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_max_size() -> Result<()> {
+        let resolve = ContainerLog::resolve_max_size;
+        assert_eq!(resolve(MaxSizeMode::Inherit, Some(10), Some(20))?, Some(10));
+        assert_eq!(resolve(MaxSizeMode::Inherit, None, Some(20))?, Some(20));
+        assert_eq!(resolve(MaxSizeMode::Inherit, None, None)?, None);
+        assert_eq!(resolve(MaxSizeMode::Unlimited, Some(10), Some(20))?, None);
+        assert_eq!(resolve(MaxSizeMode::Limited, Some(10), Some(20))?, Some(10));
+        assert!(resolve(MaxSizeMode::Limited, None, Some(20)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn driver_max_size_node() -> Result<()> {
+        let node = Some(10 << 20);
+        assert_eq!(ContainerLog::driver_max_size(0, "", node)?, Some(10 << 20));
+        assert_eq!(ContainerLog::driver_max_size(5, "", node)?, Some(5));
+        assert_eq!(ContainerLog::driver_max_size(0, "1KiB", node)?, Some(1024));
+        assert_eq!(ContainerLog::driver_max_size(0, "", None)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn from_duplicate_path() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let json_path = dir.path().join("json.log");
+        let cri_path = dir.path().join("cri.log");
+
+        let sut = from_paths(&[
+            (Type::Json, json_path.clone()),
+            (Type::ContainerRuntimeInterface, cri_path.clone()),
+        ])?;
+        assert_eq!(sut.try_read()?.drivers.len(), 2);
+
+        let err = from_paths(&[
+            (Type::Json, json_path),
+            (Type::ContainerRuntimeInterface, cri_path),
+            (Type::Json, dir.path().join(".").join("cri.log")),
+        ])
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("log drivers 1 and 2 both write to path"));
+        assert!(err.contains("cri.log"));
+        Ok(())
+    }
+
+    #[test]
+    fn from_zero_sync_interval() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let err = ContainerLog::from_drivers(
+            "id",
+            false,
+            &[(Type::Json, dir.path().join("json.log"))],
+            |_, mut driver| driver.set_durability(Durability::Interval),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("log driver 0 requires a sync interval greater than zero"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn validate_success() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let json_path = dir.path().join("json.log");
+        let cri_path = dir.path().join("cri.log");
+
+        let sut = from_paths(&[
+            (Type::Json, json_path.clone()),
+            (Type::ContainerRuntimeInterface, cri_path.clone()),
+        ])?;
+        sut.read().await.validate().await?;
+        assert!(!json_path.exists());
+        assert!(!cri_path.exists());
+
+        let mut cri_logger = CriLogger::new(&cri_path, None)?;
+        cri_logger.set_max_files(Some(3));
+        let err = cri_logger.validate().await.unwrap_err().to_string();
+        assert!(err.contains("require a rotation keeping backups"), "{err}");
+        cri_logger.set_rotation(RotationMode::Rename);
+        cri_logger.validate().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn validate_failure() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let missing = dir.path().join("missing").join("cri.log");
+        let sut = from_paths(&[(Type::ContainerRuntimeInterface, missing)])?;
+        let err = format!("{:#}", sut.read().await.validate().await.unwrap_err());
+        assert!(err.contains("validate container_runtime_interface log driver"));
+        assert!(err.contains("get log directory metadata"));
+
+        let read_only = dir.path().join("read-only");
+        fs::create_dir(&read_only)?;
+        fs::set_permissions(&read_only, fs::Permissions::from_mode(0o555))?;
+        let path = read_only.join("json.log");
+        let sut = from_paths(&[(Type::Json, path.clone())])?;
+        let res = sut.read().await.validate().await;
+
+        // Root bypasses the permission checks.
+        if nix::unistd::geteuid().is_root() {
+            return Ok(());
+        }
+        let err = format!("{:#}", res.unwrap_err());
+        assert!(err.contains("is not writable"), "{err}");
+        assert!(!path.exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn add_remove_driver() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cri_path = dir.path().join("cri.log");
+        let json_path = dir.path().join("json.log");
+        let mut sut = ContainerLog {
+            drivers: vec![Box::new(CriLogger::new(&cri_path, None)?)],
+            ..Default::default()
+        };
+        sut.init().await?;
+        sut.write(Pipe::StdOut, "first\n".as_bytes()).await?;
+
+        let new_json_logger = || JsonLogger::new(&json_path, None);
+        sut.add_driver(Box::new(new_json_logger()?)).await?;
+        assert!(sut.add_driver(Box::new(new_json_logger()?)).await.is_err());
+        assert_eq!(sut.drivers.len(), 2);
+        sut.write(Pipe::StdOut, "second\nthird".as_bytes()).await?;
+
+        let removed = sut.remove_driver(|x| x.name() == "json").await?;
+        assert_eq!(removed, 1);
+        sut.write(Pipe::StdOut, "\nfourth\n".as_bytes()).await?;
+
+        let json = fs::read_to_string(&json_path)?;
+        assert!(!json.contains("first"));
+        assert!(json.contains(r#""message":"second""#));
+        assert!(json.contains(r#""message":"third","partial":true"#));
+        assert!(!json.contains("fourth"));
+        let cri = fs::read_to_string(&cri_path)?;
+        for message in ["first", "second", "fourth"] {
+            assert!(cri.contains(&format!(" F {message}")), "{message}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn add_remove_driver_config() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let json_path = dir.path().join("json.log");
+        let sut = from_paths(&[(Type::ContainerRuntimeInterface, dir.path().join("cri.log"))])?;
+        sut.write().await.init().await?;
+
+        let mut message = capnp::message::Builder::new_default();
+        let mut driver = message.init_root::<log_driver::Builder>();
+        driver.set_type(Type::Json);
+        driver.set_path(json_path.to_str().context("invalid path")?);
+        let driver = message.get_root_as_reader::<log_driver::Reader>()?;
+        sut.write().await.add_driver_from(driver).await?;
+        sut.write()
+            .await
+            .write(Pipe::StdOut, "hello\n".as_bytes())
+            .await?;
 
-        join_all(futures)
+        // The driver inherits the metadata of the container.
+        let json = fs::read_to_string(&json_path)?;
+        assert!(json.contains(r#""id":"id""#), "{json}");
+        assert!(json.contains(r#""message":"hello""#), "{json}");
+
+        let removed = sut.write().await.remove_driver_by_path(&json_path).await?;
+        assert_eq!(removed, 1);
+        assert_eq!(sut.read().await.drivers.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_bytes_count() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let mut sut = ContainerLog {
+            drivers: vec![Box::new(JsonLogger::new(file.path(), None)?)],
+            ..Default::default()
+        };
+        sut.init().await?;
+
+        let input = "hello\nworld\npartial";
+        let written = sut.write(Pipe::StdOut, input.as_bytes()).await?;
+
+        assert_eq!(written, input.len());
+        Ok(())
+    }
+
+    #[derive(Debug, Default)]
+    struct CountingSink {
+        bytes_written: AtomicUsize,
+        rotations: Mutex<Vec<PathBuf>>,
+        errors: AtomicUsize,
+    }
+
+    impl MetricsSink for CountingSink {
+        fn on_bytes_written(&self, _pipe: Pipe, bytes: usize) {
+            self.bytes_written.fetch_add(bytes, Ordering::Relaxed);
+        }
+
+        fn on_rotation(&self, path: &Path) {
+            self.rotations.lock().unwrap().push(path.into());
+        }
+
+        fn on_error(&self, _error: &anyhow::Error) {
+            self.errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[tokio::test]
+    async fn metrics_success() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("cri.log");
+        let mut cri_logger = CriLogger::new(&path, Some(100))?;
+        cri_logger.set_rotation(RotationMode::Rename);
+        let sink = Arc::new(CountingSink::default());
+        let mut sut = ContainerLog {
+            drivers: vec![Box::new(cri_logger)],
+            ..Default::default()
+        };
+        sut.set_metrics(sink.clone());
+        sut.init().await?;
+
+        let line = format!("{}\n", "x".repeat(40));
+        sut.write(Pipe::StdOut, line.as_bytes()).await?;
+        assert_eq!(sink.bytes_written.load(Ordering::Relaxed), line.len());
+        assert!(sink.rotations.lock().unwrap().is_empty());
+
+        // Exceeding the maximum log size rotates the file.
+        sut.write(Pipe::StdOut, line.as_bytes()).await?;
+        assert_eq!(*sink.rotations.lock().unwrap(), vec![path.clone()]);
+        sut.reopen().await?;
+        assert_eq!(sink.rotations.lock().unwrap().len(), 2);
+
+        sut.close().await?;
+        let written = sink.bytes_written.load(Ordering::Relaxed);
+        assert!(sut.write(Pipe::StdOut, line.as_bytes()).await.is_err());
+        assert_eq!(sink.errors.load(Ordering::Relaxed), 1);
+
+        // Lines which no driver wrote are not counted.
+        assert!(sut.write_line(Pipe::StdOut, line.as_bytes()).await.is_err());
+        assert_eq!(sink.errors.load(Ordering::Relaxed), 2);
+        assert_eq!(sink.bytes_written.load(Ordering::Relaxed), written);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn stats_success() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let mut sut = ContainerLog {
+            drivers: vec![
+                Box::new(CriLogger::new(dir.path().join("cri.log"), Some(50))?),
+                Box::new(SyslogLogger::new("", "id")?),
+            ],
+            ..Default::default()
+        };
+        sut.drivers[0].init().await?;
+
+        // Only a single line fits into the maximum size.
+        sut.drivers[0]
+            .write(Pipe::StdOut, &mut "a\nb\nc\nd\n".as_bytes())
+            .await?;
+
+        let stats = sut.stats();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].name, "container_runtime_interface");
+        assert_eq!(stats[0].rotations, 3);
+        assert_eq!(stats[0].max_log_size, Some(50));
+        assert_eq!(stats[1].name, "syslog");
+        assert_eq!(stats[1].rotations, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn last_write() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let clock = Arc::new(ManualClock::new(start));
+        let mut cri_logger = CriLogger::new(dir.path().join("cri.log"), None)?;
+        cri_logger.set_clock(clock.clone());
+        let mut sut = ContainerLog {
+            drivers: vec![Box::new(cri_logger), Box::new(SyslogLogger::new("", "id")?)],
+            ..Default::default()
+        };
+        sut.drivers[0].init().await?;
+        assert_eq!(sut.last_write(), None);
+
+        sut.drivers[0]
+            .write(Pipe::StdOut, &mut "a\n".as_bytes())
+            .await?;
+        assert_eq!(sut.last_write(), Some(start));
+
+        // The time stays the same without any writes.
+        clock.advance(Duration::from_secs(30));
+        assert_eq!(sut.last_write(), Some(start));
+        assert_eq!(sut.stats()[1].last_write, None);
+
+        sut.drivers[0]
+            .write(Pipe::StdOut, &mut "b\n".as_bytes())
+            .await?;
+        assert_eq!(sut.last_write(), Some(start + Duration::from_secs(30)));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_separate_streams() -> Result<()> {
+        let stdout_file = NamedTempFile::new()?;
+        let stderr_file = NamedTempFile::new()?;
+        let mut drivers: Vec<Box<dyn Logger>> = vec![];
+        for (file, pipe) in [(&stdout_file, Pipe::StdOut), (&stderr_file, Pipe::StdErr)] {
+            let mut json_logger = JsonLogger::new(file.path(), None)?;
+            json_logger.set_pipe(Some(pipe));
+            drivers.push(Box::new(json_logger));
+        }
+        let mut sut = ContainerLog {
+            drivers,
+            ..Default::default()
+        };
+        sut.init().await?;
+
+        sut.write(Pipe::StdOut, "out\n".as_bytes()).await?;
+        sut.write(Pipe::StdErr, "err\n".as_bytes()).await?;
+
+        let stdout = fs::read_to_string(stdout_file.path())?;
+        assert!(stdout.contains(r#""pipe":"stdout""#));
+        assert!(stdout.contains("out"));
+        assert!(!stdout.contains("stderr"));
+        let stderr = fs::read_to_string(stderr_file.path())?;
+        assert!(stderr.contains(r#""pipe":"stderr""#));
+        assert!(stderr.contains("err"));
+        assert!(!stderr.contains("stdout"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn flush_success() -> Result<()> {
+        let cri_file = NamedTempFile::new()?;
+        let json_file = NamedTempFile::new()?;
+        let mut sut = ContainerLog {
+            drivers: vec![
+                Box::new(CriLogger::new(cri_file.path(), None)?),
+                Box::new(JsonLogger::new(json_file.path(), None)?),
+            ],
+            ..Default::default()
+        };
+        sut.init().await?;
+
+        sut.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+        sut.flush().await?;
+
+        assert!(fs::read_to_string(cri_file.path())?.contains(" stdout F hello"));
+        assert!(fs::read_to_string(json_file.path())?.contains("hello"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn close_success() -> Result<()> {
+        let json_file = NamedTempFile::new()?;
+        let cri_file = NamedTempFile::new()?;
+        let mut sut = ContainerLog {
+            drivers: vec![
+                Box::new(JsonLogger::new(json_file.path(), None)?),
+                Box::new(CriLogger::new(cri_file.path(), None)?),
+            ],
+            ..Default::default()
+        };
+        sut.init().await?;
+        sut.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+
+        sut.close().await?;
+        assert!(fs::read_to_string(json_file.path())?.contains("hello"));
+        assert!(fs::read_to_string(cri_file.path())?.contains(" stdout F hello"));
+
+        let err = sut
+            .write(Pipe::StdOut, "world\n".as_bytes())
+            .await
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("json: logger not initialized"));
+        assert!(err.contains("container_runtime_interface: logger not initialized"));
+
+        // Closing again does not fail.
+        sut.close().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_failing_driver() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let mut cri_logger = CriLogger::new(file.path(), None)?;
+        cri_logger.init().await?;
+
+        // The JSON logger is never initialized because its path is not writable.
+        let mut sut = ContainerLog {
+            drivers: vec![
+                Box::new(JsonLogger::new("/file/does/not/exist", None)?),
+                Box::new(cri_logger),
+            ],
+            ..Default::default()
+        };
+
+        let err = sut
+            .write(Pipe::StdOut, "hello\n".as_bytes())
             .await
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("json: logger not initialized"));
+        assert!(!err.contains("container_runtime_interface"));
+        assert!(fs::read_to_string(file.path())?.contains(" stdout F hello"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reopen_failing_driver() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("cri.log");
+        let moved = dir.path().join("cri.log.1");
+        let mut cri_logger = CriLogger::new(&path, None)?;
+        cri_logger.init().await?;
+
+        // The JSON logger is never initialized because its path is not writable.
+        let mut sut = ContainerLog {
+            drivers: vec![
+                Box::new(JsonLogger::new("/file/does/not/exist", None)?),
+                Box::new(cri_logger),
+            ],
+            ..Default::default()
+        };
+        fs::rename(&path, &moved)?;
+
+        let err = sut.reopen().await.unwrap_err().to_string();
+
+        assert!(err.contains("reopen 1 of 2 log driver(s)"));
+        assert!(err.contains("json: "));
+        assert!(!err.contains("container_runtime_interface"));
+        assert!(path.exists());
+        Ok(())
+    }
+
+    #[derive(Debug, Default)]
+    /// Logger recording the maximum amount of concurrently running writes of all its instances.
+    struct CountingLogger {
+        active: Arc<AtomicUsize>,
+        max_active: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Logger for CountingLogger {
+        fn name(&self) -> &'static str {
+            "counting"
+        }
+
+        async fn init(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn write(
+            &mut self,
+            _pipe: Pipe,
+            bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+        ) -> Result<usize> {
+            let active = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_active.fetch_max(active, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.active.fetch_sub(1, Ordering::SeqCst);
+            let mut buf = vec![];
+            Ok(tokio::io::AsyncReadExt::read_to_end(bytes, &mut buf).await?)
+        }
+
+        async fn reopen(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn write_max_concurrent_writers() -> Result<()> {
+        let active = Arc::new(AtomicUsize::new(0));
+        let max_active = Arc::new(AtomicUsize::new(0));
+        let mut sut = ContainerLog {
+            drivers: (0..5)
+                .map(|_| {
+                    Box::new(CountingLogger {
+                        active: active.clone(),
+                        max_active: max_active.clone(),
+                    }) as Box<dyn Logger>
+                })
+                .collect(),
+            ..Default::default()
+        };
+        sut.set_max_concurrent_writers(2);
+
+        assert_eq!(sut.write(Pipe::StdOut, "hello\n".as_bytes()).await?, 6);
+        assert_eq!(max_active.load(Ordering::SeqCst), 2);
+
+        // All loggers get written at once without a limit.
+        sut.set_max_concurrent_writers(0);
+        max_active.store(0, Ordering::SeqCst);
+        sut.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+        assert_eq!(max_active.load(Ordering::SeqCst), 5);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pause_resume() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let mut cri_logger = CriLogger::new(file.path(), None)?;
+        cri_logger.init().await?;
+        let mut sut = ContainerLog {
+            drivers: vec![Box::new(cri_logger)],
+            ..Default::default()
+        };
+
+        sut.pause(PausePolicy::Buffer(12));
+        assert!(sut.is_paused());
+        assert_eq!(sut.write(Pipe::StdOut, "hello\n".as_bytes()).await?, 6);
+        sut.write_line(Pipe::StdErr, b"world\n").await?;
+        // The buffer is full, which drops the line.
+        sut.write(Pipe::StdOut, "dropped\n".as_bytes()).await?;
+        assert!(fs::read_to_string(file.path())?.is_empty());
+
+        sut.resume().await?;
+        assert!(!sut.is_paused());
+        sut.write(Pipe::StdOut, "again\n".as_bytes()).await?;
+
+        let content = fs::read_to_string(file.path())?;
+        let lines = content.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].ends_with(" stdout F hello"));
+        assert!(lines[1].ends_with(" stderr F world"));
+        assert!(lines[2].ends_with(" stderr F # conmon: dropped 8 bytes"));
+        assert!(lines[3].ends_with(" stdout F again"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_ordered_remote_failure() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let mut cri_logger = CriLogger::new(file.path(), None)?;
+        cri_logger.init().await?;
+
+        // The syslog logger is never connected, which lets every write fail.
+        let mut sut = ContainerLog {
+            drivers: vec![Box::new(SyslogLogger::new("", "id")?), Box::new(cri_logger)],
+            ..Default::default()
+        };
+        sut.set_ordered(true);
+
+        sut.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+        sut.write_line(Pipe::StdOut, b"world\n").await?;
+
+        let content = fs::read_to_string(file.path())?;
+        assert!(content.contains(" stdout F hello"));
+        assert!(content.contains(" stdout F world"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn is_initialized() -> Result<()> {
+        let cri_file = NamedTempFile::new()?;
+        let json_file = NamedTempFile::new()?;
+        let mut sut = ContainerLog {
+            drivers: vec![
+                Box::new(CriLogger::new(cri_file.path(), None)?),
+                Box::new(JsonLogger::new(json_file.path(), None)?),
+            ],
+            ..Default::default()
+        };
+        assert!(!sut.is_initialized());
+
+        sut.init().await?;
+        assert!(sut.is_initialized());
+
+        sut.close().await?;
+        assert!(!sut.is_initialized());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn emit_exit_event() -> Result<()> {
+        let cri_file = NamedTempFile::new()?;
+        let json_file = NamedTempFile::new()?;
+        let mut sut = ContainerLog {
+            drivers: vec![
+                Box::new(CriLogger::new(cri_file.path(), None)?),
+                Box::new(JsonLogger::new(json_file.path(), None)?),
+            ],
+            ..Default::default()
+        };
+        sut.init().await?;
+        sut.write(Pipe::StdOut, "bye\n".as_bytes()).await?;
+        sut.emit_event(LogEvent::ContainerExit { exit_code: 137 })
+            .await?;
+        sut.close().await?;
+
+        let entries = fs::read_to_string(json_file.path())?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1]["event"], "container_exit");
+        assert_eq!(entries[1]["exit_code"], 137);
+        assert!(entries[1].get("message").is_none());
+
+        let content = fs::read_to_string(cri_file.path())?;
+        assert!(content
+            .ends_with(" stdout F # conmon: {\"event\":\"container_exit\",\"exit_code\":137}\n"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn init_optional_failure() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let mut sut = ContainerLog {
+            drivers: vec![
+                Box::new(OptionalLogger::new(Box::new(SyslogLogger::new(
+                    "/non-existing/log.sock",
+                    "id",
+                )?))),
+                Box::new(CriLogger::new(file.path(), None)?),
+            ],
+            ..Default::default()
+        };
+        sut.init().await?;
+        assert!(sut.is_initialized());
+        sut.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+        sut.close().await?;
+
+        let content = fs::read_to_string(file.path())?;
+        assert!(content.contains(" stdout F hello"));
+
+        // A required driver still aborts.
+        sut.drivers
+            .push(Box::new(SyslogLogger::new("/non-existing/log.sock", "id")?));
+        assert!(sut.init().await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_stdin_opt_in() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let mut sut = ContainerLog {
+            drivers: vec![Box::new(JsonLogger::new(file.path(), None)?)],
+            ..Default::default()
+        };
+        sut.init().await?;
+
+        assert_eq!(sut.write(Pipe::StdIn, "secret\n".as_bytes()).await?, 0);
+        assert!(fs::read_to_string(file.path())?.is_empty());
+
+        sut.log_stdin = true;
+        sut.write(Pipe::StdIn, "ls\n".as_bytes()).await?;
+        assert!(fs::read_to_string(file.path())?.contains(r#""pipe":"stdin""#));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_line_single_entry() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let mut sut = ContainerLog {
+            drivers: vec![Box::new(JsonLogger::new(file.path(), None)?)],
+            ..Default::default()
+        };
+        sut.init().await?;
+
+        // Contrary to `write`, embedded newlines do not split the line into multiple entries.
+        sut.write_line(Pipe::StdOut, "first\nsecond\n".as_bytes())
+            .await?;
+        sut.write(Pipe::StdOut, "first\nsecond\n".as_bytes())
+            .await?;
+
+        let messages = fs::read_to_string(file.path())?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?
             .into_iter()
-            .collect::<Result<Vec<_>>>()?;
+            .map(|entry| entry["message"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["first\nsecond", "first", "second"]);
         Ok(())
     }
 }
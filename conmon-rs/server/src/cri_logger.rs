@@ -1,16 +1,40 @@
 //! File logging functionalities.
 
-use crate::container_io::Pipe;
-use anyhow::{Context, Result};
+#[cfg(feature = "s3")]
+use crate::log_upload::S3Upload;
+use crate::{
+    clock::{Clock, SystemClock},
+    container_io::Pipe,
+    container_log::{
+        self, EmptyLines, FileAccess, LogEvent, LogStats, Logger, DEFAULT_BUFFER_SIZE,
+    },
+    log_budget::LogDiskBudget,
+    log_durability::{self, DurabilityPolicy, FlushThreshold, SyncTask, Unflushed},
+    log_error::LoggerError,
+    log_metrics::PipeStats,
+    log_reader::{CriTags, LogFormat},
+    log_retry,
+    log_rotation::{self, BackupTask, Compression, RotationMode, RotationNaming},
+    log_sink::LogSink,
+    log_transform::{self, LogTransform},
+};
+use anyhow::{bail, Context, Error, Result};
+use async_trait::async_trait;
 use getset::{CopyGetters, Getters, Setters};
 use memchr::memchr;
-use std::path::{Path, PathBuf};
+use std::{
+    collections::BTreeMap,
+    mem,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
 use tokio::{
-    fs::{File, OpenOptions},
-    io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter},
+    fs::{self, File, OpenOptions},
+    io::{self, AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader, BufWriter},
 };
-use tracing::{debug, trace};
-use tz::{DateTime, TimeZone};
+use tracing::{debug, trace, warn};
+use tz::TimeZone;
 
 #[derive(Debug, CopyGetters, Getters, Setters)]
 /// The main structure used for container log handling.
@@ -20,65 +44,310 @@ pub struct CriLogger {
     path: PathBuf,
 
     #[getset(set)]
-    /// Open file handle of the `path`.
-    file: Option<BufWriter<File>>,
+    /// Open file handle of the `path`, or the custom sink provided on creation.
+    file: Option<BufWriter<LogSink>>,
 
     #[getset(get_copy)]
-    /// Maximum allowed log size in bytes.
+    /// Maximum allowed log size in bytes. A record exceeding it on its own still gets written as
+    /// a whole into an empty log file, instead of rotating before every record.
     max_log_size: Option<usize>,
 
-    #[getset(get_copy, set)]
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Maximum allowed line size in bytes before it gets split into partial lines.
+    max_line_size: Option<usize>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Only log the output of this pipe if set.
+    pipe: Option<Pipe>,
+
+    #[getset(get_copy = "pub", set)]
     /// Current bytes written to the log file.
     bytes_written: usize,
+
+    #[getset(get_copy = "pub")]
+    /// Amount of rotations triggered by the log size.
+    rotations: usize,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Strategy used when rotating the log file.
+    rotation: RotationMode,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Maximum amount of rotated log files to keep.
+    max_files: Option<usize>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Algorithm used for compressing rotated log files.
+    compression: Compression,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Write the SHA-256 digest of every rotated log file into a sidecar next to it.
+    checksum: bool,
+
+    #[cfg(feature = "s3")]
+    #[getset(get = "pub", set = "pub")]
+    /// Upload every rotated log file into an S3 compatible object store, if set.
+    upload: Option<Arc<S3Upload>>,
+
+    #[getset(get = "pub", set = "pub")]
+    /// Naming scheme of the backups kept by the `Rename` rotation.
+    naming: RotationNaming,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Policy for syncing written lines to disk.
+    durability: DurabilityPolicy,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Rotate the log file on the first write after this interval elapsed since opening it.
+    rotate_interval: Option<Duration>,
+
+    /// The time the current log file has been opened.
+    last_rotation: SystemTime,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Reset the written bytes to the actual size of the log file before writing a line once this
+    /// interval elapsed, which keeps the rotation on track if the file got appended or truncated
+    /// externally. Disabled if not set.
+    reconcile_interval: Option<Duration>,
+
+    /// The time the written bytes have been reconciled the last time.
+    last_reconcile: SystemTime,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Reopen the log file before writing a line if its path no longer refers to the open file,
+    /// which costs an additional `stat` per line.
+    watch_path: bool,
+
+    /// Background task syncing the log file, if required by the durability policy.
+    sync_task: Option<SyncTask>,
+
+    /// Background task compressing the latest backup.
+    backup_task: BackupTask,
+
+    #[getset(set = "pub")]
+    /// Source of the line timestamps, which defaults to the system wall clock.
+    clock: Arc<dyn Clock>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Capacity of the buffers for reading the container output and writing the log file, which
+    /// is also the size at which lines get split into partial ones. Must not be zero.
+    buffer_size: usize,
+
+    #[getset(set = "pub")]
+    /// Node wide disk usage budget the written bytes get charged to, if any.
+    budget: Option<Arc<LogDiskBudget>>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Permissions and ownership of the log file, which defaults to mode `0600`.
+    file_access: FileAccess,
+
+    /// Line statistics of every pipe.
+    line_stats: PipeStats,
+
+    #[getset(get_copy = "pub")]
+    /// Time of the last written line, which allows detecting idle containers.
+    last_write: Option<SystemTime>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Retries of writes failing because the disk is full, which block the container output in
+    /// between. Retrying is disabled if zero.
+    disk_full_retries: usize,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Thresholds for flushing the written lines, which batches the writes to the log file.
+    flush_threshold: FlushThreshold,
+
+    /// Lines and bytes written since the last flush.
+    unflushed: Unflushed,
+
+    #[getset(get = "pub", set = "pub")]
+    /// Path used instead if writing to the log file fails, for example because its mount became
+    /// read-only.
+    fallback_path: Option<PathBuf>,
+
+    /// Records written since the last flush while a fallback path is set, which get written to
+    /// the fallback path if they fail to reach the log file.
+    retained: Vec<u8>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Handling of empty lines, which get written by default.
+    empty_lines: EmptyLines,
+
+    #[getset(set = "pub")]
+    /// Transformations applied in order to every message before writing it, like redacting
+    /// secrets. Lines split into partial ones get transformed separately.
+    transforms: Vec<Arc<dyn LogTransform>>,
+
+    #[getset(get = "pub", set = "pub")]
+    /// Identity of the container like its `name` and `image`, which gets written as JSON object
+    /// into a `.meta` sidecar next to the log file because the CRI format has no room for it.
+    /// The sidecar is omitted if empty.
+    identity: BTreeMap<String, String>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Tags of the partial and full records for consumers expecting other ones than `P` and `F`.
+    tags: CriTags,
+
+    #[getset(get = "pub", set = "pub")]
+    /// Symbolic link pointing at the live log file, like a `latest` link next to it. It gets
+    /// replaced atomically whenever the log file is opened, which includes rotations and the
+    /// failover to the fallback path.
+    link_path: Option<PathBuf>,
 }
 
 impl CriLogger {
-    const ERR_UNINITIALIZED: &'static str = "logger not initialized";
+    /// The beginning of the message written for dropped bytes.
+    const MARKER_PREFIX: &'static str = "# conmon: dropped";
+
+    /// The prefix of a lifecycle event, which is followed by the event as JSON object.
+    const EVENT_PREFIX: &'static str = "# conmon:";
 
     /// Create a new file logger instance.
-    pub fn new<T: AsRef<Path>>(path: T, max_log_size: Option<usize>) -> Result<CriLogger> {
+    pub fn new<T: AsRef<Path>>(
+        path: T,
+        max_log_size: Option<usize>,
+    ) -> Result<CriLogger, LoggerError> {
         Ok(Self {
             path: path.as_ref().into(),
             file: None,
             max_log_size,
+            max_line_size: None,
+            pipe: None,
             bytes_written: 0,
+            rotations: 0,
+            rotation: RotationMode::default(),
+            max_files: None,
+            compression: Compression::None,
+            checksum: false,
+            #[cfg(feature = "s3")]
+            upload: None,
+            naming: RotationNaming::default(),
+            durability: DurabilityPolicy::None,
+            sync_task: None,
+            backup_task: BackupTask::default(),
+            rotate_interval: None,
+            last_rotation: SystemTime::now(),
+            reconcile_interval: None,
+            last_reconcile: SystemTime::now(),
+            watch_path: false,
+            clock: Arc::new(SystemClock),
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            budget: None,
+            file_access: FileAccess::default(),
+            line_stats: PipeStats::default(),
+            last_write: None,
+            disk_full_retries: 0,
+            flush_threshold: FlushThreshold::default(),
+            unflushed: Unflushed::default(),
+            fallback_path: None,
+            retained: vec![],
+            empty_lines: EmptyLines::default(),
+            transforms: Vec::new(),
+            identity: BTreeMap::new(),
+            tags: CriTags::default(),
+            link_path: None,
         })
     }
 
-    /// Asynchronously initialize the CRI logger.
-    pub async fn init(&mut self) -> Result<()> {
-        debug!("Initializing CRI logger in path {}", self.path().display());
-        self.set_file(Self::open(self.path()).await?.into());
-        Ok(())
+    /// Create a new logger instance writing into the provided sink instead of a log file, like an
+    /// in-memory buffer or a socket. The sink is initialized already and never gets rotated or
+    /// synced, and it cannot be initialized again once closed.
+    pub fn new_with_sink<W>(sink: W) -> Result<CriLogger, LoggerError>
+    where
+        W: AsyncWrite + Unpin + Send + Sync + 'static,
+    {
+        let mut logger = Self::new(PathBuf::new(), None)?;
+        logger.file = Some(BufWriter::with_capacity(
+            logger.buffer_size(),
+            LogSink::writer(sink),
+        ));
+        Ok(logger)
+    }
+
+    /// Indicates if the logger writes into a custom sink instead of a log file.
+    fn custom_sink(&self) -> bool {
+        self.file.as_ref().is_some_and(|x| x.get_ref().is_writer())
+    }
+
+    /// The path of the sidecar holding the container identity of the log file at `path`.
+    pub fn identity_path<T: AsRef<Path>>(path: T) -> PathBuf {
+        let mut identity_path = path.as_ref().as_os_str().to_owned();
+        identity_path.push(".meta");
+        identity_path.into()
     }
 
-    /// Write the contents of the provided reader into the file logger.
-    pub async fn write<T>(&mut self, pipe: Pipe, bytes: T) -> Result<()>
+    /// Write the container identity into its sidecar, if there is any.
+    async fn write_identity(&self) -> Result<()> {
+        if self.identity().is_empty() {
+            return Ok(());
+        }
+        let path = Self::identity_path(self.path());
+        let identity = serde_json::to_vec(self.identity()).context("serialize identity")?;
+        fs::write(&path, identity)
+            .await
+            .context(format!("write identity sidecar '{}'", path.display()))
+    }
+
+    /// Write the contents of the provided reader into the file logger and return the amount of
+    /// consumed bytes. Every record follows the format `<RFC3339Nano> <stream> <P|F> <message>`,
+    /// where lines split because of the buffer or `max_line_size` are tagged as partial (`P`)
+    /// until the full (`F`) remainder, unless other `tags` are configured. The kubelet only accepts
+    /// the `stdout` and `stderr` streams, which means that stdin data gets discarded.
+    pub async fn write<T>(&mut self, pipe: Pipe, mut bytes: T) -> Result<usize, LoggerError>
     where
         T: AsyncBufRead + Unpin,
     {
-        let mut reader = BufReader::new(bytes);
+        let stream: &[u8] = match pipe {
+            Pipe::StdOut => b" stdout ",
+            Pipe::StdErr => b" stderr ",
+            Pipe::StdIn => {
+                let discarded = io::copy(&mut bytes, &mut io::sink())
+                    .await
+                    .context("discard stdin data")?;
+                trace!("Discarded {} bytes of stdin data", discarded);
+                return Ok(discarded.try_into().context("convert discarded bytes")?);
+            }
+        };
+
+        let mut reader = BufReader::with_capacity(self.buffer_size(), bytes);
+        let mut total_read = 0;
 
         // Get the RFC3339 timestamp
         let local_tz = TimeZone::local().context("get local timezone")?;
-        let timestamp = DateTime::now(local_tz.as_ref())
-            .context("get local datetime")?
-            .to_string();
+        let timestamp = self.clock.local_timestamp(local_tz.as_ref())?;
         let min_log_len = timestamp
             .len()
-            .checked_add(10) // len of " stdout " + "P "
+            .checked_add(9) // len of " stdout " + the space after the tag
             .context("min log line len exceeds usize")?;
 
+        let mut record = Vec::with_capacity(min_log_len);
         loop {
             // Read the line
             let mut line_buf = Vec::with_capacity(min_log_len);
-            let (read, partial) = Self::read_line(&mut reader, &mut line_buf).await?;
+            let (read, partial) =
+                Self::read_line(&mut reader, &mut line_buf, self.max_line_size()).await?;
 
             if read == 0 {
                 break;
             }
+            total_read += read;
+
+            if self.watch_path() {
+                self.reattach().await?;
+            }
+            self.reconcile().await?;
 
-            let mut bytes_to_be_written = read + min_log_len;
+            let line = log_transform::apply_line(&self.transforms, &line_buf);
+            self.line_stats.record(
+                pipe,
+                line.strip_suffix(b"\n").unwrap_or(&line).len(),
+                partial,
+            );
+            if self.empty_lines() == EmptyLines::Drop && matches!(line.as_ref(), b"\n" | b"\r\n") {
+                continue;
+            }
+            let tag = self.tags().tag(partial);
+            let mut bytes_to_be_written = line.len() + min_log_len + tag.len_utf8();
             if partial {
                 bytes_to_be_written += 1; // the added newline
             }
@@ -90,99 +359,296 @@ impl CriLogger {
                     self.reopen()
                         .await
                         .context("reopen logs because of overflowing bytes_written")?;
+                    self.rotations += 1;
                     0
                 }
             };
 
+            if self.rotation_due() {
+                self.reopen()
+                    .await
+                    .context("reopen logs because of elapsed rotate interval")?;
+                self.rotations += 1;
+                new_bytes_written = bytes_to_be_written;
+            }
+
             if let Some(max_log_size) = self.max_log_size() {
                 trace!(
                     "Verifying log size: max_log_size = {}, bytes_written = {},  bytes_to_be_written = {}, new_bytes_written = {}", 
                     max_log_size, self.bytes_written(),  bytes_to_be_written, new_bytes_written,
                 );
 
-                if new_bytes_written > max_log_size {
-                    new_bytes_written = 0;
+                // Rotating an empty log file would not make room for the record.
+                if new_bytes_written > max_log_size && self.bytes_written() > 0 {
+                    new_bytes_written = bytes_to_be_written;
                     self.reopen()
                         .await
                         .context("reopen logs because of exceeded size")?;
+                    self.rotations += 1;
                 }
             }
 
-            // Write the timestamp
-            let file = self.file.as_mut().context(Self::ERR_UNINITIALIZED)?;
-            file.write_all(timestamp.as_bytes()).await?;
+            if self.budget_exceeded(bytes_to_be_written).await? {
+                self.reopen()
+                    .await
+                    .context("reopen logs because of exceeded disk budget")?;
+                self.rotations += 1;
+                new_bytes_written = bytes_to_be_written;
+            }
 
-            // Add the pipe name
-            match pipe {
-                Pipe::StdOut => file.write_all(b" stdout ").await,
-                Pipe::StdErr => file.write_all(b" stderr ").await,
-            }?;
+            // Add the timestamp and the stream name
+            record.clear();
+            record.extend_from_slice(timestamp.as_bytes());
+            record.extend_from_slice(stream);
 
-            // Output log tag for partial or newline
-            if partial {
-                file.write_all(b"P ").await?;
-            } else {
-                file.write_all(b"F ").await?;
-            }
+            // Add the log tag for partial or newline
+            record.extend_from_slice(tag.encode_utf8(&mut [0; 4]).as_bytes());
+            record.push(b' ');
 
-            // Output the actual contents
-            file.write_all(&line_buf).await?;
+            // Add the actual contents
+            record.extend_from_slice(&line);
 
-            // Output a newline for partial
+            // Add a newline for partial
             if partial {
-                file.write_all(b"\n").await?;
+                record.push(b'\n');
+            }
+
+            if let Err(e) = self.write_record(&record).await {
+                self.fail_over(e).await?;
+                new_bytes_written = self.bytes_written();
             }
 
             self.set_bytes_written(new_bytes_written);
+            self.last_write = Some(self.clock.now());
             trace!("Wrote log line of length {}", bytes_to_be_written);
         }
 
-        self.flush().await
+        if !self.flush_threshold().is_set() {
+            if let Err(e) = self.flush().await {
+                self.fail_over(e).await?;
+                self.flush().await?;
+            }
+        }
+        Ok(total_read)
     }
 
-    /// Reopen the container log file.
-    pub async fn reopen(&mut self) -> Result<()> {
-        debug!("Reopen container log {}", self.path().display());
-        self.file
-            .as_mut()
-            .context(Self::ERR_UNINITIALIZED)?
-            .get_ref()
-            .sync_all()
-            .await?;
-        self.init().await
+    /// Write a single record and flush it as required by the durability policy and flush
+    /// threshold.
+    async fn write_record(&mut self, record: &[u8]) -> Result<()> {
+        if self.fallback_path.is_some() {
+            self.retained.extend_from_slice(record);
+        }
+        let retries = self.disk_full_retries();
+        let file = self.file.as_mut().ok_or(LoggerError::Uninitialized)?;
+        if retries > 0 {
+            // Writing the record directly allows detecting its written part on a full disk.
+            file.flush().await?;
+            log_retry::write_all(file.get_mut(), record, retries).await?;
+        } else {
+            file.write_all(record).await?;
+        }
+
+        let mut flushed = true;
+        match self.durability {
+            DurabilityPolicy::EveryWrite => log_durability::sync(file).await?,
+            // The file is opened with O_DSYNC, which means that flushing the line is enough.
+            DurabilityPolicy::Dsync => file.flush().await?,
+            DurabilityPolicy::None | DurabilityPolicy::Interval(_) => flushed = false,
+        }
+        if self.flush_threshold.is_set() && self.unflushed.add(record.len(), self.flush_threshold) {
+            file.flush().await?;
+            flushed = true;
+        }
+        if flushed {
+            self.retained.clear();
+        }
+        Ok(())
     }
 
-    /// Ensures that all content is written to disk.
-    pub async fn flush(&mut self) -> Result<()> {
-        self.file
-            .as_mut()
-            .context(Self::ERR_UNINITIALIZED)?
-            .flush()
-            .await
-            .context("flush file writer")
-    }
-
-    /// Open the provided path with the default options.
-    async fn open<T: AsRef<Path>>(path: T) -> Result<BufWriter<File>> {
-        Ok(BufWriter::new(
-            OpenOptions::new()
-                .create(true)
-                .read(true)
-                .truncate(true)
-                .write(true)
-                .mode(0o600)
-                .open(&path)
+    /// Switch to the fallback path because of the failed write, which fails if there is none. The
+    /// records written since the last flush get written to the fallback again, because the file
+    /// does not report which of them reached it. The switch only gets reported as warning, which
+    /// keeps the container output the only content of the log. Switching happens only once.
+    async fn fail_over(&mut self, e: Error) -> Result<()> {
+        let Some(fallback_path) = self.fallback_path.take() else {
+            return Err(e);
+        };
+        warn!(
+            "Switching container log {} to fallback path {}: {:#}",
+            self.path().display(),
+            fallback_path.display(),
+            e
+        );
+        let retained = mem::take(&mut self.retained);
+        self.file = None;
+        if let Some(budget) = &self.budget {
+            budget.unregister(self.path()).await?;
+        }
+        self.path = fallback_path;
+        self.open_file(true).await?;
+
+        let file = self.file.as_mut().ok_or(LoggerError::Uninitialized)?;
+        file.write_all(&retained).await?;
+        self.bytes_written += retained.len();
+        Ok(())
+    }
+
+    /// Open the provided path with the default options and the flags of the durability policy,
+    /// where `append` continues an existing file instead of truncating it.
+    async fn open<T: AsRef<Path>>(
+        path: T,
+        durability: DurabilityPolicy,
+        append: bool,
+        file_access: FileAccess,
+    ) -> Result<File> {
+        let mut options = OpenOptions::new();
+        options
+            .create(true)
+            .read(true)
+            .truncate(!append)
+            .append(append)
+            .write(true);
+        #[cfg(unix)]
+        options
+            .mode(file_access.mode.unwrap_or(0o600))
+            .custom_flags(durability.open_flags() | file_access.open_flags());
+        let file = file_access.open(&options, path.as_ref()).await?;
+        file_access.apply(&file).await?;
+        Ok(file)
+    }
+
+    /// Open the log file, where `append` continues an existing file and seeds the written bytes
+    /// from its length.
+    async fn open_file(&mut self, append: bool) -> Result<()> {
+        // Replacing the writer would discard its buffered lines.
+        if self.file.is_some() {
+            self.flush().await?;
+        }
+        let file = Self::open(self.path(), self.durability(), append, self.file_access()).await?;
+        if append {
+            self.bytes_written = file
+                .metadata()
                 .await
-                .context(format!("open log file path '{}'", path.as_ref().display()))?,
-        ))
+                .context("get log file metadata")?
+                .len()
+                .try_into()?;
+        }
+        self.sync_task = SyncTask::from_policy(self.durability(), &file).await?;
+        self.set_file(Some(BufWriter::with_capacity(
+            self.buffer_size(),
+            file.into(),
+        )));
+        self.update_link().await?;
+        self.last_rotation = self.clock.now();
+        if let Some(budget) = &self.budget {
+            budget.register(self.path(), self.naming()).await?;
+        }
+        Ok(())
     }
 
-    async fn read_line<T>(r: &mut BufReader<T>, buf: &mut Vec<u8>) -> Result<(usize, bool)>
+    /// Point the symbolic link at the log file, if any. The link gets created next to it first and
+    /// renamed afterwards, which means that readers never miss it.
+    async fn update_link(&self) -> Result<()> {
+        let Some(link_path) = self.link_path() else {
+            return Ok(());
+        };
+        // A relative target keeps the link valid if the directory gets mounted elsewhere.
+        let target = match self.path().file_name() {
+            Some(file_name) if link_path.parent() == self.path().parent() => Path::new(file_name),
+            _ => self.path(),
+        };
+        let mut tmp_path = link_path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+        if let Err(e) = fs::remove_file(&tmp_path).await {
+            if e.kind() != io::ErrorKind::NotFound {
+                return Err(e).context(format!("remove stale link '{}'", tmp_path.display()));
+            }
+        }
+        fs::symlink(target, &tmp_path)
+            .await
+            .context(format!("create link '{}'", tmp_path.display()))?;
+        fs::rename(&tmp_path, link_path)
+            .await
+            .context(format!("replace link '{}'", link_path.display()))
+    }
+
+    /// Charge the written bytes to the disk budget and return if the log file has to be rotated
+    /// early because the budget is still exceeded after pruning backups.
+    async fn budget_exceeded(&self, bytes: usize) -> Result<bool> {
+        match &self.budget {
+            Some(budget) => budget.charge(bytes.try_into()?).await,
+            None => Ok(false),
+        }
+    }
+
+    /// Reopen the log file if its path no longer refers to the open file, which continues a file
+    /// recreated in the meantime instead of truncating it.
+    async fn reattach(&mut self) -> Result<()> {
+        let file = self.file.as_ref().ok_or(LoggerError::Uninitialized)?;
+        // A custom sink has no path to reattach to.
+        let Some(file) = file.get_ref().file() else {
+            return Ok(());
+        };
+        if !log_rotation::is_detached(&self.path, file).await? {
+            return Ok(());
+        }
+        debug!("Reopen detached container log {}", self.path().display());
+        self.flush().await.context("flush detached log file")?;
+        self.open_file(true).await
+    }
+
+    /// Reset the written bytes to the actual size of the log file if the reconcile interval
+    /// elapsed.
+    async fn reconcile(&mut self) -> Result<()> {
+        let Some(interval) = self.reconcile_interval() else {
+            return Ok(());
+        };
+        let now = self.clock.now();
+        if now
+            .duration_since(self.last_reconcile)
+            .is_ok_and(|elapsed| elapsed < interval)
+        {
+            return Ok(());
+        }
+        self.last_reconcile = now;
+        let file = self.file.as_ref().ok_or(LoggerError::Uninitialized)?;
+        let Some(size) = log_rotation::file_size(file).await? else {
+            return Ok(());
+        };
+        if size != self.bytes_written {
+            debug!(
+                "Reconciling written bytes {} with log file size {}",
+                self.bytes_written, size
+            );
+            self.bytes_written = size;
+        }
+        Ok(())
+    }
+
+    /// Verify if the rotate interval elapsed since opening the current log file.
+    fn rotation_due(&self) -> bool {
+        self.rotate_interval().is_some_and(|interval| {
+            self.clock
+                .now()
+                .duration_since(self.last_rotation)
+                .is_ok_and(|elapsed| elapsed >= interval)
+        })
+    }
+
+    async fn read_line<T>(
+        r: &mut BufReader<T>,
+        buf: &mut Vec<u8>,
+        max_line_size: Option<usize>,
+    ) -> Result<(usize, bool)>
     where
         T: AsyncBufRead + Unpin,
     {
         let (partial, read) = {
             let available = r.fill_buf().await?;
+            let available = &available[..max_line_size.map_or(available.len(), |max_line_size| {
+                max_line_size.min(available.len())
+            })];
             match memchr(b'\n', available) {
                 Some(i) => {
                     buf.extend_from_slice(&available[..=i]);
@@ -199,12 +665,198 @@ impl CriLogger {
     }
 }
 
+#[async_trait]
+impl Logger for CriLogger {
+    fn name(&self) -> &'static str {
+        "container_runtime_interface"
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.file.is_some()
+    }
+
+    /// A custom sink cannot be read back.
+    fn source(&self) -> Option<(PathBuf, LogFormat)> {
+        (!self.custom_sink()).then(|| (self.path().clone(), LogFormat::Cri(self.tags())))
+    }
+
+    fn accepts(&self, pipe: Pipe) -> bool {
+        self.pipe().map_or(true, |x| x == pipe)
+    }
+
+    fn stats(&self) -> LogStats {
+        LogStats {
+            name: self.name(),
+            bytes_written: self.bytes_written(),
+            max_log_size: self.max_log_size(),
+            rotations: self.rotations(),
+            dropped_bytes: 0,
+            lines: self.line_stats,
+            last_write: self.last_write(),
+        }
+    }
+
+    /// Verify that the log file is writable and the rotation options are coherent.
+    async fn validate(&self) -> Result<()> {
+        if self.buffer_size() == 0 {
+            bail!("buffer size must not be zero")
+        }
+        self.rotation()
+            .validate(self.max_files(), self.compression(), self.naming())?;
+        if self.checksum() && self.rotation() == RotationMode::Truncate {
+            bail!("checksums require a rotation keeping backups")
+        }
+        #[cfg(feature = "s3")]
+        if self.upload().is_some() && self.rotation() == RotationMode::Truncate {
+            bail!("uploads require a rotation keeping backups")
+        }
+        if let Some(fallback_path) = self.fallback_path() {
+            container_log::validate_path(fallback_path).await?;
+        }
+        if let Some(link_path) = self.link_path() {
+            container_log::validate_path(link_path).await?;
+        }
+        if self.custom_sink() {
+            return Ok(());
+        }
+        container_log::validate_path(self.path()).await
+    }
+
+    /// Asynchronously initialize the CRI logger, where a custom sink is initialized already.
+    async fn init(&mut self) -> Result<()> {
+        if self.custom_sink() {
+            return Ok(());
+        }
+        debug!("Initializing CRI logger in path {}", self.path().display());
+        self.open_file(false).await?;
+        self.write_identity().await
+    }
+
+    async fn write(
+        &mut self,
+        pipe: Pipe,
+        bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> Result<usize> {
+        Ok(CriLogger::write(self, pipe, bytes).await?)
+    }
+
+    /// Write the marker as a full stderr record with a message like `# conmon: dropped N bytes`,
+    /// which keeps the file parseable by the kubelet.
+    async fn write_marker(&mut self, dropped_bytes: usize) -> Result<()> {
+        let marker = format!("{} {} bytes\n", Self::MARKER_PREFIX, dropped_bytes);
+        CriLogger::write(self, Pipe::StdErr, marker.as_bytes()).await?;
+        Ok(())
+    }
+
+    /// Write the event as a full stdout record with a message like `# conmon: {"event":...}`,
+    /// because the CRI format has no other place for it. Callers only write events which got
+    /// enabled explicitly, like the exit event.
+    async fn write_event(&mut self, event: LogEvent) -> Result<()> {
+        let line = format!("{} {}\n", Self::EVENT_PREFIX, event);
+        CriLogger::write(self, Pipe::StdOut, line.as_bytes()).await?;
+        Ok(())
+    }
+
+    /// Reopen the container log file, where a custom sink only gets flushed.
+    async fn reopen(&mut self) -> Result<()> {
+        debug!("Reopen container log {}", self.path().display());
+        self.flush().await?;
+        if self.custom_sink() {
+            return Ok(());
+        }
+        self.file
+            .as_mut()
+            .ok_or(LoggerError::Uninitialized)?
+            .get_ref()
+            .sync_all()
+            .await?;
+        self.backup_task.wait().await;
+        let now = self.clock.now();
+        let backup = self
+            .rotation()
+            .rotate(
+                self.path(),
+                now,
+                self.max_files(),
+                self.compression(),
+                self.naming(),
+            )
+            .await
+            .map_err(|source| LoggerError::RotationFailed {
+                path: self.path().clone(),
+                source,
+            })?;
+        if let Some(backup) = backup {
+            let checksum = self.checksum();
+            #[cfg(feature = "s3")]
+            let upload = match self.upload() {
+                Some(upload) => Some((
+                    upload.clone(),
+                    upload.key(self.path(), now, self.compression())?,
+                )),
+                None => None,
+            };
+            self.backup_task
+                .spawn(backup, self.compression(), move |backup| async move {
+                    if checksum {
+                        log_rotation::write_checksum(backup.clone()).await?;
+                    }
+                    #[cfg(feature = "s3")]
+                    if let Some((upload, key)) = upload {
+                        upload.start(backup, key).await?;
+                    }
+                    Ok(())
+                });
+        }
+        self.init().await
+    }
+
+    /// Ensures that all content is written to disk.
+    async fn flush(&mut self) -> Result<()> {
+        self.unflushed.reset();
+        self.file
+            .as_mut()
+            .ok_or(LoggerError::Uninitialized)?
+            .flush()
+            .await
+            .context("flush file writer")?;
+        self.retained.clear();
+        Ok(())
+    }
+
+    /// Flush the buffered records and sync the log file before closing it. Partial lines get
+    /// written as partial records right away, hence none are retained.
+    async fn close(&mut self) -> Result<()> {
+        self.backup_task.wait().await;
+        #[cfg(feature = "s3")]
+        if let Some(upload) = self.upload() {
+            upload.wait().await;
+        }
+        if self.file.is_none() {
+            return Ok(());
+        }
+        self.flush().await?;
+        if let Some(file) = self.file.take() {
+            file.get_ref().sync_all().await.context("sync log file")?;
+        }
+        self.sync_task = None;
+        if let Some(budget) = &self.budget {
+            budget.unregister(self.path()).await?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::fs;
+    use crate::clock::ManualClock;
+    use flate2::read::GzDecoder;
+    use sha2::{Digest, Sha256};
+    use std::{fs, io::Read, os::fd::AsRawFd, time::UNIX_EPOCH};
     use tempfile::NamedTempFile;
     use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+    use tokio::io::AsyncReadExt;
 
     #[tokio::test]
     async fn write_stdout_success() -> Result<()> {
@@ -227,6 +879,52 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn close_unflushed() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut sut = CriLogger::new(path, None)?;
+        sut.set_flush_threshold(FlushThreshold {
+            lines: Some(100),
+            bytes: None,
+        });
+        sut.init().await?;
+
+        sut.write(Pipe::StdOut, "a\npartial".as_bytes()).await?;
+        assert_eq!(fs::read_to_string(path)?, "");
+
+        sut.close().await?;
+        let res = fs::read_to_string(path)?;
+        assert!(res.contains(" stdout F a\n"), "{res}");
+        assert!(res.contains(" stdout P partial\n"), "{res}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_custom_sink() -> Result<()> {
+        let (writer, mut reader) = io::duplex(64 * 1024);
+        let mut sut = CriLogger::new_with_sink(writer)?;
+        sut.validate().await?;
+        sut.init().await?;
+        assert!(sut.source().is_none());
+
+        sut.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+        // Reopening only flushes the sink instead of rotating it.
+        sut.reopen().await?;
+        sut.write(Pipe::StdErr, "world\n".as_bytes()).await?;
+        sut.close().await?;
+
+        let mut output = String::new();
+        reader.read_to_string(&mut output).await?;
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].ends_with(" stdout F hello"));
+        assert!(lines[1].ends_with(" stderr F world"));
+        let timestamp = lines[0].split_whitespace().next().context("no timestamp")?;
+        OffsetDateTime::parse(timestamp, &Rfc3339).context("unable to parse timestamp")?;
+        Ok(())
+    }
+
     #[tokio::test]
     async fn write_stdout_stderr_success() -> Result<()> {
         let buffer = "a\nb\nc\n";
@@ -251,6 +949,99 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn write_buffer_size() -> Result<()> {
+        let line = format!("{}\n", "a".repeat(40));
+
+        for (buffer_size, expected) in [
+            (DEFAULT_BUFFER_SIZE, ["F"].as_slice()),
+            (16, &["P", "P", "F"]),
+        ] {
+            let file = NamedTempFile::new()?;
+            let path = file.path();
+            let mut sut = CriLogger::new(path, None)?;
+            sut.set_buffer_size(buffer_size);
+            sut.init().await?;
+
+            sut.write(Pipe::StdOut, line.as_bytes()).await?;
+
+            let res = fs::read_to_string(path)?;
+            let tags = res
+                .lines()
+                .filter_map(|x| x.split(' ').nth(2))
+                .collect::<Vec<_>>();
+            assert_eq!(tags, expected, "{buffer_size}");
+            let message = res
+                .lines()
+                .filter_map(|x| x.splitn(4, ' ').nth(3))
+                .collect::<String>();
+            assert_eq!(message, line.trim_end());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_uninitialized() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let mut sut = CriLogger::new(file.path(), None)?;
+
+        let res = sut.write(Pipe::StdOut, "a\n".as_bytes()).await;
+        assert!(matches!(res, Err(LoggerError::Uninitialized)), "{res:?}");
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn init_no_follow() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let target = dir.path().join("target");
+        fs::write(&target, "sensitive")?;
+        let path = dir.path().join("0.log");
+        std::os::unix::fs::symlink(&target, &path)?;
+
+        let mut sut = CriLogger::new(&path, None)?;
+        sut.set_file_access(FileAccess {
+            no_follow: true,
+            ..Default::default()
+        });
+        let err = sut.init().await.unwrap_err();
+        assert!(
+            matches!(LoggerError::from(err), LoggerError::Symlink(x) if x == path),
+            "symlink not refused"
+        );
+        assert_eq!(fs::read_to_string(&target)?, "sensitive");
+
+        // Following the symlink is allowed by default.
+        sut.set_file_access(FileAccess::default());
+        sut.init().await?;
+        assert!(fs::read_to_string(&target)?.is_empty());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn write_link_path() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("0.log");
+        let link_path = dir.path().join("latest");
+        let mut sut = CriLogger::new(&path, None)?;
+        sut.set_rotation(RotationMode::Rename)
+            .set_link_path(Some(link_path.clone()));
+        sut.init().await?;
+        sut.write(Pipe::StdOut, "a\n".as_bytes()).await?;
+        assert_eq!(fs::read_link(&link_path)?, Path::new("0.log"));
+
+        sut.reopen().await?;
+        sut.write(Pipe::StdOut, "b\n".as_bytes()).await?;
+
+        assert_eq!(fs::read_link(&link_path)?, Path::new("0.log"));
+        let newest = fs::read_to_string(&link_path)?;
+        assert!(newest.contains(" stdout F b"));
+        assert!(!newest.contains(" stdout F a"));
+        assert!(!dir.path().join("latest.tmp").exists());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn write_reopen() -> Result<()> {
         let buffer = "a\nb\nc\nd\ne\nf\n";
@@ -292,6 +1083,494 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn write_rotate_max_files() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("0.log");
+        let mut sut = CriLogger::new(&path, Some(50))?;
+        sut.set_rotation(RotationMode::Rename)
+            .set_max_files(Some(2));
+        sut.init().await?;
+
+        // Only a single line fits into the maximum size, which results in nine rotations.
+        sut.write(Pipe::StdOut, "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n".as_bytes())
+            .await?;
+
+        let mut files = fs::read_dir(dir.path())?
+            .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+            .collect::<Result<Vec<_>>>()?;
+        files.sort();
+        assert_eq!(files, ["0.log", "0.log.1", "0.log.2"]);
+        assert!(fs::read_to_string(&path)?.contains(" stdout F j"));
+        assert!(
+            fs::read_to_string(log_rotation::backup_path(&path, 1, Compression::None))?
+                .contains(" stdout F i")
+        );
+        assert!(
+            fs::read_to_string(log_rotation::backup_path(&path, 2, Compression::None))?
+                .contains(" stdout F h")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_checksum() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("0.log");
+        let mut sut = CriLogger::new(&path, None)?;
+        sut.set_rotation(RotationMode::Rename).set_checksum(true);
+        sut.validate().await?;
+        sut.init().await?;
+
+        let assert_checksum = |generation| -> Result<()> {
+            let backup = log_rotation::backup_path(&path, generation, Compression::None);
+            let digest = Sha256::digest(fs::read(&backup)?);
+            assert_eq!(
+                fs::read_to_string(log_rotation::checksum_path(&backup))?,
+                format!("{:x}  0.log.{}\n", digest, generation)
+            );
+            Ok(())
+        };
+
+        sut.write(Pipe::StdOut, "a\n".as_bytes()).await?;
+        sut.reopen().await?;
+        // The checksum gets written in the background.
+        sut.backup_task.wait().await;
+        assert_checksum(1)?;
+
+        // The sidecar follows its backup to the next generation.
+        sut.write(Pipe::StdOut, "b\n".as_bytes()).await?;
+        sut.reopen().await?;
+        sut.backup_task.wait().await;
+        assert_checksum(1)?;
+        assert_checksum(2)?;
+
+        sut.set_rotation(RotationMode::Truncate);
+        assert!(sut.validate().await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_reconcile_external_append() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("0.log");
+        let mut sut = CriLogger::new(&path, Some(200))?;
+        sut.set_rotation(RotationMode::Rename)
+            .set_reconcile_interval(Some(Duration::ZERO));
+        sut.init().await?;
+        sut.write(Pipe::StdOut, "a\n".as_bytes()).await?;
+
+        // The externally appended data only fits into the maximum size without the next line.
+        let external = format!("{}\n", "x".repeat(149));
+        let mut file = fs::OpenOptions::new().append(true).open(&path)?;
+        std::io::Write::write_all(&mut file, external.as_bytes())?;
+        sut.write(Pipe::StdOut, "b\n".as_bytes()).await?;
+
+        assert_eq!(sut.rotations(), 1);
+        let backup = fs::read_to_string(log_rotation::backup_path(&path, 1, Compression::None))?;
+        assert!(backup.contains(" stdout F a\n"));
+        assert!(backup.ends_with(&external));
+        assert!(fs::read_to_string(&path)?.contains(" stdout F b"));
+        assert_eq!(
+            u64::try_from(sut.bytes_written())?,
+            fs::metadata(&path)?.len()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_line_exceeding_max_size() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("0.log");
+        let mut sut = CriLogger::new(&path, Some(10))?;
+        sut.set_rotation(RotationMode::Rename);
+        sut.init().await?;
+
+        let line = format!("{}\n", "x".repeat(99));
+        sut.write(Pipe::StdOut, line.as_bytes()).await?;
+        sut.write(Pipe::StdOut, line.as_bytes()).await?;
+        sut.flush().await?;
+
+        let expected = format!(" stdout F {line}");
+        let backup = fs::read_to_string(log_rotation::backup_path(&path, 1, Compression::None))?;
+        assert_eq!(backup.lines().count(), 1);
+        assert!(backup.ends_with(&expected));
+        let live = fs::read_to_string(&path)?;
+        assert_eq!(live.lines().count(), 1);
+        assert!(live.ends_with(&expected));
+        assert_eq!(sut.rotations(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_rotate_interval() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("0.log");
+        let start = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let clock = Arc::new(ManualClock::new(start));
+        let mut sut = CriLogger::new(&path, Some(1024))?;
+        sut.set_rotation(RotationMode::Timestamp)
+            .set_rotate_interval(Some(Duration::from_secs(3600)))
+            .set_clock(clock.clone());
+        sut.init().await?;
+
+        sut.write(Pipe::StdOut, "a\n".as_bytes()).await?;
+        clock.advance(Duration::from_secs(3599));
+        sut.write(Pipe::StdOut, "b\n".as_bytes()).await?;
+        assert_eq!(sut.rotations(), 0);
+
+        clock.advance(Duration::from_secs(1));
+        sut.write(Pipe::StdOut, "c\n".as_bytes()).await?;
+        assert_eq!(sut.rotations(), 1);
+
+        let backup = log_rotation::BackupFormat::timestamp().timestamp_path(
+            &path,
+            start + Duration::from_secs(3600),
+            Compression::None,
+        )?;
+        assert_eq!(
+            parse_cri_log(&fs::read_to_string(backup)?)?,
+            [
+                ("stdout".into(), "a\n".into()),
+                ("stdout".into(), "b\n".into())
+            ]
+        );
+        assert_eq!(
+            parse_cri_log(&fs::read_to_string(&path)?)?,
+            [("stdout".into(), "c\n".into())]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_dsync() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut sut = CriLogger::new(path, None)?;
+        sut.set_durability(DurabilityPolicy::Dsync);
+        sut.init().await?;
+
+        let fd = sut
+            .file
+            .as_ref()
+            .and_then(|x| x.get_ref().file())
+            .context("no file")?
+            .as_raw_fd();
+        let fdinfo = fs::read_to_string(format!("/proc/self/fdinfo/{fd}"))?;
+        let flags = fdinfo
+            .lines()
+            .find_map(|line| line.strip_prefix("flags:"))
+            .context("no flags")?;
+        assert_ne!(i32::from_str_radix(flags.trim(), 8)? & libc::O_DSYNC, 0);
+
+        sut.write(Pipe::StdOut, "durable\n".as_bytes()).await?;
+        assert_eq!(
+            parse_cri_log(&fs::read_to_string(path)?)?,
+            [("stdout".into(), "durable\n".into())]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_watch_path() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("0.log");
+        let mut sut = CriLogger::new(&path, None)?;
+        sut.set_watch_path(true);
+        sut.init().await?;
+
+        sut.write(Pipe::StdOut, "a\n".as_bytes()).await?;
+        fs::remove_file(&path)?;
+        sut.write(Pipe::StdOut, "b\n".as_bytes()).await?;
+        sut.write(Pipe::StdOut, "c\n".as_bytes()).await?;
+
+        let contents = fs::read_to_string(&path)?;
+        assert_eq!(
+            parse_cri_log(&contents)?,
+            [
+                ("stdout".into(), "b\n".into()),
+                ("stdout".into(), "c\n".into())
+            ]
+        );
+        assert_eq!(sut.bytes_written(), contents.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reopen_compress() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("0.log");
+        let mut sut = CriLogger::new(&path, None)?;
+        sut.set_rotation(RotationMode::Rename)
+            .set_compression(Compression::Gzip);
+        sut.init().await?;
+
+        sut.write(Pipe::StdOut, "a\nb\n".as_bytes()).await?;
+        let expected = fs::read(&path)?;
+        sut.reopen().await?;
+        // The backup gets compressed in the background.
+        assert!(log_rotation::backup_path(&path, 1, Compression::None).exists());
+        sut.backup_task.wait().await;
+        assert!(!log_rotation::backup_path(&path, 1, Compression::None).exists());
+
+        let mut contents = Vec::new();
+        GzDecoder::new(fs::File::open(log_rotation::backup_path(
+            &path,
+            1,
+            Compression::Gzip,
+        ))?)
+        .read_to_end(&mut contents)?;
+        assert_eq!(contents, expected);
+        assert!(fs::read(&path)?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_max_line_size() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut sut = CriLogger::new(path, None)?;
+        sut.set_max_line_size(Some(4));
+        sut.init().await?;
+
+        sut.write(Pipe::StdOut, "abcdefghij\n".as_bytes()).await?;
+
+        let res = fs::read_to_string(path)?;
+        let lines = res
+            .lines()
+            .map(|line| line.split_once(" stdout ").map(|(_, x)| x))
+            .collect::<Option<Vec<_>>>()
+            .context("no stdout lines")?;
+        assert_eq!(lines, ["P abcd", "P efgh", "F ij"]);
+        Ok(())
+    }
+
+    /// Parse the records similar to the kubelet and reconstruct the full lines per stream.
+    fn parse_cri_log(contents: &str) -> Result<Vec<(String, String)>> {
+        let mut lines = Vec::new();
+        let mut partial = String::new();
+        for record in contents.split_inclusive('\n') {
+            let (timestamp, rest) = record.split_once(' ').context("no timestamp")?;
+            OffsetDateTime::parse(timestamp, &Rfc3339).context("unable to parse timestamp")?;
+            let (stream, rest) = rest.split_once(' ').context("no stream")?;
+            assert!(
+                ["stdout", "stderr"].contains(&stream),
+                "invalid stream {stream}"
+            );
+            let (tag, message) = rest.split_once(' ').context("no tag")?;
+            match tag {
+                "P" => partial.push_str(message.strip_suffix('\n').context("no newline")?),
+                "F" => {
+                    partial.push_str(message);
+                    lines.push((stream.into(), std::mem::take(&mut partial)));
+                }
+                _ => panic!("invalid tag {tag}"),
+            }
+        }
+        assert!(partial.is_empty());
+        Ok(lines)
+    }
+
+    #[tokio::test]
+    async fn write_long_line_reconstruct() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut sut = CriLogger::new(path, None)?;
+        sut.set_max_line_size(Some(16));
+        sut.init().await?;
+
+        let long_line = format!("{}\n", "0123456789".repeat(5));
+        sut.write(Pipe::StdOut, long_line.as_bytes()).await?;
+        sut.write(Pipe::StdErr, "short\n".as_bytes()).await?;
+
+        let res = fs::read_to_string(path)?;
+        let tags = res
+            .lines()
+            .map(|line| line.split(' ').nth(2))
+            .collect::<Option<Vec<_>>>()
+            .context("no tags")?;
+        assert_eq!(tags, ["P", "P", "P", "F", "F"]);
+        assert_eq!(
+            parse_cri_log(&res)?,
+            [
+                ("stdout".into(), long_line),
+                ("stderr".into(), "short\n".into())
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_stdin_discarded() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut sut = CriLogger::new(path, None)?;
+        sut.init().await?;
+
+        assert_eq!(sut.write(Pipe::StdIn, "ls\n".as_bytes()).await?, 3);
+        assert!(fs::read_to_string(path)?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_marker_success() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut sut = CriLogger::new(path, None)?;
+        sut.init().await?;
+
+        sut.write_marker(42).await?;
+
+        let res = fs::read_to_string(path)?;
+        assert!(res.ends_with(" stderr F # conmon: dropped 42 bytes\n"));
+        assert_eq!(
+            parse_cri_log(&res)?,
+            [("stderr".into(), "# conmon: dropped 42 bytes\n".into())]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_line_stats() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let mut sut = CriLogger::new(file.path(), None)?;
+        sut.set_max_line_size(Some(100));
+        sut.init().await?;
+
+        let long = "x".repeat(300);
+        sut.write(Pipe::StdOut, format!("a\n{long}\n").as_bytes())
+            .await?;
+        sut.write(Pipe::StdErr, "b\n".as_bytes()).await?;
+
+        // The long line got split, but is accounted once with its full length.
+        let stats = sut.stats().lines;
+        assert_eq!(stats.stdout.lines, 2);
+        assert_eq!(stats.stdout.buckets, [1, 0, 1, 0, 0, 0]);
+        assert_eq!(stats.stderr.lines, 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_disk_full_retries() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut sut = CriLogger::new(path, None)?;
+        sut.set_disk_full_retries(3);
+        sut.init().await?;
+
+        sut.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+        sut.write(Pipe::StdErr, "world\n".as_bytes()).await?;
+
+        let lines = parse_cri_log(&fs::read_to_string(path)?)?;
+        assert_eq!(
+            lines,
+            [
+                ("stdout".into(), "hello\n".into()),
+                ("stderr".into(), "world\n".into()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_durability_every_write() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut sut = CriLogger::new(path, None)?;
+        sut.set_durability(DurabilityPolicy::EveryWrite);
+        sut.init().await?;
+
+        sut.write(Pipe::StdOut, "hello world\n".as_bytes()).await?;
+
+        // Read through a second handle without calling flush on the logger.
+        assert!(fs::read_to_string(path)?.contains(" stdout F hello world\n"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_empty_lines() -> Result<()> {
+        for (empty_lines, expected) in [
+            (EmptyLines::Keep, vec!["a\n", "\n", "\r\n", " \n", "b\n"]),
+            (EmptyLines::Drop, vec!["a\n", " \n", "b\n"]),
+        ] {
+            let file = NamedTempFile::new()?;
+            let path = file.path();
+            let mut sut = CriLogger::new(path, None)?;
+            sut.set_empty_lines(empty_lines);
+            sut.init().await?;
+
+            sut.write(Pipe::StdOut, "a\n\n\r\n \nb\n".as_bytes())
+                .await?;
+
+            let messages = parse_cri_log(&fs::read_to_string(path)?)?
+                .into_iter()
+                .map(|(_, message)| message)
+                .collect::<Vec<_>>();
+            assert_eq!(messages, expected, "{empty_lines:?}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_flush_threshold() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut sut = CriLogger::new(path, None)?;
+        sut.set_flush_threshold(FlushThreshold {
+            lines: Some(3),
+            bytes: None,
+        });
+        sut.init().await?;
+
+        // Read through a second handle without calling flush on the logger.
+        sut.write(Pipe::StdOut, "one\ntwo\n".as_bytes()).await?;
+        assert_eq!(fs::read_to_string(path)?, "");
+
+        sut.write(Pipe::StdOut, "three\n".as_bytes()).await?;
+        assert_eq!(
+            parse_cri_log(&fs::read_to_string(path)?)?,
+            [
+                ("stdout".into(), "one\n".into()),
+                ("stdout".into(), "two\n".into()),
+                ("stdout".into(), "three\n".into())
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_fallback_path() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let fallback_path = file.path();
+        // Writes to the primary path fail because the device is always full.
+        let mut sut = CriLogger::new("/dev/full", None)?;
+        sut.set_fallback_path(Some(fallback_path.into()));
+        sut.init().await?;
+
+        sut.write(Pipe::StdOut, "one\n".as_bytes()).await?;
+        sut.write(Pipe::StdOut, "two\n".as_bytes()).await?;
+
+        assert_eq!(sut.path(), fallback_path);
+        assert!(sut.fallback_path().is_none());
+        assert_eq!(
+            parse_cri_log(&fs::read_to_string(fallback_path)?)?,
+            [
+                ("stdout".into(), "one\n".into()),
+                ("stdout".into(), "two\n".into())
+            ]
+        );
+        assert_eq!(
+            sut.bytes_written() as u64,
+            fs::metadata(fallback_path)?.len()
+        );
+
+        // Without fallback path the failure gets returned.
+        let mut sut = CriLogger::new("/dev/full", None)?;
+        sut.init().await?;
+        assert!(sut.write(Pipe::StdOut, "one\n".as_bytes()).await.is_err());
+        Ok(())
+    }
+
     #[tokio::test]
     async fn init_failure() -> Result<()> {
         let mut sut = CriLogger::new("/file/does/not/exist", None)?;
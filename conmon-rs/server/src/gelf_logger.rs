@@ -0,0 +1,362 @@
+//! Graylog Extended Log Format (GELF) logging functionalities.
+
+use crate::{
+    clock::{Clock, SystemClock},
+    container_io::Pipe,
+    container_log::Logger,
+    line_buffer::LineBuffer,
+};
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use getset::{CopyGetters, Getters, Setters};
+use nix::unistd;
+use serde::Serialize;
+use std::{sync::Arc, time::UNIX_EPOCH};
+use tokio::{
+    io::{AsyncBufRead, AsyncWriteExt},
+    net::{TcpStream, UdpSocket},
+};
+use tracing::debug;
+
+#[derive(Debug, Getters, CopyGetters, Setters)]
+/// Logger sending container output as GELF messages to Graylog or any compatible receiver, on
+/// par with the Docker `gelf` logging driver.
+pub struct GelfLogger {
+    #[getset(get = "pub")]
+    /// Address of the receiver, either `udp://<host>:<port>` or `tcp://<host>:<port>`.
+    address: String,
+
+    #[getset(get = "pub")]
+    /// Identifier of the container, added as `_container_id` field.
+    container_id: String,
+
+    /// Hostname included in every message.
+    host: String,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Maximum size of a single UDP datagram, where larger messages get chunked.
+    chunk_size: usize,
+
+    /// Connected socket to the receiver.
+    socket: Option<GelfSocket>,
+
+    /// Identifier of the next chunked message.
+    message_id: u64,
+
+    /// Lines of every pipe assembled across writes.
+    lines: LineBuffer,
+
+    #[getset(set = "pub")]
+    /// Source of the message timestamps, which defaults to the system wall clock.
+    clock: Arc<dyn Clock>,
+}
+
+#[derive(Debug)]
+enum GelfSocket {
+    Udp(UdpSocket),
+    Tcp(TcpStream),
+}
+
+#[derive(Debug, Serialize)]
+/// A single GELF message, where additional fields are prefixed by an underscore.
+struct GelfMessage<'a> {
+    version: &'static str,
+    host: &'a str,
+    short_message: &'a str,
+    timestamp: f64,
+    level: u8,
+    #[serde(rename = "_pipe")]
+    pipe: String,
+    #[serde(rename = "_container_id", skip_serializing_if = "str::is_empty")]
+    container_id: &'a str,
+}
+
+impl GelfLogger {
+    const ERR_UNINITIALIZED: &'static str = "logger not initialized";
+
+    /// The default receiver address.
+    const DEFAULT_ADDRESS: &'static str = "udp://localhost:12201";
+
+    /// The prefix of an address for GELF over UDP.
+    const UDP_PREFIX: &'static str = "udp://";
+
+    /// The prefix of an address for GELF over TCP.
+    const TCP_PREFIX: &'static str = "tcp://";
+
+    /// The supported GELF specification version.
+    const VERSION: &'static str = "1.1";
+
+    /// The default UDP datagram size, which fits into the common Ethernet MTU.
+    pub const DEFAULT_CHUNK_SIZE: usize = 1420;
+
+    /// The magic bytes starting every chunk.
+    const CHUNK_MAGIC: [u8; 2] = [0x1e, 0x0f];
+
+    /// The length of the header of every chunk: magic, message ID, sequence number and count.
+    const CHUNK_HEADER_LEN: usize = 12;
+
+    /// The maximum amount of chunks of a single message according to the specification.
+    const MAX_CHUNKS: usize = 128;
+
+    /// Create a new GELF logger instance. An empty address uses the default
+    /// `udp://localhost:12201` receiver.
+    pub fn new(address: &str, container_id: &str) -> Result<GelfLogger> {
+        let address = if address.is_empty() {
+            Self::DEFAULT_ADDRESS
+        } else {
+            address
+        };
+        if !address.starts_with(Self::UDP_PREFIX) && !address.starts_with(Self::TCP_PREFIX) {
+            bail!("unsupported GELF address '{}'", address)
+        }
+        let host = unistd::gethostname()
+            .context("get hostname")?
+            .to_string_lossy()
+            .into_owned();
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+        // Seed the message IDs by the current time to keep them distinct across restarts.
+        let message_id = clock
+            .now()
+            .duration_since(UNIX_EPOCH)
+            .map(|x| x.as_nanos() as u64)
+            .unwrap_or_default();
+        Ok(Self {
+            address: address.into(),
+            container_id: container_id.into(),
+            host,
+            chunk_size: Self::DEFAULT_CHUNK_SIZE,
+            socket: None,
+            message_id,
+            lines: LineBuffer::default(),
+            clock,
+        })
+    }
+
+    /// Write the contents of the provided reader as one GELF message per line, where a trailing
+    /// line without newline is retained until more data for the same pipe arrives or
+    /// `flush_partial` is called.
+    pub async fn write<T>(&mut self, pipe: Pipe, bytes: T) -> Result<usize>
+    where
+        T: AsyncBufRead + Unpin,
+    {
+        let (read, lines) = self.lines.read(pipe, bytes).await?;
+        for line in lines {
+            self.send(pipe, &line).await?;
+        }
+        Ok(read)
+    }
+
+    /// Send a single line as one GELF message, which gets chunked over UDP if it exceeds the
+    /// chunk size and terminated by a null byte over TCP.
+    async fn send(&mut self, pipe: Pipe, line: &[u8]) -> Result<()> {
+        let mut message = self.format(pipe, line)?;
+        let message_id = self.message_id;
+        match self.socket.as_mut().context(Self::ERR_UNINITIALIZED)? {
+            GelfSocket::Udp(socket) => {
+                self.message_id = self.message_id.wrapping_add(1);
+                for chunk in Self::chunks(message_id, &message, self.chunk_size)? {
+                    socket.send(&chunk).await.context("send GELF chunk")?;
+                }
+            }
+            GelfSocket::Tcp(stream) => {
+                message.push(0);
+                stream
+                    .write_all(&message)
+                    .await
+                    .context("send GELF message")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Split a message into the datagrams sent over UDP. Messages fitting into a single datagram
+    /// are sent as is.
+    fn chunks(message_id: u64, message: &[u8], chunk_size: usize) -> Result<Vec<Vec<u8>>> {
+        if message.len() <= chunk_size {
+            return Ok(vec![message.into()]);
+        }
+        let payload_size = chunk_size
+            .checked_sub(Self::CHUNK_HEADER_LEN)
+            .filter(|x| *x > 0)
+            .context("GELF chunk size too small")?;
+        let count = (message.len() + payload_size - 1) / payload_size;
+        if count > Self::MAX_CHUNKS {
+            bail!(
+                "GELF message of {} bytes exceeds {} chunks",
+                message.len(),
+                Self::MAX_CHUNKS
+            )
+        }
+        Ok(message
+            .chunks(payload_size)
+            .enumerate()
+            .map(|(i, payload)| {
+                let mut chunk = Vec::with_capacity(Self::CHUNK_HEADER_LEN + payload.len());
+                chunk.extend_from_slice(&Self::CHUNK_MAGIC);
+                chunk.extend_from_slice(&message_id.to_be_bytes());
+                chunk.push(i as u8);
+                chunk.push(count as u8);
+                chunk.extend_from_slice(payload);
+                chunk
+            })
+            .collect())
+    }
+
+    async fn connect(&self) -> Result<GelfSocket> {
+        if let Some(addr) = self.address().strip_prefix(Self::TCP_PREFIX) {
+            let stream = TcpStream::connect(addr)
+                .await
+                .context(format!("connect to GELF address '{}'", self.address()))?;
+            return Ok(GelfSocket::Tcp(stream));
+        }
+
+        let addr = self
+            .address()
+            .strip_prefix(Self::UDP_PREFIX)
+            .unwrap_or(self.address());
+        let socket = UdpSocket::bind("0.0.0.0:0")
+            .await
+            .context("bind UDP socket")?;
+        socket
+            .connect(addr)
+            .await
+            .context(format!("connect to GELF address '{}'", self.address()))?;
+        Ok(GelfSocket::Udp(socket))
+    }
+
+    /// Format a single line as JSON encoded GELF message.
+    fn format(&self, pipe: Pipe, line: &[u8]) -> Result<Vec<u8>> {
+        let (secs, nanos) = self.clock.unix_time()?;
+        let message = String::from_utf8_lossy(line);
+        serde_json::to_vec(&GelfMessage {
+            version: Self::VERSION,
+            host: &self.host,
+            short_message: message.trim_end_matches('\n'),
+            timestamp: secs as f64 + f64::from(nanos / 1_000_000) / 1000.0,
+            level: pipe.severity().code(),
+            pipe: pipe.as_str().into(),
+            container_id: self.container_id(),
+        })
+        .context("serialize GELF message")
+    }
+}
+
+#[async_trait]
+impl Logger for GelfLogger {
+    fn name(&self) -> &'static str {
+        "gelf"
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.socket.is_some()
+    }
+
+    /// Asynchronously connect to the receiver.
+    async fn init(&mut self) -> Result<()> {
+        debug!("Initializing GELF logger for address {}", self.address());
+        self.socket = Some(self.connect().await?);
+        Ok(())
+    }
+
+    async fn write(
+        &mut self,
+        pipe: Pipe,
+        bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> Result<usize> {
+        GelfLogger::write(self, pipe, bytes).await
+    }
+
+    async fn write_line(&mut self, pipe: Pipe, line: &[u8]) -> Result<()> {
+        self.send(pipe, line).await
+    }
+
+    async fn flush_partial(&mut self, pipe: Pipe) -> Result<()> {
+        if let Some(line) = self.lines.take(pipe) {
+            self.send(pipe, &line).await?;
+        }
+        Ok(())
+    }
+
+    /// Reconnect to the receiver.
+    async fn reopen(&mut self) -> Result<()> {
+        debug!("Reopen GELF logger for address {}", self.address());
+        self.socket.as_ref().context(Self::ERR_UNINITIALIZED)?;
+        self.init().await
+    }
+
+    /// Messages are sent unbuffered, which means there is nothing to flush.
+    async fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.socket = None;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    async fn receiver() -> Result<(UdpSocket, String)> {
+        let server = UdpSocket::bind("127.0.0.1:0").await?;
+        let address = format!("udp://{}", server.local_addr()?);
+        Ok((server, address))
+    }
+
+    #[tokio::test]
+    async fn write_success() -> Result<()> {
+        let (server, address) = receiver().await?;
+        let mut sut = GelfLogger::new(&address, "0123456789abcdef")?;
+        sut.init().await?;
+        sut.write(Pipe::StdErr, "hello world\n".as_bytes()).await?;
+
+        let mut buf = vec![0; 2048];
+        let len = server.recv(&mut buf).await?;
+        let message: Value = serde_json::from_slice(&buf[..len])?;
+
+        assert_eq!(message["version"], "1.1");
+        assert_eq!(message["host"], sut.host);
+        assert_eq!(message["short_message"], "hello world");
+        assert!(message["timestamp"].as_f64().context("no timestamp")? > 0.0);
+        assert_eq!(message["level"], 3);
+        assert_eq!(message["_pipe"], "stderr");
+        assert_eq!(message["_container_id"], "0123456789abcdef");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_chunked() -> Result<()> {
+        let (server, address) = receiver().await?;
+        let mut sut = GelfLogger::new(&address, "id")?;
+        sut.set_chunk_size(100);
+        sut.init().await?;
+        let line = "x".repeat(300);
+        sut.write_line(Pipe::StdOut, line.as_bytes()).await?;
+
+        let mut buf = vec![0; 2048];
+        let mut message = vec![];
+        let mut count = None;
+        for i in 0.. {
+            let len = server.recv(&mut buf).await?;
+            assert!(len <= 100);
+            let chunk = &buf[..len];
+            assert_eq!(chunk[..2], GelfLogger::CHUNK_MAGIC);
+            assert_eq!(chunk[10], i);
+            count = count.or(Some(chunk[11]));
+            message.extend_from_slice(&chunk[GelfLogger::CHUNK_HEADER_LEN..]);
+            if Some(i + 1) == count {
+                break;
+            }
+        }
+        let message: Value = serde_json::from_slice(&message)?;
+        assert_eq!(message["short_message"], line);
+
+        assert!(GelfLogger::chunks(0, &[0; 200], 1).is_err());
+        assert!(GelfLogger::chunks(0, &[0; 10_000], 20).is_err());
+        assert!(GelfLogger::new("unix:///dev/log", "id").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+//! Journald logging functionalities.
+
+use crate::{
+    container_io::{Pipe, Severity},
+    container_log::Logger,
+    line_buffer::LineBuffer,
+};
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use getset::Getters;
+use libsystemd::logging::{self, Priority, SD_JOURNAL_SOCK_PATH};
+use tokio::{fs, io::AsyncBufRead, task};
+use tracing::debug;
+
+#[derive(Debug, Getters)]
+/// Logger forwarding container output to the systemd journal.
+pub struct JournaldLogger {
+    #[getset(get)]
+    /// Identifier of the container, attached to every journal entry.
+    container_id: String,
+
+    /// Indicates if the journal socket has been verified to exist.
+    initialized: bool,
+
+    /// Lines of every pipe assembled across writes.
+    lines: LineBuffer,
+}
+
+impl JournaldLogger {
+    const ERR_UNINITIALIZED: &'static str = "logger not initialized";
+
+    /// The length of the short container identifier, similar to the docker journald driver.
+    const SHORT_ID_LEN: usize = 12;
+
+    /// Create a new journald logger instance.
+    pub fn new(container_id: &str) -> Result<JournaldLogger> {
+        Ok(Self {
+            container_id: container_id.into(),
+            initialized: false,
+            lines: LineBuffer::default(),
+        })
+    }
+
+    /// Write the contents of the provided reader as one journal entry per line, where a trailing
+    /// line without newline is retained until more data for the same pipe arrives or
+    /// `flush_partial` is called.
+    pub async fn write<T>(&mut self, pipe: Pipe, bytes: T) -> Result<usize>
+    where
+        T: AsyncBufRead + Unpin,
+    {
+        if !self.initialized {
+            bail!(Self::ERR_UNINITIALIZED)
+        }
+
+        let (read, lines) = self.lines.read(pipe, bytes).await?;
+        self.send(pipe, lines).await?;
+        Ok(read)
+    }
+
+    /// Send every line as one journal entry. Sending blocks on the journal socket, which is why
+    /// it runs on the blocking thread pool instead of the runtime.
+    async fn send(&self, pipe: Pipe, lines: Vec<Vec<u8>>) -> Result<()> {
+        if lines.is_empty() {
+            return Ok(());
+        }
+        let container_id = self.container_id().clone();
+        task::spawn_blocking(move || {
+            for line in lines {
+                let message = String::from_utf8_lossy(&line);
+                logging::journal_send(
+                    Self::priority(pipe),
+                    message.trim_end_matches('\n'),
+                    Self::fields(&container_id).into_iter(),
+                )
+                .context("send journal entry")?;
+            }
+            Ok(())
+        })
+        .await
+        .context("wait for journal entries to be sent")?
+    }
+
+    /// Map the pipe to the journal priority of its entries.
+    fn priority(pipe: Pipe) -> Priority {
+        match pipe.severity() {
+            Severity::Info => Priority::Info,
+            Severity::Error => Priority::Error,
+        }
+    }
+
+    /// Additional structured fields attached to every entry of the container.
+    fn fields(container_id: &str) -> [(&str, &str); 2] {
+        let short_id = container_id
+            .get(..Self::SHORT_ID_LEN)
+            .unwrap_or(container_id);
+        [
+            ("CONTAINER_ID", short_id),
+            ("CONTAINER_ID_FULL", container_id),
+        ]
+    }
+}
+
+#[async_trait]
+impl Logger for JournaldLogger {
+    fn name(&self) -> &'static str {
+        "journald"
+    }
+
+    /// Verify that the journal socket is available.
+    async fn init(&mut self) -> Result<()> {
+        debug!("Initializing journald logger via {}", SD_JOURNAL_SOCK_PATH);
+        if !fs::try_exists(SD_JOURNAL_SOCK_PATH)
+            .await
+            .context("check journal socket")?
+        {
+            bail!("journal socket '{}' does not exist", SD_JOURNAL_SOCK_PATH)
+        }
+        self.initialized = true;
+        Ok(())
+    }
+
+    async fn write(
+        &mut self,
+        pipe: Pipe,
+        bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> Result<usize> {
+        JournaldLogger::write(self, pipe, bytes).await
+    }
+
+    async fn write_line(&mut self, pipe: Pipe, line: &[u8]) -> Result<()> {
+        if !self.initialized {
+            bail!(Self::ERR_UNINITIALIZED)
+        }
+        self.send(pipe, vec![line.to_vec()]).await
+    }
+
+    async fn flush_partial(&mut self, pipe: Pipe) -> Result<()> {
+        if let Some(line) = self.lines.take(pipe) {
+            self.send(pipe, vec![line]).await?;
+        }
+        Ok(())
+    }
+
+    /// The journal does not have to be reopened, which means that this only verifies the socket.
+    async fn reopen(&mut self) -> Result<()> {
+        debug!("Reopen journald logger");
+        if !self.initialized {
+            bail!(Self::ERR_UNINITIALIZED)
+        }
+        self.init().await
+    }
+
+    /// Messages are sent unbuffered, which means there is nothing to flush.
+    async fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.initialized = false;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fields_success() -> Result<()> {
+        assert_eq!(
+            JournaldLogger::fields("0123456789abcdef"),
+            [
+                ("CONTAINER_ID", "0123456789ab"),
+                ("CONTAINER_ID_FULL", "0123456789abcdef"),
+            ]
+        );
+        assert_eq!(u8::from(JournaldLogger::priority(Pipe::StdOut)), 6);
+        assert_eq!(u8::from(JournaldLogger::priority(Pipe::StdErr)), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_uninitialized() -> Result<()> {
+        let mut sut = JournaldLogger::new("id")?;
+        assert!(sut.write(Pipe::StdOut, "hello\n".as_bytes()).await.is_err());
+        assert!(sut.reopen().await.is_err());
+        Ok(())
+    }
+}
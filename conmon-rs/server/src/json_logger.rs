@@ -1,154 +1,3069 @@
-use crate::container_io::Pipe;
-use anyhow::{Context, Result};
+#[cfg(feature = "s3")]
+use crate::log_upload::S3Upload;
+use crate::{
+    clock::{Clock, SystemClock},
+    container_io::Pipe,
+    container_log::{
+        self, EmptyLines, FileAccess, LogEvent, LogStats, Logger, DEFAULT_BUFFER_SIZE,
+    },
+    line_buffer,
+    log_budget::LogDiskBudget,
+    log_durability::{self, DurabilityPolicy, FlushThreshold, SyncTask, Unflushed},
+    log_error::LoggerError,
+    log_metrics::PipeStats,
+    log_reader::LogFormat,
+    log_retry,
+    log_rotation::{self, BackupTask, Compression, RotationMode, RotationNaming},
+    log_sink::LogSink,
+    log_transform::{self, LogTransform},
+    telemetry::Telemetry,
+};
+use anyhow::{bail, Context, Error, Result};
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use getset::{CopyGetters, Getters, Setters};
-use serde_json::json;
-use std::path::{Path, PathBuf};
+use memchr::memchr;
+use opentelemetry::trace::{SpanId, TraceId};
+use regex::Regex;
+use serde::{
+    ser::{Error as _, SerializeMap},
+    Serialize, Serializer,
+};
+use serde_json::Value;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    io::SeekFrom,
+    mem,
+    path::{Path, PathBuf},
+    str,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
 use tokio::{
-    fs::{File, OpenOptions},
-    io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter},
+    fs::{self, File, OpenOptions},
+    io::{
+        AsyncBufRead, AsyncBufReadExt, AsyncSeekExt, AsyncWrite, AsyncWriteExt, BufReader,
+        BufWriter,
+    },
 };
-use tracing::debug;
+use tracing::{debug, warn};
+use tz::TimeZoneRef;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Available framings of the written JSON entries.
+pub enum JsonFraming {
+    #[default]
+    /// Newline delimited JSON, one entry per line.
+    Ndjson,
+
+    /// JSON text sequences (RFC 7464), every entry is prefixed with a record separator (RS) and
+    /// suffixed with a line feed.
+    JsonSeq,
+
+    /// A single JSON array per log file, which gets closed when flushing the logger.
+    Array,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Available labels of the pipe field.
+pub enum PipeLabel {
+    #[default]
+    /// The full name of the pipe, like `stdout`.
+    Name,
+
+    /// The short name of the pipe, like `out`.
+    Short,
+
+    /// The numeric file descriptor of the pipe, like `1`.
+    Fd,
+}
+
+impl PipeLabel {
+    /// Retrieve the label of the provided pipe.
+    fn label(self, pipe: Pipe) -> PipeValue {
+        match (self, pipe) {
+            (PipeLabel::Name, _) => PipeValue::Text(pipe.as_str()),
+            (PipeLabel::Short, Pipe::StdIn) => PipeValue::Text("in"),
+            (PipeLabel::Short, Pipe::StdOut) => PipeValue::Text("out"),
+            (PipeLabel::Short, Pipe::StdErr) => PipeValue::Text("err"),
+            (PipeLabel::Fd, Pipe::StdIn) => PipeValue::Fd(0),
+            (PipeLabel::Fd, Pipe::StdOut) => PipeValue::Fd(1),
+            (PipeLabel::Fd, Pipe::StdErr) => PipeValue::Fd(2),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+/// The serialized pipe field, which is either a string or a number.
+enum PipeValue {
+    Text(&'static str),
+    Fd(u8),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// Names of the JSON keys which can be renamed to match the schema of an ingestion pipeline,
+/// like `short_message` for GELF.
+pub struct JsonKeys {
+    /// The key of the timestamp.
+    timestamp: Cow<'static, str>,
+
+    /// The key of the pipe.
+    pipe: Cow<'static, str>,
+
+    /// The key of the message.
+    message: Cow<'static, str>,
+}
+
+impl Default for JsonKeys {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl JsonKeys {
+    /// The default key names.
+    const DEFAULT: Self = Self {
+        timestamp: Cow::Borrowed("timestamp"),
+        pipe: Cow::Borrowed("pipe"),
+        message: Cow::Borrowed("message"),
+    };
+
+    /// The keys which cannot be renamed.
+    const RESERVED: [&'static str; 11] = [
+        "seq",
+        "level",
+        "message_b64",
+        "partial",
+        "truncated",
+        "dropped_bytes",
+        "repeated",
+        "event",
+        "exit_code",
+        "hostname",
+        "container",
+    ];
+
+    /// Create new key names, where empty ones keep their default. The keys must be distinct
+    /// from each other and from the reserved ones.
+    pub fn new(timestamp: &str, pipe: &str, message: &str) -> Result<Self> {
+        let key = |name: &str, default| match name {
+            "" => default,
+            name => Cow::Owned(name.into()),
+        };
+        let keys = Self {
+            timestamp: key(timestamp, Self::DEFAULT.timestamp),
+            pipe: key(pipe, Self::DEFAULT.pipe),
+            message: key(message, Self::DEFAULT.message),
+        };
+        for (i, x) in [&keys.timestamp, &keys.pipe, &keys.message]
+            .into_iter()
+            .enumerate()
+        {
+            if Self::RESERVED.contains(&x.as_ref()) {
+                bail!("JSON key '{}' is reserved", x)
+            }
+            if [&keys.timestamp, &keys.pipe, &keys.message][..i].contains(&x) {
+                bail!("JSON key '{}' is used more than once", x)
+            }
+        }
+        Ok(keys)
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+/// A single entry written by the JSON logger, where unset optional fields are omitted.
+pub struct JsonLogEntry<'a> {
+    /// The RFC3339 timestamp of the entry, in UTC unless a time zone is configured.
+    timestamp: String,
+
+    /// The sequence number of the entry, strictly increasing across all pipes.
+    seq: Option<u64>,
+
+    /// The pipe the message originates from.
+    pipe: Option<PipeValue>,
+
+    /// The level parsed from the beginning of the message.
+    level: Option<String>,
+
+    /// The message without trailing newline, borrowed from the line if it is valid UTF-8.
+    message: Option<Cow<'a, str>>,
+
+    /// The base64 encoded message, used if it is not valid UTF-8.
+    message_b64: Option<String>,
+
+    /// Indicates that the message is not terminated by a newline.
+    partial: bool,
+
+    /// Indicates that container output has been dropped before this entry.
+    truncated: bool,
+
+    /// The amount of dropped bytes if `truncated` is set.
+    dropped_bytes: Option<usize>,
+
+    /// The amount of consecutive identical messages collapsed into this entry.
+    repeated: Option<u64>,
+
+    /// The lifecycle event, whose fields are added to the entry.
+    event: Option<LogEvent>,
+
+    /// The trace and span ID of the active tracing span.
+    trace: Option<(TraceId, SpanId)>,
+
+    /// The name of the node running the container.
+    hostname: Option<&'a str>,
+
+    /// The static container metadata.
+    container: Option<&'a BTreeMap<String, String>>,
+
+    /// The names of the renamable keys, which are the default ones if not set.
+    keys: Option<&'a JsonKeys>,
+}
+
+impl Serialize for JsonLogEntry<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let keys = self.keys.unwrap_or(&JsonKeys::DEFAULT);
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry(&keys.timestamp, &self.timestamp)?;
+        if let Some(seq) = self.seq {
+            map.serialize_entry("seq", &seq)?;
+        }
+        if let Some(pipe) = self.pipe {
+            map.serialize_entry(&keys.pipe, &pipe)?;
+        }
+        if let Some(level) = &self.level {
+            map.serialize_entry("level", level)?;
+        }
+        if let Some(message) = &self.message {
+            map.serialize_entry(&keys.message, message)?;
+        }
+        if let Some(message_b64) = &self.message_b64 {
+            map.serialize_entry("message_b64", message_b64)?;
+        }
+        if self.partial {
+            map.serialize_entry("partial", &true)?;
+        }
+        if self.truncated {
+            map.serialize_entry("truncated", &true)?;
+        }
+        if let Some(dropped_bytes) = self.dropped_bytes {
+            map.serialize_entry("dropped_bytes", &dropped_bytes)?;
+        }
+        if let Some(repeated) = self.repeated {
+            map.serialize_entry("repeated", &repeated)?;
+        }
+        if let Some(event) = &self.event {
+            let Value::Object(fields) = serde_json::to_value(event).map_err(S::Error::custom)?
+            else {
+                return Err(S::Error::custom("log event is no object"));
+            };
+            for (key, value) in fields {
+                map.serialize_entry(&key, &value)?;
+            }
+        }
+        if let Some((trace_id, span_id)) = self.trace {
+            map.serialize_entry("trace_id", &trace_id.to_string())?;
+            map.serialize_entry("span_id", &span_id.to_string())?;
+        }
+        if let Some(hostname) = self.hostname {
+            map.serialize_entry("hostname", hostname)?;
+        }
+        if let Some(container) = self.container {
+            map.serialize_entry("container", container)?;
+        }
+        map.end()
+    }
+}
+
+/// Append the bytes as content of a JSON string escaped exactly like the Docker `json-file`
+/// logging driver does. Unlike serde_json, HTML characters, the line and paragraph separators and
+/// control characters other than `\n`, `\r` and `\t` get escaped as `\u` sequences, and every
+/// invalid UTF-8 byte gets replaced by an escaped replacement character.
+fn push_docker_string(buf: &mut Vec<u8>, mut bytes: &[u8]) {
+    loop {
+        let (valid, invalid) = match str::from_utf8(bytes) {
+            Ok(valid) => (valid, 0),
+            Err(e) => (
+                // The prefix has been validated already, hence it cannot fail.
+                str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default(),
+                // An incomplete sequence at the end has no error length.
+                e.error_len().unwrap_or(bytes.len() - e.valid_up_to()),
+            ),
+        };
+        for c in valid.chars() {
+            match c {
+                '"' => buf.extend_from_slice(b"\\\""),
+                '\\' => buf.extend_from_slice(b"\\\\"),
+                '\n' => buf.extend_from_slice(b"\\n"),
+                '\r' => buf.extend_from_slice(b"\\r"),
+                '\t' => buf.extend_from_slice(b"\\t"),
+                '\u{0}'..='\u{1f}' | '<' | '>' | '&' | '\u{2028}' | '\u{2029}' => {
+                    buf.extend_from_slice(format!("\\u{:04x}", u32::from(c)).as_bytes())
+                }
+                c => buf.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            }
+        }
+        for _ in 0..invalid {
+            buf.extend_from_slice(b"\\ufffd");
+        }
+        bytes = &bytes[valid.len() + invalid..];
+        if bytes.is_empty() {
+            break;
+        }
+    }
+}
+
+#[derive(Debug)]
+/// A line retained for collapsing its identical successors or joining its continuation lines.
+struct RepeatedLine {
+    /// The pipe the line originates from.
+    pipe: Pipe,
+
+    /// The line including its line ending, followed by the joined continuation lines.
+    line: Vec<u8>,
+
+    /// The timestamp of the first occurrence.
+    timestamp: String,
+
+    /// The time of the first occurrence, which starts the window.
+    since: SystemTime,
+
+    /// The amount of occurrences.
+    count: u64,
+}
 
 #[derive(Debug, CopyGetters, Getters, Setters)]
+/// Logger writing the container output as JSON entries. Flushing is asynchronous, which means
+/// that dropping the logger without `close` may lose buffered entries.
 pub struct JsonLogger {
     #[getset(get)]
     path: PathBuf,
 
     #[getset(set)]
-    file: Option<BufWriter<File>>,
+    /// Open file handle of the `path`, or the custom sink provided on creation.
+    file: Option<BufWriter<LogSink>>,
 
     #[getset(get_copy)]
     max_log_size: Option<usize>,
 
-    #[getset(get_copy, set)]
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Maximum allowed line size in bytes before it gets split into multiple partial entries.
+    max_line_size: Option<usize>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Strip the surrounding whitespace of messages and replace invalid UTF-8 sequences, instead
+    /// of preserving them exactly.
+    trim: bool,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Framing of the written entries.
+    framing: JsonFraming,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// The label used for the pipe field.
+    pipe_label: PipeLabel,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Only log the output of this pipe if set.
+    pipe: Option<Pipe>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Append to an existing log file on initialization instead of truncating it, which is not
+    /// supported by the array framing.
+    append: bool,
+
+    #[getset(get_copy)]
+    /// Indicates if the path is a FIFO, which gets streamed to instead of being rotated or synced.
+    fifo: bool,
+
+    #[getset(get = "pub", set = "pub")]
+    /// Static metadata identifying the container, nested under the `container` key of every
+    /// entry to not collide with the reserved top level fields.
+    metadata: BTreeMap<String, String>,
+
+    #[getset(get = "pub", set = "pub")]
+    /// Pattern detecting a leading level of the message, which gets moved into the `level`
+    /// field. Level parsing is disabled if not set.
+    level_pattern: Option<Regex>,
+
+    #[getset(get = "pub", set = "pub")]
+    /// Names of the renamable keys of every entry.
+    keys: JsonKeys,
+
+    #[getset(get = "pub", set = "pub")]
+    /// Name of the node added as `hostname` field of every entry, which identifies the origin of
+    /// shipped logs. Disabled if not set.
+    hostname: Option<String>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Add the trace and span ID of the active tracing span to every entry, if there is one.
+    trace_context: bool,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Time zone of the entry timestamps, which get the UTC offset appended. Defaults to UTC.
+    time_zone: Option<TimeZoneRef<'static>>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Write the entries exactly like the Docker `json-file` logging driver, which allows tools
+    /// built for Docker to parse the log files unchanged. The entries only contain the `log`,
+    /// `stream` and `time` fields, where the timestamp is always in UTC and markers and events get
+    /// omitted.
+    docker_compat: bool,
+
+    #[getset(set = "pub")]
+    /// Source of the entry timestamps, which defaults to the system wall clock.
+    clock: Arc<dyn Clock>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// The sequence number of the next entry, which gets added as `seq` field if set. A single
+    /// counter is used for all pipes, which keeps the entries totally ordered even if their
+    /// timestamps are equal.
+    seq: Option<u64>,
+
+    /// Entries written to the current log file.
+    entries: usize,
+
+    /// Indicates if the JSON array of the current log file has been closed.
+    array_closed: bool,
+
+    #[getset(get_copy = "pub", set)]
     bytes_written: usize,
+
+    #[getset(get_copy = "pub")]
+    rotations: usize,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    rotation: RotationMode,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    max_files: Option<usize>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    compression: Compression,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Write the SHA-256 digest of every rotated log file into a sidecar next to it.
+    checksum: bool,
+
+    #[cfg(feature = "s3")]
+    #[getset(get = "pub", set = "pub")]
+    /// Upload every rotated log file into an S3 compatible object store, if set.
+    upload: Option<Arc<S3Upload>>,
+
+    #[getset(get = "pub", set = "pub")]
+    naming: RotationNaming,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Policy for syncing written entries to disk.
+    durability: DurabilityPolicy,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Rotate the log file on the first write after this interval elapsed since opening it.
+    rotate_interval: Option<Duration>,
+
+    /// The time the current log file has been opened.
+    last_rotation: SystemTime,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Reset the written bytes to the actual size of the log file before writing a entry once this
+    /// interval elapsed, which keeps the rotation on track if the file got appended or truncated
+    /// externally. Disabled if not set.
+    reconcile_interval: Option<Duration>,
+
+    /// The time the written bytes have been reconciled the last time.
+    last_reconcile: SystemTime,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Reopen the log file before writing an entry if its path no longer refers to the open file,
+    /// which costs an additional `stat` per entry.
+    watch_path: bool,
+
+    sync_task: Option<SyncTask>,
+
+    /// Background task compressing the latest backup.
+    backup_task: BackupTask,
+
+    partial_lines: HashMap<Pipe, Vec<u8>>,
+
+    /// Reused buffer for reading lines, which is cleared instead of reallocated between writes.
+    line_buf: Vec<u8>,
+
+    /// Reused buffer for serializing entries.
+    entry_buf: Vec<u8>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Capacity of the buffers for reading the container output and writing the log file. Must
+    /// not be zero.
+    buffer_size: usize,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Collapse consecutive identical lines of a pipe within this window since their first
+    /// occurrence into a single entry with a `repeated` count, if set.
+    repeat_window: Option<Duration>,
+
+    #[getset(get = "pub", set = "pub")]
+    /// Pattern detecting the continuation lines which get joined into the entry of their
+    /// preceding line, like the frames of a stack trace. Joining is disabled if not set.
+    multiline_pattern: Option<Regex>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Write a joined entry with the next line once this timeout elapsed since its first line.
+    multiline_timeout: Duration,
+
+    /// The line retained for collapsing its identical successors or joining its continuation
+    /// lines.
+    repeated_line: Option<RepeatedLine>,
+
+    #[getset(set = "pub")]
+    /// Node wide disk usage budget the written bytes get charged to, if any.
+    budget: Option<Arc<LogDiskBudget>>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Permissions and ownership of the log file, which do not apply to FIFOs.
+    file_access: FileAccess,
+
+    /// Line statistics of every pipe.
+    line_stats: PipeStats,
+
+    #[getset(get_copy = "pub")]
+    /// Time of the last written line, which allows detecting idle containers.
+    last_write: Option<SystemTime>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Retries of writes failing because the disk is full, which block the container output in
+    /// between. Retrying is disabled if zero.
+    disk_full_retries: usize,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Thresholds for flushing the written lines, which batches the writes to the log file.
+    flush_threshold: FlushThreshold,
+
+    /// Lines and bytes written since the last flush.
+    unflushed: Unflushed,
+
+    #[getset(get = "pub", set = "pub")]
+    /// Path used instead if writing to the log file fails, for example because its mount became
+    /// read-only. Not supported by the array framing and FIFOs.
+    fallback_path: Option<PathBuf>,
+
+    /// Entries written since the last flush while a fallback path is set, which get written to
+    /// the fallback path if they fail to reach the log file.
+    retained: Vec<u8>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Handling of empty lines, which get written by default.
+    empty_lines: EmptyLines,
+
+    #[getset(set = "pub")]
+    /// Transformations applied in order to every message before writing it, like redacting
+    /// secrets. Lines split into partial ones get transformed separately.
+    transforms: Vec<Arc<dyn LogTransform>>,
 }
 
 impl JsonLogger {
-    const ERR_UNINITIALIZED: &'static str = "logger not initialized";
+    /// The record separator prefixing every entry of a JSON text sequence.
+    const RECORD_SEPARATOR: u8 = 0x1e;
+
+    /// The bytes opening the JSON array of a log file.
+    const ARRAY_OPEN: &'static [u8] = b"[\n";
+
+    /// The bytes separating the entries of the JSON array.
+    const ARRAY_SEPARATOR: &'static [u8] = b",\n";
+
+    /// The bytes closing the JSON array of a log file.
+    const ARRAY_CLOSE: &'static [u8] = b"\n]\n";
+
+    /// The maximum length of the framing bytes written in front of an entry.
+    const FRAMING_PREFIX_LEN: usize = Self::ARRAY_SEPARATOR.len();
 
-    pub fn new<T: AsRef<Path>>(path: T, max_log_size: Option<usize>) -> Result<JsonLogger> {
+    /// The maximum capacity retained by the reused buffers, which avoids holding on to the
+    /// memory of single huge lines.
+    const RETAINED_CAPACITY: usize = 64 * 1024;
+
+    /// The name of the capture group containing the level.
+    const LEVEL_GROUP: &'static str = "level";
+
+    /// The default level pattern, matching common uppercase levels like `ERROR`, `[WARN]` or
+    /// `INFO:` followed by whitespace.
+    pub const DEFAULT_LEVEL_PATTERN: &'static str =
+        r"^\[?(?P<level>TRACE|DEBUG|INFO|NOTICE|WARN|WARNING|ERROR|CRITICAL|FATAL)\]?:?(?:\s+|$)";
+
+    /// The default continuation pattern, matching the indented frames and the causes of common
+    /// stack traces. Blank lines are no continuation lines.
+    pub const DEFAULT_MULTILINE_PATTERN: &'static str = r"^(?:[ \t]+\S|Caused by: )";
+
+    /// The default timeout of a joined entry.
+    pub const DEFAULT_MULTILINE_TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// Create a new JSON logger instance.
+    pub fn new<T: AsRef<Path>>(
+        path: T,
+        max_log_size: Option<usize>,
+    ) -> Result<JsonLogger, LoggerError> {
         Ok(Self {
             path: path.as_ref().into(),
             file: None,
             max_log_size,
+            max_line_size: None,
+            trim: false,
+            framing: JsonFraming::default(),
+            pipe_label: PipeLabel::default(),
+            append: false,
+            pipe: None,
+            fifo: false,
+            metadata: BTreeMap::new(),
+            level_pattern: None,
+            keys: JsonKeys::default(),
+            hostname: None,
+            trace_context: false,
+            time_zone: None,
+            docker_compat: false,
+            clock: Arc::new(SystemClock),
+            seq: None,
+            entries: 0,
+            array_closed: false,
             bytes_written: 0,
+            rotations: 0,
+            rotation: RotationMode::default(),
+            max_files: None,
+            compression: Compression::None,
+            checksum: false,
+            #[cfg(feature = "s3")]
+            upload: None,
+            naming: RotationNaming::default(),
+            durability: DurabilityPolicy::None,
+            sync_task: None,
+            backup_task: BackupTask::default(),
+            rotate_interval: None,
+            last_rotation: SystemTime::now(),
+            reconcile_interval: None,
+            last_reconcile: SystemTime::now(),
+            watch_path: false,
+            partial_lines: HashMap::new(),
+            line_buf: Vec::new(),
+            entry_buf: Vec::new(),
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            repeat_window: None,
+            multiline_pattern: None,
+            multiline_timeout: Self::DEFAULT_MULTILINE_TIMEOUT,
+            repeated_line: None,
+            budget: None,
+            file_access: FileAccess::default(),
+            line_stats: PipeStats::default(),
+            last_write: None,
+            disk_full_retries: 0,
+            flush_threshold: FlushThreshold::default(),
+            unflushed: Unflushed::default(),
+            fallback_path: None,
+            retained: vec![],
+            empty_lines: EmptyLines::default(),
+            transforms: Vec::new(),
         })
     }
 
-    pub async fn init(&mut self) -> Result<()> {
-        debug!("Initializing JSON logger in path {}", self.path().display());
-        self.set_file(Self::open(self.path()).await?.into());
-        Ok(())
+    /// Create a new logger instance writing into the provided sink instead of a log file, like an
+    /// in-memory buffer or a socket. The sink is initialized already and never gets rotated or
+    /// synced, and it cannot be initialized again once closed. The array framing is not supported,
+    /// because it requires seeking.
+    pub fn new_with_sink<W>(sink: W) -> Result<JsonLogger, LoggerError>
+    where
+        W: AsyncWrite + Unpin + Send + Sync + 'static,
+    {
+        let mut logger = Self::new(PathBuf::new(), None)?;
+        logger.file = Some(BufWriter::with_capacity(
+            logger.buffer_size(),
+            LogSink::writer(sink),
+        ));
+        Ok(logger)
+    }
+
+    /// Indicates if the logger writes into a custom sink instead of a log file.
+    fn custom_sink(&self) -> bool {
+        self.file.as_ref().is_some_and(|x| x.get_ref().is_writer())
+    }
+
+    /// The current time as RFC3339 timestamp within the configured time zone.
+    fn timestamp(&self) -> Result<String> {
+        match self.time_zone().filter(|_| !self.docker_compat()) {
+            Some(time_zone) => self.clock.local_timestamp(time_zone),
+            None => self.clock.timestamp(),
+        }
+    }
+
+    /// Compile the level pattern, where an empty pattern results in the default one.
+    pub fn level_regex(pattern: &str) -> Result<Regex> {
+        let pattern = if pattern.is_empty() {
+            Self::DEFAULT_LEVEL_PATTERN
+        } else {
+            pattern
+        };
+        let regex = Regex::new(pattern).context("compile level pattern")?;
+        if !regex
+            .capture_names()
+            .any(|name| name == Some(Self::LEVEL_GROUP))
+        {
+            bail!(
+                "level pattern '{}' has no '{}' capture group",
+                pattern,
+                Self::LEVEL_GROUP
+            )
+        }
+        Ok(regex)
+    }
+
+    /// Compile the continuation pattern, where an empty pattern results in the default one.
+    pub fn multiline_regex(pattern: &str) -> Result<Regex> {
+        let pattern = if pattern.is_empty() {
+            Self::DEFAULT_MULTILINE_PATTERN
+        } else {
+            pattern
+        };
+        Regex::new(pattern).context("compile multiline pattern")
+    }
+
+    /// Indicates if entries would get lost without flushing the logger.
+    fn has_unflushed(&self) -> bool {
+        self.repeated_line.is_some()
+            || !self.partial_lines.is_empty()
+            || self.file.as_ref().is_some_and(|x| !x.buffer().is_empty())
     }
 
-    pub async fn write<T>(&mut self, pipe: Pipe, bytes: T) -> Result<()>
+    /// Write the contents of the provided reader into the logger. A trailing line without a
+    /// newline is retained until more data for the same pipe arrives or `flush_partial` is called.
+    /// A failed write retains the line it was writing as well, which gets written first next time.
+    pub async fn write<T>(&mut self, pipe: Pipe, bytes: T) -> Result<usize, LoggerError>
     where
         T: AsyncBufRead + Unpin,
     {
-        let mut reader = BufReader::new(bytes);
-        let mut line_buf = Vec::new();
-
-        while reader.read_until(b'\n', &mut line_buf).await? > 0 {
-            let log_entry = json!({
-                "timestamp": format!("{:?}", std::time::SystemTime::now()),
-                "pipe": match pipe {
-                    Pipe::StdOut => "stdout",
-                    Pipe::StdErr => "stderr",
-                },
-                "message": String::from_utf8_lossy(&line_buf).trim().to_string()
-            });
+        let mut reader = BufReader::with_capacity(self.buffer_size(), bytes);
+        let mut line_buf = self
+            .partial_lines
+            .remove(&pipe)
+            .unwrap_or_else(|| mem::take(&mut self.line_buf));
+        let res = self.write_lines(pipe, &mut reader, &mut line_buf).await;
+
+        if line_buf.is_empty() {
+            line_buf.shrink_to(Self::RETAINED_CAPACITY);
+            self.line_buf = line_buf;
+        } else {
+            self.partial_lines.insert(pipe, line_buf);
+        }
 
-            let log_str = log_entry.to_string();
-            let bytes = log_str.as_bytes();
-            self.bytes_written += bytes.len();
+        res
+    }
 
-            if let Some(max_size) = self.max_log_size {
-                if self.bytes_written > max_size {
-                    self.reopen().await?;
-                    self.bytes_written = 0;
-                }
+    /// Write the lines of the reader as entries, where `line_buf` starts with the retained line
+    /// and ends with the unwritten rest.
+    async fn write_lines<T>(
+        &mut self,
+        pipe: Pipe,
+        reader: &mut BufReader<T>,
+        line_buf: &mut Vec<u8>,
+    ) -> Result<usize, LoggerError>
+    where
+        T: AsyncBufRead + Unpin,
+    {
+        if line_buf.last() == Some(&b'\n') {
+            // A complete line retained by a failed write gets written first.
+            self.write_entry(pipe, line_buf, false).await?;
+            line_buf.clear();
+        }
+        let mut read = 0;
+
+        loop {
+            let n = Self::read_line(reader, line_buf, self.max_line_size()).await?;
+            if n == 0 {
+                break;
             }
+            read += n;
 
-            let file = self.file.as_mut().context(Self::ERR_UNINITIALIZED)?;
-            file.write_all(bytes).await?;
-            file.write_all(b"\n").await?;
-            self.flush().await?;
+            if line_buf.last() == Some(&b'\n') {
+                self.write_entry(pipe, line_buf, false).await?;
+            } else if self
+                .max_line_size()
+                .is_some_and(|max_line_size| line_buf.len() >= max_line_size)
+            {
+                // A split UTF-8 sequence starts the next entry, which decodes it once complete.
+                let len = match line_buffer::complete_utf8_len(line_buf) {
+                    0 => line_buf.len(),
+                    len => len,
+                };
+                self.write_entry(pipe, &line_buf[..len], true).await?;
+                line_buf.drain(..len);
+                continue;
+            } else {
+                continue;
+            }
             line_buf.clear();
         }
 
-        Ok(())
+        Ok(read)
     }
 
-    pub async fn reopen(&mut self) -> Result<()> {
-        debug!("Reopen JSON log {}", self.path().display());
-        self.file
-            .as_mut()
-            .context(Self::ERR_UNINITIALIZED)?
-            .get_ref()
-            .sync_all()
-            .await?;
-        self.init().await
+    /// Write a single line as entry, where identical full lines within the repeat window get
+    /// collapsed into the retained one. Continuation lines get joined into the retained one
+    /// instead if a multiline pattern is set.
+    async fn write_entry(&mut self, pipe: Pipe, line_buf: &[u8], partial: bool) -> Result<()> {
+        self.line_stats.record(
+            pipe,
+            line_buf.strip_suffix(b"\n").unwrap_or(line_buf).len(),
+            partial,
+        );
+        if let (Some(pattern), false) = (self.multiline_pattern(), partial) {
+            let continuation = pattern.is_match(&String::from_utf8_lossy(line_buf));
+            let (now, timeout) = (self.clock.now(), self.multiline_timeout());
+            let max_line_size = self.max_line_size();
+            if let Some(entry) = self.repeated_line.as_mut().filter(|x| {
+                continuation
+                    && x.pipe == pipe
+                    && now
+                        .duration_since(x.since)
+                        .is_ok_and(|elapsed| elapsed < timeout)
+                    && max_line_size.map_or(true, |max| x.line.len() + line_buf.len() <= max)
+            }) {
+                entry.line.extend_from_slice(line_buf);
+                return Ok(());
+            }
+            self.write_repeated_line().await?;
+            self.repeated_line = Some(RepeatedLine {
+                pipe,
+                line: line_buf.into(),
+                timestamp: self.timestamp()?,
+                since: now,
+                count: 1,
+            });
+            return Ok(());
+        }
+        if let (Some(window), false) = (self.repeat_window(), partial) {
+            let now = self.clock.now();
+            if let Some(repeated_line) = self.repeated_line.as_mut().filter(|x| {
+                x.pipe == pipe
+                    && x.line == line_buf
+                    && now
+                        .duration_since(x.since)
+                        .is_ok_and(|elapsed| elapsed < window)
+            }) {
+                repeated_line.count += 1;
+                return Ok(());
+            }
+            self.write_repeated_line().await?;
+            self.repeated_line = Some(RepeatedLine {
+                pipe,
+                line: line_buf.into(),
+                timestamp: self.timestamp()?,
+                since: now,
+                count: 1,
+            });
+            return Ok(());
+        }
+        self.write_repeated_line().await?;
+        let timestamp = self.timestamp()?;
+        self.write_message(pipe, line_buf, partial, timestamp, None)
+            .await
     }
 
-    pub async fn flush(&mut self) -> Result<()> {
-        self.file
-            .as_mut()
-            .context(Self::ERR_UNINITIALIZED)?
-            .flush()
-            .await
-            .context("flush file writer")
+    /// Write the retained line, if any, with the amount of its occurrences.
+    async fn write_repeated_line(&mut self) -> Result<()> {
+        let Some(repeated_line) = self.repeated_line.take() else {
+            return Ok(());
+        };
+        self.write_message(
+            repeated_line.pipe,
+            &repeated_line.line,
+            false,
+            repeated_line.timestamp,
+            (repeated_line.count > 1).then_some(repeated_line.count),
+        )
+        .await
     }
 
-    async fn open<T: AsRef<Path>>(path: T) -> Result<BufWriter<File>> {
-        Ok(BufWriter::new(
-            OpenOptions::new()
-                .create(true)
-                .read(true)
-                .truncate(true)
-                .write(true)
-                .open(&path)
-                .await
-                .context(format!("open log file path '{}'", path.as_ref().display()))?,
-        ))
+    /// Write a single line as entry with the provided timestamp and repeat count.
+    async fn write_message(
+        &mut self,
+        pipe: Pipe,
+        line_buf: &[u8],
+        partial: bool,
+        timestamp: String,
+        repeated: Option<u64>,
+    ) -> Result<()> {
+        if self.docker_compat() {
+            return self
+                .write_docker_entry(pipe, line_buf, partial, &timestamp)
+                .await;
+        }
+        let mut log_entry = JsonLogEntry {
+            timestamp,
+            pipe: Some(self.pipe_label().label(pipe)),
+            partial,
+            repeated,
+            ..Default::default()
+        };
+
+        // A carriage return directly preceding the newline is part of the line ending.
+        let line = line_buf
+            .strip_suffix(b"\r\n")
+            .or_else(|| line_buf.strip_suffix(b"\n"))
+            .unwrap_or(line_buf);
+        let line = log_transform::apply(&self.transforms, line);
+        let line = line.as_ref();
+        if self.empty_lines() == EmptyLines::Drop
+            && (line.is_empty() || (self.trim() && String::from_utf8_lossy(line).trim().is_empty()))
+        {
+            return Ok(());
+        }
+        if self.trim() {
+            log_entry.message = Some(match String::from_utf8_lossy(line) {
+                Cow::Borrowed(message) => Cow::Borrowed(message.trim()),
+                Cow::Owned(message) => Cow::Owned(message.trim().into()),
+            });
+        } else {
+            match str::from_utf8(line) {
+                Ok(message) => log_entry.message = Some(Cow::Borrowed(message)),
+                // Non UTF-8 output would get lost in a JSON string, so keep the raw bytes instead.
+                Err(_) => log_entry.message_b64 = Some(STANDARD.encode(line)),
+            }
+        }
+        if let Some((level, message)) = log_entry
+            .message
+            .as_deref()
+            .and_then(|message| self.parse_level(message))
+        {
+            log_entry.level = Some(level);
+            log_entry.message = Some(message.into());
+        }
+        self.write_value(log_entry).await
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::io::Cursor;
-    use tokio::io::AsyncReadExt;
+    /// Write a single line as entry of the Docker `json-file` logging driver, whose `log` field
+    /// keeps the line ending of full lines.
+    async fn write_docker_entry(
+        &mut self,
+        pipe: Pipe,
+        line_buf: &[u8],
+        partial: bool,
+        timestamp: &str,
+    ) -> Result<()> {
+        let line = line_buf.strip_suffix(b"\n").unwrap_or(line_buf);
+        let line = log_transform::apply(&self.transforms, line);
+        if self.empty_lines() == EmptyLines::Drop && line.is_empty() {
+            return Ok(());
+        }
+        let mut buf = self.take_entry_buf();
+        buf.extend_from_slice(br#"{"log":""#);
+        push_docker_string(&mut buf, &line);
+        if !partial {
+            buf.extend_from_slice(b"\\n");
+        }
+        buf.extend_from_slice(br#"","stream":""#);
+        buf.extend_from_slice(<&str>::from(pipe).as_bytes());
+        buf.extend_from_slice(br#"","time":""#);
+        buf.extend_from_slice(timestamp.as_bytes());
+        buf.extend_from_slice(br#""}"#);
+        self.write_buf(buf).await
+    }
 
-    #[tokio::test]
-    async fn test_json_logger_new() {
-        let logger = JsonLogger::new("/tmp/test.log", Some(1000)).unwrap();
-        assert_eq!(logger.path().to_str().unwrap(), "/tmp/test.log");
-        assert_eq!(logger.max_log_size().unwrap(), 1000);
+    /// Retrieve the buffer of the next entry, which reserves room for the framing in front of it.
+    /// This allows writing the framed entry at once.
+    fn take_entry_buf(&mut self) -> Vec<u8> {
+        let mut buf = mem::take(&mut self.entry_buf);
+        buf.clear();
+        buf.resize(Self::FRAMING_PREFIX_LEN, 0);
+        buf
     }
 
-    #[tokio::test]
-    async fn test_json_logger_init() {
-        let mut logger = JsonLogger::new("/tmp/test_init.log", Some(1000)).unwrap();
-        logger.init().await.unwrap();
-        assert!(logger.file.is_some());
+    /// Write a single entry with the attached metadata by respecting the framing.
+    async fn write_value(&mut self, mut log_entry: JsonLogEntry<'_>) -> Result<()> {
+        let mut buf = self.take_entry_buf();
+        log_entry.seq = self.seq;
+        self.seq = self.seq.map(|seq| seq.wrapping_add(1));
+        log_entry.hostname = self.hostname().as_deref();
+        if self.trace_context() {
+            log_entry.trace = Telemetry::current_ids();
+        }
+        if self.keys != JsonKeys::DEFAULT {
+            log_entry.keys = Some(&self.keys);
+        }
+        if !self.metadata().is_empty() {
+            log_entry.container = Some(self.metadata());
+        }
+        serde_json::to_writer(&mut buf, &log_entry).context("serialize log entry")?;
+        self.write_buf(buf).await
     }
 
-    #[tokio::test]
-    async fn test_json_logger_write() {
-        let mut logger = JsonLogger::new("/tmp/test_write.log", Some(1000)).unwrap();
-        logger.init().await.unwrap();
+    /// Write the serialized entry following the reserved framing prefix, which rotates the log
+    /// file before if required.
+    async fn write_buf(&mut self, mut buf: Vec<u8>) -> Result<()> {
+        if self.watch_path() && !self.fifo() {
+            self.reattach().await?;
+        }
+        let len = buf.len() - Self::FRAMING_PREFIX_LEN;
+        if !self.fifo() {
+            self.reconcile().await?;
+        }
+        self.bytes_written += len;
 
-        let cursor = Cursor::new(b"Test log message\n".to_vec());
-        logger.write(Pipe::StdOut, cursor).await.unwrap();
+        // The reader of a FIFO consumes the entries, which means that it never gets rotated.
+        if !self.fifo() && self.rotation_due() {
+            self.reopen().await?;
+            self.bytes_written = len;
+            self.rotations += 1;
+        }
+        if !self.fifo() && self.budget_exceeded(len).await? {
+            self.reopen().await?;
+            self.bytes_written = len;
+            self.rotations += 1;
+        }
+        // An entry exceeding the max size on its own gets written into an empty log file, because
+        // rotating it would not make room for the entry.
+        if let Some(max_size) = self.max_log_size {
+            if self.bytes_written > max_size && self.bytes_written > len {
+                self.reopen().await?;
+                self.bytes_written = len;
+                self.rotations += 1;
+            }
+        }
 
-        // Read back from the file
-        let mut file = File::open("/tmp/test_write.log").await.unwrap();
-        let mut contents = String::new();
-        file.read_to_string(&mut contents).await.unwrap();
+        let sync = self.durability() == DurabilityPolicy::EveryWrite && !self.fifo();
+        let framing = self.framing();
+        let start = match framing {
+            JsonFraming::Ndjson => {
+                buf.push(b'\n');
+                Self::FRAMING_PREFIX_LEN
+            }
+            JsonFraming::JsonSeq => {
+                buf[Self::FRAMING_PREFIX_LEN - 1] = Self::RECORD_SEPARATOR;
+                buf.push(b'\n');
+                Self::FRAMING_PREFIX_LEN - 1
+            }
+            JsonFraming::Array => {
+                let file = self.file.as_mut().ok_or(LoggerError::Uninitialized)?;
+                if self.array_closed {
+                    // Continue the array by overwriting its closing bytes.
+                    file.seek(SeekFrom::Current(-(Self::ARRAY_CLOSE.len() as i64)))
+                        .await
+                        .context("seek before closing array bytes")?;
+                    self.array_closed = false;
+                }
+                if self.entries > 0 {
+                    buf[..Self::FRAMING_PREFIX_LEN].copy_from_slice(Self::ARRAY_SEPARATOR);
+                    0
+                } else {
+                    Self::FRAMING_PREFIX_LEN
+                }
+            }
+        };
+        // The entries of an array or FIFO cannot be continued in another file.
+        match self.write_framed(&buf[start..], len, sync).await {
+            Err(e) if framing != JsonFraming::Array && !self.fifo() => {
+                self.fail_over(e).await?;
+            }
+            res => res?,
+        }
+        self.last_write = Some(self.clock.now());
+        buf.clear();
+        buf.shrink_to(Self::RETAINED_CAPACITY);
+        self.entry_buf = buf;
+        Ok(())
+    }
 
-        // Check if the file contains the logged message
-        assert!(contents.contains("Test log message"));
+    /// Write a single framed entry of `len` bytes and flush it as required by the durability
+    /// policy and flush threshold.
+    async fn write_framed(&mut self, entry: &[u8], len: usize, sync: bool) -> Result<()> {
+        if self.fallback_path.is_some() {
+            self.retained.extend_from_slice(entry);
+        }
+        // The array framing overwrites its closing bytes, which prevents detecting the written part
+        // of an entry by the end of the file.
+        let retries = match self.framing() {
+            JsonFraming::Array => 0,
+            _ if self.fifo => 0,
+            _ => self.disk_full_retries,
+        };
+        let file = self.file.as_mut().ok_or(LoggerError::Uninitialized)?;
+        if retries > 0 {
+            file.flush().await?;
+            log_retry::write_all(file.get_mut(), entry, retries).await?;
+        } else {
+            file.write_all(entry).await?;
+        }
+        self.entries += 1;
+
+        if sync {
+            log_durability::sync(file).await?;
+        } else if self.flush_threshold.is_set() && !self.unflushed.add(len, self.flush_threshold) {
+            return Ok(());
+        } else {
+            file.flush().await.context("flush file writer")?;
+        }
+        self.retained.clear();
+        Ok(())
+    }
+
+    /// Switch to the fallback path because of the failed write, which fails if there is none. A
+    /// `log_path_failover` event gets written to the fallback, followed by the entries written
+    /// since the last flush, because the file does not report which of them reached it. Switching
+    /// happens only once.
+    async fn fail_over(&mut self, e: Error) -> Result<()> {
+        let Some(fallback_path) = self.fallback_path.take() else {
+            return Err(e);
+        };
+        warn!(
+            "Switching JSON log {} to fallback path {}: {:#}",
+            self.path().display(),
+            fallback_path.display(),
+            e
+        );
+        let retained = mem::take(&mut self.retained);
+        self.file = None;
+        if let Some(budget) = &self.budget {
+            budget.unregister(self.path()).await?;
+        }
+        let previous_path = mem::replace(&mut self.path, fallback_path);
+        self.open_file(true).await?;
+
+        let mut marker = match self.framing() {
+            JsonFraming::JsonSeq => vec![Self::RECORD_SEPARATOR],
+            JsonFraming::Ndjson | JsonFraming::Array => vec![],
+        };
+        serde_json::to_writer(
+            &mut marker,
+            &JsonLogEntry {
+                timestamp: self.timestamp()?,
+                event: Some(LogEvent::LogPathFailover { previous_path }),
+                ..Default::default()
+            },
+        )
+        .context("serialize log entry")?;
+        marker.push(b'\n');
+        let file = self.file.as_mut().ok_or(LoggerError::Uninitialized)?;
+        for x in [&marker, &retained] {
+            file.write_all(x).await?;
+            self.bytes_written += x.len();
+        }
+        file.flush().await.context("flush file writer")
+    }
+
+    /// Split the message into its leading level and the remainder, if a level pattern is set.
+    fn parse_level(&self, message: &str) -> Option<(String, String)> {
+        let captures = self.level_pattern().as_ref()?.captures(message)?;
+        let level = captures.name(Self::LEVEL_GROUP)?.as_str();
+        let remainder = &message[captures.get(0)?.end()..];
+        Some((level.into(), remainder.into()))
+    }
+
+    /// Reopen the log file if its path no longer refers to the open file, which continues a file
+    /// recreated in the meantime instead of truncating it.
+    async fn reattach(&mut self) -> Result<()> {
+        let file = self.file.as_ref().ok_or(LoggerError::Uninitialized)?;
+        // A custom sink has no path to reattach to.
+        let Some(file) = file.get_ref().file() else {
+            return Ok(());
+        };
+        if !log_rotation::is_detached(&self.path, file).await? {
+            return Ok(());
+        }
+        debug!("Reopen detached JSON log {}", self.path().display());
+        self.flush().await.context("flush detached log file")?;
+        self.open_file(true).await
+    }
+
+    /// Reset the written bytes to the actual size of the log file if the reconcile interval
+    /// elapsed.
+    async fn reconcile(&mut self) -> Result<()> {
+        let Some(interval) = self.reconcile_interval() else {
+            return Ok(());
+        };
+        let now = self.clock.now();
+        if now
+            .duration_since(self.last_reconcile)
+            .is_ok_and(|elapsed| elapsed < interval)
+        {
+            return Ok(());
+        }
+        self.last_reconcile = now;
+        let file = self.file.as_ref().ok_or(LoggerError::Uninitialized)?;
+        let Some(size) = log_rotation::file_size(file).await? else {
+            return Ok(());
+        };
+        if size != self.bytes_written {
+            debug!(
+                "Reconciling written bytes {} with log file size {}",
+                self.bytes_written, size
+            );
+            self.bytes_written = size;
+        }
+        Ok(())
+    }
+
+    /// Verify if the rotate interval elapsed since opening the current log file.
+    fn rotation_due(&self) -> bool {
+        self.rotate_interval().is_some_and(|interval| {
+            self.clock
+                .now()
+                .duration_since(self.last_rotation)
+                .is_ok_and(|elapsed| elapsed >= interval)
+        })
+    }
+
+    /// Read until the next newline into `buf`, but never let `buf` grow beyond `max_line_size`.
+    async fn read_line<T>(
+        r: &mut BufReader<T>,
+        buf: &mut Vec<u8>,
+        max_line_size: Option<usize>,
+    ) -> Result<usize>
+    where
+        T: AsyncBufRead + Unpin,
+    {
+        let read = {
+            let available = r.fill_buf().await?;
+            let limit = max_line_size.map_or(available.len(), |max_line_size| {
+                max_line_size.saturating_sub(buf.len()).min(available.len())
+            });
+            let read = memchr(b'\n', &available[..limit]).map_or(limit, |i| i + 1);
+            buf.extend_from_slice(&available[..read]);
+            read
+        };
+        r.consume(read);
+        Ok(read)
+    }
+
+    /// Open the provided path, where a FIFO gets opened write only without creating or truncating
+    /// it. Opening a FIFO waits until the other end got opened for reading.
+    async fn open<T: AsRef<Path>>(
+        path: T,
+        append: bool,
+        fifo: bool,
+        durability: DurabilityPolicy,
+        file_access: FileAccess,
+    ) -> Result<File> {
+        let mut options = OpenOptions::new();
+        if fifo {
+            options.write(true);
+            #[cfg(unix)]
+            options.custom_flags(file_access.open_flags());
+        } else {
+            // Every entry gets flushed, which makes it durable when opened with O_DSYNC.
+            options
+                .create(true)
+                .read(true)
+                .truncate(!append)
+                .append(append)
+                .write(true);
+            #[cfg(unix)]
+            if let Some(mode) = file_access.mode {
+                options.mode(mode);
+            }
+            #[cfg(unix)]
+            options.custom_flags(durability.open_flags() | file_access.open_flags());
+        }
+        let file = file_access.open(&options, path.as_ref()).await?;
+        if !fifo {
+            file_access.apply(&file).await?;
+        }
+        Ok(file)
+    }
+
+    #[cfg(unix)]
+    /// Verify if the metadata belongs to a FIFO.
+    fn is_fifo(metadata: &std::fs::Metadata) -> bool {
+        use std::os::unix::fs::FileTypeExt;
+        metadata.file_type().is_fifo()
+    }
+
+    #[cfg(not(unix))]
+    /// FIFOs only exist on Unix.
+    fn is_fifo(_: &std::fs::Metadata) -> bool {
+        false
+    }
+
+    /// Open the log file, where `append` continues an existing file and seeds the written bytes
+    /// from its length.
+    async fn open_file(&mut self, append: bool) -> Result<()> {
+        // Replacing the writer would discard its buffered entries.
+        if self.file.is_some() {
+            self.flush().await?;
+        }
+        self.fifo = fs::metadata(self.path())
+            .await
+            .is_ok_and(|metadata| Self::is_fifo(&metadata));
+        if self.fifo() && self.framing() == JsonFraming::Array {
+            return Err(LoggerError::Unsupported(
+                "the JSON array framing is not supported for FIFOs",
+            )
+            .into());
+        }
+        if append && self.framing() == JsonFraming::Array {
+            return Err(LoggerError::Unsupported(
+                "append mode is not supported by the JSON array framing",
+            )
+            .into());
+        }
+        let file = Self::open(
+            self.path(),
+            append,
+            self.fifo(),
+            self.durability(),
+            self.file_access(),
+        )
+        .await?;
+        self.bytes_written = if append {
+            file.metadata()
+                .await
+                .context("get log file metadata")?
+                .len()
+                .try_into()?
+        } else {
+            0
+        };
+        self.sync_task = if self.fifo() {
+            None
+        } else {
+            SyncTask::from_policy(self.durability(), &file).await?
+        };
+        let mut file = BufWriter::with_capacity(self.buffer_size(), LogSink::from(file));
+        if self.framing() == JsonFraming::Array {
+            file.write_all(Self::ARRAY_OPEN)
+                .await
+                .context("open JSON array")?;
+        }
+        self.entries = 0;
+        self.array_closed = false;
+        self.set_file(file.into());
+        self.last_rotation = self.clock.now();
+        if let (Some(budget), false) = (&self.budget, self.fifo()) {
+            budget.register(self.path(), self.naming()).await?;
+        }
+        Ok(())
+    }
+
+    /// Charge the written bytes to the disk budget and return if the log file has to be rotated
+    /// early because the budget is still exceeded after pruning backups.
+    async fn budget_exceeded(&self, bytes: usize) -> Result<bool> {
+        match &self.budget {
+            Some(budget) => budget.charge(bytes.try_into()?).await,
+            None => Ok(false),
+        }
+    }
+}
+
+#[async_trait]
+impl Logger for JsonLogger {
+    fn name(&self) -> &'static str {
+        "json"
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.file.is_some()
+    }
+
+    /// FIFOs are consumed by their reader and custom sinks have no path, which means that they
+    /// cannot be read back.
+    fn source(&self) -> Option<(PathBuf, LogFormat)> {
+        (!self.fifo() && !self.custom_sink()).then(|| (self.path().clone(), LogFormat::Json))
+    }
+
+    fn accepts(&self, pipe: Pipe) -> bool {
+        self.pipe().map_or(true, |x| x == pipe)
+    }
+
+    fn stats(&self) -> LogStats {
+        LogStats {
+            name: self.name(),
+            bytes_written: self.bytes_written(),
+            max_log_size: self.max_log_size(),
+            rotations: self.rotations(),
+            dropped_bytes: 0,
+            lines: self.line_stats,
+            last_write: self.last_write(),
+        }
+    }
+
+    /// Verify that the log file is writable and the rotation options are coherent.
+    async fn validate(&self) -> Result<()> {
+        if self.buffer_size() == 0 {
+            bail!("buffer size must not be zero")
+        }
+        if self.docker_compat() && self.framing() != JsonFraming::Ndjson {
+            bail!("Docker compatible entries require the ndjson framing")
+        }
+        if self.docker_compat() && self.repeat_window().is_some() {
+            bail!("Docker compatible entries do not support collapsing repeated lines")
+        }
+        if self.multiline_pattern().is_some() && self.repeat_window().is_some() {
+            bail!("joining multiline entries does not support collapsing repeated lines")
+        }
+        self.rotation()
+            .validate(self.max_files(), self.compression(), self.naming())?;
+        if self.checksum() && self.rotation() == RotationMode::Truncate {
+            bail!("checksums require a rotation keeping backups")
+        }
+        #[cfg(feature = "s3")]
+        if self.upload().is_some() && self.rotation() == RotationMode::Truncate {
+            bail!("uploads require a rotation keeping backups")
+        }
+        if self.append() && self.framing() == JsonFraming::Array {
+            return Err(LoggerError::Unsupported(
+                "append mode is not supported by the JSON array framing",
+            )
+            .into());
+        }
+        if let Some(fallback_path) = self.fallback_path() {
+            container_log::validate_path(fallback_path).await?;
+        }
+        if self.custom_sink() {
+            if self.framing() == JsonFraming::Array {
+                return Err(LoggerError::Unsupported(
+                    "the JSON array framing is not supported for custom sinks",
+                )
+                .into());
+            }
+            return Ok(());
+        }
+        container_log::validate_path(self.path()).await
+    }
+
+    /// A custom sink is initialized already.
+    async fn init(&mut self) -> Result<()> {
+        if self.custom_sink() {
+            return Ok(());
+        }
+        debug!("Initializing JSON logger in path {}", self.path().display());
+        self.open_file(self.append()).await
+    }
+
+    async fn write(
+        &mut self,
+        pipe: Pipe,
+        bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> Result<usize> {
+        Ok(JsonLogger::write(self, pipe, bytes).await?)
+    }
+
+    async fn write_line(&mut self, pipe: Pipe, line: &[u8]) -> Result<()> {
+        self.write_entry(pipe, line, false).await
+    }
+
+    async fn reopen(&mut self) -> Result<()> {
+        debug!("Reopen JSON log {}", self.path().display());
+        self.flush().await?;
+        if self.fifo() || self.custom_sink() {
+            // The reader of the FIFO or sink consumes the entries, which means there is nothing to
+            // rotate.
+            return Ok(());
+        }
+        self.file
+            .as_mut()
+            .ok_or(LoggerError::Uninitialized)?
+            .get_ref()
+            .sync_all()
+            .await?;
+        self.backup_task.wait().await;
+        let now = self.clock.now();
+        let backup = self
+            .rotation()
+            .rotate(
+                self.path(),
+                now,
+                self.max_files(),
+                self.compression(),
+                self.naming(),
+            )
+            .await
+            .map_err(|source| LoggerError::RotationFailed {
+                path: self.path().clone(),
+                source,
+            })?;
+        if let Some(backup) = backup {
+            let checksum = self.checksum();
+            #[cfg(feature = "s3")]
+            let upload = match self.upload() {
+                Some(upload) => Some((
+                    upload.clone(),
+                    upload.key(self.path(), now, self.compression())?,
+                )),
+                None => None,
+            };
+            self.backup_task
+                .spawn(backup, self.compression(), move |backup| async move {
+                    if checksum {
+                        log_rotation::write_checksum(backup.clone()).await?;
+                    }
+                    #[cfg(feature = "s3")]
+                    if let Some((upload, key)) = upload {
+                        upload.start(backup, key).await?;
+                    }
+                    Ok(())
+                });
+        }
+        // The rotated file always starts empty, even in append mode.
+        self.open_file(false).await
+    }
+
+    /// Flush the file writer, which closes the JSON array when using the array framing. A
+    /// retained repeated line gets written before.
+    async fn flush(&mut self) -> Result<()> {
+        self.write_repeated_line().await?;
+        self.unflushed.reset();
+        let file = self.file.as_mut().ok_or(LoggerError::Uninitialized)?;
+        if self.framing == JsonFraming::Array && !self.array_closed {
+            file.write_all(Self::ARRAY_CLOSE)
+                .await
+                .context("close JSON array")?;
+            self.array_closed = true;
+        }
+        file.flush().await.context("flush file writer")?;
+        self.retained.clear();
+        Ok(())
+    }
+
+    /// Write the retained partial lines, then flush and sync the log file before closing it, which
+    /// returns once all entries are on disk.
+    async fn close(&mut self) -> Result<()> {
+        self.backup_task.wait().await;
+        #[cfg(feature = "s3")]
+        if let Some(upload) = self.upload() {
+            upload.wait().await;
+        }
+        if self.file.is_none() {
+            return Ok(());
+        }
+        for pipe in [Pipe::StdIn, Pipe::StdOut, Pipe::StdErr] {
+            self.flush_partial(pipe).await?;
+        }
+        self.flush().await?;
+        if let Some(file) = self.file.take() {
+            if !self.fifo() {
+                file.get_ref().sync_all().await.context("sync log file")?;
+            }
+        }
+        self.sync_task = None;
+        if let (Some(budget), false) = (&self.budget, self.fifo()) {
+            budget.unregister(self.path()).await?;
+        }
+        Ok(())
+    }
+
+    /// Write an entry like `{"truncated": true, "dropped_bytes": N}` without a message.
+    async fn write_marker(&mut self, dropped_bytes: usize) -> Result<()> {
+        self.write_repeated_line().await?;
+        if self.docker_compat() {
+            return Ok(());
+        }
+        self.write_value(JsonLogEntry {
+            timestamp: self.timestamp()?,
+            truncated: true,
+            dropped_bytes: Some(dropped_bytes),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Write an entry without pipe and message, carrying the fields of the event.
+    async fn write_event(&mut self, event: LogEvent) -> Result<()> {
+        self.write_repeated_line().await?;
+        if self.docker_compat() {
+            return Ok(());
+        }
+        self.write_value(JsonLogEntry {
+            timestamp: self.timestamp()?,
+            event: Some(event),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Write the retained partial line of the provided pipe as an entry marked as partial, unless
+    /// it is a complete line retained by a failed write.
+    async fn flush_partial(&mut self, pipe: Pipe) -> Result<()> {
+        if let Some(line_buf) = self.partial_lines.remove(&pipe) {
+            let partial = line_buf.last() != Some(&b'\n');
+            if let Err(e) = self.write_entry(pipe, &line_buf, partial).await {
+                self.partial_lines.insert(pipe, line_buf);
+                return Err(e);
+            }
+        }
+        self.write_repeated_line().await
+    }
+
+    /// Joined multiline entries expire after the multiline timeout, while collapsed lines expire
+    /// after the repeat window.
+    fn expiry_interval(&self) -> Option<Duration> {
+        match self.multiline_pattern() {
+            Some(_) => Some(self.multiline_timeout()),
+            None => self.repeat_window(),
+        }
+    }
+
+    /// Write the retained line once its timeout elapsed since its first occurrence.
+    async fn flush_expired(&mut self) -> Result<()> {
+        let (Some(timeout), Some(repeated_line)) = (self.expiry_interval(), &self.repeated_line)
+        else {
+            return Ok(());
+        };
+        if self
+            .clock
+            .now()
+            .duration_since(repeated_line.since)
+            .is_ok_and(|elapsed| elapsed >= timeout)
+        {
+            self.write_repeated_line().await?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for JsonLogger {
+    fn drop(&mut self) {
+        if self.has_unflushed() {
+            warn!(
+                "Dropping JSON logger for {} with unflushed entries",
+                self.path().display()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        clock::{FixedClock, ManualClock},
+        log_reader::{self, TimeFilter},
+    };
+    use flate2::read::GzDecoder;
+    use serde::Deserialize;
+    use serde_json::json;
+    use std::{
+        fs,
+        io::{Cursor, Read},
+        os::unix::fs::{MetadataExt, PermissionsExt},
+        time::{Duration, UNIX_EPOCH},
+    };
+    use tempfile::NamedTempFile;
+    use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+    use tokio::io::AsyncReadExt;
+
+    #[tokio::test]
+    async fn test_json_logger_new() {
+        let logger = JsonLogger::new("/tmp/test.log", Some(1000)).unwrap();
+        assert_eq!(logger.path().to_str().unwrap(), "/tmp/test.log");
+        assert_eq!(logger.max_log_size().unwrap(), 1000);
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_init() {
+        let mut logger = JsonLogger::new("/tmp/test_init.log", Some(1000)).unwrap();
+        logger.init().await.unwrap();
+        assert!(logger.file.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write() {
+        let mut logger = JsonLogger::new("/tmp/test_write.log", Some(1000)).unwrap();
+        logger.init().await.unwrap();
+
+        let cursor = Cursor::new(b"Test log message\n".to_vec());
+        logger.write(Pipe::StdOut, cursor).await.unwrap();
+
+        // Read back from the file
+        let mut file = File::open("/tmp/test_write.log").await.unwrap();
+        let mut contents = String::new();
+        file.read_to_string(&mut contents).await.unwrap();
+
+        // Check if the file contains the logged message
+        assert!(contents.contains("Test log message"));
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_rotate_rename() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("test.log");
+        let mut logger = JsonLogger::new(&path, Some(200))?;
+        logger.set_rotation(RotationMode::Rename);
+        logger.init().await?;
+
+        for line in ["a\n", "b\n", "c\n", "d\n"] {
+            logger.write(Pipe::StdOut, line.as_bytes()).await?;
+        }
+
+        let messages = |path| -> Result<Vec<String>> {
+            fs::read_to_string(path)?
+                .lines()
+                .map(|line| {
+                    let entry: serde_json::Value = serde_json::from_str(line)?;
+                    Ok(entry["message"].as_str().context("no message")?.into())
+                })
+                .collect()
+        };
+        assert_eq!(
+            messages(log_rotation::backup_path(&path, 1, Compression::None))?,
+            ["a", "b"]
+        );
+        assert_eq!(messages(path.clone())?, ["c", "d"]);
+        assert!(!log_rotation::backup_path(&path, 2, Compression::None).exists());
+        assert_eq!(logger.rotations(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_entry_exceeding_max_size() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("test.log");
+        let mut logger = JsonLogger::new(&path, Some(10))?;
+        logger.set_rotation(RotationMode::Rename);
+        logger.init().await?;
+
+        let message = "x".repeat(99);
+        for _ in 0..2 {
+            logger
+                .write(Pipe::StdOut, format!("{message}\n").as_bytes())
+                .await?;
+        }
+        logger.flush().await?;
+
+        for path in [
+            log_rotation::backup_path(&path, 1, Compression::None),
+            path.clone(),
+        ] {
+            let content = fs::read_to_string(path)?;
+            let entries = content
+                .lines()
+                .map(serde_json::from_str)
+                .collect::<Result<Vec<serde_json::Value>, _>>()?;
+            assert_eq!(entries.len(), 1);
+            assert_eq!(entries[0]["message"], message);
+        }
+        assert_eq!(logger.rotations(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_rotate_compress() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("test.log");
+        let mut logger = JsonLogger::new(&path, None)?;
+        logger
+            .set_rotation(RotationMode::Rename)
+            .set_compression(Compression::Gzip);
+        logger.init().await?;
+
+        logger.write(Pipe::StdOut, "a\nb\n".as_bytes()).await?;
+        let expected = fs::read(&path)?;
+        logger.reopen().await?;
+        // The backup gets compressed in the background.
+        logger.backup_task.wait().await;
+
+        let mut contents = Vec::new();
+        GzDecoder::new(fs::File::open(log_rotation::backup_path(
+            &path,
+            1,
+            Compression::Gzip,
+        ))?)
+        .read_to_end(&mut contents)?;
+        assert_eq!(contents, expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_partial_line() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.init().await?;
+
+        logger.write(Pipe::StdOut, "abc".as_bytes()).await?;
+        assert!(fs::read_to_string(path)?.is_empty());
+
+        logger.write(Pipe::StdOut, "def\n".as_bytes()).await?;
+        logger.write(Pipe::StdErr, "ghi".as_bytes()).await?;
+        logger.flush_partial(Pipe::StdErr).await?;
+
+        let contents = fs::read_to_string(path)?;
+        let entries = contents
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["message"], "abcdef");
+        assert!(entries[0].get("partial").is_none());
+        assert_eq!(entries[1]["message"], "ghi");
+        assert_eq!(entries[1]["partial"], true);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_raw() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.init().await?;
+
+        logger
+            .write(Pipe::StdOut, "  indented \n".as_bytes())
+            .await?;
+        logger
+            .write(Pipe::StdOut, &b"\xff\xfeinvalid\n"[..])
+            .await?;
+
+        let contents = fs::read_to_string(path)?;
+        let entries = contents
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?;
+        assert_eq!(entries[0]["message"], "  indented ");
+        assert!(entries[1].get("message").is_none());
+        let raw = STANDARD.decode(
+            entries[1]["message_b64"]
+                .as_str()
+                .context("no raw message")?,
+        )?;
+        assert_eq!(raw, b"\xff\xfeinvalid");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_level() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.set_level_pattern(JsonLogger::level_regex("")?.into());
+        logger.init().await?;
+
+        logger
+            .write(
+                Pipe::StdOut,
+                "ERROR something broke\n[WARN] careful\nall good\nINFORMATION\n".as_bytes(),
+            )
+            .await?;
+
+        let contents = fs::read_to_string(path)?;
+        let entries = contents
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?;
+        assert_eq!(entries[0]["level"], "ERROR");
+        assert_eq!(entries[0]["message"], "something broke");
+        assert_eq!(entries[1]["level"], "WARN");
+        assert_eq!(entries[1]["message"], "careful");
+        for (entry, message) in entries[2..].iter().zip(["all good", "INFORMATION"]) {
+            assert!(entry.get("level").is_none());
+            assert_eq!(entry["message"], message);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_level_pattern() -> Result<()> {
+        assert!(JsonLogger::level_regex("^(E|W) ").is_err());
+        assert!(JsonLogger::level_regex("(").is_err());
+
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.set_level_pattern(JsonLogger::level_regex(r"^(?P<level>[EWI])\d{4} ")?.into());
+        logger.init().await?;
+
+        logger
+            .write(Pipe::StdErr, "E0101 glog style\n".as_bytes())
+            .await?;
+
+        let entry: serde_json::Value = serde_json::from_str(fs::read_to_string(path)?.trim())?;
+        assert_eq!(entry["level"], "E");
+        assert_eq!(entry["message"], "glog style");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_append() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let new_logger = |append| -> Result<JsonLogger> {
+            let mut logger = JsonLogger::new(path, Some(1000))?;
+            logger.set_append(append);
+            Ok(logger)
+        };
+
+        let mut logger = new_logger(true)?;
+        logger.init().await?;
+        logger.write(Pipe::StdOut, "first\n".as_bytes()).await?;
+        drop(logger);
+
+        let mut logger = new_logger(true)?;
+        logger.init().await?;
+        assert_eq!(logger.bytes_written() as u64, fs::metadata(path)?.len());
+        logger.write(Pipe::StdOut, "second\n".as_bytes()).await?;
+
+        let contents = fs::read_to_string(path)?;
+        let messages = contents
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?
+            .into_iter()
+            .map(|entry| entry["message"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["first", "second"]);
+
+        // Without append mode the previous entries get truncated.
+        let mut logger = new_logger(false)?;
+        logger.init().await?;
+        assert!(fs::read_to_string(path)?.is_empty());
+
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.set_framing(JsonFraming::Array).set_append(true);
+        let err = logger.validate().await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<LoggerError>(),
+            Some(LoggerError::Unsupported(_))
+        ));
+        assert!(logger.init().await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_uninitialized() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let mut logger = JsonLogger::new(file.path(), None)?;
+
+        let res = logger.write(Pipe::StdOut, "a\n".as_bytes()).await;
+        assert!(matches!(res, Err(LoggerError::Uninitialized)), "{res:?}");
+
+        // The retained partial line survives failed writes and flushes.
+        logger.write(Pipe::StdErr, "part".as_bytes()).await?;
+        let res = logger.write(Pipe::StdErr, "ial\n".as_bytes()).await;
+        assert!(matches!(res, Err(LoggerError::Uninitialized)), "{res:?}");
+        assert!(logger.flush_partial(Pipe::StdErr).await.is_err());
+
+        logger.init().await?;
+        logger.write(Pipe::StdErr, "next\n".as_bytes()).await?;
+        logger.flush().await?;
+        let messages = fs::read_to_string(file.path())?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<Value>, _>>()?
+            .into_iter()
+            .map(|entry| entry["message"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["partial", "next"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_marker() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.init().await?;
+
+        logger.write_marker(42).await?;
+
+        let entry: serde_json::Value = serde_json::from_str(fs::read_to_string(path)?.trim())?;
+        assert_eq!(entry["truncated"], true);
+        assert_eq!(entry["dropped_bytes"], 42);
+        assert!(entry.get("message").is_none());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_json_logger_write_fifo() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("fifo");
+        nix::unistd::mkfifo(&path, nix::sys::stat::Mode::S_IRWXU)?;
+
+        let reader = std::thread::spawn({
+            let path = path.clone();
+            move || fs::read_to_string(path)
+        });
+
+        let mut logger = JsonLogger::new(&path, Some(1))?;
+        logger.set_durability(DurabilityPolicy::EveryWrite);
+        logger.init().await?;
+        logger.write(Pipe::StdOut, "a\nb\n".as_bytes()).await?;
+        drop(logger);
+
+        let contents = reader.join().expect("join reader thread")?;
+        let messages = contents
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?
+            .into_iter()
+            .map(|entry| entry["message"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["a", "b"]);
+        assert!(JsonLogger::is_fifo(&fs::metadata(&path)?));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_trim() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.set_trim(true);
+        logger.init().await?;
+
+        logger.write(Pipe::StdOut, &b"  \xffmessage \n"[..]).await?;
+
+        let entry: serde_json::Value = serde_json::from_str(fs::read_to_string(path)?.trim())?;
+        assert_eq!(entry["message"], "\u{fffd}message");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_durability() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.set_durability(DurabilityPolicy::EveryWrite);
+        logger.init().await?;
+        assert!(logger.sync_task.is_none());
+
+        logger
+            .write(Pipe::StdOut, "hello world\n".as_bytes())
+            .await?;
+        assert!(fs::read_to_string(path)?.contains("hello world"));
+
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.set_durability(DurabilityPolicy::Interval(Duration::from_millis(10)));
+        logger.init().await?;
+        assert!(logger.sync_task.is_some());
+
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.set_durability(DurabilityPolicy::Interval(Duration::ZERO));
+        assert!(logger.init().await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_max_line_size() -> Result<()> {
+        const MAX_LINE_SIZE: usize = 64 * 1024;
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.set_max_line_size(Some(MAX_LINE_SIZE));
+        logger.init().await?;
+
+        let input = vec![b'a'; 1024 * 1024];
+        logger.write(Pipe::StdOut, &input[..]).await?;
+        logger.flush_partial(Pipe::StdOut).await?;
+
+        let contents = fs::read_to_string(path)?;
+        let entries = contents
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?;
+        assert_eq!(entries.len(), input.len() / MAX_LINE_SIZE);
+        for entry in entries {
+            let message = entry["message"].as_str().context("no message")?;
+            assert_eq!(message.len(), MAX_LINE_SIZE);
+            assert_eq!(entry["partial"], true);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_max_line_size_codepoint() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.set_max_line_size(Some(8));
+        logger.init().await?;
+
+        // The maximum line size would split the emoji, which therefore starts the second entry.
+        let input = "hello😀 world\n".as_bytes();
+        let (first, second) = input.split_at(7);
+        logger.write(Pipe::StdOut, first).await?;
+        logger.write(Pipe::StdOut, second).await?;
+
+        let messages = fs::read_to_string(path)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?
+            .into_iter()
+            .map(|entry| entry["message"].as_str().map(String::from))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            vec![
+                Some("hello".into()),
+                Some("😀 wor".into()),
+                Some("ld".into())
+            ]
+        );
+        Ok(())
+    }
+
+    async fn write_framed(framing: JsonFraming) -> Result<String> {
+        let file = NamedTempFile::new()?;
+        let mut logger = JsonLogger::new(file.path(), None)?;
+        logger.set_framing(framing);
+        logger.init().await?;
+
+        logger.write(Pipe::StdOut, "a\nb\n".as_bytes()).await?;
+        logger.flush().await?;
+        logger.write(Pipe::StdErr, "c\n".as_bytes()).await?;
+        logger.flush().await?;
+
+        Ok(fs::read_to_string(file.path())?)
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_framing_ndjson() -> Result<()> {
+        let contents = write_framed(JsonFraming::Ndjson).await?;
+        let messages = contents
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?
+            .into_iter()
+            .map(|entry| entry["message"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["a", "b", "c"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_framing_json_seq() -> Result<()> {
+        let contents = write_framed(JsonFraming::JsonSeq).await?;
+        let records = contents
+            .strip_prefix('\u{1e}')
+            .context("no leading record separator")?
+            .split('\u{1e}')
+            .map(|record| {
+                let text = record.strip_suffix('\n').context("no trailing line feed")?;
+                Ok(serde_json::from_str(text)?)
+            })
+            .collect::<Result<Vec<serde_json::Value>>>()?;
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0]["message"], "a");
+        assert_eq!(records[2]["message"], "c");
+        assert_eq!(records[2]["pipe"], "stderr");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_framing_array() -> Result<()> {
+        let contents = write_framed(JsonFraming::Array).await?;
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&contents)?;
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0]["message"], "a");
+        assert_eq!(entries[1]["message"], "b");
+        assert_eq!(entries[2]["message"], "c");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_framing_array_rotate() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("test.log");
+        let mut logger = JsonLogger::new(&path, None)?;
+        logger.set_framing(JsonFraming::Array);
+        logger.set_rotation(RotationMode::Rename);
+        logger.init().await?;
+
+        logger.write(Pipe::StdOut, "a\n".as_bytes()).await?;
+        logger.reopen().await?;
+        logger.flush().await?;
+
+        let backup: Vec<serde_json::Value> = serde_json::from_str(&fs::read_to_string(
+            log_rotation::backup_path(&path, 1, Compression::None),
+        )?)?;
+        assert_eq!(backup.len(), 1);
+        let current: Vec<serde_json::Value> = serde_json::from_str(&fs::read_to_string(&path)?)?;
+        assert!(current.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_metadata() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.set_metadata(BTreeMap::from([
+            ("id".into(), "0123456789abcdef".into()),
+            ("pod_name".into(), "pod".into()),
+            ("message".into(), "reserved".into()),
+        ]));
+        logger.init().await?;
+
+        logger.write(Pipe::StdOut, "a\nb\n".as_bytes()).await?;
+
+        let contents = fs::read_to_string(path)?;
+        let entries = contents
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?;
+        assert_eq!(entries.len(), 2);
+        for (entry, message) in entries.iter().zip(["a", "b"]) {
+            assert_eq!(entry["message"], message);
+            assert_eq!(
+                entry["container"],
+                json!({"id": "0123456789abcdef", "pod_name": "pod", "message": "reserved"})
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_stdin() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.init().await?;
+
+        logger.write(Pipe::StdIn, "ls -l\n".as_bytes()).await?;
+
+        let entry: serde_json::Value = serde_json::from_str(fs::read_to_string(path)?.trim())?;
+        assert_eq!(entry["pipe"], "stdin");
+        assert_eq!(entry["message"], "ls -l");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_timestamp() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.init().await?;
+
+        logger
+            .write(Pipe::StdOut, "Test log message\n".as_bytes())
+            .await?;
+
+        let contents = fs::read_to_string(path)?;
+        let entry: serde_json::Value = serde_json::from_str(contents.trim())?;
+        let timestamp = entry["timestamp"].as_str().context("no timestamp")?;
+        let parsed =
+            OffsetDateTime::parse(timestamp, &Rfc3339).context("unable to parse timestamp")?;
+        assert!(parsed.offset().is_utc());
+        Ok(())
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(deny_unknown_fields)]
+    /// The documented shape of an entry, which fails on any unexpected field.
+    struct ContractEntry {
+        timestamp: String,
+        seq: Option<u64>,
+        pipe: Option<String>,
+        level: Option<String>,
+        message: Option<String>,
+        message_b64: Option<String>,
+        #[serde(default)]
+        partial: bool,
+        #[serde(default)]
+        truncated: bool,
+        dropped_bytes: Option<usize>,
+        container: Option<BTreeMap<String, String>>,
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_entry_contract() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        let timestamp = "2023-11-14T22:13:20.000000000Z";
+        logger
+            .set_clock(Arc::new(FixedClock(
+                UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+            )))
+            .set_metadata(BTreeMap::from([("id".into(), "123".into())]))
+            .set_level_pattern(JsonLogger::level_regex("")?.into())
+            .set_seq(Some(0));
+        logger.init().await?;
+
+        logger
+            .write(Pipe::StdErr, "ERROR failed\n".as_bytes())
+            .await?;
+        logger.write(Pipe::StdOut, b"\xff".as_slice()).await?;
+        logger.flush_partial(Pipe::StdOut).await?;
+        logger.write_marker(42).await?;
+
+        let entries = fs::read_to_string(path)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<ContractEntry>, _>>()?;
+        let container = Some(BTreeMap::from([("id".into(), "123".into())]));
+        assert_eq!(
+            entries,
+            [
+                ContractEntry {
+                    timestamp: timestamp.into(),
+                    seq: Some(0),
+                    pipe: Some("stderr".into()),
+                    level: Some("ERROR".into()),
+                    message: Some("failed".into()),
+                    message_b64: None,
+                    partial: false,
+                    truncated: false,
+                    dropped_bytes: None,
+                    container: container.clone(),
+                },
+                ContractEntry {
+                    timestamp: timestamp.into(),
+                    seq: Some(1),
+                    pipe: Some("stdout".into()),
+                    level: None,
+                    message: None,
+                    message_b64: Some("/w==".into()),
+                    partial: true,
+                    truncated: false,
+                    dropped_bytes: None,
+                    container: container.clone(),
+                },
+                ContractEntry {
+                    timestamp: timestamp.into(),
+                    seq: Some(2),
+                    pipe: None,
+                    level: None,
+                    message: None,
+                    message_b64: None,
+                    partial: false,
+                    truncated: true,
+                    dropped_bytes: Some(42),
+                    container,
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_crlf() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.init().await?;
+
+        logger
+            .write(Pipe::StdOut, "line\r\n\rcarriage \r\r\n".as_bytes())
+            .await?;
+
+        let messages = fs::read_to_string(path)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?
+            .into_iter()
+            .map(|entry| entry["message"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["line", "\rcarriage \r"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_reuse_buffers() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.init().await?;
+
+        logger.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+        assert!(logger.line_buf.is_empty());
+        assert!(logger.line_buf.capacity() > 0);
+        assert!(logger.entry_buf.capacity() > 0);
+
+        let huge = format!("{}\n", "a".repeat(2 * JsonLogger::RETAINED_CAPACITY));
+        logger.write(Pipe::StdOut, huge.as_bytes()).await?;
+        assert!(logger.line_buf.capacity() <= JsonLogger::RETAINED_CAPACITY);
+        assert!(logger.entry_buf.capacity() <= JsonLogger::RETAINED_CAPACITY);
+
+        logger.write(Pipe::StdOut, "world\n".as_bytes()).await?;
+        let messages = fs::read_to_string(path)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?
+            .into_iter()
+            .map(|entry| entry["message"].as_str().map(str::len))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [Some(5), Some(2 * JsonLogger::RETAINED_CAPACITY), Some(5)]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_golden() -> Result<()> {
+        let entry = |message: &str| {
+            format!(
+                r#"{{"timestamp":"2023-11-14T22:13:20.000000000Z","pipe":"stdout","message":"{message}","container":{{"id":"123"}}}}"#
+            )
+        };
+        for (framing, expected) in [
+            (
+                JsonFraming::Ndjson,
+                format!("{}\n{}\n", entry("a"), entry("b")),
+            ),
+            (
+                JsonFraming::JsonSeq,
+                format!("\x1e{}\n\x1e{}\n", entry("a"), entry("b")),
+            ),
+            (
+                JsonFraming::Array,
+                format!("[\n{},\n{}\n]\n", entry("a"), entry("b")),
+            ),
+        ] {
+            let file = NamedTempFile::new()?;
+            let path = file.path();
+            let mut logger = JsonLogger::new(path, None)?;
+            logger.set_framing(framing);
+            logger
+                .set_clock(Arc::new(FixedClock(
+                    UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+                )))
+                .set_metadata(BTreeMap::from([("id".into(), "123".into())]));
+            logger.init().await?;
+
+            logger.write(Pipe::StdOut, "a\n".as_bytes()).await?;
+            logger.flush().await?;
+            logger.write(Pipe::StdOut, "b\n".as_bytes()).await?;
+            logger.flush().await?;
+
+            assert_eq!(fs::read_to_string(path)?, expected, "{framing:?}");
+            assert_eq!(logger.bytes_written(), 2 * entry("a").len(), "{framing:?}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_pipe_label() -> Result<()> {
+        for (pipe_label, expected) in [
+            (PipeLabel::Name, [json!("stdout"), json!("stderr")]),
+            (PipeLabel::Short, [json!("out"), json!("err")]),
+            (PipeLabel::Fd, [json!(1), json!(2)]),
+        ] {
+            let file = NamedTempFile::new()?;
+            let path = file.path();
+            let mut logger = JsonLogger::new(path, None)?;
+            logger.set_pipe_label(pipe_label);
+            logger.init().await?;
+
+            logger.write(Pipe::StdOut, "out\n".as_bytes()).await?;
+            logger.write(Pipe::StdErr, "err\n".as_bytes()).await?;
+
+            let pipes = fs::read_to_string(path)?
+                .lines()
+                .map(serde_json::from_str)
+                .collect::<Result<Vec<serde_json::Value>, _>>()?
+                .into_iter()
+                .map(|entry| entry["pipe"].clone())
+                .collect::<Vec<_>>();
+            assert_eq!(pipes, expected, "{pipe_label:?}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_seq() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger
+            .set_seq(Some(0))
+            .set_clock(Arc::new(FixedClock(SystemTime::UNIX_EPOCH)));
+        logger.init().await?;
+
+        for i in 0..10 {
+            let pipe = if i % 3 == 0 {
+                Pipe::StdErr
+            } else {
+                Pipe::StdOut
+            };
+            logger.write(pipe, format!("{i}\n").as_bytes()).await?;
+        }
+        logger.write_marker(5).await?;
+
+        let seqs = fs::read_to_string(path)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?
+            .into_iter()
+            .map(|entry| entry["seq"].as_u64().context("no seq"))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(seqs, (0..11).collect::<Vec<_>>());
+        assert_eq!(logger.seq(), Some(11));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_file_access() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("json.log");
+        let mut logger = JsonLogger::new(&path, None)?;
+        logger.set_file_access(FileAccess {
+            mode: Some(0o640),
+            uid: Some(nix::unistd::geteuid().as_raw()),
+            gid: Some(nix::unistd::getegid().as_raw()),
+            ..Default::default()
+        });
+        logger.init().await?;
+        logger.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+
+        let metadata = fs::metadata(&path)?;
+        assert_eq!(metadata.permissions().mode() & 0o777, 0o640);
+        assert_eq!(metadata.uid(), nix::unistd::geteuid().as_raw());
+
+        // Reopening a rotated file keeps the requested mode.
+        logger.reopen().await?;
+        assert_eq!(fs::metadata(&path)?.permissions().mode() & 0o777, 0o640);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_hostname() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.set_hostname(Some("node-1".into()));
+        logger.init().await?;
+
+        logger.write(Pipe::StdOut, "a\nb\n".as_bytes()).await?;
+        logger.write(Pipe::StdErr, "c\n".as_bytes()).await?;
+        logger.write_marker(5).await?;
+
+        let entries = fs::read_to_string(path)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?;
+        assert_eq!(entries.len(), 4);
+        for entry in entries {
+            assert_eq!(entry["hostname"], "node-1");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_close_unflushed() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger
+            .set_repeat_window(Some(Duration::from_secs(60)))
+            .set_flush_threshold(FlushThreshold {
+                lines: Some(100),
+                bytes: None,
+            });
+        logger.init().await?;
+
+        logger
+            .write(Pipe::StdOut, "a\nb\nb\npartial".as_bytes())
+            .await?;
+        assert!(logger.has_unflushed());
+        assert_eq!(fs::read_to_string(path)?, "");
+
+        logger.close().await?;
+        assert!(!logger.has_unflushed());
+        let entries = fs::read_to_string(path)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?;
+        let messages = entries
+            .iter()
+            .map(|x| x["message"].as_str().unwrap_or_default())
+            .collect::<Vec<_>>();
+        assert_eq!(messages, ["a", "b", "partial"]);
+        assert_eq!(entries[1]["repeated"], 2);
+        assert_eq!(entries[2]["partial"], true);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_empty_lines() -> Result<()> {
+        for (trim, empty_lines, expected) in [
+            (false, EmptyLines::Keep, vec!["a", "", "  ", "b"]),
+            (false, EmptyLines::Drop, vec!["a", "  ", "b"]),
+            (true, EmptyLines::Keep, vec!["a", "", "", "b"]),
+            (true, EmptyLines::Drop, vec!["a", "b"]),
+        ] {
+            let file = NamedTempFile::new()?;
+            let path = file.path();
+            let mut logger = JsonLogger::new(path, None)?;
+            logger.set_trim(trim);
+            logger.set_empty_lines(empty_lines);
+            logger.init().await?;
+
+            logger
+                .write(Pipe::StdOut, "a\n\n  \nb\n".as_bytes())
+                .await?;
+
+            let messages = fs::read_to_string(path)?
+                .lines()
+                .map(serde_json::from_str)
+                .collect::<Result<Vec<serde_json::Value>, _>>()?
+                .into_iter()
+                .map(|x| x["message"].as_str().unwrap_or_default().to_owned())
+                .collect::<Vec<_>>();
+            assert_eq!(messages, expected, "{trim} {empty_lines:?}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_keys() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.set_keys(JsonKeys::new("", "_pipe", "short_message")?);
+        logger.init().await?;
+
+        logger.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+
+        let entries = fs::read_to_string(path)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?;
+        assert_eq!(entries.len(), 1);
+        let entry = entries[0].as_object().context("no object")?;
+        assert_eq!(
+            entry.keys().collect::<Vec<_>>(),
+            ["_pipe", "short_message", "timestamp"]
+        );
+        assert_eq!(entry["_pipe"], "stdout");
+        assert_eq!(entry["short_message"], "hello");
+
+        assert!(JsonKeys::new("msg", "", "msg").is_err());
+        assert!(JsonKeys::new("", "", "level").is_err());
+        assert_eq!(JsonKeys::new("", "", "")?, JsonKeys::default());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_multiline() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        let clock = Arc::new(ManualClock::new(SystemTime::UNIX_EPOCH));
+        logger
+            .set_multiline_pattern(JsonLogger::multiline_regex("")?.into())
+            .set_clock(clock.clone());
+        logger.init().await?;
+
+        let trace = concat!(
+            "Exception in thread \"main\" java.lang.IllegalStateException: boom\n",
+            "\tat com.example.Foo.bar(Foo.java:10)\n",
+            "\tat com.example.Foo.main(Foo.java:5)\n",
+            "Caused by: java.lang.NullPointerException\n",
+            "\t... 1 more\n",
+        );
+        logger.write(Pipe::StdErr, "starting\n".as_bytes()).await?;
+        // The trace arrives in chunks, which still become a single entry.
+        let (first, second) = trace.split_at(50);
+        logger.write(Pipe::StdErr, first.as_bytes()).await?;
+        logger.write(Pipe::StdErr, second.as_bytes()).await?;
+        // An indented line after the timeout starts a new entry.
+        clock.advance(JsonLogger::DEFAULT_MULTILINE_TIMEOUT);
+        logger.write(Pipe::StdErr, "  late\n".as_bytes()).await?;
+        logger.write(Pipe::StdOut, "done".as_bytes()).await?;
+        // The end of the stream writes the pending entry.
+        logger.flush_partial(Pipe::StdOut).await?;
+        logger.flush().await?;
+
+        let messages = fs::read_to_string(path)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?
+            .into_iter()
+            .map(|entry| entry["message"].as_str().map(String::from))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                Some("starting".into()),
+                Some(trace.trim_end().into()),
+                Some("  late".into()),
+                Some("done".into()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_multiline_expired() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        let clock = Arc::new(ManualClock::new(SystemTime::UNIX_EPOCH));
+        logger
+            .set_multiline_pattern(JsonLogger::multiline_regex("")?.into())
+            .set_max_line_size(Some(32))
+            .set_clock(clock.clone());
+        logger.init().await?;
+        assert_eq!(
+            logger.expiry_interval(),
+            Some(JsonLogger::DEFAULT_MULTILINE_TIMEOUT)
+        );
+
+        let messages = || -> Result<Vec<String>> {
+            fs::read_to_string(path)?
+                .lines()
+                .map(|line| {
+                    let entry: serde_json::Value = serde_json::from_str(line)?;
+                    entry["message"]
+                        .as_str()
+                        .map(String::from)
+                        .context("no message")
+                })
+                .collect()
+        };
+
+        // A blank line is no continuation line.
+        logger
+            .write(
+                Pipe::StdErr,
+                "error: boom
+	at main
+
+"
+                .as_bytes(),
+            )
+            .await?;
+        logger.flush_expired().await?;
+        assert_eq!(
+            messages()?,
+            ["error: boom
+	at main"]
+        );
+
+        // The joined entry does not exceed the maximum line size.
+        logger
+            .write(
+                Pipe::StdErr,
+                "error: again
+	at first frame
+	at second frame
+"
+                .as_bytes(),
+            )
+            .await?;
+        // Without another line the entry gets written once the timeout elapsed.
+        logger.flush_expired().await?;
+        assert_eq!(messages()?.len(), 3);
+        clock.advance(JsonLogger::DEFAULT_MULTILINE_TIMEOUT);
+        logger.flush_expired().await?;
+        assert_eq!(
+            messages()?,
+            [
+                "error: boom
+	at main",
+                "",
+                "error: again
+	at first frame",
+                "	at second frame",
+            ]
+        );
+        assert!(!logger.has_unflushed());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_repeat_window() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("json.log");
+        let mut logger = JsonLogger::new(&path, None)?;
+        let clock = Arc::new(ManualClock::new(SystemTime::UNIX_EPOCH));
+        logger
+            .set_repeat_window(Duration::from_secs(10).into())
+            .set_rotation(RotationMode::Rename)
+            .set_clock(clock.clone());
+        logger.init().await?;
+
+        for _ in 0..5 {
+            logger.write(Pipe::StdOut, "same\n".as_bytes()).await?;
+        }
+        // Written only on flush, because the next line may repeat it as well.
+        assert!(fs::read_to_string(&path)?.is_empty());
+        logger.flush().await?;
+
+        // Different pipes, lines and an elapsed window end the repetition.
+        logger.write(Pipe::StdErr, "same\n".as_bytes()).await?;
+        logger
+            .write(Pipe::StdErr, "other\nother\n".as_bytes())
+            .await?;
+        clock.advance(Duration::from_secs(10));
+        logger.write(Pipe::StdErr, "other\n".as_bytes()).await?;
+        // Rotating writes the retained line into the rotated file.
+        logger.reopen().await?;
+        assert!(fs::read_to_string(&path)?.is_empty());
+
+        let backup = log_rotation::backup_path(&path, 1, Compression::None);
+        let entries = fs::read_to_string(backup)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?;
+        let entries = entries
+            .iter()
+            .map(|entry| (entry["message"].as_str(), entry["repeated"].as_u64()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            vec![
+                (Some("same"), Some(5)),
+                (Some("same"), None),
+                (Some("other"), Some(2)),
+                (Some("other"), None),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_watch_path() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("0.log");
+        let mut logger = JsonLogger::new(&path, None)?;
+        logger.set_watch_path(true);
+        logger.init().await?;
+
+        logger.write(Pipe::StdOut, "a\n".as_bytes()).await?;
+        fs::rename(&path, dir.path().join("0.log.old"))?;
+        logger.write(Pipe::StdOut, "b\n".as_bytes()).await?;
+
+        let messages = |path| -> Result<Vec<String>> {
+            fs::read_to_string(path)?
+                .lines()
+                .map(|line| {
+                    let entry: serde_json::Value = serde_json::from_str(line)?;
+                    Ok(entry["message"].as_str().context("no message")?.into())
+                })
+                .collect()
+        };
+        assert_eq!(messages(path.clone())?, ["b"]);
+        assert_eq!(messages(dir.path().join("0.log.old"))?, ["a"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_fixed_clock() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.set_clock(Arc::new(FixedClock(
+            UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789),
+        )));
+        logger.init().await?;
+
+        logger.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+
+        let entry: serde_json::Value = serde_json::from_str(fs::read_to_string(path)?.trim())?;
+        assert_eq!(entry["timestamp"], "2023-11-14T22:13:20.123456789Z");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_custom_sink() -> Result<()> {
+        let mut logger = JsonLogger::new_with_sink(Vec::new())?;
+        logger.set_framing(JsonFraming::Array);
+        assert!(logger.validate().await.is_err());
+
+        let (writer, mut reader) = tokio::io::duplex(64 * 1024);
+        let mut logger = JsonLogger::new_with_sink(writer)?;
+        logger.set_clock(Arc::new(FixedClock(
+            UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+        )));
+        logger.validate().await?;
+        logger.init().await?;
+        assert!(logger.source().is_none());
+
+        logger.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+        logger.reopen().await?;
+        logger.write(Pipe::StdErr, "world\n".as_bytes()).await?;
+        logger.close().await?;
+
+        let mut output = vec![];
+        reader.read_to_end(&mut output).await?;
+        assert_eq!(
+            String::from_utf8(output)?,
+            concat!(
+                r#"{"timestamp":"2023-11-14T22:13:20.000000000Z","pipe":"stdout","message":"hello"}"#,
+                "\n",
+                r#"{"timestamp":"2023-11-14T22:13:20.000000000Z","pipe":"stderr","message":"world"}"#,
+                "\n",
+            )
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_docker_compat() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger
+            .set_clock(Arc::new(FixedClock(
+                UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789),
+            )))
+            .set_time_zone(tzdb::tz_by_name("Asia/Kolkata"))
+            .set_metadata([("id".into(), "123".into())].into())
+            .set_seq(Some(0))
+            .set_docker_compat(true);
+        logger.validate().await?;
+        logger.init().await?;
+
+        logger
+            .write(Pipe::StdOut, "hello <b> & \"x\"\n".as_bytes())
+            .await?;
+        logger.write_marker(42).await?;
+        logger
+            .write(Pipe::StdErr, &b"a\tb\x01\xff\xe2\x80\xa8\n"[..])
+            .await?;
+        logger.write(Pipe::StdOut, "part".as_bytes()).await?;
+        logger.flush_partial(Pipe::StdOut).await?;
+        logger.flush().await?;
+
+        let time = "2023-11-14T22:13:20.123456789Z";
+        let contents = fs::read_to_string(path)?;
+        assert_eq!(
+            contents.lines().collect::<Vec<_>>(),
+            [
+                format!(
+                    r#"{{"log":"hello \u003cb\u003e \u0026 \"x\"\n","stream":"stdout","time":"{time}"}}"#
+                ),
+                format!(
+                    r#"{{"log":"a\tb\u0001\ufffd\u2028\n","stream":"stderr","time":"{time}"}}"#
+                ),
+                format!(r#"{{"log":"part","stream":"stdout","time":"{time}"}}"#),
+            ]
+        );
+        for line in contents.lines() {
+            let entry: serde_json::Map<String, Value> = serde_json::from_str(line)?;
+            assert_eq!(entry.keys().collect::<Vec<_>>(), ["log", "stream", "time"]);
+        }
+        // The entries can be read back as well.
+        let lines = log_reader::tail(path, LogFormat::Json, 10, TimeFilter::default()).await?;
+        assert_eq!(lines[0].timestamp, time);
+        assert_eq!(lines[0].pipe, "stdout");
+        assert_eq!(lines[0].message, br#"hello <b> & "x""#);
+
+        logger.framing = JsonFraming::JsonSeq;
+        assert!(logger.validate().await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_write_time_zone() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger
+            .set_clock(Arc::new(FixedClock(
+                UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789),
+            )))
+            .set_time_zone(tzdb::tz_by_name("Asia/Kolkata"));
+        logger.init().await?;
+
+        logger.write(Pipe::StdOut, "hello\n".as_bytes()).await?;
+
+        let entry: serde_json::Value = serde_json::from_str(fs::read_to_string(path)?.trim())?;
+        let timestamp = entry["timestamp"].as_str().context("no timestamp")?;
+        assert_eq!(timestamp, "2023-11-15T03:43:20.123456789+05:30");
+        let parsed = OffsetDateTime::parse(timestamp, &Rfc3339)?;
+        assert_eq!(parsed.offset().whole_minutes(), 330);
+        assert_eq!(parsed.unix_timestamp(), 1_700_000_000);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_rotate_boundary() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("0.log");
+        let entry_len =
+            r#"{"timestamp":"2023-11-14T22:13:20.000000000Z","pipe":"stdout","message":"0"}"#.len();
+        let mut logger = JsonLogger::new(&path, Some(3 * entry_len))?;
+        logger
+            .set_rotation(RotationMode::Rename)
+            .set_clock(Arc::new(FixedClock(
+                UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+            )));
+        logger.init().await?;
+
+        // The first three entries exactly fill the file, while the fourth one triggers the rotation.
+        logger.write(Pipe::StdOut, "0\n1\n2\n".as_bytes()).await?;
+        assert_eq!(logger.bytes_written(), 3 * entry_len);
+        assert_eq!(logger.rotations(), 0);
+        logger.write(Pipe::StdOut, "3\n".as_bytes()).await?;
+        assert_eq!(logger.rotations(), 1);
+
+        let messages = |path| -> Result<Vec<String>> {
+            fs::read_to_string(path)?
+                .lines()
+                .map(|line| {
+                    let entry: serde_json::Value = serde_json::from_str(line)?;
+                    Ok(entry["message"].as_str().context("no message")?.into())
+                })
+                .collect()
+        };
+        assert_eq!(
+            messages(log_rotation::backup_path(&path, 1, Compression::None))?,
+            ["0", "1", "2"]
+        );
+        assert_eq!(messages(path.clone())?, ["3"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_json_logger_fallback_path() -> Result<()> {
+        let file = NamedTempFile::new()?;
+        let fallback_path = file.path();
+        // Writes to the primary path fail because the device is always full.
+        let mut logger = JsonLogger::new("/dev/full", None)?;
+        logger.set_fallback_path(Some(fallback_path.into()));
+        logger.init().await?;
+
+        logger.write(Pipe::StdOut, "one\ntwo\n".as_bytes()).await?;
+
+        assert_eq!(logger.path(), fallback_path);
+        let entries = fs::read_to_string(fallback_path)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?;
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0]["event"], "log_path_failover");
+        assert_eq!(entries[0]["previous_path"], "/dev/full");
+        assert_eq!(entries[1]["message"], "one");
+        assert_eq!(entries[2]["message"], "two");
+        Ok(())
+    }
+
+    #[cfg(feature = "trace-context")]
+    #[tokio::test]
+    async fn test_json_logger_trace_context() -> Result<()> {
+        use opentelemetry::trace::TracerProvider as _;
+        use tracing::Instrument;
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let provider = opentelemetry_sdk::trace::TracerProvider::builder().build();
+        let tracer = provider.tracer("test");
+        let subscriber =
+            tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let file = NamedTempFile::new()?;
+        let path = file.path();
+        let mut logger = JsonLogger::new(path, None)?;
+        logger.set_trace_context(true);
+        logger.init().await?;
+
+        let span = tracing::info_span!("container_io");
+        let (trace_id, span_id) = span
+            .in_scope(Telemetry::current_ids)
+            .context("no active trace")?;
+        logger
+            .write(Pipe::StdOut, "traced\n".as_bytes())
+            .instrument(span)
+            .await?;
+        logger.write(Pipe::StdOut, "untraced\n".as_bytes()).await?;
+
+        let entries = fs::read_to_string(path)?
+            .lines()
+            .map(serde_json::from_str)
+            .collect::<Result<Vec<serde_json::Value>, _>>()?;
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["trace_id"], trace_id.to_string());
+        assert_eq!(entries[0]["span_id"], span_id.to_string());
+        assert_eq!(entries[0]["trace_id"].as_str().map(str::len), Some(32));
+        assert!(entries[1].get("trace_id").is_none());
+        assert!(entries[1].get("span_id").is_none());
+        Ok(())
     }
 
     #[tokio::test]
@@ -4,6 +4,28 @@
 pub use server::Server;
 pub use version::Version;
 
+/// The CRI and JSON log drivers for embedding conmon-rs as a library, which write into a log file
+/// or any custom sink, and the parsers reading their entries back.
+pub mod logger {
+    pub use crate::{
+        container_io::Pipe,
+        container_log::Logger,
+        cri_logger::CriLogger,
+        json_logger::JsonLogger,
+        log_error::LoggerError,
+        log_reader::{parse_cri, parse_json, ParseError, ParsedLine},
+    };
+}
+
+#[doc(hidden)]
+/// Internals exercised by the benchmarks, which are not part of the stable API.
+pub mod bench {
+    pub use crate::{
+        container_io::Pipe,
+        container_log::{ContainerLog, ContainerLogOptions, SharedContainerLog},
+    };
+}
+
 #[macro_use]
 mod macros;
 
@@ -11,20 +33,48 @@ mod attach;
 mod capnp_util;
 mod child;
 mod child_reaper;
+mod clock;
+mod command_logger;
 mod config;
 mod container_io;
 mod container_log;
 mod cri_logger;
 mod fd_socket;
+#[cfg(feature = "gelf")]
+mod gelf_logger;
 mod init;
+#[cfg(feature = "journald")]
 mod journal;
+#[cfg(feature = "journald")]
+mod journald_logger;
 mod json_logger;
+mod line_buffer;
 mod listener;
+mod log_budget;
+mod log_durability;
+mod log_error;
+mod log_metrics;
+mod log_queue;
+mod log_reader;
+mod log_retry;
+mod log_rotation;
+mod log_sink;
+mod log_transform;
+#[cfg(feature = "s3")]
+mod log_upload;
+mod memory_logger;
+mod null_logger;
 mod oom_watcher;
+mod optional_logger;
+#[cfg(feature = "otlp")]
+mod otlp_logger;
 mod pause;
 mod rpc;
 mod server;
+mod size;
 mod streams;
+mod syslog_logger;
 mod telemetry;
 mod terminal;
+mod timeout_logger;
 mod version;
@@ -0,0 +1,145 @@
+//! Line assembly across multiple writes.
+
+use crate::container_io::Pipe;
+use anyhow::Result;
+use std::{collections::HashMap, mem};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, BufReader};
+
+#[derive(Debug)]
+/// Assembles the lines of every pipe across multiple writes, which allows decoding their UTF-8
+/// sequences only once complete.
+pub struct LineBuffer {
+    /// Trailing lines without newline, retained until more data of their pipe arrives.
+    partial_lines: HashMap<Pipe, Vec<u8>>,
+
+    /// Length at which a retained line gets returned without newline.
+    max_len: usize,
+}
+
+impl Default for LineBuffer {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_LEN)
+    }
+}
+
+impl LineBuffer {
+    /// The default length at which a retained line gets returned.
+    pub const DEFAULT_MAX_LEN: usize = 64 * 1024;
+
+    /// Create a new line buffer returning retained lines once they reach `max_len`.
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            partial_lines: HashMap::new(),
+            max_len,
+        }
+    }
+
+    /// Read the provided reader and return the amount of read bytes as well as the complete
+    /// lines, including the retained beginning of the first one. A trailing line without newline
+    /// is retained, unless it reaches the maximum length. It then gets returned up to the last
+    /// complete UTF-8 sequence.
+    pub async fn read<T>(&mut self, pipe: Pipe, bytes: T) -> Result<(usize, Vec<Vec<u8>>)>
+    where
+        T: AsyncBufRead + Unpin,
+    {
+        let mut reader = BufReader::new(bytes);
+        let mut line_buf = self.partial_lines.remove(&pipe).unwrap_or_default();
+        let mut lines = vec![];
+        let mut read = 0;
+
+        loop {
+            let n = reader.read_until(b'\n', &mut line_buf).await?;
+            if n == 0 {
+                break;
+            }
+            read += n;
+
+            if line_buf.last() == Some(&b'\n') {
+                lines.push(mem::take(&mut line_buf));
+            }
+        }
+
+        if line_buf.len() >= self.max_len {
+            let len = match complete_utf8_len(&line_buf) {
+                0 => line_buf.len(),
+                len => len,
+            };
+            let remainder = line_buf.split_off(len);
+            lines.push(mem::replace(&mut line_buf, remainder));
+        }
+        if !line_buf.is_empty() {
+            self.partial_lines.insert(pipe, line_buf);
+        }
+        Ok((read, lines))
+    }
+
+    /// Take the retained line of the provided pipe, if any.
+    pub fn take(&mut self, pipe: Pipe) -> Option<Vec<u8>> {
+        self.partial_lines.remove(&pipe)
+    }
+}
+
+/// Retrieve the length of `buf` without a trailing incomplete UTF-8 sequence, which may get
+/// completed by subsequent bytes. Invalid sequences are considered complete.
+pub fn complete_utf8_len(buf: &[u8]) -> usize {
+    // A UTF-8 sequence consists of a leading byte followed by up to three continuation bytes.
+    for i in 1..=buf.len().min(4) {
+        let byte = buf[buf.len() - i];
+        if byte & 0xc0 == 0x80 {
+            continue;
+        }
+        let len = match byte {
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            0xf0..=0xf7 => 4,
+            _ => 1,
+        };
+        return if len > i { buf.len() - i } else { buf.len() };
+    }
+    buf.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complete_utf8_len_success() {
+        let emoji = "😀".as_bytes();
+        assert_eq!(complete_utf8_len(b""), 0);
+        assert_eq!(complete_utf8_len(b"abc"), 3);
+        assert_eq!(complete_utf8_len(emoji), 4);
+        for i in 1..emoji.len() {
+            let mut buf = b"ab".to_vec();
+            buf.extend_from_slice(&emoji[..i]);
+            assert_eq!(complete_utf8_len(&buf), 2, "{i}");
+        }
+        assert_eq!(complete_utf8_len(b"a\x80\x80\x80\x80"), 5);
+        assert_eq!(complete_utf8_len(b"a\xff"), 2);
+    }
+
+    #[tokio::test]
+    async fn read_split_codepoint() -> Result<()> {
+        let emoji = "😀".as_bytes();
+        let mut sut = LineBuffer::new(6);
+
+        let (read, lines) = sut.read(Pipe::StdOut, &b"a\nb"[..]).await?;
+        assert_eq!(read, 3);
+        assert_eq!(lines, vec![b"a\n".to_vec()]);
+
+        // The maximum length only returns the complete UTF-8 sequences.
+        let (_, lines) = sut
+            .read(Pipe::StdOut, &[b"cde", &emoji[..2]].concat()[..])
+            .await?;
+        assert_eq!(lines, vec![b"bcde".to_vec()]);
+        assert!(sut.read(Pipe::StdErr, &emoji[..1]).await?.1.is_empty());
+
+        let (_, lines) = sut
+            .read(Pipe::StdOut, &[&emoji[2..], b"\n"].concat()[..])
+            .await?;
+        assert_eq!(lines, vec!["😀\n".as_bytes().to_vec()]);
+        assert_eq!(sut.take(Pipe::StdErr), Some(emoji[..1].to_vec()));
+        assert_eq!(sut.take(Pipe::StdOut), None);
+        Ok(())
+    }
+}
@@ -0,0 +1,201 @@
+//! Node wide disk usage budget of the container logs.
+
+use crate::log_rotation::{self, RotationNaming};
+use anyhow::{Context, Result};
+use std::{
+    collections::HashMap,
+    io,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::SystemTime,
+};
+use tokio::fs;
+use tracing::debug;
+
+#[derive(Debug)]
+/// Tracks the total bytes of all managed log files including their backups, which gets shared
+/// between the loggers of every container.
+pub struct LogDiskBudget {
+    /// Maximum total bytes of all log files.
+    limit: u64,
+
+    /// Bytes currently used by the log files, which gets recalculated from disk when exceeding
+    /// the limit.
+    used: AtomicU64,
+
+    /// The log files accounted by the budget with the naming scheme of their backups.
+    paths: Mutex<HashMap<PathBuf, RotationNaming>>,
+}
+
+impl LogDiskBudget {
+    /// Create a new budget allowing `limit` bytes in total.
+    pub fn new(limit: u64) -> Self {
+        Self {
+            limit,
+            used: AtomicU64::new(0),
+            paths: Mutex::default(),
+        }
+    }
+
+    /// Account the log file at `path` and its backups named by `naming`, which may already use
+    /// space on disk.
+    pub async fn register<T: AsRef<Path>>(&self, path: T, naming: &RotationNaming) -> Result<()> {
+        self.lock().insert(path.as_ref().into(), naming.clone());
+        self.recalculate().await
+    }
+
+    /// Stop accounting the log file at `path`, while keeping it on disk.
+    pub async fn unregister<T: AsRef<Path>>(&self, path: T) -> Result<()> {
+        self.lock().remove(path.as_ref());
+        self.recalculate().await
+    }
+
+    /// Add `bytes` written by a logger to the used ones. If this exceeds the limit, then the
+    /// oldest backups of all accounted log files get removed until the usage fits again. The
+    /// result is `true` if the live log files alone still exceed the limit, which means that the
+    /// caller should rotate its log file early.
+    pub async fn charge(&self, bytes: u64) -> Result<bool> {
+        let used = self
+            .used
+            .fetch_add(bytes, Ordering::Relaxed)
+            .saturating_add(bytes);
+        if used <= self.limit {
+            return Ok(false);
+        }
+        debug!(
+            "Log disk usage {} exceeds budget of {} bytes",
+            used, self.limit
+        );
+        self.prune().await
+    }
+
+    /// Remove the oldest backups until the usage fits into the limit and return if it still
+    /// exceeds it.
+    async fn prune(&self) -> Result<bool> {
+        let (mut used, mut backups) = self.scan().await?;
+        backups.sort();
+        let mut backups = backups.into_iter();
+        while used > self.limit {
+            let Some((_, len, backup)) = backups.next() else {
+                break;
+            };
+            debug!("Removing log backup {} to fit budget", backup.display());
+            match fs::remove_file(&backup).await {
+                // Another logger may prune concurrently.
+                Err(e) if e.kind() != io::ErrorKind::NotFound => {
+                    return Err(e).context(format!("remove log backup '{}'", backup.display()))
+                }
+                _ => used = used.saturating_sub(len),
+            }
+            log_rotation::remove_checksum(&backup).await?;
+        }
+        self.used.store(used, Ordering::Relaxed);
+        Ok(used > self.limit)
+    }
+
+    /// Recalculate the used bytes from disk.
+    async fn recalculate(&self) -> Result<()> {
+        let (used, _) = self.scan().await?;
+        self.used.store(used, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Retrieve the total bytes of all accounted log files as well as their backups with their
+    /// modification time and length.
+    async fn scan(&self) -> Result<(u64, Vec<(SystemTime, u64, PathBuf)>)> {
+        let paths: Vec<(PathBuf, RotationNaming)> = self
+            .lock()
+            .iter()
+            .map(|(path, naming)| (path.clone(), naming.clone()))
+            .collect();
+        let mut used = 0u64;
+        let mut backups = vec![];
+        for (path, naming) in paths {
+            if let Some((_, len)) = Self::stat(&path).await? {
+                used = used.saturating_add(len);
+            }
+            for backup in log_rotation::backups(&path, &naming).await? {
+                if let Some((modified, len)) = Self::stat(&backup).await? {
+                    used = used.saturating_add(len);
+                    backups.push((modified, len, backup));
+                }
+            }
+        }
+        Ok((used, backups))
+    }
+
+    /// Retrieve the modification time and length of the file at `path`, if it exists.
+    async fn stat(path: &Path) -> Result<Option<(SystemTime, u64)>> {
+        match fs::metadata(path).await {
+            Ok(metadata) => Ok(Some((
+                metadata
+                    .modified()
+                    .context("get log file modification time")?,
+                metadata.len(),
+            ))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e).context(format!("get log file metadata of '{}'", path.display())),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<PathBuf, RotationNaming>> {
+        // The set stays consistent even if a holder panicked.
+        self.paths.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        container_io::Pipe, container_log::Logger, cri_logger::CriLogger, json_logger::JsonLogger,
+        log_rotation::RotationMode,
+    };
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn prune_oldest_backup() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let cri_path = dir.path().join("cri.log");
+        let json_path = dir.path().join("json.log");
+        let budget = Arc::new(LogDiskBudget::new(4096));
+
+        let mut cri = CriLogger::new(&cri_path, None)?;
+        cri.set_rotation(RotationMode::Rename)
+            .set_budget(budget.clone().into());
+        cri.init().await?;
+        let mut json = JsonLogger::new(&json_path, None)?;
+        json.set_rotation(RotationMode::Rename)
+            .set_budget(budget.clone().into());
+        json.init().await?;
+
+        // The backups are larger than a single line to prune only the oldest one.
+        let line = format!("{}\n", "x".repeat(100));
+        let lines = line.repeat(10);
+        cri.write(Pipe::StdOut, lines.as_bytes()).await?;
+        cri.reopen().await?;
+        let oldest = log_rotation::backup_path(&cri_path, 1, Default::default());
+        assert!(oldest.exists());
+
+        // Distinct modification times of the backups.
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        json.write(Pipe::StdOut, lines.as_bytes()).await?;
+        json.reopen().await?;
+        let newer = log_rotation::backup_path(&json_path, 1, Default::default());
+        assert!(newer.exists());
+        assert!(budget.used.load(Ordering::Relaxed) < budget.limit);
+
+        while oldest.exists() {
+            json.write(Pipe::StdOut, line.as_bytes()).await?;
+        }
+        assert!(newer.exists());
+        assert!(budget.used.load(Ordering::Relaxed) <= budget.limit);
+
+        cri.close().await?;
+        json.close().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+//! Log file durability functionalities.
+
+use crate::log_sink::LogSink;
+use anyhow::{bail, Context, Result};
+use std::time::Duration;
+use tokio::{
+    fs::File,
+    io::{AsyncWriteExt, BufWriter},
+    task::{self, JoinHandle},
+    time::{self, MissedTickBehavior},
+};
+use tracing::{debug, error};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Available policies for syncing written log lines to disk.
+pub enum DurabilityPolicy {
+    #[default]
+    /// Only sync the log file when reopening it.
+    None,
+
+    /// Flush and sync the log file after every written line.
+    EveryWrite,
+
+    /// Sync the log file periodically from a background task.
+    Interval(Duration),
+
+    /// Open the log file with `O_DSYNC` and flush every written line, which makes each line
+    /// durable once written without an explicit sync. This is the most expensive policy, because
+    /// every line blocks until the storage device acknowledged the write.
+    Dsync,
+}
+
+impl DurabilityPolicy {
+    #[cfg(unix)]
+    /// Retrieve the flags used for opening the log file.
+    pub fn open_flags(self) -> i32 {
+        match self {
+            DurabilityPolicy::Dsync => libc::O_DSYNC,
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Thresholds for batching the writes of buffered log lines, which get flushed once either the
+/// lines or bytes written since the last flush reach their threshold. Lines get flushed after
+/// every write if none is set.
+pub struct FlushThreshold {
+    /// Flush after this amount of lines.
+    pub lines: Option<usize>,
+
+    /// Flush after this amount of bytes.
+    pub bytes: Option<usize>,
+}
+
+impl FlushThreshold {
+    /// Indicates if any threshold is set.
+    pub fn is_set(self) -> bool {
+        self.lines.is_some() || self.bytes.is_some()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Lines and bytes written since the last flush.
+pub struct Unflushed {
+    lines: usize,
+    bytes: usize,
+}
+
+impl Unflushed {
+    /// Count a written line of `len` bytes and indicate if it reached the threshold, which resets
+    /// the count.
+    pub fn add(&mut self, len: usize, threshold: FlushThreshold) -> bool {
+        self.lines += 1;
+        self.bytes += len;
+        let reached = threshold.lines.is_some_and(|x| self.lines >= x)
+            || threshold.bytes.is_some_and(|x| self.bytes >= x);
+        if reached {
+            self.reset();
+        }
+        reached
+    }
+
+    /// Reset the count after a flush.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[derive(Debug)]
+/// Background task periodically syncing a log file to disk, which gets aborted on drop.
+pub struct SyncTask(JoinHandle<()>);
+
+impl SyncTask {
+    /// Spawn a new task for `file` if the policy requires periodic syncing.
+    pub async fn from_policy(policy: DurabilityPolicy, file: &File) -> Result<Option<Self>> {
+        match policy {
+            DurabilityPolicy::Interval(period) => Ok(Some(Self::spawn(file, period).await?)),
+            DurabilityPolicy::None | DurabilityPolicy::EveryWrite | DurabilityPolicy::Dsync => {
+                Ok(None)
+            }
+        }
+    }
+
+    /// Spawn a new task syncing a duplicate handle of `file` every `period`, which must not be
+    /// zero.
+    async fn spawn(file: &File, period: Duration) -> Result<Self> {
+        if period.is_zero() {
+            bail!("sync interval must not be zero")
+        }
+        let file = file.try_clone().await.context("clone log file handle")?;
+        debug!("Syncing log file every {:?}", period);
+        Ok(Self(task::spawn(async move {
+            let mut interval = time::interval(period);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            loop {
+                interval.tick().await;
+                if let Err(e) = file.sync_data().await {
+                    error!("Unable to sync log file: {:#}", e);
+                }
+            }
+        })))
+    }
+}
+
+impl Drop for SyncTask {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Flush the writer and sync the file data to disk.
+pub async fn sync(writer: &mut BufWriter<LogSink>) -> Result<()> {
+    writer.flush().await.context("flush file writer")?;
+    writer
+        .get_ref()
+        .sync_data()
+        .await
+        .context("sync log file data")
+}
@@ -0,0 +1,115 @@
+//! Error types of the log drivers.
+
+use std::{io, path::PathBuf, time::Duration};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+/// Errors returned by the file based log drivers, which allow callers to branch on their kind.
+pub enum LoggerError {
+    #[error("logger not initialized")]
+    /// The logger has been used before initializing it.
+    Uninitialized,
+
+    #[error("log file I/O")]
+    /// Reading or writing the log file failed.
+    Io(#[source] io::Error),
+
+    #[error("disk full")]
+    /// Writing the log file failed because the disk is full, even after retrying.
+    DiskFull(#[source] io::Error),
+
+    #[error("rotate log file '{}'", path.display())]
+    /// Rotating the log file failed.
+    RotationFailed {
+        path: PathBuf,
+        #[source]
+        source: anyhow::Error,
+    },
+
+    #[error("log file path '{}' is a symbolic link", .0.display())]
+    /// The log file path is a symbolic link, which the file access policy refuses to follow.
+    Symlink(PathBuf),
+
+    #[error("log driver timed out after {0:?}")]
+    /// Writing to the log driver did not finish in time, for example because of a hung mount.
+    Timeout(Duration),
+
+    #[error("{0}")]
+    /// The requested configuration is not supported.
+    Unsupported(&'static str),
+
+    #[error(transparent)]
+    /// Any other error.
+    Other(anyhow::Error),
+}
+
+impl LoggerError {
+    /// Retrieve the kind of the underlying I/O error, like `NotFound` or `PermissionDenied`.
+    pub fn io_kind(&self) -> Option<io::ErrorKind> {
+        match self {
+            LoggerError::Io(error) | LoggerError::DiskFull(error) => Some(error.kind()),
+            LoggerError::RotationFailed { source: error, .. } | LoggerError::Other(error) => error
+                .chain()
+                .find_map(|x| x.downcast_ref::<io::Error>())
+                .map(io::Error::kind),
+            LoggerError::Uninitialized
+            | LoggerError::Symlink(_)
+            | LoggerError::Timeout(_)
+            | LoggerError::Unsupported(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for LoggerError {
+    /// Distinguish a full disk, reported as `ENOSPC`, from other I/O errors.
+    fn from(error: io::Error) -> Self {
+        if error.raw_os_error() == Some(libc::ENOSPC) {
+            LoggerError::DiskFull(error)
+        } else {
+            LoggerError::Io(error)
+        }
+    }
+}
+
+impl From<anyhow::Error> for LoggerError {
+    /// Recover the logger error wrapped by the `anyhow::Error`, even if context has been added to
+    /// it.
+    fn from(error: anyhow::Error) -> Self {
+        match error.downcast::<LoggerError>() {
+            Ok(error) => error,
+            Err(error) => LoggerError::Other(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn from_anyhow() {
+        let error = Err::<(), _>(LoggerError::Uninitialized)
+            .context("write log line")
+            .unwrap_err();
+        assert!(matches!(
+            LoggerError::from(error),
+            LoggerError::Uninitialized
+        ));
+
+        let error = Err::<(), _>(io::Error::from(io::ErrorKind::PermissionDenied))
+            .context("write log line")
+            .unwrap_err();
+        let error = LoggerError::from(error);
+        assert!(matches!(error, LoggerError::Other(_)));
+        assert_eq!(error.io_kind(), Some(io::ErrorKind::PermissionDenied));
+        assert_eq!(LoggerError::Unsupported("").io_kind(), None);
+
+        let error = LoggerError::from(io::Error::from(io::ErrorKind::PermissionDenied));
+        assert!(matches!(error, LoggerError::Io(_)));
+        assert_eq!(error.io_kind(), Some(io::ErrorKind::PermissionDenied));
+
+        let error = LoggerError::from(io::Error::from_raw_os_error(libc::ENOSPC));
+        assert!(matches!(error, LoggerError::DiskFull(_)));
+    }
+}
@@ -0,0 +1,179 @@
+//! Metrics reporting of the container logs.
+
+use crate::container_io::Pipe;
+use clap::crate_name;
+use opentelemetry::{
+    global,
+    metrics::{Counter, Unit},
+    KeyValue,
+};
+use std::{fmt::Debug, path::Path, slice};
+
+/// Receiver of the log metrics, which allows updating any metrics backend without coupling the
+/// log drivers to it. All hooks do nothing by default.
+pub trait MetricsSink: Debug + Send + Sync {
+    /// Container output of `pipe` with the length of `bytes` has been written to the log
+    /// drivers.
+    fn on_bytes_written(&self, _pipe: Pipe, _bytes: usize) {}
+
+    /// The log file at `path` has been rotated.
+    fn on_rotation(&self, _path: &Path) {}
+
+    /// Log drivers dropped `bytes` because they did not keep up.
+    fn on_dropped(&self, _bytes: usize) {}
+
+    /// A log driver failed with `error`.
+    fn on_error(&self, _error: &anyhow::Error) {}
+}
+
+#[derive(Debug, Default)]
+/// Sink discarding all metrics.
+pub struct NoopMetricsSink;
+
+impl MetricsSink for NoopMetricsSink {}
+
+#[derive(Debug)]
+/// Sink recording the metrics of a single container as counters of the global OpenTelemetry
+/// meter provider, which discards them until a provider gets installed.
+pub struct TelemetryMetricsSink {
+    bytes_written: Counter<u64>,
+    rotations: Counter<u64>,
+    dropped_bytes: Counter<u64>,
+    errors: Counter<u64>,
+    container_id: KeyValue,
+}
+
+impl TelemetryMetricsSink {
+    /// Create a new sink attributing all metrics to the container `id`.
+    pub fn new(id: &str) -> Self {
+        let meter = global::meter(crate_name!());
+        Self {
+            bytes_written: meter
+                .u64_counter("conmon.log.bytes_written")
+                .with_description("Container output written to the log drivers")
+                .with_unit(Unit::new("By"))
+                .init(),
+            rotations: meter
+                .u64_counter("conmon.log.rotations")
+                .with_description("Rotations of the log files")
+                .init(),
+            dropped_bytes: meter
+                .u64_counter("conmon.log.dropped_bytes")
+                .with_description("Container output dropped by the log drivers")
+                .with_unit(Unit::new("By"))
+                .init(),
+            errors: meter
+                .u64_counter("conmon.log.errors")
+                .with_description("Failures of the log drivers")
+                .init(),
+            container_id: KeyValue::new("container_id", id.to_string()),
+        }
+    }
+}
+
+impl MetricsSink for TelemetryMetricsSink {
+    fn on_bytes_written(&self, pipe: Pipe, bytes: usize) {
+        let pipe: &'static str = pipe.into();
+        self.bytes_written.add(
+            bytes as u64,
+            &[self.container_id.clone(), KeyValue::new("pipe", pipe)],
+        );
+    }
+
+    fn on_rotation(&self, path: &Path) {
+        self.rotations.add(
+            1,
+            &[
+                self.container_id.clone(),
+                KeyValue::new("path", path.display().to_string()),
+            ],
+        );
+    }
+
+    fn on_dropped(&self, bytes: usize) {
+        self.dropped_bytes
+            .add(bytes as u64, slice::from_ref(&self.container_id));
+    }
+
+    fn on_error(&self, _error: &anyhow::Error) {
+        self.errors.add(1, slice::from_ref(&self.container_id));
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Amount of lines of a single pipe and a coarse histogram of their lengths.
+pub struct LineStats {
+    /// The amount of complete lines.
+    pub lines: u64,
+
+    /// The amount of lines per length bucket, see `LineStats::BUCKETS`.
+    pub buckets: [u64; LineStats::BUCKETS.len() + 1],
+
+    /// The length of the partial line being assembled.
+    partial_len: usize,
+}
+
+impl LineStats {
+    /// The inclusive upper bounds of the line length buckets in bytes, followed by an unbounded
+    /// last bucket.
+    pub const BUCKETS: [usize; 5] = [64, 256, 1024, 4096, 16384];
+
+    /// Account a written part of a line without its newline, where the parts of partial lines
+    /// add up until the line is complete.
+    pub fn record(&mut self, len: usize, partial: bool) {
+        self.partial_len += len;
+        if partial {
+            return;
+        }
+        let len = std::mem::take(&mut self.partial_len);
+        self.lines += 1;
+        self.buckets[Self::BUCKETS.partition_point(|x| *x < len)] += 1;
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// The line statistics of every pipe.
+pub struct PipeStats {
+    /// Lines of the standard input.
+    pub stdin: LineStats,
+
+    /// Lines of the standard output.
+    pub stdout: LineStats,
+
+    /// Lines of the standard error.
+    pub stderr: LineStats,
+}
+
+impl PipeStats {
+    /// Account a written part of a line of `pipe`.
+    pub fn record(&mut self, pipe: Pipe, len: usize, partial: bool) {
+        match pipe {
+            Pipe::StdIn => &mut self.stdin,
+            Pipe::StdOut => &mut self.stdout,
+            Pipe::StdErr => &mut self.stderr,
+        }
+        .record(len, partial)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_buckets() {
+        let mut sut = PipeStats::default();
+        for len in [0, 64, 65, 300, 300, 20000] {
+            sut.record(Pipe::StdOut, len, false);
+        }
+        // A partial line is accounted once complete.
+        sut.record(Pipe::StdErr, 1000, true);
+        sut.record(Pipe::StdErr, 4000, false);
+
+        assert_eq!(sut.stdout.lines, 6);
+        assert_eq!(sut.stdout.buckets, [2, 1, 2, 0, 0, 1]);
+        assert_eq!(sut.stderr.lines, 1);
+        assert_eq!(sut.stderr.buckets, [0, 0, 0, 0, 1, 0]);
+        assert_eq!(sut.stdin, LineStats::default());
+    }
+}
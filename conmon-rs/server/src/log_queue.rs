@@ -0,0 +1,603 @@
+//! Bounded log queue functionalities.
+
+use crate::{
+    container_io::Pipe,
+    container_log::{LogEvent, LogStats, Logger},
+    log_metrics::PipeStats,
+    log_reader::{LogFormat, LogLine},
+};
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use getset::CopyGetters;
+use std::{
+    collections::VecDeque,
+    path::PathBuf,
+    sync::{Arc, Mutex as StdMutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+use tokio::{
+    io::{AsyncBufRead, AsyncReadExt},
+    select,
+    sync::{Mutex, Notify},
+    task,
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, debug_span, error, warn, Instrument};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Available strategies if the queue of a log driver is full.
+pub enum QueuePolicy {
+    #[default]
+    /// Wait until the log driver removed an entry from the queue.
+    Block,
+
+    /// Drop the oldest queued entry in favor of the new one.
+    DropOldest,
+
+    /// Drop the new entry.
+    DropNewest,
+}
+
+#[derive(Debug)]
+/// Output of a pipe waiting to be written.
+struct Entry {
+    pipe: Pipe,
+    data: Vec<u8>,
+
+    /// Indicates that the data is a single line written by `write_line`.
+    line: bool,
+}
+
+impl Entry {
+    /// Write the entry into the logger by using the method it got queued with.
+    async fn write(&self, logger: &mut Box<dyn Logger>) -> Result<()> {
+        if self.line {
+            logger.write_line(self.pipe, &self.data).await
+        } else {
+            logger.write(self.pipe, &mut self.data.as_slice()).await?;
+            Ok(())
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+/// Entries waiting to be written by the log driver.
+struct Queue {
+    entries: VecDeque<Entry>,
+
+    /// Total bytes of all dropped entries.
+    dropped_bytes: usize,
+
+    /// Dropped bytes which have not been reported by a marker yet.
+    unreported_bytes: usize,
+
+    /// The time of the last written marker.
+    last_marker: Option<Instant>,
+}
+
+impl Queue {
+    fn drop_entry(&mut self, data: &[u8]) {
+        self.dropped_bytes += data.len();
+        self.unreported_bytes += data.len();
+    }
+}
+
+#[derive(Debug, Default)]
+/// State shared between the queued logger and its drain task.
+struct Shared {
+    queue: StdMutex<Queue>,
+
+    /// Notified if an entry got queued.
+    queued: Notify,
+
+    /// Notified if an entry got removed from the queue.
+    removed: Notify,
+}
+
+impl Shared {
+    fn queue(&self) -> MutexGuard<'_, Queue> {
+        self.queue.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Take the unreported dropped bytes, if the last marker is older than the marker interval or
+    /// `force` is set.
+    fn take_marker(&self, force: bool) -> Option<usize> {
+        let mut queue = self.queue();
+        if queue.unreported_bytes == 0
+            || (!force
+                && queue
+                    .last_marker
+                    .is_some_and(|last| last.elapsed() < QueuedLogger::MARKER_INTERVAL))
+        {
+            return None;
+        }
+        queue.last_marker = Some(Instant::now());
+        Some(std::mem::take(&mut queue.unreported_bytes))
+    }
+
+    /// Remove the oldest entry from the queue.
+    fn pop(&self) -> Option<Entry> {
+        let entry = self.queue().entries.pop_front();
+        if entry.is_some() {
+            self.removed.notify_one();
+        }
+        entry
+    }
+}
+
+#[derive(Debug, CopyGetters)]
+/// Logger decoupling the container output from a slow log driver by a bounded queue, which gets
+/// drained by a dedicated task.
+pub struct QueuedLogger {
+    /// Name of the wrapped log driver.
+    name: &'static str,
+
+    /// Pipes accepted by the wrapped log driver.
+    pipes: Vec<Pipe>,
+
+    /// Timeout of the entries retained by the wrapped log driver.
+    expiry_interval: Option<Duration>,
+
+    /// Readable file of the wrapped log driver, which is known after initialization.
+    source: Option<(PathBuf, LogFormat)>,
+
+    /// The wrapped log driver, locked by the drain task for every written entry.
+    inner: Arc<Mutex<Box<dyn Logger>>>,
+
+    #[getset(get_copy = "pub")]
+    /// Maximum amount of queued entries.
+    depth: usize,
+
+    #[getset(get_copy = "pub")]
+    /// Strategy used if the queue is full.
+    policy: QueuePolicy,
+
+    /// State shared with the drain task.
+    shared: Arc<Shared>,
+
+    /// Token for stopping the drain task, available after initialization.
+    token: Option<CancellationToken>,
+}
+
+impl QueuedLogger {
+    const ERR_UNINITIALIZED: &'static str = "logger not initialized";
+
+    /// The minimum time between two markers about dropped bytes.
+    const MARKER_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// Create a new queued logger instance, which buffers up to `depth` writes for the provided
+    /// log driver.
+    pub fn new(inner: Box<dyn Logger>, depth: usize, policy: QueuePolicy) -> Result<Self> {
+        if depth == 0 {
+            bail!("log queue depth has to be greater than zero")
+        }
+        Ok(Self {
+            name: inner.name(),
+            pipes: [Pipe::StdIn, Pipe::StdOut, Pipe::StdErr]
+                .into_iter()
+                .filter(|pipe| inner.accepts(*pipe))
+                .collect(),
+            expiry_interval: inner.expiry_interval(),
+            source: None,
+            inner: Arc::new(Mutex::new(inner)),
+            depth,
+            policy,
+            shared: Default::default(),
+            token: None,
+        })
+    }
+
+    /// Total bytes which got dropped because the queue was full.
+    pub fn dropped_bytes(&self) -> usize {
+        self.shared.queue().dropped_bytes
+    }
+
+    /// Add an entry to the queue by respecting the policy.
+    async fn enqueue(&self, entry: Entry) {
+        loop {
+            {
+                let mut queue = self.shared.queue();
+                if queue.entries.len() < self.depth() {
+                    queue.entries.push_back(entry);
+                    self.shared.queued.notify_one();
+                    return;
+                }
+
+                match self.policy() {
+                    QueuePolicy::Block => {}
+                    QueuePolicy::DropOldest => {
+                        if let Some(dropped) = queue.entries.pop_front() {
+                            warn!("Dropping oldest queued {} log entry", self.name);
+                            queue.drop_entry(&dropped.data);
+                        }
+                        queue.entries.push_back(entry);
+                        return;
+                    }
+                    QueuePolicy::DropNewest => {
+                        warn!(
+                            "Dropping new {} log entry because the queue is full",
+                            self.name
+                        );
+                        queue.drop_entry(&entry.data);
+                        return;
+                    }
+                }
+            }
+            self.shared.removed.notified().await;
+        }
+    }
+
+    /// Write all queued entries and a final marker for still unreported dropped bytes, which
+    /// keeps their order because the drain task only removes entries while holding the lock of
+    /// the logger.
+    async fn write_queued(logger: &mut Box<dyn Logger>, shared: &Shared) -> Result<()> {
+        while let Some(entry) = shared.pop() {
+            entry.write(logger).await?;
+        }
+        if let Some(dropped_bytes) = shared.take_marker(true) {
+            logger.write_marker(dropped_bytes).await?;
+        }
+        Ok(())
+    }
+
+    /// Write queued entries until the token gets cancelled.
+    async fn drain(
+        inner: Arc<Mutex<Box<dyn Logger>>>,
+        shared: Arc<Shared>,
+        token: CancellationToken,
+    ) {
+        loop {
+            let mut logger = select! {
+                logger = inner.lock() => logger,
+                _ = token.cancelled() => return,
+            };
+
+            // Markers are rate limited to not flood the logger if the queue is full all the time.
+            if let Some(dropped_bytes) = shared.take_marker(false) {
+                if let Err(e) = logger.write_marker(dropped_bytes).await {
+                    error!("Unable to write log marker: {:#}", e);
+                }
+            }
+
+            let Some(entry) = shared.pop() else {
+                drop(logger);
+                select! {
+                    _ = shared.queued.notified() => continue,
+                    _ = token.cancelled() => return,
+                }
+            };
+
+            if let Err(e) = entry.write(&mut logger).await {
+                error!("Unable to write queued log entry: {:#}", e);
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Logger for QueuedLogger {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn source(&self) -> Option<(PathBuf, LogFormat)> {
+        self.source.clone()
+    }
+
+    fn accepts(&self, pipe: Pipe) -> bool {
+        self.pipes.contains(&pipe)
+    }
+
+    async fn validate(&self) -> Result<()> {
+        self.inner.lock().await.validate().await
+    }
+
+    async fn lines(&self) -> Option<Vec<LogLine>> {
+        self.inner.lock().await.lines().await
+    }
+
+    /// A driver busy writing has been initialized before.
+    fn is_initialized(&self) -> bool {
+        self.inner.try_lock().map_or(true, |x| x.is_initialized())
+    }
+
+    /// Only provides the name and dropped bytes if the log driver is busy writing.
+    fn stats(&self) -> LogStats {
+        let stats = self.inner.try_lock().map_or(
+            LogStats {
+                name: self.name,
+                bytes_written: 0,
+                max_log_size: None,
+                rotations: 0,
+                dropped_bytes: 0,
+                lines: PipeStats::default(),
+                last_write: None,
+            },
+            |logger| logger.stats(),
+        );
+        LogStats {
+            dropped_bytes: stats.dropped_bytes + self.dropped_bytes(),
+            ..stats
+        }
+    }
+
+    /// Initialize the log driver and spawn the task draining the queue.
+    async fn init(&mut self) -> Result<()> {
+        debug!("Initializing queue for {} logger", self.name);
+        let mut inner = self.inner.lock().await;
+        inner.init().await?;
+        self.source = inner.source();
+        drop(inner);
+
+        // Stop a previously running drain task.
+        if let Some(token) = self.token.take() {
+            token.cancel();
+        }
+        let token = CancellationToken::new();
+        task::spawn(
+            Self::drain(self.inner.clone(), self.shared.clone(), token.clone())
+                .instrument(debug_span!("log_queue")),
+        );
+        self.token = Some(token);
+        Ok(())
+    }
+
+    /// Queue the provided data and return the amount of consumed bytes, even if they got dropped.
+    async fn write(
+        &mut self,
+        pipe: Pipe,
+        bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> Result<usize> {
+        self.token.as_ref().context(Self::ERR_UNINITIALIZED)?;
+        let mut data = Vec::new();
+        let read = bytes
+            .read_to_end(&mut data)
+            .await
+            .context("read log entry")?;
+        if read > 0 {
+            self.enqueue(Entry {
+                pipe,
+                data,
+                line: false,
+            })
+            .await;
+        }
+        Ok(read)
+    }
+
+    /// Queue the provided line, which gets written as single entry by the log driver.
+    async fn write_line(&mut self, pipe: Pipe, line: &[u8]) -> Result<()> {
+        self.token.as_ref().context(Self::ERR_UNINITIALIZED)?;
+        self.enqueue(Entry {
+            pipe,
+            data: line.into(),
+            line: true,
+        })
+        .await;
+        Ok(())
+    }
+
+    /// Write all queued entries before the event, which keeps it behind the preceding output.
+    async fn write_event(&mut self, event: LogEvent) -> Result<()> {
+        let mut logger = self.inner.lock().await;
+        Self::write_queued(&mut logger, &self.shared).await?;
+        logger.write_event(event).await
+    }
+
+    /// Write all queued entries before reopening the log driver.
+    async fn reopen(&mut self) -> Result<()> {
+        let mut logger = self.inner.lock().await;
+        Self::write_queued(&mut logger, &self.shared).await?;
+        logger.reopen().await
+    }
+
+    /// Write all queued entries before flushing the log driver.
+    async fn flush(&mut self) -> Result<()> {
+        let mut logger = self.inner.lock().await;
+        Self::write_queued(&mut logger, &self.shared).await?;
+        logger.flush().await
+    }
+
+    /// Write all queued entries and stop the drain task before closing the log driver.
+    async fn close(&mut self) -> Result<()> {
+        if let Some(token) = self.token.take() {
+            token.cancel();
+        }
+        let mut logger = self.inner.lock().await;
+        Self::write_queued(&mut logger, &self.shared).await?;
+        logger.close().await
+    }
+
+    async fn flush_partial(&mut self, pipe: Pipe) -> Result<()> {
+        let mut logger = self.inner.lock().await;
+        Self::write_queued(&mut logger, &self.shared).await?;
+        logger.flush_partial(pipe).await
+    }
+
+    fn expiry_interval(&self) -> Option<Duration> {
+        self.expiry_interval
+    }
+
+    async fn flush_expired(&mut self) -> Result<()> {
+        let mut logger = self.inner.lock().await;
+        Self::write_queued(&mut logger, &self.shared).await?;
+        logger.flush_expired().await
+    }
+}
+
+impl Drop for QueuedLogger {
+    fn drop(&mut self) {
+        if let Some(token) = self.token.take() {
+            token.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::{
+        sync::Notify,
+        time::{self, Instant},
+    };
+
+    /// The time needed by the slow logger for every write, which elapses instantly because the
+    /// tests run with a paused clock.
+    const SLOW: Duration = Duration::from_millis(200);
+
+    /// The data written by the slow logger.
+    type Written = Arc<StdMutex<Vec<u8>>>;
+
+    #[derive(Debug, Default)]
+    /// Logger taking `SLOW` for every write, like a log file on a slow disk. It notifies the
+    /// start of every write.
+    struct SlowLogger(Written, Arc<StdMutex<Vec<usize>>>, Arc<Notify>);
+
+    #[async_trait]
+    impl Logger for SlowLogger {
+        fn name(&self) -> &'static str {
+            "slow"
+        }
+
+        async fn init(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn write(
+            &mut self,
+            _pipe: Pipe,
+            bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+        ) -> Result<usize> {
+            self.2.notify_one();
+            time::sleep(SLOW).await;
+            let mut data = Vec::new();
+            let read = bytes.read_to_end(&mut data).await?;
+            self.0.lock().unwrap().extend(data);
+            Ok(read)
+        }
+
+        async fn write_marker(&mut self, dropped_bytes: usize) -> Result<()> {
+            self.1.lock().unwrap().push(dropped_bytes);
+            Ok(())
+        }
+
+        async fn reopen(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    fn new_sut(depth: usize, policy: QueuePolicy) -> Result<(QueuedLogger, Written, Arc<Notify>)> {
+        let written = Arc::new(StdMutex::new(Vec::new()));
+        let started = Arc::new(Notify::new());
+        let sut = QueuedLogger::new(
+            Box::new(SlowLogger(
+                written.clone(),
+                Default::default(),
+                started.clone(),
+            )),
+            depth,
+            policy,
+        )?;
+        Ok((sut, written, started))
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn write_block() -> Result<()> {
+        let (mut sut, written, _) = new_sut(2, QueuePolicy::Block)?;
+        sut.init().await?;
+
+        // The first entry gets removed by the drain task, which leaves room for two more.
+        let start = Instant::now();
+        for line in ["a\n", "b\n", "c\n"] {
+            sut.write(Pipe::StdOut, &mut line.as_bytes()).await?;
+        }
+        assert!(start.elapsed() < SLOW);
+
+        // The queue is full, which blocks until the slow write finished.
+        sut.write(Pipe::StdOut, &mut "d\n".as_bytes()).await?;
+        assert!(start.elapsed() >= SLOW);
+
+        sut.flush().await?;
+        assert_eq!(*written.lock().unwrap(), b"a\nb\nc\nd\n");
+        assert_eq!(sut.dropped_bytes(), 0);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn write_drop_newest() -> Result<()> {
+        let (mut sut, written, _) = new_sut(2, QueuePolicy::DropNewest)?;
+        sut.init().await?;
+
+        let start = Instant::now();
+        for i in 0..10 {
+            sut.write(Pipe::StdOut, &mut format!("{i}\n").as_bytes())
+                .await?;
+        }
+        assert!(start.elapsed() < SLOW);
+
+        sut.flush().await?;
+        let written = written.lock().unwrap().len();
+        assert!(sut.dropped_bytes() > 0);
+        assert_eq!(written + sut.dropped_bytes(), 20);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn write_drop_oldest() -> Result<()> {
+        let (mut sut, written, started) = new_sut(1, QueuePolicy::DropOldest)?;
+        sut.init().await?;
+
+        // Wait until the drain task is busy writing the first entry.
+        sut.write(Pipe::StdOut, &mut "a\n".as_bytes()).await?;
+        started.notified().await;
+
+        let start = Instant::now();
+        for line in ["b\n", "c\n", "d\n"] {
+            sut.write(Pipe::StdOut, &mut line.as_bytes()).await?;
+        }
+        assert!(start.elapsed() < SLOW);
+
+        sut.flush().await?;
+        assert_eq!(*written.lock().unwrap(), b"a\nd\n");
+        assert_eq!(sut.dropped_bytes(), 4);
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn write_single_marker() -> Result<()> {
+        let markers = Arc::new(StdMutex::new(Vec::new()));
+        let started = Arc::new(Notify::new());
+        let slow_logger = SlowLogger(Default::default(), markers.clone(), started.clone());
+        let mut sut = QueuedLogger::new(Box::new(slow_logger), 1, QueuePolicy::DropNewest)?;
+        sut.init().await?;
+
+        // Wait until the drain task is busy writing the first entry.
+        sut.write(Pipe::StdOut, &mut "a\n".as_bytes()).await?;
+        started.notified().await;
+
+        // The second entry fills the queue, which drops all following ones.
+        for line in ["b\n", "c\n", "d\n", "e\n"] {
+            sut.write(Pipe::StdOut, &mut line.as_bytes()).await?;
+        }
+
+        sut.flush().await?;
+        assert_eq!(*markers.lock().unwrap(), [6]);
+        assert_eq!(sut.stats().dropped_bytes, 6);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn new_failure() -> Result<()> {
+        assert!(new_sut(0, QueuePolicy::Block).is_err());
+        let (mut sut, _, _) = new_sut(1, QueuePolicy::Block)?;
+        assert!(sut
+            .write(Pipe::StdOut, &mut "a\n".as_bytes())
+            .await
+            .is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,1204 @@
+//! Log reading functionalities.
+
+use crate::{
+    container_io::Pipe,
+    log_rotation::{self, Compression},
+};
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, DecodeError, Engine as _};
+use flate2::read::GzDecoder;
+use futures::{stream, Stream};
+use getset::{CopyGetters, Setters};
+use memchr::{memchr, memrchr};
+use serde::Deserialize;
+use serde_json::Value;
+use std::{
+    cmp::Ordering,
+    collections::VecDeque,
+    io::{self, BufRead, BufReader},
+    mem,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use thiserror::Error;
+use tokio::{
+    fs::{self, File},
+    io::{AsyncReadExt, AsyncSeekExt},
+    task, time,
+};
+use tz::UtcDateTime;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Available formats of readable log files.
+pub enum LogFormat {
+    /// The CRI format written by the `CriLogger`, whose records carry the provided tags.
+    Cri(CriTags),
+
+    /// The JSON format written by the `JsonLogger`.
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The tags of the CRI records, which default to `P` for partial and `F` for full ones.
+pub struct CriTags {
+    /// The tag of a record continued by the next one.
+    partial: char,
+
+    /// The tag of a record terminating the line.
+    full: char,
+}
+
+impl Default for CriTags {
+    fn default() -> Self {
+        Self {
+            partial: 'P',
+            full: 'F',
+        }
+    }
+}
+
+impl CriTags {
+    /// Create new tags from single non-whitespace characters, where empty ones are the default.
+    pub fn new(partial: &str, full: &str) -> Result<Self> {
+        let tag = |tag: &str, default| {
+            let mut chars = tag.chars();
+            match (chars.next(), chars.next()) {
+                (None, _) => Ok(default),
+                (Some(x), None) if !x.is_whitespace() => Ok(x),
+                _ => bail!("CRI tag '{}' is no single non-whitespace character", tag),
+            }
+        };
+        let default = Self::default();
+        let tags = Self {
+            partial: tag(partial, default.partial)?,
+            full: tag(full, default.full)?,
+        };
+        if tags.partial == tags.full {
+            bail!("partial and full CRI tag are both '{}'", tags.partial)
+        }
+        Ok(tags)
+    }
+
+    /// The tag of a partial or full record.
+    pub fn tag(&self, partial: bool) -> char {
+        if partial {
+            self.partial
+        } else {
+            self.full
+        }
+    }
+
+    /// Parse the tag of a record into its partial flag.
+    fn parse(&self, tag: &[u8]) -> Option<bool> {
+        [true, false]
+            .into_iter()
+            .find(|partial| tag == self.tag(*partial).encode_utf8(&mut [0; 4]).as_bytes())
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// A single line read from a log file, where partial entries got joined.
+pub struct LogLine {
+    /// The timestamp of the beginning of the line.
+    pub timestamp: String,
+
+    /// The pipe label of the line.
+    pub pipe: String,
+
+    /// The content of the line without trailing newline.
+    pub message: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// A single entry of a log file with typed fields, where partial entries are not joined.
+pub struct ParsedLine {
+    /// The time the entry has been written.
+    pub timestamp: SystemTime,
+
+    /// The pipe the entry originates from.
+    pub pipe: Pipe,
+
+    /// Indicates that the entry is not terminated by a newline.
+    pub partial: bool,
+
+    /// The content of the entry without trailing newline.
+    pub message: Vec<u8>,
+}
+
+#[derive(Debug, Error)]
+/// Failures of parsing a single entry of a log file.
+pub enum ParseError {
+    #[error("no {0} in CRI record")]
+    MissingField(&'static str),
+
+    #[error("invalid CRI tag '{0}'")]
+    InvalidTag(String),
+
+    #[error("invalid timestamp '{0}'")]
+    InvalidTimestamp(String),
+
+    #[error("invalid pipe '{0}'")]
+    InvalidPipe(String),
+
+    #[error("parse JSON entry")]
+    InvalidJson(#[source] serde_json::Error),
+
+    #[error("decode base64 message")]
+    InvalidBase64(#[source] DecodeError),
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Available policies for lines with an unparsable timestamp when filtering by time.
+pub enum InvalidTimestamp {
+    #[default]
+    /// Pass the line through.
+    Keep,
+
+    /// Skip the line.
+    Skip,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, CopyGetters, Setters)]
+/// Time range and pipe of the lines to be read.
+pub struct TimeFilter {
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Skip lines older than this time, where lines with exactly this timestamp are included.
+    since: Option<SystemTime>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Skip lines from this time on, where lines with exactly this timestamp are excluded.
+    until: Option<SystemTime>,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Policy for lines whose timestamp cannot be parsed.
+    invalid: InvalidTimestamp,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Skip lines of other pipes, which selects a single stream of a combined log file.
+    pipe: Option<Pipe>,
+}
+
+impl TimeFilter {
+    /// Locate the line relative to the range, where `None` indicates an unparsable timestamp.
+    fn position(&self, line: &LogLine) -> Option<Ordering> {
+        if self.since.is_none() && self.until.is_none() {
+            return Some(Ordering::Equal);
+        }
+        let timestamp = parse_timestamp(&line.timestamp).ok()?;
+        if self.since.is_some_and(|since| timestamp < since) {
+            Some(Ordering::Less)
+        } else if self.until.is_some_and(|until| timestamp >= until) {
+            Some(Ordering::Greater)
+        } else {
+            Some(Ordering::Equal)
+        }
+    }
+
+    /// Check whether the line originates from the selected pipe, if any.
+    fn matches_pipe(&self, line: &LogLine) -> bool {
+        self.pipe
+            .map_or(true, |pipe| parse_pipe(&line.pipe) == Some(pipe))
+    }
+
+    /// Check whether the line has to be returned.
+    fn contains(&self, line: &LogLine) -> bool {
+        if !self.matches_pipe(line) {
+            return false;
+        }
+        match self.position(line) {
+            Some(position) => position == Ordering::Equal,
+            None => self.invalid == InvalidTimestamp::Keep,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+/// A single parsed record, which may only be a part of a line.
+struct Record {
+    line: LogLine,
+    partial: bool,
+}
+
+#[derive(Deserialize)]
+/// The fields of a JSON entry required for reading it back, which include the ones of the
+/// Docker compatible entries.
+struct JsonRecord {
+    #[serde(alias = "time")]
+    timestamp: String,
+    #[serde(alias = "stream")]
+    pipe: Option<Value>,
+    message: Option<String>,
+    message_b64: Option<String>,
+    log: Option<String>,
+    #[serde(default)]
+    partial: bool,
+}
+
+/// Read the last `n` lines of the log file at `path`, which continues with the most recent backup
+/// if the file contains less lines. The file gets read backwards in chunks, which means that only
+/// the required part of it gets read. Entries without a message like markers are skipped, as well
+/// as lines outside of the `filter` range.
+pub async fn tail<T: AsRef<Path>>(
+    path: T,
+    format: LogFormat,
+    n: usize,
+    filter: TimeFilter,
+) -> Result<Vec<LogLine>> {
+    let path = path.as_ref();
+    let mut lines = VecDeque::new();
+    if n == 0 {
+        return Ok(vec![]);
+    }
+
+    let mut reader = ReverseReader::open(path).await?;
+    let complete = collect(&mut reader, format, n, filter, &mut lines).await?;
+
+    if !complete {
+        if let Some(backup) = log_rotation::latest_backup(path).await? {
+            match compression(&backup) {
+                Compression::None => {
+                    let mut reader = ReverseReader::open(&backup).await?;
+                    collect(&mut reader, format, n, filter, &mut lines).await?;
+                }
+                compression => {
+                    let missing = n - lines.len();
+                    let older =
+                        collect_compressed(backup, compression, format, missing, filter).await?;
+                    for line in older.into_iter().rev() {
+                        lines.push_front(line);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(lines.into())
+}
+
+/// Retrieve the compression of the log backup at `path` by its extension.
+fn compression(path: &Path) -> Compression {
+    match path.extension() {
+        Some(x) if x == "gz" => Compression::Gzip,
+        Some(x) if x == "zst" => Compression::Zstd,
+        _ => Compression::None,
+    }
+}
+
+/// Retrieve the last `n` lines matching the filter from a compressed backup. Compressed data can
+/// only be read from the start, which is why the backup gets streamed through the decoder while
+/// keeping only the latest lines in memory.
+async fn collect_compressed(
+    path: PathBuf,
+    compression: Compression,
+    format: LogFormat,
+    n: usize,
+    filter: TimeFilter,
+) -> Result<VecDeque<LogLine>> {
+    task::spawn_blocking(move || {
+        let file =
+            std::fs::File::open(&path).context(format!("open log backup '{}'", path.display()))?;
+        let mut reader: Box<dyn BufRead> = match compression {
+            Compression::Zstd => Box::new(BufReader::new(zstd::Decoder::new(file)?)),
+            _ => Box::new(BufReader::new(GzDecoder::new(file))),
+        };
+
+        let mut lines = VecDeque::with_capacity(n);
+        let mut add = |line: LogLine| {
+            if filter.contains(&line) {
+                if lines.len() == n {
+                    lines.pop_front();
+                }
+                lines.push_back(line);
+            }
+        };
+        let mut parts = vec![];
+        let mut raw = vec![];
+        loop {
+            raw.clear();
+            if reader
+                .read_until(b'\n', &mut raw)
+                .context(format!("decompress log backup '{}'", path.display()))?
+                == 0
+            {
+                break;
+            }
+            let Some(record) = parse(format, &raw)? else {
+                continue;
+            };
+            parts.push(record.line);
+            if !record.partial {
+                add(join(mem::take(&mut parts)));
+            }
+        }
+        // A trailing partial line may continue in the newer file, but is still a line on its own.
+        if !parts.is_empty() {
+            add(join(parts));
+        }
+        Ok(lines)
+    })
+    .await
+    .context("join decompression task")?
+}
+
+/// Retrieve the last `n` lines matching the filter from lines kept in memory.
+pub fn tail_lines(lines: Vec<LogLine>, n: usize, filter: TimeFilter) -> Vec<LogLine> {
+    let mut lines = lines
+        .into_iter()
+        .filter(|line| filter.contains(line))
+        .collect::<Vec<_>>();
+    lines.drain(..lines.len().saturating_sub(n));
+    lines
+}
+
+/// Prepend lines of the reader matching the filter to `lines` until it contains `n` of them.
+/// Returns `true` if enough lines have been found or if a line older than the range got reached.
+async fn collect(
+    reader: &mut ReverseReader,
+    format: LogFormat,
+    n: usize,
+    filter: TimeFilter,
+    lines: &mut VecDeque<LogLine>,
+) -> Result<bool> {
+    // Add a line and return `true` if no further lines are required.
+    let mut add = |line: LogLine| match filter.position(&line) {
+        Some(Ordering::Less) => true,
+        Some(Ordering::Greater) => false,
+        position => {
+            if (position.is_some() || filter.invalid() == InvalidTimestamp::Keep)
+                && filter.matches_pipe(&line)
+            {
+                lines.push_front(line);
+            }
+            lines.len() >= n
+        }
+    };
+
+    // Parts of the line currently being assembled, in reverse order.
+    let mut parts: Vec<LogLine> = vec![];
+
+    while let Some(raw) = reader.next_line().await? {
+        let Some(record) = parse(format, &raw)? else {
+            continue;
+        };
+        if !record.partial
+            && !parts.is_empty()
+            && add(join(mem::take(&mut parts).into_iter().rev()))
+        {
+            return Ok(true);
+        }
+        parts.push(record.line);
+    }
+
+    // The remaining parts may continue in an older file, but are still a line on their own.
+    Ok(!parts.is_empty() && add(join(parts.into_iter().rev())))
+}
+
+/// Join the parts of a line, where the first one provides the timestamp and pipe.
+fn join(parts: impl IntoIterator<Item = LogLine>) -> LogLine {
+    let mut parts = parts.into_iter();
+    let mut line = parts.next().unwrap_or_default();
+    for part in parts {
+        line.message.extend(part.message);
+    }
+    line
+}
+
+/// Follow the log file at `path` like `tail -F`, which yields all existing and subsequently
+/// written lines. The path gets reopened if it has been rotated, truncated or recreated. Lines
+/// outside of the `filter` range get skipped, while the stream ends on the first line reaching
+/// its end.
+pub fn follow<T: AsRef<Path>>(
+    path: T,
+    format: LogFormat,
+    filter: TimeFilter,
+) -> impl Stream<Item = Result<LogLine>> {
+    let follower = Follower::new(path.as_ref(), format, filter);
+    stream::try_unfold(follower, |mut follower| async move {
+        let line = follower.next_line().await?;
+        Ok(line.map(|line| (line, follower)))
+    })
+}
+
+/// Parse an RFC3339 timestamp like `2023-11-14T22:13:20.123456789+01:00` as written by the
+/// loggers.
+pub fn parse_timestamp(timestamp: &str) -> Result<SystemTime> {
+    let parse = || -> Option<SystemTime> {
+        let (date, time) = timestamp.split_once('T')?;
+        let mut date = date.splitn(3, '-');
+        let year = date.next()?.parse().ok()?;
+        let month = date.next()?.parse().ok()?;
+        let day = date.next()?.parse().ok()?;
+
+        let (time, offset) = match time.strip_suffix('Z') {
+            Some(time) => (time, 0),
+            None => {
+                let (time, zone) = time.split_at(time.rfind(['+', '-'])?);
+                let (hours, minutes) = zone[1..].split_once(':')?;
+                let offset = hours.parse::<i64>().ok()? * 3600 + minutes.parse::<i64>().ok()? * 60;
+                match zone.starts_with('-') {
+                    true => (time, -offset),
+                    false => (time, offset),
+                }
+            }
+        };
+
+        let (time, fraction) = time.split_once('.').unwrap_or((time, ""));
+        let mut time = time.splitn(3, ':');
+        let hour = time.next()?.parse().ok()?;
+        let minute = time.next()?.parse().ok()?;
+        let second = time.next()?.parse().ok()?;
+        let nanoseconds = if fraction.is_empty() {
+            0
+        } else if fraction.len() <= 9 && fraction.bytes().all(|b| b.is_ascii_digit()) {
+            format!("{fraction:0<9}").parse().ok()?
+        } else {
+            return None;
+        };
+
+        let utc = UtcDateTime::new(year, month, day, hour, minute, second, nanoseconds).ok()?;
+        let unix_time = u64::try_from(utc.unix_time().checked_sub(offset)?).ok()?;
+        UNIX_EPOCH.checked_add(Duration::new(unix_time, nanoseconds))
+    };
+    match parse() {
+        Some(time) => Ok(time),
+        None => bail!("invalid RFC3339 timestamp '{}'", timestamp),
+    }
+}
+
+/// Parse a single raw line of the log file, where `None` indicates that it contains no message.
+fn parse(format: LogFormat, raw: &[u8]) -> Result<Option<Record>> {
+    let raw = raw.strip_suffix(b"\n").unwrap_or(raw);
+    Ok(match format {
+        LogFormat::Cri(tags) => Some(parse_cri_record(raw, tags)?),
+        LogFormat::Json => parse_json_record(raw)?,
+    })
+}
+
+/// Parse a single line of a CRI log file into typed fields.
+pub fn parse_cri(raw: &[u8]) -> Result<ParsedLine, ParseError> {
+    parse_cri_record(raw.strip_suffix(b"\n").unwrap_or(raw), CriTags::default())?.try_into()
+}
+
+/// Parse a single line of a JSON log file of any framing into typed fields, where `None`
+/// indicates that it contains no message, like a marker or the framing of an array.
+pub fn parse_json(raw: &[u8]) -> Result<Option<ParsedLine>, ParseError> {
+    parse_json_record(raw.strip_suffix(b"\n").unwrap_or(raw))?
+        .map(TryInto::try_into)
+        .transpose()
+}
+
+impl TryFrom<Record> for ParsedLine {
+    type Error = ParseError;
+
+    fn try_from(record: Record) -> Result<Self, Self::Error> {
+        let LogLine {
+            timestamp,
+            pipe,
+            message,
+        } = record.line;
+        let Some(pipe) = parse_pipe(&pipe) else {
+            return Err(ParseError::InvalidPipe(pipe));
+        };
+        Ok(Self {
+            timestamp: parse_timestamp(&timestamp)
+                .map_err(|_| ParseError::InvalidTimestamp(timestamp))?,
+            pipe,
+            partial: record.partial,
+            message,
+        })
+    }
+}
+
+/// Parse the pipe label of any of the formats written by the loggers.
+fn parse_pipe(label: &str) -> Option<Pipe> {
+    match label {
+        "stdin" | "in" | "0" => Some(Pipe::StdIn),
+        "stdout" | "out" | "1" => Some(Pipe::StdOut),
+        "stderr" | "err" | "2" => Some(Pipe::StdErr),
+        _ => None,
+    }
+}
+
+/// Parse a CRI record following the format `<timestamp> <stream> <P|F> <message>`, where the
+/// `tags` replace `P` and `F`.
+fn parse_cri_record(raw: &[u8], tags: CriTags) -> Result<Record, ParseError> {
+    let mut fields = raw.splitn(4, |b| *b == b' ');
+    let mut next = |name| fields.next().ok_or(ParseError::MissingField(name));
+    let timestamp = String::from_utf8_lossy(next("timestamp")?).into_owned();
+    let pipe = String::from_utf8_lossy(next("stream")?).into_owned();
+    let tag = next("tag")?;
+    let Some(partial) = tags.parse(tag) else {
+        return Err(ParseError::InvalidTag(String::from_utf8_lossy(tag).into()));
+    };
+    let message = fields.next().unwrap_or_default().to_vec();
+    Ok(Record {
+        line: LogLine {
+            timestamp,
+            pipe,
+            message,
+        },
+        partial,
+    })
+}
+
+/// Parse a JSON entry of any framing, where the framing bytes of JSON text sequences and arrays
+/// get skipped.
+fn parse_json_record(raw: &[u8]) -> Result<Option<Record>, ParseError> {
+    let raw = raw.strip_prefix(b"\x1e").unwrap_or(raw);
+    let raw = raw.strip_suffix(b",").unwrap_or(raw);
+    if matches!(raw, b"" | b"[" | b"]") {
+        return Ok(None);
+    }
+
+    let mut record: JsonRecord = serde_json::from_slice(raw).map_err(ParseError::InvalidJson)?;
+    // The `log` field of Docker compatible entries keeps the newline of full lines.
+    if let Some(log) = record.log.take() {
+        record.partial = !log.ends_with('\n');
+        record.message = Some(log.strip_suffix('\n').unwrap_or(&log).into());
+    }
+    let message = match (record.message, record.message_b64) {
+        (Some(message), _) => message.into_bytes(),
+        (None, Some(message_b64)) => STANDARD
+            .decode(message_b64)
+            .map_err(ParseError::InvalidBase64)?,
+        (None, None) => return Ok(None),
+    };
+    let pipe = match record.pipe {
+        Some(Value::String(pipe)) => pipe,
+        Some(pipe) => pipe.to_string(),
+        None => String::new(),
+    };
+    Ok(Some(Record {
+        line: LogLine {
+            timestamp: record.timestamp,
+            pipe,
+            message,
+        },
+        partial: record.partial,
+    }))
+}
+
+#[derive(Debug)]
+/// State of a followed log file.
+struct Follower {
+    path: PathBuf,
+    format: LogFormat,
+    filter: TimeFilter,
+
+    /// Set if a line reached the end of the filter range.
+    done: bool,
+
+    /// The currently followed file, which gets opened as soon as the path exists.
+    file: Option<File>,
+
+    /// The amount of bytes read from the current file.
+    pos: u64,
+
+    /// Data of the current file which does not form a complete line yet.
+    buf: Vec<u8>,
+
+    /// Parts of the line currently being assembled.
+    parts: Vec<LogLine>,
+
+    /// Complete lines which have not been yielded yet.
+    pending: VecDeque<LogLine>,
+}
+
+impl Follower {
+    /// The interval for checking the file for new data.
+    const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+    /// The amount of bytes read at once.
+    const CHUNK_SIZE: usize = 64 * 1024;
+
+    fn new(path: &Path, format: LogFormat, filter: TimeFilter) -> Self {
+        Self {
+            path: path.into(),
+            format,
+            filter,
+            done: false,
+            file: None,
+            pos: 0,
+            buf: vec![],
+            parts: vec![],
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Wait for the next complete line, where `None` indicates the end of the filter range.
+    async fn next_line(&mut self) -> Result<Option<LogLine>> {
+        loop {
+            if let Some(line) = self.pending.pop_front() {
+                return Ok(Some(line));
+            }
+            if self.done {
+                return Ok(None);
+            }
+            // The current file is read completely before switching to a new one.
+            if self.read().await? == 0 && !self.reopen_if_rotated().await? {
+                time::sleep(Self::POLL_INTERVAL).await;
+            }
+        }
+    }
+
+    /// Read the available data of the current file and return the amount of read bytes.
+    async fn read(&mut self) -> Result<usize> {
+        let Some(file) = self.file.as_mut() else {
+            return Ok(0);
+        };
+        let mut chunk = vec![0; Self::CHUNK_SIZE];
+        let n = file.read(&mut chunk).await.context("read log file")?;
+        self.pos += u64::try_from(n)?;
+        self.buf.extend_from_slice(&chunk[..n]);
+
+        while let Some(i) = memchr(b'\n', &self.buf).filter(|_| !self.done) {
+            let raw = self.buf.drain(..=i).collect::<Vec<_>>();
+            let Some(record) = parse(self.format, &raw)? else {
+                continue;
+            };
+            self.parts.push(record.line);
+            if record.partial {
+                continue;
+            }
+            let line = join(mem::take(&mut self.parts));
+            if self.filter.position(&line) == Some(Ordering::Greater) {
+                self.done = true;
+            } else if self.filter.contains(&line) {
+                self.pending.push_back(line);
+            }
+        }
+        Ok(n)
+    }
+
+    /// Open the path if it does not refer to the current file any more or if the file has been
+    /// truncated. Returns `true` if a file has been opened.
+    async fn reopen_if_rotated(&mut self) -> Result<bool> {
+        let current = match fs::metadata(&self.path).await {
+            Ok(metadata) => metadata,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+            Err(e) => return Err(e).context("get log file metadata"),
+        };
+        if let Some(file) = self.file.as_ref() {
+            let open = file
+                .metadata()
+                .await
+                .context("get open log file metadata")?;
+            // Without a known file identity, only a truncation is detected as rotation.
+            if log_rotation::is_same_file(&open, &current) != Some(false)
+                && current.len() >= self.pos
+            {
+                return Ok(false);
+            }
+        }
+
+        self.file = Some(
+            File::open(&self.path)
+                .await
+                .context(format!("open log file '{}'", self.path.display()))?,
+        );
+        self.pos = 0;
+        self.buf.clear();
+        Ok(true)
+    }
+}
+
+#[derive(Debug)]
+/// Reader returning the lines of a file from the last to the first one.
+struct ReverseReader {
+    /// The file to be read.
+    file: File,
+
+    /// The offset of the buffered data within the file.
+    pos: u64,
+
+    /// Data which has not been returned yet.
+    buf: Vec<u8>,
+}
+
+impl ReverseReader {
+    /// The amount of bytes read at once.
+    const CHUNK_SIZE: u64 = 64 * 1024;
+
+    /// Open the uncompressed file at `path`.
+    async fn open(path: &Path) -> Result<Self> {
+        let file = File::open(path)
+            .await
+            .context(format!("open log file '{}'", path.display()))?;
+        let pos = file
+            .metadata()
+            .await
+            .context("get log file metadata")?
+            .len();
+        Ok(Self {
+            file,
+            pos,
+            buf: vec![],
+        })
+    }
+
+    /// Retrieve the previous line including its trailing newline.
+    async fn next_line(&mut self) -> Result<Option<Vec<u8>>> {
+        loop {
+            // The trailing newline belongs to the line itself.
+            let search_end = self.buf.len() - usize::from(self.buf.last() == Some(&b'\n'));
+            if let Some(i) = memrchr(b'\n', &self.buf[..search_end]) {
+                return Ok(Some(self.buf.split_off(i + 1)));
+            }
+
+            if self.pos == 0 {
+                return Ok((!self.buf.is_empty()).then(|| mem::take(&mut self.buf)));
+            }
+
+            let len = self.pos.min(Self::CHUNK_SIZE);
+            self.pos -= len;
+            let mut chunk = vec![0; len.try_into()?];
+            self.file
+                .seek(std::io::SeekFrom::Start(self.pos))
+                .await
+                .context("seek log file")?;
+            self.file
+                .read_exact(&mut chunk)
+                .await
+                .context("read log file")?;
+            chunk.append(&mut self.buf);
+            self.buf = chunk;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        clock::FixedClock,
+        container_log::Logger,
+        cri_logger::CriLogger,
+        json_logger::{JsonLogger, PipeLabel},
+        log_rotation::RotationMode,
+    };
+    use futures::StreamExt;
+    use std::sync::Arc;
+    use tempfile::tempdir;
+
+    fn messages(lines: &[LogLine]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| String::from_utf8_lossy(&line.message).into_owned())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn tail_cri() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("0.log");
+        let mut logger = CriLogger::new(&path, None)?;
+        logger.set_max_line_size(Some(8));
+        logger.init().await?;
+
+        for i in 0..100 {
+            logger
+                .write(Pipe::StdOut, format!("line {i}\n").as_bytes())
+                .await?;
+        }
+        // Exceeds the max line size, which results in partial records.
+        logger
+            .write(Pipe::StdErr, "a very long line\n".as_bytes())
+            .await?;
+
+        let lines = tail(
+            &path,
+            LogFormat::Cri(CriTags::default()),
+            5,
+            TimeFilter::default(),
+        )
+        .await?;
+        assert_eq!(
+            messages(&lines),
+            [
+                "line 96",
+                "line 97",
+                "line 98",
+                "line 99",
+                "a very long line"
+            ]
+        );
+        assert_eq!(lines[4].pipe, "stderr");
+        assert!(lines.iter().all(|line| !line.timestamp.is_empty()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tail_cri_tags() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("0.log");
+        let tags = CriTags::new("+", "=")?;
+        let mut logger = CriLogger::new(&path, None)?;
+        logger.set_max_line_size(Some(4));
+        logger.set_tags(tags);
+        logger.init().await?;
+
+        logger
+            .write(Pipe::StdOut, "abcdefghij\n".as_bytes())
+            .await?;
+
+        let records = fs::read_to_string(&path)
+            .await?
+            .lines()
+            .map(|line| {
+                let mut fields = line.splitn(4, ' ').skip(2);
+                Some((fields.next()?.to_owned(), fields.next()?.to_owned()))
+            })
+            .collect::<Option<Vec<_>>>()
+            .context("no tags")?;
+        assert_eq!(
+            records,
+            [
+                ("+".into(), "abcd".into()),
+                ("+".into(), "efgh".into()),
+                ("=".into(), "ij".into())
+            ]
+        );
+        let lines = tail(&path, LogFormat::Cri(tags), 1, TimeFilter::default()).await?;
+        assert_eq!(messages(&lines), ["abcdefghij"]);
+
+        assert_eq!(CriTags::new("", "")?, CriTags::default());
+        assert!(CriTags::new(" ", "F").is_err());
+        assert!(CriTags::new("P", "FF").is_err());
+        assert!(CriTags::new("F", "").is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tail_json_rotated() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("0.log");
+        let mut logger = JsonLogger::new(&path, None)?;
+        logger.set_rotation(RotationMode::Rename);
+        logger.init().await?;
+
+        for i in 0..100 {
+            logger
+                .write(Pipe::StdOut, format!("line {i}\n").as_bytes())
+                .await?;
+        }
+        logger.reopen().await?;
+        logger.write(Pipe::StdOut, "last\n".as_bytes()).await?;
+
+        let lines = tail(&path, LogFormat::Json, 5, TimeFilter::default()).await?;
+        assert_eq!(
+            messages(&lines),
+            ["line 96", "line 97", "line 98", "line 99", "last"]
+        );
+        assert_eq!(
+            tail(&path, LogFormat::Json, 0, TimeFilter::default()).await?,
+            []
+        );
+        assert_eq!(
+            tail(&path, LogFormat::Json, 1000, TimeFilter::default())
+                .await?
+                .len(),
+            101
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tail_compressed_backup() -> Result<()> {
+        for compression in [Compression::Gzip, Compression::Zstd] {
+            let dir = tempdir()?;
+            let path = dir.path().join("0.log");
+            let mut logger = CriLogger::new(&path, None)?;
+            logger
+                .set_rotation(RotationMode::Rename)
+                .set_compression(compression);
+            logger.init().await?;
+
+            for i in 0..10 {
+                logger
+                    .write(Pipe::StdOut, format!("line {i}\n").as_bytes())
+                    .await?;
+                if i % 3 == 0 {
+                    logger
+                        .write(Pipe::StdErr, format!("err {i}\n").as_bytes())
+                        .await?;
+                }
+            }
+            logger.reopen().await?;
+            logger.write(Pipe::StdOut, "last\n".as_bytes()).await?;
+            // Closing waits for the backup to be compressed.
+            logger.close().await?;
+
+            let backup = log_rotation::latest_backup(&path)
+                .await?
+                .context("no backup")?;
+            assert!(backup.to_string_lossy().ends_with(compression.suffix()));
+
+            let lines = tail(
+                &path,
+                LogFormat::Cri(CriTags::default()),
+                3,
+                TimeFilter::default(),
+            )
+            .await?;
+            assert_eq!(messages(&lines), ["line 9", "err 9", "last"]);
+
+            // Only the lines matching the filter count towards the tailed ones.
+            let mut filter = TimeFilter::default();
+            filter.set_pipe(Some(Pipe::StdErr));
+            let lines = tail(&path, LogFormat::Cri(CriTags::default()), 3, filter).await?;
+            assert_eq!(messages(&lines), ["err 3", "err 6", "err 9"]);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reverse_reader_chunks() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("0.log");
+        let line = "x".repeat(ReverseReader::CHUNK_SIZE as usize - 1);
+        std::fs::write(&path, format!("first\n{line}\n{line}\nlast"))?;
+
+        let mut reader = ReverseReader::open(&path).await?;
+        assert_eq!(reader.next_line().await?, Some(b"last".to_vec()));
+        assert_eq!(reader.next_line().await?, Some(format!("{line}\n").into()));
+        assert_eq!(reader.next_line().await?, Some(format!("{line}\n").into()));
+        assert_eq!(reader.next_line().await?, Some(b"first\n".to_vec()));
+        assert_eq!(reader.next_line().await?, None);
+        Ok(())
+    }
+
+    async fn next(stream: &mut (impl Stream<Item = Result<LogLine>> + Unpin)) -> Result<LogLine> {
+        time::timeout(Duration::from_secs(5), stream.next())
+            .await?
+            .context("stream ended")?
+    }
+
+    #[tokio::test]
+    async fn parse_round_trip() -> Result<()> {
+        let dir = tempdir()?;
+        let now = UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789);
+
+        let cri_path = dir.path().join("cri.log");
+        let mut cri_logger = CriLogger::new(&cri_path, None)?;
+        cri_logger.set_clock(Arc::new(FixedClock(now)));
+        cri_logger.init().await?;
+        cri_logger
+            .write(Pipe::StdErr, "hello\nworld".as_bytes())
+            .await?;
+        cri_logger.flush().await?;
+
+        let json_path = dir.path().join("json.log");
+        let mut json_logger = JsonLogger::new(&json_path, None)?;
+        json_logger
+            .set_clock(Arc::new(FixedClock(now)))
+            .set_pipe_label(PipeLabel::Fd);
+        json_logger.init().await?;
+        json_logger
+            .write(Pipe::StdErr, "hello\nworld".as_bytes())
+            .await?;
+        json_logger.flush_partial(Pipe::StdErr).await?;
+        json_logger.write_marker(5).await?;
+        json_logger.flush().await?;
+
+        let expected = [("hello", false), ("world", true)].map(|(message, partial)| ParsedLine {
+            timestamp: now,
+            pipe: Pipe::StdErr,
+            partial,
+            message: message.into(),
+        });
+        let cri_lines = fs::read_to_string(&cri_path)
+            .await?
+            .lines()
+            .map(|x| parse_cri(x.as_bytes()))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(cri_lines, expected);
+        let json_lines = fs::read_to_string(&json_path)
+            .await?
+            .lines()
+            .map(|x| parse_json(x.as_bytes()))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(json_lines.len(), 3);
+        assert_eq!(
+            json_lines.into_iter().flatten().collect::<Vec<_>>(),
+            expected
+        );
+
+        assert!(matches!(
+            parse_cri(b"2023-11-14T22:13:20Z stdout"),
+            Err(ParseError::MissingField("tag"))
+        ));
+        assert!(matches!(
+            parse_cri(b"2023-11-14T22:13:20Z stdout X hello"),
+            Err(ParseError::InvalidTag(_))
+        ));
+        assert!(matches!(
+            parse_cri(b"yesterday stdout F hello"),
+            Err(ParseError::InvalidTimestamp(_))
+        ));
+        assert!(matches!(
+            parse_cri(b"2023-11-14T22:13:20Z stdfoo F hello"),
+            Err(ParseError::InvalidPipe(_))
+        ));
+        assert!(matches!(
+            parse_json(b"{broken"),
+            Err(ParseError::InvalidJson(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_timestamp_success() -> Result<()> {
+        let expected = UNIX_EPOCH + Duration::new(1_700_000_000, 123_000_000);
+        assert_eq!(parse_timestamp("2023-11-14T22:13:20.123Z")?, expected);
+        assert_eq!(
+            parse_timestamp("2023-11-14T23:13:20.123000000+01:00")?,
+            expected
+        );
+        assert_eq!(
+            parse_timestamp("2023-11-14T22:13:20Z")?,
+            UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+        );
+        assert!(parse_timestamp("2023-11-14 22:13:20").is_err());
+        assert!(parse_timestamp("2023-11-14T22:13:20.1234567890Z").is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn follow_success() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("0.log");
+        let mut logger = CriLogger::new(&path, None)?;
+        logger.set_rotation(RotationMode::Rename);
+        logger.init().await?;
+        logger.write(Pipe::StdOut, "first\n".as_bytes()).await?;
+
+        let mut stream = Box::pin(follow(
+            &path,
+            LogFormat::Cri(CriTags::default()),
+            TimeFilter::default(),
+        ));
+        assert_eq!(next(&mut stream).await?.message, b"first");
+
+        logger.write(Pipe::StdOut, "second\n".as_bytes()).await?;
+        logger.write(Pipe::StdErr, "third\n".as_bytes()).await?;
+        logger.reopen().await?;
+        logger.write(Pipe::StdOut, "fourth\n".as_bytes()).await?;
+
+        let mut lines = vec![];
+        for _ in 0..3 {
+            lines.push(next(&mut stream).await?);
+        }
+        assert_eq!(messages(&lines), ["second", "third", "fourth"]);
+        assert_eq!(lines[1].pipe, "stderr");
+        Ok(())
+    }
+
+    /// Lines of 22:13:19, 22:13:20, an invalid timestamp, 22:13:21 and 22:13:22 UTC.
+    const FILTER_LOG: &str = "2023-11-14T22:13:19.000000000Z stdout F one\n\
+        2023-11-14T23:13:20.000000000+01:00 stdout F two\n\
+        invalid stdout F three\n\
+        2023-11-14T22:13:21.000000000Z stdout F four\n\
+        2023-11-14T22:13:22.000000000Z stdout F five\n";
+
+    fn filter(since: u64, until: u64, invalid: InvalidTimestamp) -> TimeFilter {
+        let mut filter = TimeFilter::default();
+        filter
+            .set_since(Some(UNIX_EPOCH + Duration::from_secs(since)))
+            .set_until(Some(UNIX_EPOCH + Duration::from_secs(until)))
+            .set_invalid(invalid);
+        filter
+    }
+
+    #[tokio::test]
+    async fn tail_pipe_filter() -> Result<()> {
+        let dir = tempdir()?;
+        let cri_path = dir.path().join("cri.log");
+        let mut cri = CriLogger::new(&cri_path, None)?;
+        cri.init().await?;
+        // The short labels are read as well.
+        let json_path = dir.path().join("json.log");
+        let mut json = JsonLogger::new(&json_path, None)?;
+        json.set_pipe_label(PipeLabel::Short);
+        json.init().await?;
+
+        for i in 0..4 {
+            let pipe = if i % 2 == 0 {
+                Pipe::StdOut
+            } else {
+                Pipe::StdErr
+            };
+            let line = format!("{pipe} {i}\n");
+            cri.write(pipe, line.as_bytes()).await?;
+            json.write(pipe, line.as_bytes()).await?;
+        }
+        json.flush().await?;
+
+        for (path, format) in [
+            (&cri_path, LogFormat::Cri(CriTags::default())),
+            (&json_path, LogFormat::Json),
+        ] {
+            let mut sut = TimeFilter::default();
+            let lines = tail(path, format, 10, sut).await?;
+            assert_eq!(lines.len(), 4, "{format:?}");
+
+            sut.set_pipe(Some(Pipe::StdOut));
+            let lines = tail(path, format, 10, sut).await?;
+            assert_eq!(messages(&lines), ["stdout 0", "stdout 2"], "{format:?}");
+            let lines = tail(path, format, 1, sut).await?;
+            assert_eq!(messages(&lines), ["stdout 2"], "{format:?}");
+
+            sut.set_pipe(Some(Pipe::StdErr));
+            let lines = tail(path, format, 10, sut).await?;
+            assert_eq!(messages(&lines), ["stderr 1", "stderr 3"], "{format:?}");
+
+            sut.set_pipe(Some(Pipe::StdIn));
+            assert_eq!(tail(path, format, 10, sut).await?, [], "{format:?}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tail_time_filter() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("0.log");
+        std::fs::write(&path, FILTER_LOG)?;
+
+        // The start is inclusive and the end exclusive.
+        let sut = filter(1_700_000_000, 1_700_000_002, InvalidTimestamp::Keep);
+        let lines = tail(&path, LogFormat::Cri(CriTags::default()), 10, sut).await?;
+        assert_eq!(messages(&lines), ["two", "three", "four"]);
+
+        let sut = filter(1_700_000_000, 1_700_000_002, InvalidTimestamp::Skip);
+        let lines = tail(&path, LogFormat::Cri(CriTags::default()), 10, sut).await?;
+        assert_eq!(messages(&lines), ["two", "four"]);
+
+        let sut = filter(1_700_000_000, 1_700_000_003, InvalidTimestamp::Skip);
+        let lines = tail(&path, LogFormat::Cri(CriTags::default()), 1, sut).await?;
+        assert_eq!(messages(&lines), ["five"]);
+
+        let sut = filter(1_700_000_001, 1_700_000_001, InvalidTimestamp::Skip);
+        assert_eq!(
+            tail(&path, LogFormat::Cri(CriTags::default()), 10, sut).await?,
+            []
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn follow_time_filter() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("0.log");
+        std::fs::write(&path, FILTER_LOG)?;
+
+        let sut = filter(1_700_000_000, 1_700_000_002, InvalidTimestamp::Skip);
+        let lines = time::timeout(
+            Duration::from_secs(5),
+            follow(&path, LogFormat::Cri(CriTags::default()), sut).collect::<Vec<_>>(),
+        )
+        .await?
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?;
+        assert_eq!(messages(&lines), ["two", "four"]);
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+//! Retrying log writes which failed because of a full disk.
+
+use crate::log_error::LoggerError;
+use std::time::Duration;
+use tokio::{
+    io::{AsyncSeek, AsyncSeekExt, AsyncWrite, AsyncWriteExt, SeekFrom},
+    time,
+};
+use tracing::warn;
+
+/// The delay between retries, which gives rotations or cleanups elsewhere time to free space.
+pub const DELAY: Duration = Duration::from_millis(100);
+
+/// Write the record at the end of the writer and flush it, where a full disk gets retried up to
+/// `retries` times after a delay. The caller is blocked while retrying, which applies
+/// backpressure to the container output instead of losing it. The part of the record written
+/// before the disk got full is detected by the end of the writer, which means that only the
+/// remainder gets written again.
+pub async fn write_all<W>(writer: &mut W, record: &[u8], retries: usize) -> Result<(), LoggerError>
+where
+    W: AsyncWrite + AsyncSeek + Unpin,
+{
+    let start = writer.seek(SeekFrom::End(0)).await?;
+    let mut attempt = 0;
+    loop {
+        let end = writer.seek(SeekFrom::End(0)).await?;
+        let written = usize::try_from(end.saturating_sub(start))
+            .unwrap_or(usize::MAX)
+            .min(record.len());
+        let res = async {
+            writer.write_all(&record[written..]).await?;
+            writer.flush().await
+        }
+        .await;
+        match res {
+            Err(e) if e.raw_os_error() == Some(libc::ENOSPC) && attempt < retries => {
+                attempt += 1;
+                warn!(
+                    "Disk full while writing log, retrying in {:?} ({}/{})",
+                    DELAY, attempt, retries
+                );
+                time::sleep(DELAY).await;
+            }
+            res => return Ok(res?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{self, Cursor},
+        pin::Pin,
+        task::{Context, Poll},
+    };
+
+    /// Writer accepting `accept` bytes before failing once because of a full disk.
+    struct FullOnce {
+        inner: Cursor<Vec<u8>>,
+        accept: usize,
+        failed: bool,
+    }
+
+    impl AsyncWrite for FullOnce {
+        fn poll_write(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            if !self.failed {
+                if self.accept == 0 {
+                    self.failed = true;
+                    return Poll::Ready(Err(io::Error::from_raw_os_error(libc::ENOSPC)));
+                }
+                let len = buf.len().min(self.accept);
+                self.accept -= len;
+                return Pin::new(&mut self.inner).poll_write(cx, &buf[..len]);
+            }
+            Pin::new(&mut self.inner).poll_write(cx, buf)
+        }
+
+        fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.inner).poll_flush(cx)
+        }
+
+        fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Pin::new(&mut self.inner).poll_shutdown(cx)
+        }
+    }
+
+    impl AsyncSeek for FullOnce {
+        fn start_seek(mut self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+            Pin::new(&mut self.inner).start_seek(position)
+        }
+
+        fn poll_complete(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+            Pin::new(&mut self.inner).poll_complete(cx)
+        }
+    }
+
+    fn writer(accept: usize) -> FullOnce {
+        FullOnce {
+            inner: Cursor::new(b"first\n".to_vec()),
+            accept,
+            failed: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn write_all_retry() -> Result<(), LoggerError> {
+        let mut sut = writer(3);
+        write_all(&mut sut, b"second\n", 1).await?;
+        assert_eq!(sut.inner.get_ref(), b"first\nsecond\n");
+
+        let mut sut = writer(3);
+        let err = write_all(&mut sut, b"second\n", 0).await.unwrap_err();
+        assert!(matches!(err, LoggerError::DiskFull(_)));
+        assert_eq!(sut.inner.get_ref(), b"first\nsec");
+        Ok(())
+    }
+}
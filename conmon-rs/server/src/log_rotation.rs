@@ -0,0 +1,996 @@
+//! Log file rotation functionalities.
+
+use crate::log_sink::LogSink;
+use anyhow::{bail, Context, Result};
+use flate2::write::GzEncoder;
+use sha2::{Digest, Sha256};
+use std::{
+    ffi::OsString,
+    fs::File,
+    fs::Metadata,
+    future::Future,
+    io,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tokio::{
+    fs,
+    task::{self, JoinHandle},
+};
+use tracing::{debug, error, warn};
+use tz::UtcDateTime;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Available strategies for rotating a log file.
+pub enum RotationMode {
+    #[default]
+    /// Truncate the log file in place, discarding its previous content.
+    Truncate,
+
+    /// Rename the log file to a numbered backup (`<path>.1`) before opening a new one.
+    Rename,
+
+    /// Rename the log file to a backup suffixed with the UTC rotation time
+    /// (`<path>.20231114T221320.123456789Z`) before opening a new one.
+    Timestamp,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// Available schemes for naming the backups of the `Rename` rotation.
+pub enum RotationNaming {
+    #[default]
+    /// Number the backups by their generation (`<path>.1`, `<path>.2`, ...).
+    Numbered,
+
+    /// Suffix the backups with the UTC rotation time (`<path>.20231114T221320.123456789Z`).
+    Timestamp,
+
+    /// Append a custom format to the path, like `-{index}.bak` or `.{timestamp}.old`. The log
+    /// reader only follows numbered and timestamped backups.
+    Format(BackupFormat),
+}
+
+impl RotationNaming {
+    /// Retrieve the format of the backup names.
+    fn format(&self) -> BackupFormat {
+        match self {
+            RotationNaming::Numbered => BackupFormat::numbered(),
+            RotationNaming::Timestamp => BackupFormat::timestamp(),
+            RotationNaming::Format(format) => format.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The variable part of a backup name.
+enum Placeholder {
+    /// The generation of the backup, starting at 1 for the newest one.
+    Index,
+
+    /// The UTC rotation time, which has a fixed width.
+    Timestamp,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// A validated format of backup names, which gets appended to the path of the log file.
+pub struct BackupFormat {
+    /// The part in front of the placeholder.
+    prefix: String,
+
+    /// The placeholder replaced by the generation or rotation time.
+    placeholder: Placeholder,
+
+    /// The part after the placeholder.
+    suffix: String,
+}
+
+impl BackupFormat {
+    /// The placeholder of the backup generation.
+    const INDEX: &'static str = "{index}";
+
+    /// The placeholder of the rotation time.
+    const TIMESTAMP: &'static str = "{timestamp}";
+
+    /// Parse a format containing exactly one `{index}` or `{timestamp}` placeholder. Backups
+    /// live next to the log file, which means that path separators are not allowed.
+    pub fn new(format: &str) -> Result<Self> {
+        if format.contains('/') {
+            bail!(
+                "backup format '{}' must not contain a path separator",
+                format
+            )
+        }
+        let (placeholder, pattern) = match (format.find(Self::INDEX), format.find(Self::TIMESTAMP))
+        {
+            (Some(_), None) => (Placeholder::Index, Self::INDEX),
+            (None, Some(_)) => (Placeholder::Timestamp, Self::TIMESTAMP),
+            _ => bail!(
+                "backup format '{}' requires either an {} or a {} placeholder",
+                format,
+                Self::INDEX,
+                Self::TIMESTAMP
+            ),
+        };
+        let (prefix, suffix) = format.split_once(pattern).context("split backup format")?;
+        if prefix.contains(['{', '}']) || suffix.contains(['{', '}']) {
+            bail!(
+                "backup format '{}' contains an unsupported placeholder",
+                format
+            )
+        }
+        Ok(Self {
+            prefix: prefix.into(),
+            placeholder,
+            suffix: suffix.into(),
+        })
+    }
+
+    /// The format of numbered backups.
+    fn numbered() -> Self {
+        Self {
+            prefix: ".".into(),
+            placeholder: Placeholder::Index,
+            suffix: String::new(),
+        }
+    }
+
+    /// The format of timestamped backups.
+    pub fn timestamp() -> Self {
+        Self {
+            prefix: ".".into(),
+            placeholder: Placeholder::Timestamp,
+            suffix: String::new(),
+        }
+    }
+
+    /// Retrieve the name of the backup for the log file at `path`, where `value` replaces the
+    /// placeholder.
+    fn path(&self, path: &Path, value: &str, compression: Compression) -> PathBuf {
+        let mut backup = OsString::from(path);
+        backup.push(&self.prefix);
+        backup.push(value);
+        backup.push(&self.suffix);
+        backup.push(compression.suffix());
+        backup.into()
+    }
+
+    /// Retrieve the path of the backup `generation` for the log file at `path`.
+    pub fn index_path<T: AsRef<Path>>(
+        &self,
+        path: T,
+        generation: usize,
+        compression: Compression,
+    ) -> PathBuf {
+        self.path(path.as_ref(), &generation.to_string(), compression)
+    }
+
+    /// Retrieve the path of the backup rotated at the UTC time `time` for the log file at `path`.
+    pub fn timestamp_path<T: AsRef<Path>>(
+        &self,
+        path: T,
+        time: SystemTime,
+        compression: Compression,
+    ) -> Result<PathBuf> {
+        let duration = time
+            .duration_since(UNIX_EPOCH)
+            .context("get duration since UNIX epoch")?;
+        let utc =
+            UtcDateTime::from_timespec(duration.as_secs().try_into()?, duration.subsec_nanos())
+                .context("get UTC datetime")?;
+        let timestamp = format!(
+            "{:04}{:02}{:02}T{:02}{:02}{:02}.{:09}Z",
+            utc.year(),
+            utc.month(),
+            utc.month_day(),
+            utc.hour(),
+            utc.minute(),
+            utc.second(),
+            utc.nanoseconds(),
+        );
+        Ok(self.path(path.as_ref(), &timestamp, compression))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Available algorithms for compressing rotated log files.
+pub enum Compression {
+    #[default]
+    /// Keep the backups uncompressed.
+    None,
+
+    /// Gzip compress the backups to `<backup>.gz`.
+    Gzip,
+
+    /// Zstandard compress the backups to `<backup>.zst`, which is faster and results in smaller
+    /// files than gzip.
+    Zstd,
+}
+
+impl Compression {
+    /// All available algorithms.
+    pub const ALL: [Compression; 3] = [Compression::None, Compression::Gzip, Compression::Zstd];
+
+    /// Retrieve the suffix of backups compressed by the algorithm.
+    pub fn suffix(self) -> &'static str {
+        match self {
+            Compression::None => "",
+            Compression::Gzip => ".gz",
+            Compression::Zstd => ".zst",
+        }
+    }
+}
+
+impl RotationMode {
+    /// Rotate the log file at `path` according to the mode, where `now` is the rotation time used
+    /// by timestamped backups. The `Rename` mode names the backups according to `naming`. Returns
+    /// the path of the new backup, if any.
+    pub async fn rotate<T: AsRef<Path>>(
+        self,
+        path: T,
+        now: SystemTime,
+        max_files: Option<usize>,
+        compression: Compression,
+        naming: &RotationNaming,
+    ) -> Result<Option<PathBuf>> {
+        let format = match self {
+            RotationMode::Truncate => return Ok(None),
+            RotationMode::Rename => naming.format(),
+            RotationMode::Timestamp => RotationNaming::Timestamp.format(),
+        };
+        match format.placeholder {
+            Placeholder::Index => rotate(path, &format, max_files, compression).await,
+            Placeholder::Timestamp => rotate_timestamp(path, &format, now, max_files).await,
+        }
+    }
+
+    /// Verify that the backup options are coherent with the mode, which requires a mode keeping
+    /// backups. Only the `Rename` mode supports naming the backups.
+    pub fn validate(
+        self,
+        max_files: Option<usize>,
+        compression: Compression,
+        naming: &RotationNaming,
+    ) -> Result<()> {
+        if self == RotationMode::Truncate {
+            if let Some(max_files) = max_files {
+                bail!("max files {} require a rotation keeping backups", max_files)
+            }
+            if compression != Compression::None {
+                bail!("compression requires a rotation keeping backups")
+            }
+        }
+        if self != RotationMode::Rename && *naming != RotationNaming::Numbered {
+            bail!("naming the backups requires the rename rotation")
+        }
+        Ok(())
+    }
+}
+
+/// Rotate the log file at `path` by renaming it to its first backup generation named by the
+/// `format`. Already existing backups get shifted by one generation, while the oldest ones get
+/// removed if they would exceed `max_files`. The existing backups are expected to be compressed
+/// using `compression`, like `<path>.2.gz`, while the path of the new uncompressed backup gets
+/// returned for compressing it by `compress_backup`. Backups which failed to be compressed keep
+/// their uncompressed name while being shifted. The checksum sidecars of the backups follow them.
+///
+/// Backups live next to the log file, which means that every rename stays on the same
+/// filesystem and is therefore atomic.
+pub async fn rotate<T: AsRef<Path>>(
+    path: T,
+    format: &BackupFormat,
+    max_files: Option<usize>,
+    compression: Compression,
+) -> Result<Option<PathBuf>> {
+    let path = path.as_ref();
+    if !fs::try_exists(path).await.unwrap_or_default() {
+        debug!("Nothing to rotate, log {} does not exist", path.display());
+        return Ok(None);
+    }
+
+    if max_files == Some(0) {
+        debug!(
+            "Removing log {} because no backups are kept",
+            path.display()
+        );
+        fs::remove_file(path)
+            .await
+            .context(format!("remove log file '{}'", path.display()))?;
+        return Ok(None);
+    }
+
+    // The compression of every existing backup, where the index is the generation minus one.
+    let mut backups = vec![];
+    while let Some(found) = backup_compression(path, format, backups.len() + 1, compression).await {
+        backups.push(found);
+    }
+
+    if let Some(max_files) = max_files {
+        while backups.len() >= max_files {
+            let generation = backups.len();
+            let oldest = format.index_path(path, generation, backups[generation - 1]);
+            debug!("Removing oldest log backup {}", oldest.display());
+            fs::remove_file(&oldest)
+                .await
+                .context(format!("remove log backup '{}'", oldest.display()))?;
+            remove_checksum(&oldest).await?;
+            backups.pop();
+        }
+    }
+
+    // Renaming replaces an already existing target, which covers stale backups beyond a gap.
+    for (generation, compression) in backups.into_iter().enumerate().rev() {
+        let from = format.index_path(path, generation + 1, compression);
+        let to = format.index_path(path, generation + 2, compression);
+        fs::rename(&from, &to).await.context(format!(
+            "rename log backup '{}' to '{}'",
+            from.display(),
+            to.display()
+        ))?;
+        rename_checksum(&from, &to).await?;
+    }
+
+    let backup = format.index_path(path, 1, Compression::None);
+    debug!("Rotating log {} to {}", path.display(), backup.display());
+    fs::rename(path, &backup).await.context(format!(
+        "rename log file '{}' to '{}'",
+        path.display(),
+        backup.display()
+    ))?;
+    Ok(Some(backup))
+}
+
+/// Retrieve the compression of the existing backup `generation` of the log file at `path`, which
+/// is either the expected `compression` or none if compressing the backup failed.
+async fn backup_compression(
+    path: &Path,
+    format: &BackupFormat,
+    generation: usize,
+    compression: Compression,
+) -> Option<Compression> {
+    for compression in [compression, Compression::None] {
+        let backup = format.index_path(path, generation, compression);
+        if fs::try_exists(backup).await.unwrap_or_default() {
+            return Some(compression);
+        }
+    }
+    None
+}
+
+/// Rotate the log file at `path` by renaming it to a backup named by the `format` with the UTC
+/// time `now`. The oldest timestamped backups get removed if they would exceed `max_files`, where
+/// the fixed width of the timestamp ensures that the lexical order of the backups is the
+/// chronological one. Returns the path of the new uncompressed backup, if any.
+pub async fn rotate_timestamp<T: AsRef<Path>>(
+    path: T,
+    format: &BackupFormat,
+    now: SystemTime,
+    max_files: Option<usize>,
+) -> Result<Option<PathBuf>> {
+    let path = path.as_ref();
+    if !fs::try_exists(path).await.unwrap_or_default() {
+        debug!("Nothing to rotate, log {} does not exist", path.display());
+        return Ok(None);
+    }
+
+    if let Some(max_files) = max_files {
+        let mut backups = timestamp_backups(path, format).await?;
+        while backups.len() >= max_files.max(1) {
+            let oldest = backups.remove(0);
+            debug!("Removing oldest log backup {}", oldest.display());
+            fs::remove_file(&oldest)
+                .await
+                .context(format!("remove log backup '{}'", oldest.display()))?;
+            remove_checksum(&oldest).await?;
+        }
+        if max_files == 0 {
+            debug!(
+                "Removing log {} because no backups are kept",
+                path.display()
+            );
+            fs::remove_file(path)
+                .await
+                .context(format!("remove log file '{}'", path.display()))?;
+            return Ok(None);
+        }
+    }
+
+    let backup = format.timestamp_path(path, now, Compression::None)?;
+    debug!("Rotating log {} to {}", path.display(), backup.display());
+    fs::rename(path, &backup).await.context(format!(
+        "rename log file '{}' to '{}'",
+        path.display(),
+        backup.display()
+    ))?;
+    Ok(Some(backup))
+}
+
+/// Compress the new `backup` using `compression` and return the path of the result, which is the
+/// `backup` itself if no compression is selected.
+pub async fn compress_backup(backup: PathBuf, compression: Compression) -> Result<PathBuf> {
+    if compression == Compression::None {
+        return Ok(backup);
+    }
+    // Compressing is CPU bound, which means that it should not block the async runtime.
+    task::spawn_blocking(move || compress_file(&backup, compression))
+        .await
+        .context("join compression task")?
+}
+
+#[derive(Debug, Default)]
+/// Background task finishing the latest backup, which keeps compressing it out of the rotation
+/// and therefore out of the locked container log.
+pub struct BackupTask(Option<JoinHandle<()>>);
+
+impl BackupTask {
+    /// Compress the new `backup` in the background and pass the path of the result to `then`,
+    /// like for writing its checksum. Failures are only reported as errors, because the log file
+    /// has been rotated already. The previous backup has to be finished by `wait` beforehand.
+    pub fn spawn<F, T>(&mut self, backup: PathBuf, compression: Compression, then: F)
+    where
+        F: FnOnce(PathBuf) -> T + Send + 'static,
+        T: Future<Output = Result<()>> + Send,
+    {
+        self.0 = Some(task::spawn(async move {
+            let res = match compress_backup(backup.clone(), compression).await {
+                Ok(backup) => then(backup).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = res {
+                error!("Unable to finish log backup {}: {:#}", backup.display(), e);
+            }
+        }));
+    }
+
+    /// Wait for the latest backup to be finished. The next rotation shifts the backups, which
+    /// means that it has to wait as well if it happens before the compression finished.
+    pub async fn wait(&mut self) {
+        if let Some(task) = self.0.take() {
+            if let Err(e) = task.await {
+                warn!("Unable to wait for log backup task: {:#}", e);
+            }
+        }
+    }
+}
+
+/// Retrieve the path of the checksum sidecar of the `backup`, which is `<backup>.sha256`.
+pub fn checksum_path<T: AsRef<Path>>(backup: T) -> PathBuf {
+    let mut path = OsString::from(backup.as_ref());
+    path.push(".sha256");
+    path.into()
+}
+
+/// Write the SHA-256 digest of the `backup` into its checksum sidecar, which allows detecting
+/// later modifications. The sidecar uses the format of `sha256sum`, which means that
+/// `sha256sum -c` verifies it from within the log directory.
+pub async fn write_checksum(backup: PathBuf) -> Result<()> {
+    // Hashing is CPU bound, which means that it should not block the async runtime.
+    task::spawn_blocking(move || checksum_file(&backup))
+        .await
+        .context("join checksum task")?
+}
+
+/// Hash the file at `path` and write the digest into its checksum sidecar, which gets replaced
+/// atomically.
+fn checksum_file(path: &Path) -> Result<()> {
+    let mut source = File::open(path).context(format!("open log backup '{}'", path.display()))?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut source, &mut hasher).context("hash log backup")?;
+    let name = path.file_name().context("get log backup file name")?;
+
+    let sidecar = checksum_path(path);
+    let mut tmp = OsString::from(&sidecar);
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    debug!("Writing checksum of log backup {}", path.display());
+    std::fs::write(
+        &tmp,
+        format!("{:x}  {}\n", hasher.finalize(), name.to_string_lossy()),
+    )
+    .context(format!("write checksum '{}'", tmp.display()))?;
+    std::fs::rename(&tmp, &sidecar).context(format!(
+        "rename checksum '{}' to '{}'",
+        tmp.display(),
+        sidecar.display()
+    ))
+}
+
+/// Remove the checksum sidecar of the removed `backup`, if there is any.
+pub async fn remove_checksum<T: AsRef<Path>>(backup: T) -> Result<()> {
+    let sidecar = checksum_path(backup);
+    match fs::remove_file(&sidecar).await {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => {
+            Err(e).context(format!("remove checksum '{}'", sidecar.display()))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Move the checksum sidecar of the backup renamed from `from` to `to`, if there is any. The
+/// sidecar names the backup, which gets updated to keep it verifiable by `sha256sum -c`.
+async fn rename_checksum(backup_from: &Path, backup_to: &Path) -> Result<()> {
+    let (from, to) = (checksum_path(backup_from), checksum_path(backup_to));
+    let content = match fs::read_to_string(&from).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e).context(format!("read checksum '{}'", from.display())),
+    };
+    let digest = content.split_whitespace().next().unwrap_or_default();
+    let name = backup_to
+        .file_name()
+        .context("get log backup file name")?
+        .to_string_lossy();
+    fs::write(&to, format!("{digest}  {name}\n"))
+        .await
+        .context(format!("write checksum '{}'", to.display()))?;
+    fs::remove_file(&from)
+        .await
+        .context(format!("remove checksum '{}'", from.display()))
+}
+
+/// Retrieve the most recent backup of the log file at `path`, which is either the first numbered
+/// or the newest timestamped one.
+pub async fn latest_backup<T: AsRef<Path>>(path: T) -> Result<Option<PathBuf>> {
+    let path = path.as_ref();
+    for compression in Compression::ALL {
+        let backup = backup_path(path, 1, compression);
+        if fs::try_exists(&backup).await.unwrap_or_default() {
+            return Ok(Some(backup));
+        }
+    }
+    Ok(timestamp_backups(path, &BackupFormat::timestamp())
+        .await?
+        .pop())
+}
+
+/// Retrieve all numbered and timestamped backups of the log file at `path` as well as the ones
+/// named by `naming`, regardless of their compression.
+pub async fn backups<T: AsRef<Path>>(path: T, naming: &RotationNaming) -> Result<Vec<PathBuf>> {
+    let path = path.as_ref();
+    let mut formats = vec![BackupFormat::numbered(), BackupFormat::timestamp()];
+    if let RotationNaming::Format(format) = naming {
+        if !formats.contains(format) {
+            formats.push(format.clone());
+        }
+    }
+    let mut backups = vec![];
+    for format in formats {
+        match format.placeholder {
+            Placeholder::Index => backups.extend(index_backups(path, &format).await),
+            Placeholder::Timestamp => backups.extend(timestamp_backups(path, &format).await?),
+        }
+    }
+    Ok(backups)
+}
+
+/// Retrieve the consecutively numbered backups of the log file at `path` named by the `format`,
+/// sorted from newest to oldest.
+async fn index_backups(path: &Path, format: &BackupFormat) -> Vec<PathBuf> {
+    let mut backups = vec![];
+    for generation in 1.. {
+        let len = backups.len();
+        for compression in Compression::ALL {
+            let backup = format.index_path(path, generation, compression);
+            if fs::try_exists(&backup).await.unwrap_or_default() {
+                backups.push(backup);
+            }
+        }
+        if backups.len() == len {
+            break;
+        }
+    }
+    backups
+}
+
+/// Retrieve the timestamped backups of the log file at `path` named by the `format`, sorted from
+/// oldest to newest.
+async fn timestamp_backups(path: &Path, format: &BackupFormat) -> Result<Vec<PathBuf>> {
+    let (Some(dir), Some(file_name)) = (path.parent(), path.file_name()) else {
+        return Ok(vec![]);
+    };
+    let dir = if dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        dir
+    };
+    let mut prefix = file_name.to_os_string();
+    prefix.push(&format.prefix);
+    let prefix = prefix.to_string_lossy().into_owned();
+
+    let mut backups = vec![];
+    let mut entries = fs::read_dir(dir)
+        .await
+        .context(format!("read log directory '{}'", dir.display()))?;
+    while let Some(entry) = entries.next_entry().await? {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let Some(suffix) = name.strip_prefix(&prefix) else {
+            continue;
+        };
+        let suffix = [Compression::Gzip, Compression::Zstd]
+            .iter()
+            .find_map(|compression| suffix.strip_suffix(compression.suffix()))
+            .unwrap_or(suffix);
+        let Some(timestamp) = suffix.strip_suffix(format.suffix.as_str()) else {
+            continue;
+        };
+        if is_timestamp_suffix(timestamp) {
+            backups.push((timestamp.to_owned(), entry.path()));
+        }
+    }
+    backups.sort();
+    Ok(backups.into_iter().map(|(_, path)| path).collect())
+}
+
+/// Verify that the suffix has the shape `YYYYMMDDTHHMMSS.NNNNNNNNNZ`.
+fn is_timestamp_suffix(suffix: &str) -> bool {
+    let bytes = suffix.as_bytes();
+    bytes.len() == 26
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            8 => *b == b'T',
+            15 => *b == b'.',
+            25 => *b == b'Z',
+            _ => b.is_ascii_digit(),
+        })
+}
+
+#[cfg(unix)]
+/// Verify if both metadata belong to the same file by comparing their device and inode.
+pub fn is_same_file(a: &Metadata, b: &Metadata) -> Option<bool> {
+    use std::os::unix::fs::MetadataExt;
+    Some(a.dev() == b.dev() && a.ino() == b.ino())
+}
+
+#[cfg(not(unix))]
+/// Verify if both metadata belong to the same file. There is no stable file identity outside of
+/// Unix, which means that the result is always unknown.
+pub fn is_same_file(_: &Metadata, _: &Metadata) -> Option<bool> {
+    None
+}
+
+/// Verify if `path` no longer refers to the open `file`, for example because an external tool like
+/// logrotate moved or deleted it. Without a known file identity, only deleted files are detected.
+pub async fn is_detached<T: AsRef<Path>>(path: T, file: &fs::File) -> Result<bool> {
+    let open = file
+        .metadata()
+        .await
+        .context("get open log file metadata")?;
+    match fs::metadata(path.as_ref()).await {
+        Ok(current) => Ok(is_same_file(&current, &open) == Some(false)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(true),
+        Err(e) => Err(e).context(format!(
+            "get log file metadata of '{}'",
+            path.as_ref().display()
+        )),
+    }
+}
+
+/// Retrieve the actual size of the open log `file`, including the bytes still buffered by its
+/// writer. Custom writers have no size.
+pub async fn file_size(file: &tokio::io::BufWriter<LogSink>) -> Result<Option<usize>> {
+    let Some(sink) = file.get_ref().file() else {
+        return Ok(None);
+    };
+    let len = sink
+        .metadata()
+        .await
+        .context("get open log file metadata")?
+        .len();
+    Ok(Some(usize::try_from(len)? + file.buffer().len()))
+}
+
+/// Retrieve the path of the backup `generation` for the log file at `path`. Compressed backups
+/// carry the additional suffix of the `compression`.
+pub fn backup_path<T: AsRef<Path>>(
+    path: T,
+    generation: usize,
+    compression: Compression,
+) -> PathBuf {
+    BackupFormat::numbered().index_path(path, generation, compression)
+}
+
+/// Compress the file at `path` into `<path><suffix>` and remove the uncompressed file afterwards,
+/// which returns the path of the compressed file.
+fn compress_file(path: &Path, compression: Compression) -> Result<PathBuf> {
+    let mut target = OsString::from(path);
+    target.push(compression.suffix());
+    let target = PathBuf::from(target);
+    debug!(
+        "Compressing log backup {} to {}",
+        path.display(),
+        target.display()
+    );
+
+    let mut source = File::open(path).context(format!("open log backup '{}'", path.display()))?;
+    let create =
+        || File::create(&target).context(format!("create log backup '{}'", target.display()));
+    let file = match compression {
+        Compression::None => bail!("no compression algorithm selected"),
+        Compression::Gzip => {
+            let mut encoder = GzEncoder::new(create()?, flate2::Compression::default());
+            io::copy(&mut source, &mut encoder).context("compress log backup")?;
+            encoder.finish()
+        }
+        Compression::Zstd => {
+            let mut encoder = zstd::Encoder::new(create()?, zstd::DEFAULT_COMPRESSION_LEVEL)
+                .context("create zstd encoder")?;
+            io::copy(&mut source, &mut encoder).context("compress log backup")?;
+            encoder.finish()
+        }
+    };
+    file.context("finish log backup compression")?
+        .sync_all()
+        .context("sync compressed log backup")?;
+
+    std::fs::remove_file(path).context(format!("remove log backup '{}'", path.display()))?;
+    Ok(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+    use std::{fs, io::Read, time::Duration};
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn rotate_shifts_backups() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("app.log");
+
+        for content in ["first", "second", "third"] {
+            fs::write(&path, content)?;
+            rotate(&path, &BackupFormat::numbered(), None, Compression::None).await?;
+        }
+
+        assert!(!path.exists());
+        assert_eq!(
+            fs::read_to_string(backup_path(&path, 1, Compression::None))?,
+            "third"
+        );
+        assert_eq!(
+            fs::read_to_string(backup_path(&path, 2, Compression::None))?,
+            "second"
+        );
+        assert_eq!(
+            fs::read_to_string(backup_path(&path, 3, Compression::None))?,
+            "first"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_max_files() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("app.log");
+
+        for i in 0..5 {
+            fs::write(&path, i.to_string())?;
+            rotate(&path, &BackupFormat::numbered(), Some(2), Compression::None).await?;
+        }
+        fs::write(&path, "live")?;
+
+        assert_eq!(fs::read_dir(dir.path())?.count(), 3);
+        assert_eq!(
+            fs::read_to_string(backup_path(&path, 1, Compression::None))?,
+            "4"
+        );
+        assert_eq!(
+            fs::read_to_string(backup_path(&path, 2, Compression::None))?,
+            "3"
+        );
+        assert!(!backup_path(&path, 3, Compression::None).exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_missing_file() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("app.log");
+
+        rotate(&path, &BackupFormat::numbered(), None, Compression::None).await?;
+
+        assert!(!backup_path(&path, 1, Compression::None).exists());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_compress() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("app.log");
+
+        for content in ["first", "second"] {
+            fs::write(&path, content)?;
+            let backup = rotate(&path, &BackupFormat::numbered(), Some(2), Compression::Gzip)
+                .await?
+                .context("no backup")?;
+            assert_eq!(backup, backup_path(&path, 1, Compression::None));
+            compress_backup(backup, Compression::Gzip).await?;
+        }
+
+        assert!(!backup_path(&path, 1, Compression::None).exists());
+        for (generation, expected) in [(1, "second"), (2, "first")] {
+            let mut contents = String::new();
+            GzDecoder::new(File::open(backup_path(
+                &path,
+                generation,
+                Compression::Gzip,
+            ))?)
+            .read_to_string(&mut contents)?;
+            assert_eq!(contents, expected);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_compress_failed() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("app.log");
+
+        // The first backup stays uncompressed, like if compressing it failed.
+        for content in ["first", "second"] {
+            fs::write(&path, content)?;
+            rotate(&path, &BackupFormat::numbered(), Some(3), Compression::Gzip).await?;
+        }
+
+        assert_eq!(
+            fs::read_to_string(backup_path(&path, 1, Compression::None))?,
+            "second"
+        );
+        assert_eq!(
+            fs::read_to_string(backup_path(&path, 2, Compression::None))?,
+            "first"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_zstd() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("app.log");
+        let content = "line\n".repeat(1000);
+        fs::write(&path, &content)?;
+
+        let backup = RotationMode::Timestamp
+            .rotate(
+                &path,
+                UNIX_EPOCH,
+                None,
+                Compression::Zstd,
+                &RotationNaming::Numbered,
+            )
+            .await?
+            .context("no backup")?;
+        compress_backup(backup, Compression::Zstd).await?;
+
+        let backup =
+            BackupFormat::timestamp().timestamp_path(&path, UNIX_EPOCH, Compression::Zstd)?;
+        assert_eq!(latest_backup(&path).await?, Some(backup.clone()));
+        assert!(fs::metadata(&backup)?.len() < content.len() as u64);
+        assert_eq!(zstd::decode_all(File::open(backup)?)?, content.as_bytes());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_naming() -> Result<()> {
+        let now = UNIX_EPOCH + Duration::new(1_700_000_000, 123);
+        for (naming, expected) in [
+            (RotationNaming::Numbered, vec!["app.log.1", "app.log.2"]),
+            (
+                RotationNaming::Timestamp,
+                vec![
+                    "app.log.20231114T221320.000000123Z",
+                    "app.log.20231114T221321.000000123Z",
+                ],
+            ),
+            (
+                RotationNaming::Format(BackupFormat::new("-{index}.bak")?),
+                vec!["app.log-1.bak", "app.log-2.bak"],
+            ),
+            (
+                RotationNaming::Format(BackupFormat::new("-{timestamp}.old")?),
+                vec![
+                    "app.log-20231114T221320.000000123Z.old",
+                    "app.log-20231114T221321.000000123Z.old",
+                ],
+            ),
+        ] {
+            let dir = tempdir()?;
+            let path = dir.path().join("app.log");
+            for i in 0..2 {
+                fs::write(&path, i.to_string())?;
+                RotationMode::Rename
+                    .rotate(
+                        &path,
+                        now + Duration::from_secs(i),
+                        Some(5),
+                        Compression::None,
+                        &naming,
+                    )
+                    .await?;
+            }
+
+            let mut names = fs::read_dir(dir.path())?
+                .map(|x| Ok(x?.file_name().to_string_lossy().into_owned()))
+                .collect::<Result<Vec<_>>>()?;
+            names.sort();
+            assert_eq!(names, expected, "{naming:?}");
+            assert_eq!(
+                backups(&path, &naming).await?.len(),
+                expected.len(),
+                "{naming:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn backup_format_validation() -> Result<()> {
+        for format in ["-{index}", "{timestamp}", ".{index}.bak"] {
+            assert!(BackupFormat::new(format).is_ok(), "{format}");
+        }
+        for format in [
+            "",
+            ".bak",
+            "/{index}",
+            "-{index}-{timestamp}",
+            "-{index}-{index}",
+            "-{name}-{index}",
+            "-{idx}",
+        ] {
+            assert!(BackupFormat::new(format).is_err(), "{format}");
+        }
+        let naming = RotationNaming::Format(BackupFormat::new("-{index}")?);
+        assert!(RotationMode::Rename
+            .validate(None, Compression::None, &naming)
+            .is_ok());
+        assert!(RotationMode::Timestamp
+            .validate(None, Compression::None, &naming)
+            .is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn timestamp_path_success() -> Result<()> {
+        let time = UNIX_EPOCH + Duration::new(1_700_000_000, 123);
+        assert_eq!(
+            BackupFormat::timestamp().timestamp_path("/log/app.log", time, Compression::Gzip)?,
+            PathBuf::from("/log/app.log.20231114T221320.000000123Z.gz")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_timestamp_max_files() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("app.log");
+        fs::write(dir.path().join("app.log.1"), "numbered")?;
+
+        for i in 0..4 {
+            fs::write(&path, i.to_string())?;
+            let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000 + i);
+            RotationMode::Timestamp
+                .rotate(
+                    &path,
+                    now,
+                    Some(2),
+                    Compression::None,
+                    &RotationNaming::Numbered,
+                )
+                .await?;
+        }
+
+        assert!(!path.exists());
+        assert_eq!(fs::read_dir(dir.path())?.count(), 3);
+        for i in [2, 3] {
+            let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000 + i);
+            assert_eq!(
+                fs::read_to_string(BackupFormat::timestamp().timestamp_path(
+                    &path,
+                    now,
+                    Compression::None
+                )?)?,
+                i.to_string()
+            );
+        }
+        Ok(())
+    }
+}
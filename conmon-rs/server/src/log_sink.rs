@@ -0,0 +1,147 @@
+//! Destinations of the file based log drivers.
+
+use std::{
+    fmt::{self, Debug},
+    io::{self, IoSlice, SeekFrom},
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::{
+    fs::File,
+    io::{AsyncSeek, AsyncWrite},
+};
+
+/// Destination of the CRI and JSON log drivers, which is either their log file or a custom writer
+/// like an in-memory buffer or a socket. Custom writers cannot be rotated, synced or seeked.
+pub enum LogSink {
+    /// The log file opened by the log driver.
+    File(File),
+
+    /// A custom writer provided on creating the log driver.
+    Writer(Box<dyn AsyncWrite + Unpin + Send + Sync>),
+}
+
+impl Debug for LogSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogSink::File(file) => f.debug_tuple("File").field(file).finish(),
+            LogSink::Writer(_) => f.write_str("Writer"),
+        }
+    }
+}
+
+impl From<File> for LogSink {
+    fn from(file: File) -> Self {
+        LogSink::File(file)
+    }
+}
+
+impl LogSink {
+    /// Create a new sink writing into the provided writer.
+    pub fn writer<W>(writer: W) -> Self
+    where
+        W: AsyncWrite + Unpin + Send + Sync + 'static,
+    {
+        LogSink::Writer(Box::new(writer))
+    }
+
+    /// Retrieve the log file, which is `None` for a custom writer.
+    pub fn file(&self) -> Option<&File> {
+        match self {
+            LogSink::File(file) => Some(file),
+            LogSink::Writer(_) => None,
+        }
+    }
+
+    /// Indicates if the sink is a custom writer instead of a log file.
+    pub fn is_writer(&self) -> bool {
+        matches!(self, LogSink::Writer(_))
+    }
+
+    /// Sync the data and metadata of the log file to disk, which does nothing for a custom writer.
+    pub async fn sync_all(&self) -> io::Result<()> {
+        match self {
+            LogSink::File(file) => file.sync_all().await,
+            LogSink::Writer(_) => Ok(()),
+        }
+    }
+
+    /// Sync the data of the log file to disk, which does nothing for a custom writer.
+    pub async fn sync_data(&self) -> io::Result<()> {
+        match self {
+            LogSink::File(file) => file.sync_data().await,
+            LogSink::Writer(_) => Ok(()),
+        }
+    }
+
+    /// The error of seeking a custom writer.
+    fn unseekable() -> io::Error {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            "custom log sink is not seekable",
+        )
+    }
+}
+
+impl AsyncWrite for LogSink {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match self.get_mut() {
+            LogSink::File(file) => Pin::new(file).poll_write(cx, buf),
+            LogSink::Writer(writer) => Pin::new(writer).poll_write(cx, buf),
+        }
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        match self.get_mut() {
+            LogSink::File(file) => Pin::new(file).poll_write_vectored(cx, bufs),
+            LogSink::Writer(writer) => Pin::new(writer).poll_write_vectored(cx, bufs),
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        match self {
+            LogSink::File(file) => file.is_write_vectored(),
+            LogSink::Writer(writer) => writer.is_write_vectored(),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            LogSink::File(file) => Pin::new(file).poll_flush(cx),
+            LogSink::Writer(writer) => Pin::new(writer).poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.get_mut() {
+            LogSink::File(file) => Pin::new(file).poll_shutdown(cx),
+            LogSink::Writer(writer) => Pin::new(writer).poll_shutdown(cx),
+        }
+    }
+}
+
+/// Seeking is required for detecting the written part of a record on a full disk, which custom
+/// writers do not support.
+impl AsyncSeek for LogSink {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+        match self.get_mut() {
+            LogSink::File(file) => Pin::new(file).start_seek(position),
+            LogSink::Writer(_) => Err(Self::unseekable()),
+        }
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        match self.get_mut() {
+            LogSink::File(file) => Pin::new(file).poll_complete(cx),
+            LogSink::Writer(_) => Poll::Ready(Err(Self::unseekable())),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Transformations of the log messages before they get written, like redacting secrets.
+
+use anyhow::{Context, Result};
+use regex::bytes::{NoExpand, Regex};
+use std::{borrow::Cow, fmt::Debug, sync::Arc};
+
+/// A transformation applied to every message of a log driver.
+pub trait LogTransform: Debug + Send + Sync {
+    /// Transform a single message without its line ending.
+    fn transform<'a>(&self, message: Cow<'a, [u8]>) -> Cow<'a, [u8]>;
+}
+
+/// Apply all transformations in their order to the message, which stays borrowed if none of them
+/// changed it.
+pub fn apply<'a>(transforms: &[Arc<dyn LogTransform>], message: &'a [u8]) -> Cow<'a, [u8]> {
+    transforms
+        .iter()
+        .fold(Cow::Borrowed(message), |message, x| x.transform(message))
+}
+
+/// Apply all transformations to a line, where the trailing newline is kept as is.
+pub fn apply_line<'a>(transforms: &[Arc<dyn LogTransform>], line: &'a [u8]) -> Cow<'a, [u8]> {
+    if transforms.is_empty() {
+        return Cow::Borrowed(line);
+    }
+    let Some(message) = line.strip_suffix(b"\n") else {
+        return apply(transforms, line);
+    };
+    match apply(transforms, message) {
+        Cow::Borrowed(_) => Cow::Borrowed(line),
+        Cow::Owned(mut message) => {
+            message.push(b'\n');
+            Cow::Owned(message)
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Replaces all matches of a regular expression, for example to mask API keys.
+pub struct Redactor {
+    /// The pattern of the redacted content.
+    regex: Regex,
+
+    /// The literal replacement of every match.
+    replacement: Vec<u8>,
+}
+
+impl Redactor {
+    /// The replacement used if none has been provided.
+    pub const DEFAULT_REPLACEMENT: &'static str = "***";
+
+    /// Create a new redactor replacing the matches of `pattern` with the literal `replacement`.
+    pub fn new(pattern: &str, replacement: &str) -> Result<Self> {
+        Ok(Self {
+            regex: Regex::new(pattern).context("compile redact pattern")?,
+            replacement: replacement.into(),
+        })
+    }
+}
+
+impl LogTransform for Redactor {
+    fn transform<'a>(&self, message: Cow<'a, [u8]>) -> Cow<'a, [u8]> {
+        if !self.regex.is_match(&message) {
+            return message;
+        }
+        Cow::Owned(
+            self.regex
+                .replace_all(&message, NoExpand(&self.replacement))
+                .into_owned(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redact_api_key() -> Result<()> {
+        let transforms: Vec<Arc<dyn LogTransform>> = vec![Arc::new(Redactor::new(
+            r"sk-[A-Za-z0-9]{8,}",
+            Redactor::DEFAULT_REPLACEMENT,
+        )?)];
+
+        let line = b"using key sk-abcdEFGH1234 and sk-zyxwVUTS9876\n";
+        assert_eq!(
+            apply_line(&transforms, line).as_ref(),
+            b"using key *** and ***\n"
+        );
+        assert!(matches!(
+            apply_line(&transforms, b"no secrets\n"),
+            Cow::Borrowed(_)
+        ));
+        assert!(Redactor::new("(", "").is_err());
+        Ok(())
+    }
+}
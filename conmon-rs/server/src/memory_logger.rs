@@ -0,0 +1,187 @@
+//! In memory logging functionalities.
+
+use crate::{
+    clock::{Clock, SystemClock},
+    container_io::Pipe,
+    container_log::{LogStats, Logger},
+    line_buffer::LineBuffer,
+    log_metrics::PipeStats,
+    log_reader::LogLine,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use getset::{CopyGetters, Setters};
+use std::{collections::VecDeque, sync::Arc};
+use tokio::io::AsyncBufRead;
+
+#[derive(Debug, CopyGetters, Setters)]
+/// Logger keeping the most recent lines in memory instead of writing them to disk, for example
+/// for short lived debug containers. The oldest lines get dropped once the capacity is reached.
+pub struct MemoryLogger {
+    #[getset(get_copy = "pub")]
+    /// Maximum total bytes of the kept messages.
+    capacity: usize,
+
+    /// The kept lines, from oldest to newest.
+    lines: VecDeque<LogLine>,
+
+    #[getset(get_copy = "pub")]
+    /// Total bytes of the kept messages.
+    size: usize,
+
+    #[getset(get_copy = "pub")]
+    /// Amount of lines dropped to stay within the capacity.
+    dropped_lines: usize,
+
+    /// Trailing lines without newline, which are kept once complete.
+    partial: LineBuffer,
+
+    #[getset(set = "pub")]
+    /// Source of the line timestamps, which defaults to the system wall clock.
+    clock: Arc<dyn Clock>,
+}
+
+impl MemoryLogger {
+    /// The capacity used if none has been provided.
+    pub const DEFAULT_CAPACITY: usize = 1024 * 1024;
+
+    /// Create a new memory logger instance keeping up to `capacity` bytes of messages.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            lines: VecDeque::new(),
+            size: 0,
+            dropped_lines: 0,
+            partial: LineBuffer::new(capacity),
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Write the contents of the provided reader and return the amount of consumed bytes.
+    pub async fn write<T>(&mut self, pipe: Pipe, bytes: T) -> Result<usize>
+    where
+        T: AsyncBufRead + Unpin,
+    {
+        let (read, lines) = self.partial.read(pipe, bytes).await?;
+        for line in lines {
+            self.push(pipe, line)?;
+        }
+        Ok(read)
+    }
+
+    /// Keep a single line, where the oldest lines get dropped until it fits. A line exceeding
+    /// the capacity on its own gets truncated.
+    fn push(&mut self, pipe: Pipe, mut message: Vec<u8>) -> Result<()> {
+        if message.last() == Some(&b'\n') {
+            message.pop();
+        }
+        message.truncate(self.capacity);
+        while self.size + message.len() > self.capacity {
+            let Some(line) = self.lines.pop_front() else {
+                break;
+            };
+            self.size -= line.message.len();
+            self.dropped_lines += 1;
+        }
+        self.size += message.len();
+        self.lines.push_back(LogLine {
+            timestamp: self.clock.timestamp()?,
+            pipe: pipe.as_str().into(),
+            message,
+        });
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Logger for MemoryLogger {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    fn stats(&self) -> LogStats {
+        LogStats {
+            name: self.name(),
+            bytes_written: self.size(),
+            max_log_size: Some(self.capacity()),
+            rotations: 0,
+            dropped_bytes: 0,
+            lines: PipeStats::default(),
+            last_write: None,
+        }
+    }
+
+    async fn init(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn write(
+        &mut self,
+        pipe: Pipe,
+        bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> Result<usize> {
+        MemoryLogger::write(self, pipe, bytes).await
+    }
+
+    async fn write_line(&mut self, pipe: Pipe, line: &[u8]) -> Result<()> {
+        self.push(pipe, line.into())
+    }
+
+    async fn flush_partial(&mut self, pipe: Pipe) -> Result<()> {
+        if let Some(line) = self.partial.take(pipe) {
+            self.push(pipe, line)?;
+        }
+        Ok(())
+    }
+
+    /// The kept lines are not bound to a file, which means that there is nothing to reopen.
+    async fn reopen(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn lines(&self) -> Option<Vec<LogLine>> {
+        Some(self.lines.iter().cloned().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn write_past_capacity() -> Result<()> {
+        let mut sut = MemoryLogger::new(20);
+        for i in 0..10 {
+            sut.write(Pipe::StdOut, format!("line {i}\n").as_bytes())
+                .await?;
+        }
+        sut.write(Pipe::StdErr, "error".as_bytes()).await?;
+        sut.flush_partial(Pipe::StdErr).await?;
+
+        let lines = sut.lines().await.unwrap_or_default();
+        let messages = lines
+            .iter()
+            .map(|x| (x.pipe.as_str(), String::from_utf8_lossy(&x.message)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            [
+                ("stdout", "line 8".into()),
+                ("stdout", "line 9".into()),
+                ("stderr", "error".into())
+            ]
+        );
+        assert_eq!(sut.size(), 17);
+        assert_eq!(sut.dropped_lines(), 8);
+
+        sut.write_line(Pipe::StdOut, &[b'x'; 50]).await?;
+        let lines = sut.lines().await.unwrap_or_default();
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].message.len(), 20);
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+//! Null logging functionalities.
+
+use crate::{
+    container_io::Pipe,
+    container_log::{LogStats, Logger},
+    log_metrics::PipeStats,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use getset::CopyGetters;
+use tokio::io::{self, AsyncBufRead};
+
+#[derive(Debug, Default, CopyGetters)]
+/// Logger discarding all container output, for example to opt out of logging or as baseline
+/// when benchmarking the logging pipeline.
+pub struct NullLogger {
+    #[getset(get_copy = "pub")]
+    /// Total bytes discarded by the logger.
+    bytes_discarded: usize,
+}
+
+impl NullLogger {
+    /// Create a new null logger instance.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consume the contents of the provided reader and return the amount of discarded bytes.
+    pub async fn write<T>(&mut self, _pipe: Pipe, mut bytes: T) -> Result<usize>
+    where
+        T: AsyncBufRead + Unpin,
+    {
+        let discarded = io::copy(&mut bytes, &mut io::sink())
+            .await
+            .context("discard log data")?
+            .try_into()?;
+        self.bytes_discarded += discarded;
+        Ok(discarded)
+    }
+}
+
+#[async_trait]
+impl Logger for NullLogger {
+    fn name(&self) -> &'static str {
+        "null"
+    }
+
+    fn stats(&self) -> LogStats {
+        LogStats {
+            name: self.name(),
+            bytes_written: self.bytes_discarded(),
+            max_log_size: None,
+            rotations: 0,
+            dropped_bytes: 0,
+            lines: PipeStats::default(),
+            last_write: None,
+        }
+    }
+
+    async fn init(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn write(
+        &mut self,
+        pipe: Pipe,
+        bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> Result<usize> {
+        NullLogger::write(self, pipe, bytes).await
+    }
+
+    async fn write_line(&mut self, _pipe: Pipe, line: &[u8]) -> Result<()> {
+        self.bytes_discarded += line.len();
+        Ok(())
+    }
+
+    async fn reopen(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn write_success() -> Result<()> {
+        let mut sut = NullLogger::new();
+        sut.init().await?;
+        assert_eq!(sut.write(Pipe::StdOut, "hello\n".as_bytes()).await?, 6);
+        assert_eq!(sut.write(Pipe::StdErr, "world".as_bytes()).await?, 5);
+        sut.reopen().await?;
+        sut.flush().await?;
+
+        assert_eq!(sut.bytes_discarded(), 11);
+        assert_eq!(sut.stats().bytes_written, 11);
+        Ok(())
+    }
+}
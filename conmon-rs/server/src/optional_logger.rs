@@ -0,0 +1,188 @@
+//! Best effort logging functionalities.
+
+use crate::{
+    container_io::Pipe,
+    container_log::{LogEvent, LogStats, Logger},
+    log_reader::{LogFormat, LogLine},
+};
+use anyhow::{Error, Result};
+use async_trait::async_trait;
+use std::{path::PathBuf, time::Duration};
+use tokio::io::AsyncBufRead;
+use tracing::{debug, warn};
+
+#[derive(Debug)]
+/// Logger making the wrapped log driver best effort, for example a remote one next to the
+/// mandatory CRI log file. A failure of the log driver gets reported as warning and disables it
+/// instead of failing the container logging, until a reopen initializes it again.
+pub struct OptionalLogger {
+    /// The wrapped log driver.
+    inner: Box<dyn Logger>,
+
+    /// Indicates that the log driver failed and gets skipped.
+    disabled: bool,
+}
+
+impl OptionalLogger {
+    /// Create a new optional logger instance for the provided log driver.
+    pub fn new(inner: Box<dyn Logger>) -> Self {
+        Self {
+            inner,
+            disabled: false,
+        }
+    }
+
+    /// Disable the log driver because of the provided failure.
+    fn disable(&mut self, action: &str, e: Error) {
+        warn!(
+            "Disabling optional {} log driver after failed {}: {:#}",
+            self.inner.name(),
+            action,
+            e
+        );
+        self.disabled = true;
+    }
+
+    /// Disable the log driver if the result is a failure, which is ignored.
+    fn check<T: Default>(&mut self, action: &str, res: Result<T>) -> T {
+        res.unwrap_or_else(|e| {
+            self.disable(action, e);
+            T::default()
+        })
+    }
+}
+
+#[async_trait]
+impl Logger for OptionalLogger {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn source(&self) -> Option<(PathBuf, LogFormat)> {
+        self.inner.source()
+    }
+
+    fn accepts(&self, pipe: Pipe) -> bool {
+        self.inner.accepts(pipe)
+    }
+
+    /// An invalid configuration is only reported, because it disables the driver on init anyway.
+    async fn validate(&self) -> Result<()> {
+        if let Err(e) = self.inner.validate().await {
+            warn!("Invalid optional {} log driver: {:#}", self.inner.name(), e);
+        }
+        Ok(())
+    }
+
+    async fn lines(&self) -> Option<Vec<LogLine>> {
+        self.inner.lines().await
+    }
+
+    /// A disabled log driver does not get initialized.
+    fn is_initialized(&self) -> bool {
+        self.disabled || self.inner.is_initialized()
+    }
+
+    fn stats(&self) -> LogStats {
+        self.inner.stats()
+    }
+
+    async fn init(&mut self) -> Result<()> {
+        self.disabled = false;
+        let res = self.inner.init().await;
+        self.check("init", res);
+        Ok(())
+    }
+
+    /// Consume the provided data without writing it if the log driver is disabled.
+    async fn write(
+        &mut self,
+        pipe: Pipe,
+        bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> Result<usize> {
+        if self.disabled {
+            return Ok(0);
+        }
+        let res = self.inner.write(pipe, bytes).await;
+        Ok(self.check("write", res))
+    }
+
+    async fn write_line(&mut self, pipe: Pipe, line: &[u8]) -> Result<()> {
+        if self.disabled {
+            return Ok(());
+        }
+        let res = self.inner.write_line(pipe, line).await;
+        self.check("write", res);
+        Ok(())
+    }
+
+    async fn write_marker(&mut self, dropped_bytes: usize) -> Result<()> {
+        if self.disabled {
+            return Ok(());
+        }
+        let res = self.inner.write_marker(dropped_bytes).await;
+        self.check("write", res);
+        Ok(())
+    }
+
+    async fn write_event(&mut self, event: LogEvent) -> Result<()> {
+        if self.disabled {
+            return Ok(());
+        }
+        let res = self.inner.write_event(event).await;
+        self.check("write", res);
+        Ok(())
+    }
+
+    async fn flush_partial(&mut self, pipe: Pipe) -> Result<()> {
+        if self.disabled {
+            return Ok(());
+        }
+        let res = self.inner.flush_partial(pipe).await;
+        self.check("write", res);
+        Ok(())
+    }
+
+    fn expiry_interval(&self) -> Option<Duration> {
+        self.inner.expiry_interval()
+    }
+
+    async fn flush_expired(&mut self) -> Result<()> {
+        if self.disabled {
+            return Ok(());
+        }
+        let res = self.inner.flush_expired().await;
+        self.check("write", res);
+        Ok(())
+    }
+
+    /// Initialize a disabled log driver again, which enables it if it succeeds.
+    async fn reopen(&mut self) -> Result<()> {
+        if self.disabled {
+            debug!(
+                "Initializing disabled optional {} log driver again",
+                self.inner.name()
+            );
+            return self.init().await;
+        }
+        let res = self.inner.reopen().await;
+        self.check("reopen", res);
+        Ok(())
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        if self.disabled {
+            return Ok(());
+        }
+        let res = self.inner.flush().await;
+        self.check("flush", res);
+        Ok(())
+    }
+
+    /// Close a disabled log driver as well, which releases the resources it still holds.
+    async fn close(&mut self) -> Result<()> {
+        let res = self.inner.close().await;
+        self.check("close", res);
+        Ok(())
+    }
+}
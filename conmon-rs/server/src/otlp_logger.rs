@@ -0,0 +1,458 @@
+//! OpenTelemetry log export functionalities.
+
+use crate::{
+    container_io::{Pipe, Severity},
+    container_log::{LogStats, Logger},
+    line_buffer::LineBuffer,
+    log_metrics::PipeStats,
+};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use getset::{CopyGetters, Getters, Setters};
+use opentelemetry_proto::tonic::{
+    collector::logs::v1::{logs_service_client::LogsServiceClient, ExportLogsServiceRequest},
+    common::v1::{any_value::Value, AnyValue, InstrumentationScope, KeyValue},
+    logs::v1::{LogRecord, ResourceLogs, ScopeLogs, SeverityNumber},
+    resource::v1::Resource,
+};
+use opentelemetry_semantic_conventions::resource::CONTAINER_ID;
+use std::{
+    collections::{BTreeMap, VecDeque},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use tokio::io::AsyncBufRead;
+use tonic::transport::{Channel, Endpoint};
+use tracing::{debug, warn};
+
+#[derive(Debug, CopyGetters, Getters, Setters)]
+/// Logger exporting the container output as OpenTelemetry log records to an OTLP/gRPC collector.
+/// Lines get buffered and exported in batches, where the buffer keeps them while the collector is
+/// unavailable and retries the export with an exponential backoff.
+pub struct OtlpLogger {
+    #[getset(get)]
+    /// Endpoint of the collector, like `http://localhost:4317`.
+    endpoint: String,
+
+    /// Resource attributes describing the container, attached to every exported batch.
+    resource: Resource,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Amount of lines exported at once. Must not be zero.
+    batch_size: usize,
+
+    #[getset(get_copy = "pub", set = "pub")]
+    /// Maximum amount of buffered lines, beyond which the oldest ones get dropped.
+    max_buffered: usize,
+
+    /// Lines not exported yet, from oldest to newest.
+    buffer: VecDeque<LogRecord>,
+
+    /// Lines of every pipe assembled across writes.
+    lines: LineBuffer,
+
+    /// Client of the collector, available after initialization.
+    client: Option<LogsServiceClient<Channel>>,
+
+    /// The time of the last export attempt.
+    last_export: Instant,
+
+    /// Delay before retrying a failed export, zero if the last export succeeded.
+    backoff: Duration,
+
+    #[getset(get_copy = "pub")]
+    /// Bytes of the lines dropped because the buffer was full.
+    dropped_bytes: usize,
+}
+
+impl OtlpLogger {
+    const ERR_UNINITIALIZED: &'static str = "logger not initialized";
+
+    /// The default collector endpoint.
+    const DEFAULT_ENDPOINT: &'static str = "http://localhost:4317";
+
+    /// The default amount of lines exported at once.
+    pub const DEFAULT_BATCH_SIZE: usize = 512;
+
+    /// The default maximum amount of buffered lines.
+    pub const DEFAULT_MAX_BUFFERED: usize = 8192;
+
+    /// The interval after which buffered lines get exported, even if they do not fill a batch.
+    const BATCH_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// The delay before the first retry of a failed export.
+    const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+    /// The maximum delay between retries of a failed export.
+    const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+    /// The timeout of a single export request.
+    const EXPORT_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Create a new OTLP logger instance. An empty endpoint uses the default
+    /// `http://localhost:4317`. The `metadata` gets attached as resource attributes with their
+    /// keys unchanged, which means that callers should use semantic convention keys like
+    /// `k8s.pod.name`.
+    pub fn new(
+        endpoint: &str,
+        container_id: &str,
+        metadata: &BTreeMap<String, String>,
+    ) -> Result<OtlpLogger> {
+        let endpoint = if endpoint.is_empty() {
+            Self::DEFAULT_ENDPOINT
+        } else {
+            endpoint
+        };
+        Endpoint::from_shared(endpoint.to_owned())
+            .context(format!("parse OTLP endpoint '{}'", endpoint))?;
+
+        let attributes = [(CONTAINER_ID, container_id)]
+            .into_iter()
+            .chain(
+                metadata
+                    .iter()
+                    .filter(|(key, _)| key.as_str() != "id")
+                    .map(|(key, value)| (key.as_str(), value.as_str())),
+            )
+            .map(|(key, value)| Self::key_value(key, value))
+            .collect();
+
+        Ok(Self {
+            endpoint: endpoint.into(),
+            resource: Resource {
+                attributes,
+                dropped_attributes_count: 0,
+            },
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+            max_buffered: Self::DEFAULT_MAX_BUFFERED,
+            buffer: VecDeque::new(),
+            lines: LineBuffer::default(),
+            client: None,
+            last_export: Instant::now(),
+            backoff: Duration::ZERO,
+            dropped_bytes: 0,
+        })
+    }
+
+    /// Write the contents of the provided reader as one log record per line, where a trailing
+    /// line without newline is retained until more data for the same pipe arrives or
+    /// `flush_partial` is called.
+    pub async fn write<T>(&mut self, pipe: Pipe, bytes: T) -> Result<usize>
+    where
+        T: AsyncBufRead + Unpin,
+    {
+        let (read, lines) = self.lines.read(pipe, bytes).await?;
+        for line in lines {
+            self.push(pipe, &line)?;
+        }
+        self.export_due().await?;
+        Ok(read)
+    }
+
+    /// Export the buffered records if they fill a batch or the batch interval elapsed.
+    async fn export_due(&mut self) -> Result<()> {
+        if self.buffer.len() >= self.batch_size()
+            || self.last_export.elapsed() >= Self::BATCH_INTERVAL
+        {
+            self.export(false).await?;
+        }
+        Ok(())
+    }
+
+    /// Buffer a single line as log record, where the oldest record gets dropped if the buffer is
+    /// full.
+    fn push(&mut self, pipe: Pipe, line: &[u8]) -> Result<()> {
+        let (severity_number, severity_text) = match pipe.severity() {
+            Severity::Info => (SeverityNumber::Info, "INFO"),
+            Severity::Error => (SeverityNumber::Error, "ERROR"),
+        };
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .context("get duration since UNIX epoch")?
+            .as_nanos()
+            .try_into()?;
+        let message = String::from_utf8_lossy(line);
+
+        if self.buffer.len() >= self.max_buffered().max(1) {
+            if let Some(oldest) = self.buffer.pop_front() {
+                self.dropped_bytes += Self::body_len(&oldest);
+            }
+        }
+        self.buffer.push_back(LogRecord {
+            time_unix_nano: now,
+            observed_time_unix_nano: now,
+            severity_number: severity_number.into(),
+            severity_text: severity_text.into(),
+            body: Some(Self::string_value(message.trim_end_matches('\n'))),
+            attributes: vec![Self::key_value("log.iostream", pipe.as_str())],
+            ..Default::default()
+        });
+        Ok(())
+    }
+
+    /// Export the buffered records in batches. A failing export keeps the records and delays
+    /// further attempts by the backoff, unless `force` is set.
+    async fn export(&mut self, force: bool) -> Result<()> {
+        if !force && self.last_export.elapsed() < self.backoff {
+            return Ok(());
+        }
+        self.last_export = Instant::now();
+
+        while !self.buffer.is_empty() {
+            let len = self.buffer.len().min(self.batch_size().max(1));
+            // The records stay buffered until the collector accepted them.
+            let log_records = self.buffer.iter().take(len).cloned().collect();
+            let mut request = tonic::Request::new(ExportLogsServiceRequest {
+                resource_logs: vec![ResourceLogs {
+                    resource: Some(self.resource.clone()),
+                    scope_logs: vec![ScopeLogs {
+                        scope: Some(InstrumentationScope {
+                            name: env!("CARGO_PKG_NAME").into(),
+                            version: env!("CARGO_PKG_VERSION").into(),
+                            ..Default::default()
+                        }),
+                        log_records,
+                        schema_url: String::new(),
+                    }],
+                    schema_url: String::new(),
+                }],
+            });
+            request.set_timeout(Self::EXPORT_TIMEOUT);
+
+            let client = self.client.as_mut().context(Self::ERR_UNINITIALIZED)?;
+            match client.export(request).await {
+                Ok(_) => {
+                    debug!("Exported {} log records to {}", len, self.endpoint);
+                    self.buffer.drain(..len);
+                    self.backoff = Duration::ZERO;
+                }
+                Err(status) => {
+                    self.backoff =
+                        (self.backoff * 2).clamp(Self::INITIAL_BACKOFF, Self::MAX_BACKOFF);
+                    warn!(
+                        "Unable to export {} log records to {}, retrying in {:?}: {}",
+                        len,
+                        self.endpoint,
+                        self.backoff,
+                        status.message()
+                    );
+                    return Ok(());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn key_value(key: &str, value: &str) -> KeyValue {
+        KeyValue {
+            key: key.into(),
+            value: Some(Self::string_value(value)),
+        }
+    }
+
+    fn string_value(value: &str) -> AnyValue {
+        AnyValue {
+            value: Some(Value::StringValue(value.into())),
+        }
+    }
+
+    /// Retrieve the length of the message of a record.
+    fn body_len(record: &LogRecord) -> usize {
+        match record.body.as_ref().and_then(|x| x.value.as_ref()) {
+            Some(Value::StringValue(message)) => message.len(),
+            _ => 0,
+        }
+    }
+}
+
+#[async_trait]
+impl Logger for OtlpLogger {
+    fn name(&self) -> &'static str {
+        "otlp"
+    }
+
+    /// Create the client of the collector, which connects lazily on the first export.
+    async fn init(&mut self) -> Result<()> {
+        debug!("Initializing OTLP logger for endpoint {}", self.endpoint());
+        let channel = Endpoint::from_shared(self.endpoint().clone())
+            .context("parse OTLP endpoint")?
+            .connect_timeout(Self::EXPORT_TIMEOUT)
+            .connect_lazy();
+        self.client = Some(LogsServiceClient::new(channel));
+        Ok(())
+    }
+
+    async fn write(
+        &mut self,
+        pipe: Pipe,
+        bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> Result<usize> {
+        OtlpLogger::write(self, pipe, bytes).await
+    }
+
+    async fn write_line(&mut self, pipe: Pipe, line: &[u8]) -> Result<()> {
+        self.push(pipe, line)?;
+        self.export_due().await
+    }
+
+    async fn flush_partial(&mut self, pipe: Pipe) -> Result<()> {
+        if let Some(line) = self.lines.take(pipe) {
+            self.push(pipe, &line)?;
+        }
+        Ok(())
+    }
+
+    /// Export the buffered records before recreating the client.
+    async fn reopen(&mut self) -> Result<()> {
+        debug!("Reopen OTLP logger for endpoint {}", self.endpoint());
+        self.client.as_ref().context(Self::ERR_UNINITIALIZED)?;
+        self.export(false).await?;
+        self.init().await
+    }
+
+    /// Export the buffered records, unless a previous export failed within the backoff.
+    async fn flush(&mut self) -> Result<()> {
+        self.export(false).await
+    }
+
+    /// Try a final export of the buffered records, regardless of the backoff.
+    async fn close(&mut self) -> Result<()> {
+        if self.client.is_none() {
+            return Ok(());
+        }
+        self.export(true).await?;
+        if !self.buffer.is_empty() {
+            warn!(
+                "Discarding {} log records not exported to {}",
+                self.buffer.len(),
+                self.endpoint()
+            );
+            self.buffer.clear();
+        }
+        self.client = None;
+        Ok(())
+    }
+
+    fn stats(&self) -> LogStats {
+        LogStats {
+            name: self.name(),
+            bytes_written: 0,
+            max_log_size: None,
+            rotations: 0,
+            dropped_bytes: self.dropped_bytes(),
+            lines: PipeStats::default(),
+            last_write: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry_proto::tonic::collector::logs::v1::{
+        logs_service_server::{LogsService, LogsServiceServer},
+        ExportLogsServiceResponse,
+    };
+    use tokio::{net::TcpListener, sync::mpsc};
+    use tonic::transport::{server::TcpIncoming, Server};
+
+    #[derive(Debug)]
+    struct MockCollector(mpsc::UnboundedSender<ExportLogsServiceRequest>);
+
+    #[tonic::async_trait]
+    impl LogsService for MockCollector {
+        async fn export(
+            &self,
+            request: tonic::Request<ExportLogsServiceRequest>,
+        ) -> Result<tonic::Response<ExportLogsServiceResponse>, tonic::Status> {
+            self.0
+                .send(request.into_inner())
+                .map_err(|e| tonic::Status::internal(e.to_string()))?;
+            Ok(tonic::Response::new(ExportLogsServiceResponse::default()))
+        }
+    }
+
+    fn attribute<'a>(attributes: &'a [KeyValue], key: &str) -> Option<&'a Value> {
+        attributes
+            .iter()
+            .find(|x| x.key == key)
+            .and_then(|x| x.value.as_ref()?.value.as_ref())
+    }
+
+    #[tokio::test]
+    async fn export_success() -> Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let endpoint = format!("http://{}", listener.local_addr()?);
+        let incoming = TcpIncoming::from_listener(listener, true, None)
+            .map_err(|e| anyhow::format_err!("{e}"))?;
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        tokio::spawn(
+            Server::builder()
+                .add_service(LogsServiceServer::new(MockCollector(tx)))
+                .serve_with_incoming(incoming),
+        );
+
+        let metadata = BTreeMap::from([("k8s.pod.name".to_owned(), "pod".to_owned())]);
+        let mut sut = OtlpLogger::new(&endpoint, "container", &metadata)?;
+        sut.set_batch_size(3);
+        sut.init().await?;
+        sut.write(Pipe::StdOut, "a\nb\n".as_bytes()).await?;
+        assert!(rx.try_recv().is_err());
+        sut.write(Pipe::StdErr, "c\n".as_bytes()).await?;
+
+        let request = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await?
+            .context("no export request")?;
+        assert_eq!(request.resource_logs.len(), 1);
+        let resource_logs = &request.resource_logs[0];
+        let resource = resource_logs.resource.as_ref().context("no resource")?;
+        assert_eq!(
+            attribute(&resource.attributes, CONTAINER_ID),
+            Some(&Value::StringValue("container".into()))
+        );
+        assert_eq!(
+            attribute(&resource.attributes, "k8s.pod.name"),
+            Some(&Value::StringValue("pod".into()))
+        );
+
+        let records = &resource_logs.scope_logs[0].log_records;
+        assert_eq!(records.len(), 3);
+        assert_eq!(
+            records[0].body.as_ref().and_then(|x| x.value.as_ref()),
+            Some(&Value::StringValue("a".into()))
+        );
+        assert_eq!(records[0].severity_number, i32::from(SeverityNumber::Info));
+        assert_eq!(records[2].severity_number, i32::from(SeverityNumber::Error));
+        assert_eq!(
+            attribute(&records[2].attributes, "log.iostream"),
+            Some(&Value::StringValue("stderr".into()))
+        );
+
+        sut.close().await?;
+        assert!(rx.try_recv().is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn export_unavailable() -> Result<()> {
+        // Nothing listens on the port of the dropped listener.
+        let endpoint = format!(
+            "http://{}",
+            TcpListener::bind("127.0.0.1:0").await?.local_addr()?
+        );
+        let mut sut = OtlpLogger::new(&endpoint, "container", &BTreeMap::new())?;
+        sut.set_batch_size(1).set_max_buffered(2);
+        sut.init().await?;
+
+        sut.write(Pipe::StdOut, "a\nb\nc\n".as_bytes()).await?;
+        assert_eq!(sut.buffer.len(), 2);
+        assert_eq!(sut.dropped_bytes(), 1);
+        assert_eq!(sut.backoff, OtlpLogger::INITIAL_BACKOFF);
+
+        // The backoff delays further attempts.
+        sut.flush().await?;
+        assert_eq!(sut.backoff, OtlpLogger::INITIAL_BACKOFF);
+        sut.close().await?;
+        assert_eq!(sut.backoff, OtlpLogger::INITIAL_BACKOFF * 2);
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
 use crate::{
     capnp_util,
     child::Child,
-    container_io::{ContainerIO, SharedContainerIO},
-    container_log::ContainerLog,
+    container_io::{ContainerIO, Pipe, SharedContainerIO},
+    container_log::{ContainerLog, ContainerLogOptions, PausePolicy},
+    log_metrics::TelemetryMetricsSink,
+    log_reader::{self, InvalidTimestamp, TimeFilter},
     pause::Pause,
     server::{GenerateRuntimeArgs, Server},
     telemetry::Telemetry,
@@ -11,13 +13,19 @@ use crate::{
 use anyhow::{format_err, Context};
 use capnp::{capability::Promise, Error};
 use capnp_rpc::pry;
-use conmon_common::conmon_capnp::conmon;
+use conmon_common::conmon_capnp::conmon::{self, log_driver::Stream};
+use futures::StreamExt;
 use std::{
     path::{Path, PathBuf},
+    pin::pin,
     process, str,
-    time::Duration,
+    sync::Arc,
+    time::{Duration, UNIX_EPOCH},
+};
+use tokio::{
+    select,
+    time::{self, Instant},
 };
-use tokio::time::Instant;
 use tracing::{debug, debug_span, error, Instrument};
 use uuid::Uuid;
 
@@ -68,6 +76,9 @@ macro_rules! capnp_vec_path {
     };
 }
 
+/// The time to wait for further lines of an exited container when following its log.
+const FOLLOW_EXITED_TIMEOUT: Duration = Duration::from_secs(1);
+
 impl conmon::Server for Server {
     /// Retrieve version information from the server.
     fn version(
@@ -115,7 +126,19 @@ impl conmon::Server for Server {
         debug!("Got a create container request");
 
         let log_drivers = pry!(req.get_log_drivers());
-        let container_log = pry_err!(ContainerLog::from(log_drivers));
+        let log_metadata = pry!(req.get_log_metadata().and_then(capnp_util::into_map));
+        let container_log = pry_err!(ContainerLog::from(
+            log_drivers,
+            ContainerLogOptions {
+                container_id: id.clone(),
+                metadata: log_metadata,
+                log_stdin: req.get_log_stdin(),
+                budget: self.log_disk_budget().clone(),
+                default_max_size: Some(pry_err!(usize::try_from(req.get_log_max_size())))
+                    .filter(|x| *x > 0),
+                node_max_size: Some(self.config().max_log_size()).filter(|x| *x > 0),
+            },
+        ));
         let mut container_io =
             pry_err!(ContainerIO::new(req.get_terminal(), container_log.clone()));
 
@@ -141,11 +164,26 @@ impl conmon::Server for Server {
         let oom_exit_paths = capnp_vec_path!(req.get_oom_exit_paths());
         let env_vars = pry!(req.get_env_vars().and_then(capnp_util::into_map));
 
+        let log_metrics = Arc::new(TelemetryMetricsSink::new(&id));
+        let validate_log_drivers = req.get_validate_log_drivers();
+        let ordered_log_drivers = req.get_ordered_log_drivers();
+        let log_exit_event = req.get_log_exit_event();
+        let max_concurrent_log_writers = req.get_max_concurrent_log_writers();
         let additional_fds = pry_err!(self.fd_socket().take_all(pry!(req.get_additional_fds())));
         let leak_fds = pry_err!(self.fd_socket().take_all(pry!(req.get_leak_fds())));
 
         Promise::from_future(
             async move {
+                container_log
+                    .write()
+                    .await
+                    .set_metrics(log_metrics)
+                    .set_ordered(ordered_log_drivers)
+                    .set_exit_event(log_exit_event)
+                    .set_max_concurrent_writers(max_concurrent_log_writers as usize);
+                if validate_log_drivers {
+                    capnp_err!(container_log.read().await.validate().await)?;
+                }
                 capnp_err!(container_log.write().await.init().await)?;
 
                 let (grandchild_pid, token) = capnp_err!(match child_reaper
@@ -339,7 +377,7 @@ impl conmon::Server for Server {
         )
     }
 
-    /// Rotate all log drivers for a running container.
+    /// Rotate all log drivers for a running container, or for all of them.
     fn reopen_log_container(
         &mut self,
         params: conmon::ReopenLogContainerParams,
@@ -354,11 +392,27 @@ impl conmon::Server for Server {
 
         debug!("Got a reopen container log request");
 
-        let child = pry_err!(self.reaper().get(id));
+        let children = if req.get_all() {
+            pry_err!(self.reaper().children())
+        } else {
+            vec![(id.to_string(), pry_err!(self.reaper().get(id)))]
+        };
 
         Promise::from_future(
-            async move { capnp_err!(child.io().logger().await.write().await.reopen().await) }
-                .instrument(debug_span!("promise")),
+            async move {
+                // A failing container should not keep the others on their rotated files.
+                let mut errors = vec![];
+                for (id, child) in children {
+                    if let Err(e) = child.io().logger().await.write().await.reopen().await {
+                        errors.push(format!("reopen log of container {}: {:#}", id, e));
+                    }
+                }
+                if !errors.is_empty() {
+                    return Err(Error::failed(errors.join("; ")));
+                }
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
         )
     }
 
@@ -431,6 +485,274 @@ impl conmon::Server for Server {
         Promise::ok(())
     }
 
+    /// Read the last lines of the log of a container.
+    fn tail_log_container(
+        &mut self,
+        params: conmon::TailLogContainerParams,
+        mut results: conmon::TailLogContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_err!(pry_err!(req.get_id()).to_str());
+        let n = pry_err!(usize::try_from(req.get_lines()));
+        let filter = time_filter(
+            req.get_since(),
+            req.get_until(),
+            req.get_skip_invalid_timestamps(),
+            pry!(req.get_stream()),
+        );
+
+        let span = new_root_span!("tail_log_container", id);
+        let _enter = span.enter();
+        pry_err!(Telemetry::set_parent_context(pry!(req.get_metadata())));
+
+        debug!("Got a tail container log request for {} lines", n);
+
+        let child = pry_err!(self.reaper().get(id));
+
+        Promise::from_future(
+            async move {
+                // Reading the file does not require to block the container output.
+                let logger = child.io().logger().await;
+                let (source, last_write) = {
+                    let logger = logger.read().await;
+                    (logger.source(), logger.last_write())
+                };
+                let lines = match source {
+                    Some((path, format)) => {
+                        capnp_err!(log_reader::tail(path, format, n, filter).await)?
+                    }
+                    None => log_reader::tail_lines(
+                        capnp_err!(logger
+                            .read()
+                            .await
+                            .lines()
+                            .await
+                            .context("no log driver writes a readable file or keeps lines"))?,
+                        n,
+                        filter,
+                    ),
+                };
+
+                let mut resp = results.get().init_response();
+                if let Some(last_write) = last_write {
+                    resp.set_last_write(capnp_err!(u64::try_from(
+                        capnp_err!(last_write.duration_since(UNIX_EPOCH))?.as_nanos()
+                    ))?);
+                }
+                let mut list = resp.init_lines(capnp_err!(lines.len().try_into())?);
+                for (idx, line) in lines.iter().enumerate() {
+                    let mut entry = list.reborrow().get(capnp_err!(idx.try_into())?);
+                    entry.set_timestamp(&line.timestamp);
+                    entry.set_pipe(&line.pipe);
+                    entry.set_message(&line.message);
+                }
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Pause writing the output of a running container into its log drivers.
+    fn pause_log_container(
+        &mut self,
+        params: conmon::PauseLogContainerParams,
+        mut results: conmon::PauseLogContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_err!(pry_err!(req.get_id()).to_str());
+
+        let span = new_root_span!("pause_log_container", id);
+        let _enter = span.enter();
+        pry_err!(Telemetry::set_parent_context(pry!(req.get_metadata())));
+
+        debug!("Got a pause container log request");
+
+        let child = pry_err!(self.reaper().get(id));
+        let policy = match pry_err!(usize::try_from(req.get_buffer_size())) {
+            0 => PausePolicy::Drop,
+            size => PausePolicy::Buffer(size),
+        };
+
+        Promise::from_future(
+            async move {
+                let logger = child.io().logger().await;
+                let mut logger = logger.write().await;
+                let already_paused = logger.is_paused();
+                logger.pause(policy);
+
+                results
+                    .get()
+                    .init_response()
+                    .set_already_paused(already_paused);
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Resume writing the output of a running container into its log drivers.
+    fn resume_log_container(
+        &mut self,
+        params: conmon::ResumeLogContainerParams,
+        _: conmon::ResumeLogContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_err!(pry_err!(req.get_id()).to_str());
+
+        let span = new_root_span!("resume_log_container", id);
+        let _enter = span.enter();
+        pry_err!(Telemetry::set_parent_context(pry!(req.get_metadata())));
+
+        debug!("Got a resume container log request");
+
+        let child = pry_err!(self.reaper().get(id));
+
+        Promise::from_future(
+            async move { capnp_err!(child.io().logger().await.write().await.resume().await) }
+                .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Add a log driver to a running container.
+    fn add_log_driver(
+        &mut self,
+        params: conmon::AddLogDriverParams,
+        _: conmon::AddLogDriverResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_err!(pry_err!(req.get_id()).to_str());
+
+        let span = new_root_span!("add_log_driver", id);
+        let _enter = span.enter();
+        pry_err!(Telemetry::set_parent_context(pry!(req.get_metadata())));
+
+        debug!("Got an add log driver request");
+
+        let child = pry_err!(self.reaper().get(id));
+
+        Promise::from_future(
+            async move {
+                let driver = capnp_err!(params.get())?.get_request()?.get_driver()?;
+                capnp_err!(
+                    child
+                        .io()
+                        .logger()
+                        .await
+                        .write()
+                        .await
+                        .add_driver_from(driver)
+                        .await
+                )
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Remove log drivers from a running container.
+    fn remove_log_driver(
+        &mut self,
+        params: conmon::RemoveLogDriverParams,
+        mut results: conmon::RemoveLogDriverResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_err!(pry_err!(req.get_id()).to_str());
+
+        let span = new_root_span!("remove_log_driver", id);
+        let _enter = span.enter();
+        pry_err!(Telemetry::set_parent_context(pry!(req.get_metadata())));
+
+        debug!("Got a remove log driver request");
+
+        let child = pry_err!(self.reaper().get(id));
+        let path = PathBuf::from(pry!(pry!(req.get_path()).to_str()));
+
+        Promise::from_future(
+            async move {
+                let removed = capnp_err!(
+                    child
+                        .io()
+                        .logger()
+                        .await
+                        .write()
+                        .await
+                        .remove_driver_by_path(&path)
+                        .await
+                )?;
+
+                results.get().init_response().set_removed(removed as u64);
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
+    /// Follow the log of a container and send its lines to the listener.
+    fn follow_log_container(
+        &mut self,
+        params: conmon::FollowLogContainerParams,
+        _: conmon::FollowLogContainerResults,
+    ) -> Promise<(), capnp::Error> {
+        let req = pry!(pry!(params.get()).get_request());
+        let id = pry_err!(pry_err!(req.get_id()).to_str());
+        let filter = time_filter(
+            req.get_since(),
+            req.get_until(),
+            req.get_skip_invalid_timestamps(),
+            pry!(req.get_stream()),
+        );
+        let listener = pry!(req.get_listener());
+
+        let span = new_root_span!("follow_log_container", id);
+        let _enter = span.enter();
+        pry_err!(Telemetry::set_parent_context(pry!(req.get_metadata())));
+
+        debug!("Got a follow container log request");
+
+        let child = pry_err!(self.reaper().get(id));
+
+        Promise::from_future(
+            async move {
+                let (path, format) = capnp_err!(child
+                    .io()
+                    .logger()
+                    .await
+                    .read()
+                    .await
+                    .source()
+                    .context("no log driver writes a readable file"))?;
+                let mut lines = pin!(log_reader::follow(path, format, filter));
+
+                loop {
+                    let line = if child.token().is_cancelled() {
+                        // The remaining output of an exited container arrives shortly.
+                        match time::timeout(FOLLOW_EXITED_TIMEOUT, lines.next()).await {
+                            Ok(line) => line,
+                            Err(_) => break,
+                        }
+                    } else {
+                        select! {
+                            line = lines.next() => line,
+                            _ = child.token().cancelled() => continue,
+                        }
+                    };
+                    let Some(line) = line else {
+                        break;
+                    };
+                    let line = capnp_err!(line)?;
+
+                    let mut request = listener.line_request();
+                    let mut entry = request.get().init_line();
+                    entry.set_timestamp(&line.timestamp);
+                    entry.set_pipe(&line.pipe);
+                    entry.set_message(&line.message);
+                    request.send().promise.await?;
+                }
+                Ok(())
+            }
+            .instrument(debug_span!("promise")),
+        )
+    }
+
     fn start_fd_socket(
         &mut self,
         params: conmon::StartFdSocketParams,
@@ -463,3 +785,144 @@ impl conmon::Server for Server {
         )
     }
 }
+
+/// Create the filter of the read log lines, where the time range is provided in nanoseconds since
+/// the Unix epoch and 0 means unset.
+fn time_filter(
+    since: u64,
+    until: u64,
+    skip_invalid_timestamps: bool,
+    stream: Stream,
+) -> TimeFilter {
+    let time = |nanos| (nanos != 0).then(|| UNIX_EPOCH + Duration::from_nanos(nanos));
+    let mut filter = TimeFilter::default();
+    filter
+        .set_since(time(since))
+        .set_until(time(until))
+        .set_invalid(if skip_invalid_timestamps {
+            InvalidTimestamp::Skip
+        } else {
+            InvalidTimestamp::Keep
+        })
+        .set_pipe(match stream {
+            Stream::All => None,
+            Stream::Stdout => Some(Pipe::StdOut),
+            Stream::Stderr => Some(Pipe::StdErr),
+        });
+    filter
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container_log::SharedContainerLog;
+    use capnp::capability::Promise;
+    use conmon_common::conmon_capnp::conmon::log_driver::Type;
+    use std::{cell::RefCell, fs, os::unix::fs::MetadataExt, process::Command, rc::Rc};
+    use tokio_util::sync::CancellationToken;
+
+    /// Register a container `id` running `sleep` for `seconds`, which logs into the JSON file at
+    /// `path`.
+    async fn add_container(
+        server: &Server,
+        id: &str,
+        path: &Path,
+        seconds: &str,
+    ) -> anyhow::Result<SharedContainerLog> {
+        let logger =
+            ContainerLog::from_drivers(id, false, &[(Type::Json, path.into())], |_, _| {})?;
+        logger.write().await.init().await?;
+
+        let pid = Command::new("sleep").arg(seconds).spawn()?.id();
+        let child = Child::new(
+            id.into(),
+            pid,
+            vec![],
+            vec![],
+            None,
+            SharedContainerIO::new(ContainerIO::new(false, logger.clone())?),
+            vec![],
+            CancellationToken::new(),
+        );
+        server.reaper().watch_grandchild(child, vec![])?;
+        Ok(logger)
+    }
+
+    /// Listener collecting the messages of the followed lines.
+    struct Collector(Rc<RefCell<Vec<String>>>);
+
+    impl conmon::log_listener::Server for Collector {
+        fn line(
+            &mut self,
+            params: conmon::log_listener::LineParams,
+            _: conmon::log_listener::LineResults,
+        ) -> Promise<(), capnp::Error> {
+            let message = pry!(pry!(pry!(params.get()).get_line()).get_message());
+            self.0
+                .borrow_mut()
+                .push(String::from_utf8_lossy(message).into_owned());
+            Promise::ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn reopen_log_container_all() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let server = Server::new_for_test();
+        let mut paths = vec![];
+        for id in ["a", "b"] {
+            let path = dir.path().join(format!("{}.log", id));
+            add_container(&server, id, &path, "1").await?;
+            fs::rename(&path, dir.path().join(format!("{}.log.1", id)))?;
+            paths.push(path);
+        }
+
+        let client: conmon::Client = capnp_rpc::new_client(server);
+        let mut request = client.reopen_log_container_request();
+        request.get().init_request().set_all(true);
+        request.send().promise.await?;
+
+        // The rotated files got replaced by new ones at the configured paths.
+        for path in paths {
+            let rotated = path.with_extension("log.1");
+            assert!(path.exists());
+            assert_ne!(fs::metadata(&path)?.ino(), fs::metadata(rotated)?.ino());
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn follow_log_container() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let server = Server::new_for_test();
+        let logger = add_container(&server, "id", &dir.path().join("json.log"), "0.5").await?;
+        logger
+            .write()
+            .await
+            .write(Pipe::StdOut, "one\ntwo\n".as_bytes())
+            .await?;
+
+        let client: conmon::Client = capnp_rpc::new_client(server);
+        let messages = Rc::new(RefCell::new(vec![]));
+        let mut request = client.follow_log_container_request();
+        let mut req = request.get().init_request();
+        req.set_id("id");
+        req.set_listener(capnp_rpc::new_client(Collector(messages.clone())));
+
+        let write = async {
+            time::sleep(Duration::from_millis(200)).await;
+            logger
+                .write()
+                .await
+                .write(Pipe::StdOut, "three\n".as_bytes())
+                .await
+        };
+        // Following ends once the container exited and no further lines arrived.
+        let (res, write) = tokio::join!(request.send().promise, write);
+        res?;
+        write?;
+
+        assert_eq!(*messages.borrow(), ["one", "two", "three"]);
+        Ok(())
+    }
+}
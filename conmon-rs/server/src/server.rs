@@ -1,13 +1,15 @@
 #![deny(missing_docs)]
 
+#[cfg(feature = "journald")]
+use crate::journal::Journal;
 use crate::{
     child_reaper::ChildReaper,
     config::{Commands, Config, LogDriver, Verbosity},
     container_io::{ContainerIO, ContainerIOType},
     fd_socket::FdSocket,
     init::{DefaultInit, Init},
-    journal::Journal,
     listener::{DefaultListener, Listener},
+    log_budget::LogDiskBudget,
     pause::Pause,
     telemetry::Telemetry,
     version::Version,
@@ -53,15 +55,25 @@ pub struct Server {
     /// Fd socket instance.
     #[getset(get = "pub(crate)")]
     fd_socket: Arc<FdSocket>,
+
+    /// Disk usage budget shared by the log files of all containers, if configured.
+    #[getset(get = "pub(crate)")]
+    log_disk_budget: Option<Arc<LogDiskBudget>>,
 }
 
 impl Server {
     /// Create a new `Server` instance.
     pub fn new() -> Result<Self> {
+        let config = Config::default();
+        let log_disk_budget = match config.log_disk_budget() {
+            0 => None,
+            limit => Some(Arc::new(LogDiskBudget::new(limit as u64))),
+        };
         let server = Self {
-            config: Default::default(),
+            config,
             reaper: Default::default(),
             fd_socket: Default::default(),
+            log_disk_budget,
         };
 
         if let Some(v) = server.config().version() {
@@ -102,6 +114,20 @@ impl Server {
         Ok(server)
     }
 
+    #[cfg(test)]
+    /// Create a new `Server` instance with the default configuration, which skips the process
+    /// initialization.
+    pub(crate) fn new_for_test() -> Self {
+        use clap::Parser;
+
+        Self {
+            config: Config::parse_from([clap::crate_name!()]),
+            reaper: Default::default(),
+            fd_socket: Default::default(),
+            log_disk_budget: None,
+        }
+    }
+
     /// Start the `Server` instance and consume it.
     pub fn start(self) -> Result<()> {
         // We need to fork as early as possible, especially before setting up tokio.
@@ -174,6 +200,7 @@ impl Server {
                     .context("init stdout fmt layer")?;
                 info!("Using stdout logger");
             }
+            #[cfg(feature = "journald")]
             LogDriver::Systemd => {
                 let layer = tracing_subscriber::fmt::layer()
                     .with_target(true)
@@ -187,6 +214,8 @@ impl Server {
                     .context("init journald fmt layer")?;
                 info!("Using systemd/journald logger");
             }
+            #[cfg(not(feature = "journald"))]
+            LogDriver::Systemd => anyhow::bail!("systemd logging support is not enabled"),
         }
         info!("Set log level to: {}", self.config().log_level());
         Ok(())
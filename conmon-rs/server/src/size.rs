@@ -0,0 +1,64 @@
+//! Human readable size functionalities.
+
+use anyhow::{bail, Context, Result};
+
+/// Parse a size like `512`, `10KB` or `10MiB` into bytes. Units with an `i` are binary
+/// (powers of 1024), the others decimal (powers of 1000). A missing unit means bytes.
+pub fn parse_size(s: &str) -> Result<usize> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    if number.is_empty() {
+        bail!("size '{}' does not start with a number", s)
+    }
+    let number: usize = number
+        .parse()
+        .context(format!("parse number of size '{}'", s))?;
+
+    let multiplier: usize = match unit.trim_start() {
+        "" | "B" => 1,
+        "KB" => 1000,
+        "KiB" => 1 << 10,
+        "MB" => 1000 * 1000,
+        "MiB" => 1 << 20,
+        "GB" => 1000 * 1000 * 1000,
+        "GiB" => 1 << 30,
+        unit => bail!("unknown unit '{}' of size '{}'", unit, s),
+    };
+    number
+        .checked_mul(multiplier)
+        .context(format!("size '{}' exceeds usize", s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_size_success() -> Result<()> {
+        assert_eq!(parse_size("10MiB")?, 10 * 1024 * 1024);
+        assert_eq!(parse_size("10MB")?, 10 * 1000 * 1000);
+        assert_eq!(parse_size("1KiB")?, 1024);
+        assert_eq!(parse_size("1KB")?, 1000);
+        assert_eq!(parse_size("2GiB")?, 2 << 30);
+        assert_eq!(parse_size("2 GB")?, 2_000_000_000);
+        assert_eq!(parse_size("512B")?, 512);
+        assert_eq!(parse_size(" 512 ")?, 512);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_size_failure() {
+        for s in [
+            "10XB",
+            "",
+            "MiB",
+            "-1KiB",
+            "1.5MiB",
+            "10mib",
+            "99999999999999999999GiB",
+        ] {
+            assert!(parse_size(s).is_err(), "{s}");
+        }
+    }
+}
@@ -10,7 +10,7 @@ use getset::Getters;
 use tokio::{
     process::{ChildStderr, ChildStdin, ChildStdout},
     sync::mpsc::{self, UnboundedReceiver, UnboundedSender},
-    task,
+    task::{self, JoinHandle},
 };
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, debug_span, error, Instrument};
@@ -52,22 +52,26 @@ impl Streams {
         })
     }
 
+    /// Start the read loops and return the handles of the stdout and stderr ones.
     pub fn handle_stdio_receive(
         &self,
         stdin: Option<ChildStdin>,
         stdout: Option<ChildStdout>,
         stderr: Option<ChildStderr>,
         token: CancellationToken,
-    ) {
+    ) -> Vec<JoinHandle<()>> {
         debug!("Start reading from IO streams");
+        let mut readers = vec![];
         let logger = self.logger().clone();
         let attach = self.attach().clone();
         let message_tx = self.message_tx_stdout().clone();
 
         if let Some(stdin) = stdin {
+            let logger = logger.clone();
             task::spawn(
                 async move {
-                    if let Err(e) = ContainerIO::read_loop_stdin(stdin, attach, token).await {
+                    if let Err(e) = ContainerIO::read_loop_stdin(stdin, logger, attach, token).await
+                    {
                         error!("Stdin read loop failure: {:#}", e);
                     }
                 }
@@ -77,7 +81,7 @@ impl Streams {
 
         let attach = self.attach().clone();
         if let Some(stdout) = stdout {
-            task::spawn(
+            readers.push(task::spawn(
                 async move {
                     if let Err(e) =
                         ContainerIO::read_loop(stdout, Pipe::StdOut, logger, message_tx, attach)
@@ -87,14 +91,14 @@ impl Streams {
                     }
                 }
                 .instrument(debug_span!("stdout")),
-            );
+            ));
         }
 
         let logger = self.logger().clone();
         let attach = self.attach().clone();
         let message_tx = self.message_tx_stderr().clone();
         if let Some(stderr) = stderr {
-            task::spawn(
+            readers.push(task::spawn(
                 async move {
                     if let Err(e) =
                         ContainerIO::read_loop(stderr, Pipe::StdErr, logger, message_tx, attach)
@@ -104,8 +108,10 @@ impl Streams {
                     }
                 }
                 .instrument(debug_span!("stderr")),
-            );
+            ));
         }
+
+        readers
     }
 }
 
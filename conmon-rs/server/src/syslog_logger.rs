@@ -0,0 +1,278 @@
+//! Syslog logging functionalities.
+
+use crate::{container_io::Pipe, container_log::Logger, line_buffer::LineBuffer};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use getset::Getters;
+use nix::unistd;
+use tokio::{
+    io::AsyncBufRead,
+    net::{UdpSocket, UnixDatagram},
+};
+use tracing::debug;
+use tz::UtcDateTime;
+
+#[derive(Debug, Getters)]
+/// Logger forwarding container output to a syslog daemon using RFC5424 messages.
+pub struct SyslogLogger {
+    #[getset(get)]
+    /// Address of the syslog socket, either a unix socket path or `udp://<host>:<port>`.
+    address: String,
+
+    #[getset(get)]
+    /// Identifier of the container, used as application name.
+    container_id: String,
+
+    /// Hostname included in every message.
+    hostname: String,
+
+    /// Connected socket to the syslog daemon.
+    socket: Option<SyslogSocket>,
+
+    /// Lines of every pipe assembled across writes.
+    lines: LineBuffer,
+}
+
+#[derive(Debug)]
+enum SyslogSocket {
+    Unix(UnixDatagram),
+    Udp(UdpSocket),
+}
+
+impl SyslogLogger {
+    const ERR_UNINITIALIZED: &'static str = "logger not initialized";
+
+    /// The default syslog socket path.
+    const DEFAULT_ADDRESS: &'static str = "/dev/log";
+
+    /// The prefix of an address for syslog over UDP.
+    const UDP_PREFIX: &'static str = "udp://";
+
+    /// The `user-level messages` facility.
+    const FACILITY_USER: u8 = 1;
+
+    /// The maximum length of the application name field according to RFC5424.
+    const MAX_APP_NAME_LEN: usize = 48;
+
+    /// The length of the short container identifier used as application name.
+    const SHORT_ID_LEN: usize = 12;
+
+    /// Create a new syslog logger instance. An empty address uses the default `/dev/log`
+    /// socket.
+    pub fn new(address: &str, container_id: &str) -> Result<SyslogLogger> {
+        let hostname = unistd::gethostname()
+            .context("get hostname")?
+            .to_string_lossy()
+            .into_owned();
+        Ok(Self {
+            address: if address.is_empty() {
+                Self::DEFAULT_ADDRESS.into()
+            } else {
+                address.into()
+            },
+            container_id: container_id.into(),
+            hostname,
+            socket: None,
+            lines: LineBuffer::default(),
+        })
+    }
+
+    /// Write the contents of the provided reader as one syslog message per line, where a trailing
+    /// line without newline is retained until more data for the same pipe arrives or
+    /// `flush_partial` is called.
+    pub async fn write<T>(&mut self, pipe: Pipe, bytes: T) -> Result<usize>
+    where
+        T: AsyncBufRead + Unpin,
+    {
+        let (read, lines) = self.lines.read(pipe, bytes).await?;
+        for line in lines {
+            self.send(pipe, &line).await?;
+        }
+        Ok(read)
+    }
+
+    /// Send a single line as one syslog message.
+    async fn send(&self, pipe: Pipe, line: &[u8]) -> Result<()> {
+        let message = self.format(pipe, line)?;
+        match self.socket.as_ref().context(Self::ERR_UNINITIALIZED)? {
+            SyslogSocket::Unix(socket) => socket.send(message.as_bytes()).await,
+            SyslogSocket::Udp(socket) => socket.send(message.as_bytes()).await,
+        }
+        .context("send syslog message")?;
+        Ok(())
+    }
+
+    async fn connect(&self) -> Result<SyslogSocket> {
+        if let Some(addr) = self.address().strip_prefix(Self::UDP_PREFIX) {
+            let socket = UdpSocket::bind("0.0.0.0:0")
+                .await
+                .context("bind UDP socket")?;
+            socket
+                .connect(addr)
+                .await
+                .context(format!("connect to syslog address '{}'", self.address()))?;
+            return Ok(SyslogSocket::Udp(socket));
+        }
+
+        let socket = UnixDatagram::unbound().context("create unix datagram socket")?;
+        socket
+            .connect(self.address())
+            .context(format!("connect to syslog socket '{}'", self.address()))?;
+        Ok(SyslogSocket::Unix(socket))
+    }
+
+    /// Format a single line as RFC5424 message:
+    /// `<PRI>1 TIMESTAMP HOSTNAME APP-NAME PROCID MSGID STRUCTURED-DATA MSG`
+    fn format(&self, pipe: Pipe, line: &[u8]) -> Result<String> {
+        let priority = Self::FACILITY_USER * 8 + pipe.severity().code();
+
+        // RFC5424 limits the fractional seconds to microseconds.
+        let now = UtcDateTime::now().context("get current UTC datetime")?;
+        let timestamp = format!(
+            "{}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}Z",
+            now.year(),
+            now.month(),
+            now.month_day(),
+            now.hour(),
+            now.minute(),
+            now.second(),
+            now.nanoseconds() / 1000,
+        );
+
+        let app_name = match self.container_id().get(..Self::SHORT_ID_LEN) {
+            Some(short_id) => short_id,
+            None if self.container_id().is_empty() => "-",
+            None => self.container_id(),
+        };
+        let app_name = app_name.get(..Self::MAX_APP_NAME_LEN).unwrap_or(app_name);
+
+        let message = String::from_utf8_lossy(line);
+        Ok(format!(
+            "<{}>1 {} {} {} - {} - {}",
+            priority,
+            timestamp,
+            self.hostname,
+            app_name,
+            pipe,
+            message.trim_end_matches('\n'),
+        ))
+    }
+}
+
+#[async_trait]
+impl Logger for SyslogLogger {
+    fn name(&self) -> &'static str {
+        "syslog"
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.socket.is_some()
+    }
+
+    /// Asynchronously connect to the syslog socket.
+    async fn init(&mut self) -> Result<()> {
+        debug!("Initializing syslog logger for address {}", self.address());
+        self.socket = Some(self.connect().await?);
+        Ok(())
+    }
+
+    async fn write(
+        &mut self,
+        pipe: Pipe,
+        bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> Result<usize> {
+        SyslogLogger::write(self, pipe, bytes).await
+    }
+
+    async fn write_line(&mut self, pipe: Pipe, line: &[u8]) -> Result<()> {
+        self.send(pipe, line).await
+    }
+
+    async fn flush_partial(&mut self, pipe: Pipe) -> Result<()> {
+        if let Some(line) = self.lines.take(pipe) {
+            self.send(pipe, &line).await?;
+        }
+        Ok(())
+    }
+
+    /// Reconnect to the syslog socket.
+    async fn reopen(&mut self) -> Result<()> {
+        debug!("Reopen syslog logger for address {}", self.address());
+        self.socket.as_ref().context(Self::ERR_UNINITIALIZED)?;
+        self.init().await
+    }
+
+    /// Messages are sent unbuffered, which means there is nothing to flush.
+    async fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.socket = None;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn write_success() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("log.sock");
+        let server = UnixDatagram::bind(&path)?;
+
+        let mut sut = SyslogLogger::new(path.to_str().context("path to str")?, "0123456789abcdef")?;
+        sut.init().await?;
+        sut.write(Pipe::StdErr, "hello world\n".as_bytes()).await?;
+
+        let mut buf = vec![0; 1024];
+        let len = server.recv(&mut buf).await?;
+        let message = String::from_utf8(buf[..len].to_vec())?;
+        let fields = message.splitn(8, ' ').collect::<Vec<_>>();
+
+        assert_eq!(fields[0], "<11>1");
+        assert!(fields[1].ends_with('Z'));
+        assert_eq!(fields[3], "0123456789ab");
+        assert_eq!(fields[4], "-");
+        assert_eq!(fields[5], "stderr");
+        assert_eq!(fields[6], "-");
+        assert_eq!(fields[7], "hello world");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_split_codepoint() -> Result<()> {
+        let dir = tempdir()?;
+        let path = dir.path().join("log.sock");
+        let server = UnixDatagram::bind(&path)?;
+
+        let mut sut = SyslogLogger::new(path.to_str().context("path to str")?, "id")?;
+        sut.init().await?;
+        let line = "smile 😀\n".as_bytes();
+        let (first, second) = line.split_at(line.len() - 3);
+        sut.write(Pipe::StdOut, first).await?;
+        sut.write(Pipe::StdOut, second).await?;
+        sut.write(Pipe::StdOut, "partial".as_bytes()).await?;
+        sut.flush_partial(Pipe::StdOut).await?;
+
+        let mut buf = vec![0; 1024];
+        let len = server.recv(&mut buf).await?;
+        let message = String::from_utf8(buf[..len].to_vec())?;
+        assert!(message.ends_with(" smile 😀"), "{message}");
+        let len = server.recv(&mut buf).await?;
+        let message = String::from_utf8(buf[..len].to_vec())?;
+        assert!(message.ends_with(" partial"), "{message}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn write_uninitialized() -> Result<()> {
+        let mut sut = SyslogLogger::new("", "id")?;
+        assert_eq!(sut.address(), SyslogLogger::DEFAULT_ADDRESS);
+        assert!(sut.write(Pipe::StdOut, "hello\n".as_bytes()).await.is_err());
+        Ok(())
+    }
+}
@@ -5,7 +5,14 @@ use capnp::struct_list::Reader;
 use clap::crate_name;
 use conmon_common::conmon_capnp::conmon;
 use nix::unistd::gethostname;
-use opentelemetry::{global, propagation::Extractor, KeyValue};
+#[cfg(feature = "trace-context")]
+use opentelemetry::trace::TraceContextExt;
+use opentelemetry::{
+    global,
+    propagation::Extractor,
+    trace::{SpanId, TraceId},
+    KeyValue,
+};
 use opentelemetry_otlp::{ExportConfig, WithExportConfig};
 use opentelemetry_sdk::{
     propagation::TraceContextPropagator,
@@ -62,6 +69,23 @@ impl Telemetry {
         global::shutdown_tracer_provider();
     }
 
+    #[cfg(feature = "trace-context")]
+    /// Return the trace and span ID of the current span, if it is part of a valid trace.
+    pub fn current_ids() -> Option<(TraceId, SpanId)> {
+        let context = Span::current().context();
+        let span = context.span();
+        let span_context = span.span_context();
+        span_context
+            .is_valid()
+            .then(|| (span_context.trace_id(), span_context.span_id()))
+    }
+
+    #[cfg(not(feature = "trace-context"))]
+    /// Return no IDs, because trace context support is not enabled.
+    pub fn current_ids() -> Option<(TraceId, SpanId)> {
+        None
+    }
+
     /// Set a new parent context from the provided slice data.
     pub fn set_parent_context(reader: Reader<conmon::text_text_map_entry::Owned>) -> Result<()> {
         if reader.is_empty() {
@@ -30,7 +30,7 @@ use tokio::{
     io::{unix::AsyncFd, AsyncRead, AsyncWrite, AsyncWriteExt, Interest, ReadBuf},
     net::UnixStream,
     sync::mpsc::{self, Receiver, Sender, UnboundedReceiver},
-    task,
+    task::{self, JoinHandle},
 };
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, debug_span, error, trace, Instrument};
@@ -100,8 +100,12 @@ impl Terminal {
         })
     }
 
-    /// Waits for the socket client to be connected.
-    pub async fn wait_connected(&mut self, stdin: bool, token: CancellationToken) -> Result<()> {
+    /// Waits for the socket client to be connected and returns the handle of the stdout read loop.
+    pub async fn wait_connected(
+        &mut self,
+        stdin: bool,
+        token: CancellationToken,
+    ) -> Result<JoinHandle<()>> {
         debug!("Waiting for terminal socket connection");
         let fd = self
             .connected_rx
@@ -121,7 +125,7 @@ impl Terminal {
         let (message_tx, message_rx) = mpsc::unbounded_channel();
         self.message_rx = Some(message_rx);
 
-        task::spawn({
+        let reader = task::spawn({
             let fd = fd.clone();
             async move {
                 if let Err(e) = ContainerIO::read_loop(
@@ -135,16 +139,18 @@ impl Terminal {
                 {
                     error!("Stdout read loop failure: {:#}", e)
                 }
-                Ok::<_, anyhow::Error>(())
             }
             .instrument(debug_span!("read_loop"))
         });
 
         if stdin {
             let attach_clone = self.attach.clone();
+            let logger_clone = self.logger.clone();
             task::spawn(
                 async move {
-                    if let Err(e) = ContainerIO::read_loop_stdin(&*fd, attach_clone, token).await {
+                    if let Err(e) =
+                        ContainerIO::read_loop_stdin(&*fd, logger_clone, attach_clone, token).await
+                    {
                         error!("Stdin read loop failure: {:#}", e);
                     }
                 }
@@ -152,7 +158,7 @@ impl Terminal {
             );
         }
 
-        Ok(())
+        Ok(reader)
     }
 
     /// Resize the terminal width and height.
@@ -0,0 +1,178 @@
+//! Write timeout functionalities.
+
+use crate::{
+    container_io::Pipe,
+    container_log::{LogEvent, LogStats, Logger},
+    log_error::LoggerError,
+    log_reader::{LogFormat, LogLine},
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::{future::Future, path::PathBuf, time::Duration};
+use tokio::{io::AsyncBufRead, time};
+
+#[derive(Debug)]
+/// Logger failing the writes of the wrapped log driver which exceed the timeout, for example
+/// because its log directory is on a hung NFS mount. A timed out write fails by
+/// `LoggerError::Timeout` instead of blocking the container output, which disables optional log
+/// drivers.
+pub struct TimeoutLogger {
+    /// The wrapped log driver.
+    inner: Box<dyn Logger>,
+
+    /// The maximum duration of a single write.
+    timeout: Duration,
+}
+
+impl TimeoutLogger {
+    /// Create a new timeout logger instance for the provided log driver.
+    pub fn new(inner: Box<dyn Logger>, timeout: Duration) -> Self {
+        Self { inner, timeout }
+    }
+
+    /// Run the provided write of the log driver, which fails if it exceeds the timeout.
+    async fn run<T>(timeout: Duration, write: impl Future<Output = Result<T>>) -> Result<T> {
+        time::timeout(timeout, write)
+            .await
+            .map_err(|_| LoggerError::Timeout(timeout))?
+    }
+}
+
+#[async_trait]
+impl Logger for TimeoutLogger {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    fn source(&self) -> Option<(PathBuf, LogFormat)> {
+        self.inner.source()
+    }
+
+    fn accepts(&self, pipe: Pipe) -> bool {
+        self.inner.accepts(pipe)
+    }
+
+    async fn validate(&self) -> Result<()> {
+        self.inner.validate().await
+    }
+
+    async fn lines(&self) -> Option<Vec<LogLine>> {
+        self.inner.lines().await
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.inner.is_initialized()
+    }
+
+    fn stats(&self) -> LogStats {
+        self.inner.stats()
+    }
+
+    async fn init(&mut self) -> Result<()> {
+        self.inner.init().await
+    }
+
+    async fn write(
+        &mut self,
+        pipe: Pipe,
+        bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> Result<usize> {
+        Self::run(self.timeout, self.inner.write(pipe, bytes)).await
+    }
+
+    async fn write_line(&mut self, pipe: Pipe, line: &[u8]) -> Result<()> {
+        Self::run(self.timeout, self.inner.write_line(pipe, line)).await
+    }
+
+    async fn write_marker(&mut self, dropped_bytes: usize) -> Result<()> {
+        Self::run(self.timeout, self.inner.write_marker(dropped_bytes)).await
+    }
+
+    async fn write_event(&mut self, event: LogEvent) -> Result<()> {
+        Self::run(self.timeout, self.inner.write_event(event)).await
+    }
+
+    async fn flush_partial(&mut self, pipe: Pipe) -> Result<()> {
+        Self::run(self.timeout, self.inner.flush_partial(pipe)).await
+    }
+
+    fn expiry_interval(&self) -> Option<Duration> {
+        self.inner.expiry_interval()
+    }
+
+    async fn flush_expired(&mut self) -> Result<()> {
+        Self::run(self.timeout, self.inner.flush_expired()).await
+    }
+
+    /// Reopening is not limited, because rotating a large log file may take a while.
+    async fn reopen(&mut self) -> Result<()> {
+        self.inner.reopen().await
+    }
+
+    async fn flush(&mut self) -> Result<()> {
+        Self::run(self.timeout, self.inner.flush()).await
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        self.inner.close().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[derive(Debug)]
+    /// Logger blocking every write, like a log file on a hung NFS mount.
+    struct HungLogger;
+
+    #[async_trait]
+    impl Logger for HungLogger {
+        fn name(&self) -> &'static str {
+            "hung"
+        }
+
+        async fn init(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn write(
+            &mut self,
+            _pipe: Pipe,
+            _bytes: &mut (dyn AsyncBufRead + Unpin + Send),
+        ) -> Result<usize> {
+            time::sleep(Duration::from_secs(60)).await;
+            Ok(0)
+        }
+
+        async fn reopen(&mut self) -> Result<()> {
+            Ok(())
+        }
+
+        async fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn write_timeout() -> Result<()> {
+        let mut sut = TimeoutLogger::new(Box::new(HungLogger), Duration::from_millis(50));
+        sut.init().await?;
+
+        let start = Instant::now();
+        let err = sut
+            .write(Pipe::StdOut, &mut "hello\n".as_bytes())
+            .await
+            .unwrap_err();
+
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(matches!(
+            LoggerError::from(err),
+            LoggerError::Timeout(x) if x == Duration::from_millis(50)
+        ));
+        // The writes without any I/O still succeed.
+        sut.flush().await?;
+        Ok(())
+    }
+}
@@ -176,6 +176,126 @@ func (c Conmon) StartFdSocket(ctx context.Context, params func(Conmon_startFdSoc
 
 }
 
+func (c Conmon) TailLogContainer(ctx context.Context, params func(Conmon_tailLogContainer_Params) error) (Conmon_tailLogContainer_Results_Future, capnp.ReleaseFunc) {
+
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      8,
+			InterfaceName: "internal/proto/conmon.capnp:Conmon",
+			MethodName:    "tailLogContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_tailLogContainer_Params(s)) }
+	}
+
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_tailLogContainer_Results_Future{Future: ans.Future()}, release
+
+}
+
+func (c Conmon) PauseLogContainer(ctx context.Context, params func(Conmon_pauseLogContainer_Params) error) (Conmon_pauseLogContainer_Results_Future, capnp.ReleaseFunc) {
+
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      9,
+			InterfaceName: "internal/proto/conmon.capnp:Conmon",
+			MethodName:    "pauseLogContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_pauseLogContainer_Params(s)) }
+	}
+
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_pauseLogContainer_Results_Future{Future: ans.Future()}, release
+
+}
+
+func (c Conmon) ResumeLogContainer(ctx context.Context, params func(Conmon_resumeLogContainer_Params) error) (Conmon_resumeLogContainer_Results_Future, capnp.ReleaseFunc) {
+
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      10,
+			InterfaceName: "internal/proto/conmon.capnp:Conmon",
+			MethodName:    "resumeLogContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_resumeLogContainer_Params(s)) }
+	}
+
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_resumeLogContainer_Results_Future{Future: ans.Future()}, release
+
+}
+
+func (c Conmon) AddLogDriver(ctx context.Context, params func(Conmon_addLogDriver_Params) error) (Conmon_addLogDriver_Results_Future, capnp.ReleaseFunc) {
+
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      11,
+			InterfaceName: "internal/proto/conmon.capnp:Conmon",
+			MethodName:    "addLogDriver",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_addLogDriver_Params(s)) }
+	}
+
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_addLogDriver_Results_Future{Future: ans.Future()}, release
+
+}
+
+func (c Conmon) RemoveLogDriver(ctx context.Context, params func(Conmon_removeLogDriver_Params) error) (Conmon_removeLogDriver_Results_Future, capnp.ReleaseFunc) {
+
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      12,
+			InterfaceName: "internal/proto/conmon.capnp:Conmon",
+			MethodName:    "removeLogDriver",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_removeLogDriver_Params(s)) }
+	}
+
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_removeLogDriver_Results_Future{Future: ans.Future()}, release
+
+}
+
+func (c Conmon) FollowLogContainer(ctx context.Context, params func(Conmon_followLogContainer_Params) error) (Conmon_followLogContainer_Results_Future, capnp.ReleaseFunc) {
+
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      13,
+			InterfaceName: "internal/proto/conmon.capnp:Conmon",
+			MethodName:    "followLogContainer",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_followLogContainer_Params(s)) }
+	}
+
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_followLogContainer_Results_Future{Future: ans.Future()}, release
+
+}
+
 func (c Conmon) WaitStreaming() error {
 	return capnp.Client(c).WaitStreaming()
 }
@@ -264,6 +384,18 @@ type Conmon_Server interface {
 	CreateNamespaces(context.Context, Conmon_createNamespaces) error
 
 	StartFdSocket(context.Context, Conmon_startFdSocket) error
+
+	TailLogContainer(context.Context, Conmon_tailLogContainer) error
+
+	PauseLogContainer(context.Context, Conmon_pauseLogContainer) error
+
+	ResumeLogContainer(context.Context, Conmon_resumeLogContainer) error
+
+	AddLogDriver(context.Context, Conmon_addLogDriver) error
+
+	RemoveLogDriver(context.Context, Conmon_removeLogDriver) error
+
+	FollowLogContainer(context.Context, Conmon_followLogContainer) error
 }
 
 // Conmon_NewServer creates a new Server from an implementation of Conmon_Server.
@@ -282,7 +414,7 @@ func Conmon_ServerToClient(s Conmon_Server) Conmon {
 // This can be used to create a more complicated Server.
 func Conmon_Methods(methods []server.Method, s Conmon_Server) []server.Method {
 	if cap(methods) == 0 {
-		methods = make([]server.Method, 0, 8)
+		methods = make([]server.Method, 0, 14)
 	}
 
 	methods = append(methods, server.Method{
@@ -381,6 +513,78 @@ func Conmon_Methods(methods []server.Method, s Conmon_Server) []server.Method {
 		},
 	})
 
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      8,
+			InterfaceName: "internal/proto/conmon.capnp:Conmon",
+			MethodName:    "tailLogContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.TailLogContainer(ctx, Conmon_tailLogContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      9,
+			InterfaceName: "internal/proto/conmon.capnp:Conmon",
+			MethodName:    "pauseLogContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.PauseLogContainer(ctx, Conmon_pauseLogContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      10,
+			InterfaceName: "internal/proto/conmon.capnp:Conmon",
+			MethodName:    "resumeLogContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.ResumeLogContainer(ctx, Conmon_resumeLogContainer{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      11,
+			InterfaceName: "internal/proto/conmon.capnp:Conmon",
+			MethodName:    "addLogDriver",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.AddLogDriver(ctx, Conmon_addLogDriver{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      12,
+			InterfaceName: "internal/proto/conmon.capnp:Conmon",
+			MethodName:    "removeLogDriver",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.RemoveLogDriver(ctx, Conmon_removeLogDriver{call})
+		},
+	})
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xb737e899dd6633f1,
+			MethodID:      13,
+			InterfaceName: "internal/proto/conmon.capnp:Conmon",
+			MethodName:    "followLogContainer",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.FollowLogContainer(ctx, Conmon_followLogContainer{call})
+		},
+	})
+
 	return methods
 }
 
@@ -520,6 +724,108 @@ func (c Conmon_startFdSocket) AllocResults() (Conmon_startFdSocket_Results, erro
 	return Conmon_startFdSocket_Results(r), err
 }
 
+// Conmon_tailLogContainer holds the state for a server call to Conmon.tailLogContainer.
+// See server.Call for documentation.
+type Conmon_tailLogContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_tailLogContainer) Args() Conmon_tailLogContainer_Params {
+	return Conmon_tailLogContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_tailLogContainer) AllocResults() (Conmon_tailLogContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_tailLogContainer_Results(r), err
+}
+
+// Conmon_pauseLogContainer holds the state for a server call to Conmon.pauseLogContainer.
+// See server.Call for documentation.
+type Conmon_pauseLogContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_pauseLogContainer) Args() Conmon_pauseLogContainer_Params {
+	return Conmon_pauseLogContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_pauseLogContainer) AllocResults() (Conmon_pauseLogContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_pauseLogContainer_Results(r), err
+}
+
+// Conmon_resumeLogContainer holds the state for a server call to Conmon.resumeLogContainer.
+// See server.Call for documentation.
+type Conmon_resumeLogContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_resumeLogContainer) Args() Conmon_resumeLogContainer_Params {
+	return Conmon_resumeLogContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_resumeLogContainer) AllocResults() (Conmon_resumeLogContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_resumeLogContainer_Results(r), err
+}
+
+// Conmon_addLogDriver holds the state for a server call to Conmon.addLogDriver.
+// See server.Call for documentation.
+type Conmon_addLogDriver struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_addLogDriver) Args() Conmon_addLogDriver_Params {
+	return Conmon_addLogDriver_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_addLogDriver) AllocResults() (Conmon_addLogDriver_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_addLogDriver_Results(r), err
+}
+
+// Conmon_removeLogDriver holds the state for a server call to Conmon.removeLogDriver.
+// See server.Call for documentation.
+type Conmon_removeLogDriver struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_removeLogDriver) Args() Conmon_removeLogDriver_Params {
+	return Conmon_removeLogDriver_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_removeLogDriver) AllocResults() (Conmon_removeLogDriver_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_removeLogDriver_Results(r), err
+}
+
+// Conmon_followLogContainer holds the state for a server call to Conmon.followLogContainer.
+// See server.Call for documentation.
+type Conmon_followLogContainer struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_followLogContainer) Args() Conmon_followLogContainer_Params {
+	return Conmon_followLogContainer_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_followLogContainer) AllocResults() (Conmon_followLogContainer_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_followLogContainer_Results(r), err
+}
+
 // Conmon_List is a list of Conmon.
 type Conmon_List = capnp.CapList[Conmon]
 
@@ -873,12 +1179,12 @@ type Conmon_CreateContainerRequest capnp.Struct
 const Conmon_CreateContainerRequest_TypeID = 0xba77e3fa3aa9b6ca
 
 func NewConmon_CreateContainerRequest(s *capnp.Segment) (Conmon_CreateContainerRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 13})
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 14})
 	return Conmon_CreateContainerRequest(st), err
 }
 
 func NewRootConmon_CreateContainerRequest(s *capnp.Segment) (Conmon_CreateContainerRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 13})
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 14})
 	return Conmon_CreateContainerRequest(st), err
 }
 
@@ -1217,13 +1523,83 @@ func (s Conmon_CreateContainerRequest) NewLeakFds(n int32) (capnp.UInt64List, er
 	err = capnp.Struct(s).SetPtr(12, l.ToPtr())
 	return l, err
 }
+func (s Conmon_CreateContainerRequest) LogStdin() bool {
+	return capnp.Struct(s).Bit(2)
+}
+
+func (s Conmon_CreateContainerRequest) SetLogStdin(v bool) {
+	capnp.Struct(s).SetBit(2, v)
+}
+
+func (s Conmon_CreateContainerRequest) LogMetadata() (Conmon_TextTextMapEntry_List, error) {
+	p, err := capnp.Struct(s).Ptr(13)
+	return Conmon_TextTextMapEntry_List(p.List()), err
+}
+
+func (s Conmon_CreateContainerRequest) HasLogMetadata() bool {
+	return capnp.Struct(s).HasPtr(13)
+}
+
+func (s Conmon_CreateContainerRequest) SetLogMetadata(v Conmon_TextTextMapEntry_List) error {
+	return capnp.Struct(s).SetPtr(13, v.ToPtr())
+}
+
+// NewLogMetadata sets the logMetadata field to a newly
+// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
+func (s Conmon_CreateContainerRequest) NewLogMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_TextTextMapEntry_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(13, l.ToPtr())
+	return l, err
+}
+func (s Conmon_CreateContainerRequest) ValidateLogDrivers() bool {
+	return capnp.Struct(s).Bit(3)
+}
+
+func (s Conmon_CreateContainerRequest) SetValidateLogDrivers(v bool) {
+	capnp.Struct(s).SetBit(3, v)
+}
+
+func (s Conmon_CreateContainerRequest) OrderedLogDrivers() bool {
+	return capnp.Struct(s).Bit(4)
+}
+
+func (s Conmon_CreateContainerRequest) SetOrderedLogDrivers(v bool) {
+	capnp.Struct(s).SetBit(4, v)
+}
+
+func (s Conmon_CreateContainerRequest) LogMaxSize() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_CreateContainerRequest) SetLogMaxSize(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+func (s Conmon_CreateContainerRequest) LogExitEvent() bool {
+	return capnp.Struct(s).Bit(5)
+}
+
+func (s Conmon_CreateContainerRequest) SetLogExitEvent(v bool) {
+	capnp.Struct(s).SetBit(5, v)
+}
+
+func (s Conmon_CreateContainerRequest) MaxConcurrentLogWriters() uint32 {
+	return capnp.Struct(s).Uint32(4)
+}
+
+func (s Conmon_CreateContainerRequest) SetMaxConcurrentLogWriters(v uint32) {
+	capnp.Struct(s).SetUint32(4, v)
+}
 
 // Conmon_CreateContainerRequest_List is a list of Conmon_CreateContainerRequest.
 type Conmon_CreateContainerRequest_List = capnp.StructList[Conmon_CreateContainerRequest]
 
 // NewConmon_CreateContainerRequest creates a new list of Conmon_CreateContainerRequest.
 func NewConmon_CreateContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_CreateContainerRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 13}, sz)
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 14}, sz)
 	return capnp.StructList[Conmon_CreateContainerRequest](l), err
 }
 
@@ -1241,12 +1617,12 @@ type Conmon_LogDriver capnp.Struct
 const Conmon_LogDriver_TypeID = 0xae78ee8eb6b3a134
 
 func NewConmon_LogDriver(s *capnp.Segment) (Conmon_LogDriver, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1})
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 176, PointerCount: 25})
 	return Conmon_LogDriver(st), err
 }
 
 func NewRootConmon_LogDriver(s *capnp.Segment) (Conmon_LogDriver, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1})
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 176, PointerCount: 25})
 	return Conmon_LogDriver(st), err
 }
 
@@ -1316,3518 +1692,7987 @@ func (s Conmon_LogDriver) SetMaxSize(v uint64) {
 	capnp.Struct(s).SetUint64(8, v)
 }
 
-// Conmon_LogDriver_List is a list of Conmon_LogDriver.
-type Conmon_LogDriver_List = capnp.StructList[Conmon_LogDriver]
+func (s Conmon_LogDriver) MaxFiles() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
 
-// NewConmon_LogDriver creates a new list of Conmon_LogDriver.
-func NewConmon_LogDriver_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_LogDriver](l), err
+func (s Conmon_LogDriver) SetMaxFiles(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
 }
 
-// Conmon_LogDriver_Future is a wrapper for a Conmon_LogDriver promised by a client call.
-type Conmon_LogDriver_Future struct{ *capnp.Future }
+func (s Conmon_LogDriver) Rotation() Conmon_LogDriver_Rotation {
+	return Conmon_LogDriver_Rotation(capnp.Struct(s).Uint16(2))
+}
 
-func (f Conmon_LogDriver_Future) Struct() (Conmon_LogDriver, error) {
-	p, err := f.Future.Ptr()
-	return Conmon_LogDriver(p.Struct()), err
+func (s Conmon_LogDriver) SetRotation(v Conmon_LogDriver_Rotation) {
+	capnp.Struct(s).SetUint16(2, uint16(v))
 }
 
-type Conmon_LogDriver_Type uint16
+func (s Conmon_LogDriver) Compress() bool {
+	return capnp.Struct(s).Bit(32)
+}
 
-// Conmon_LogDriver_Type_TypeID is the unique identifier for the type Conmon_LogDriver_Type.
-const Conmon_LogDriver_Type_TypeID = 0xf026e3d750335bc1
+func (s Conmon_LogDriver) SetCompress(v bool) {
+	capnp.Struct(s).SetBit(32, v)
+}
 
-// Values of Conmon_LogDriver_Type.
-const (
-	Conmon_LogDriver_Type_containerRuntimeInterface Conmon_LogDriver_Type = 0
-	Conmon_LogDriver_Type_json                      Conmon_LogDriver_Type = 1
-)
+func (s Conmon_LogDriver) Durability() Conmon_LogDriver_Durability {
+	return Conmon_LogDriver_Durability(capnp.Struct(s).Uint16(6))
+}
 
-// String returns the enum's constant name.
-func (c Conmon_LogDriver_Type) String() string {
-	switch c {
-	case Conmon_LogDriver_Type_containerRuntimeInterface:
-		return "containerRuntimeInterface"
-	case Conmon_LogDriver_Type_json:
-		return "json"
+func (s Conmon_LogDriver) SetDurability(v Conmon_LogDriver_Durability) {
+	capnp.Struct(s).SetUint16(6, uint16(v))
+}
 
-	default:
-		return ""
-	}
+func (s Conmon_LogDriver) SyncInterval() uint64 {
+	return capnp.Struct(s).Uint64(24)
 }
 
-// Conmon_LogDriver_TypeFromString returns the enum value with a name,
-// or the zero value if there's no such value.
-func Conmon_LogDriver_TypeFromString(c string) Conmon_LogDriver_Type {
-	switch c {
-	case "containerRuntimeInterface":
-		return Conmon_LogDriver_Type_containerRuntimeInterface
-	case "json":
-		return Conmon_LogDriver_Type_json
+func (s Conmon_LogDriver) SetSyncInterval(v uint64) {
+	capnp.Struct(s).SetUint64(24, v)
+}
 
-	default:
-		return 0
-	}
+func (s Conmon_LogDriver) MaxLineSize() uint64 {
+	return capnp.Struct(s).Uint64(32)
 }
 
-type Conmon_LogDriver_Type_List = capnp.EnumList[Conmon_LogDriver_Type]
+func (s Conmon_LogDriver) SetMaxLineSize(v uint64) {
+	capnp.Struct(s).SetUint64(32, v)
+}
 
-func NewConmon_LogDriver_Type_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_Type_List, error) {
-	return capnp.NewEnumList[Conmon_LogDriver_Type](s, sz)
+func (s Conmon_LogDriver) Args() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return capnp.TextList(p.List()), err
 }
 
-type Conmon_CgroupManager uint16
+func (s Conmon_LogDriver) HasArgs() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
 
-// Conmon_CgroupManager_TypeID is the unique identifier for the type Conmon_CgroupManager.
-const Conmon_CgroupManager_TypeID = 0xaa4bbac12765a78a
+func (s Conmon_LogDriver) SetArgs(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+}
 
-// Values of Conmon_CgroupManager.
-const (
-	Conmon_CgroupManager_systemd  Conmon_CgroupManager = 0
-	Conmon_CgroupManager_cgroupfs Conmon_CgroupManager = 1
-)
+// NewArgs sets the args field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_LogDriver) NewArgs(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	return l, err
+}
+func (s Conmon_LogDriver) Backpressure() Conmon_LogDriver_Backpressure {
+	return Conmon_LogDriver_Backpressure(capnp.Struct(s).Uint16(40))
+}
 
-// String returns the enum's constant name.
-func (c Conmon_CgroupManager) String() string {
-	switch c {
-	case Conmon_CgroupManager_systemd:
-		return "systemd"
-	case Conmon_CgroupManager_cgroupfs:
-		return "cgroupfs"
+func (s Conmon_LogDriver) SetBackpressure(v Conmon_LogDriver_Backpressure) {
+	capnp.Struct(s).SetUint16(40, uint16(v))
+}
 
-	default:
-		return ""
-	}
+func (s Conmon_LogDriver) Framing() Conmon_LogDriver_Framing {
+	return Conmon_LogDriver_Framing(capnp.Struct(s).Uint16(42))
 }
 
-// Conmon_CgroupManagerFromString returns the enum value with a name,
-// or the zero value if there's no such value.
-func Conmon_CgroupManagerFromString(c string) Conmon_CgroupManager {
-	switch c {
-	case "systemd":
-		return Conmon_CgroupManager_systemd
-	case "cgroupfs":
-		return Conmon_CgroupManager_cgroupfs
+func (s Conmon_LogDriver) SetFraming(v Conmon_LogDriver_Framing) {
+	capnp.Struct(s).SetUint16(42, uint16(v))
+}
 
-	default:
-		return 0
-	}
+func (s Conmon_LogDriver) QueueDepth() uint64 {
+	return capnp.Struct(s).Uint64(48)
 }
 
-type Conmon_CgroupManager_List = capnp.EnumList[Conmon_CgroupManager]
+func (s Conmon_LogDriver) SetQueueDepth(v uint64) {
+	capnp.Struct(s).SetUint64(48, v)
+}
 
-func NewConmon_CgroupManager_List(s *capnp.Segment, sz int32) (Conmon_CgroupManager_List, error) {
-	return capnp.NewEnumList[Conmon_CgroupManager](s, sz)
+func (s Conmon_LogDriver) QueuePolicy() Conmon_LogDriver_QueuePolicy {
+	return Conmon_LogDriver_QueuePolicy(capnp.Struct(s).Uint16(44))
 }
 
-type Conmon_CreateContainerResponse capnp.Struct
+func (s Conmon_LogDriver) SetQueuePolicy(v Conmon_LogDriver_QueuePolicy) {
+	capnp.Struct(s).SetUint16(44, uint16(v))
+}
 
-// Conmon_CreateContainerResponse_TypeID is the unique identifier for the type Conmon_CreateContainerResponse.
-const Conmon_CreateContainerResponse_TypeID = 0xde3a625e70772b9a
+func (s Conmon_LogDriver) ParseLevel() bool {
+	return capnp.Struct(s).Bit(33)
+}
 
-func NewConmon_CreateContainerResponse(s *capnp.Segment) (Conmon_CreateContainerResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
-	return Conmon_CreateContainerResponse(st), err
+func (s Conmon_LogDriver) SetParseLevel(v bool) {
+	capnp.Struct(s).SetBit(33, v)
 }
 
-func NewRootConmon_CreateContainerResponse(s *capnp.Segment) (Conmon_CreateContainerResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
-	return Conmon_CreateContainerResponse(st), err
+func (s Conmon_LogDriver) LevelPattern() (string, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.Text(), err
 }
 
-func ReadRootConmon_CreateContainerResponse(msg *capnp.Message) (Conmon_CreateContainerResponse, error) {
-	root, err := msg.Root()
-	return Conmon_CreateContainerResponse(root.Struct()), err
+func (s Conmon_LogDriver) HasLevelPattern() bool {
+	return capnp.Struct(s).HasPtr(2)
 }
 
-func (s Conmon_CreateContainerResponse) String() string {
-	str, _ := text.Marshal(0xde3a625e70772b9a, capnp.Struct(s))
-	return str
+func (s Conmon_LogDriver) LevelPatternBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.TextBytes(), err
 }
 
-func (s Conmon_CreateContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
-	return capnp.Struct(s).EncodeAsPtr(seg)
+func (s Conmon_LogDriver) SetLevelPattern(v string) error {
+	return capnp.Struct(s).SetText(2, v)
 }
 
-func (Conmon_CreateContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_CreateContainerResponse {
-	return Conmon_CreateContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+func (s Conmon_LogDriver) Append() bool {
+	return capnp.Struct(s).Bit(34)
 }
 
-func (s Conmon_CreateContainerResponse) ToPtr() capnp.Ptr {
-	return capnp.Struct(s).ToPtr()
+func (s Conmon_LogDriver) SetAppend(v bool) {
+	capnp.Struct(s).SetBit(34, v)
 }
-func (s Conmon_CreateContainerResponse) IsValid() bool {
-	return capnp.Struct(s).IsValid()
+
+func (s Conmon_LogDriver) MaxSizeText() (string, error) {
+	p, err := capnp.Struct(s).Ptr(3)
+	return p.Text(), err
 }
 
-func (s Conmon_CreateContainerResponse) Message() *capnp.Message {
-	return capnp.Struct(s).Message()
+func (s Conmon_LogDriver) HasMaxSizeText() bool {
+	return capnp.Struct(s).HasPtr(3)
 }
 
-func (s Conmon_CreateContainerResponse) Segment() *capnp.Segment {
-	return capnp.Struct(s).Segment()
+func (s Conmon_LogDriver) MaxSizeTextBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(3)
+	return p.TextBytes(), err
 }
-func (s Conmon_CreateContainerResponse) ContainerPid() uint32 {
-	return capnp.Struct(s).Uint32(0)
+
+func (s Conmon_LogDriver) SetMaxSizeText(v string) error {
+	return capnp.Struct(s).SetText(3, v)
 }
 
-func (s Conmon_CreateContainerResponse) SetContainerPid(v uint32) {
-	capnp.Struct(s).SetUint32(0, v)
+func (s Conmon_LogDriver) MaxLineSizeText() (string, error) {
+	p, err := capnp.Struct(s).Ptr(4)
+	return p.Text(), err
 }
 
-// Conmon_CreateContainerResponse_List is a list of Conmon_CreateContainerResponse.
-type Conmon_CreateContainerResponse_List = capnp.StructList[Conmon_CreateContainerResponse]
+func (s Conmon_LogDriver) HasMaxLineSizeText() bool {
+	return capnp.Struct(s).HasPtr(4)
+}
 
-// NewConmon_CreateContainerResponse creates a new list of Conmon_CreateContainerResponse.
-func NewConmon_CreateContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_CreateContainerResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0}, sz)
-	return capnp.StructList[Conmon_CreateContainerResponse](l), err
+func (s Conmon_LogDriver) MaxLineSizeTextBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(4)
+	return p.TextBytes(), err
 }
 
-// Conmon_CreateContainerResponse_Future is a wrapper for a Conmon_CreateContainerResponse promised by a client call.
-type Conmon_CreateContainerResponse_Future struct{ *capnp.Future }
+func (s Conmon_LogDriver) SetMaxLineSizeText(v string) error {
+	return capnp.Struct(s).SetText(4, v)
+}
 
-func (f Conmon_CreateContainerResponse_Future) Struct() (Conmon_CreateContainerResponse, error) {
-	p, err := f.Future.Ptr()
-	return Conmon_CreateContainerResponse(p.Struct()), err
+func (s Conmon_LogDriver) Stream() Conmon_LogDriver_Stream {
+	return Conmon_LogDriver_Stream(capnp.Struct(s).Uint16(46))
 }
 
-type Conmon_ExecSyncContainerRequest capnp.Struct
+func (s Conmon_LogDriver) SetStream(v Conmon_LogDriver_Stream) {
+	capnp.Struct(s).SetUint16(46, uint16(v))
+}
 
-// Conmon_ExecSyncContainerRequest_TypeID is the unique identifier for the type Conmon_ExecSyncContainerRequest.
-const Conmon_ExecSyncContainerRequest_TypeID = 0xf41122f890a371a6
+func (s Conmon_LogDriver) TimestampSource() Conmon_LogDriver_TimestampSource {
+	return Conmon_LogDriver_TimestampSource(capnp.Struct(s).Uint16(56))
+}
 
-func NewConmon_ExecSyncContainerRequest(s *capnp.Segment) (Conmon_ExecSyncContainerRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 5})
-	return Conmon_ExecSyncContainerRequest(st), err
+func (s Conmon_LogDriver) SetTimestampSource(v Conmon_LogDriver_TimestampSource) {
+	capnp.Struct(s).SetUint16(56, uint16(v))
 }
 
-func NewRootConmon_ExecSyncContainerRequest(s *capnp.Segment) (Conmon_ExecSyncContainerRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 5})
-	return Conmon_ExecSyncContainerRequest(st), err
+func (s Conmon_LogDriver) RotateInterval() uint64 {
+	return capnp.Struct(s).Uint64(64)
 }
 
-func ReadRootConmon_ExecSyncContainerRequest(msg *capnp.Message) (Conmon_ExecSyncContainerRequest, error) {
-	root, err := msg.Root()
-	return Conmon_ExecSyncContainerRequest(root.Struct()), err
+func (s Conmon_LogDriver) SetRotateInterval(v uint64) {
+	capnp.Struct(s).SetUint64(64, v)
 }
 
-func (s Conmon_ExecSyncContainerRequest) String() string {
-	str, _ := text.Marshal(0xf41122f890a371a6, capnp.Struct(s))
-	return str
+func (s Conmon_LogDriver) PipeLabel() Conmon_LogDriver_PipeLabel {
+	return Conmon_LogDriver_PipeLabel(capnp.Struct(s).Uint16(58))
 }
 
-func (s Conmon_ExecSyncContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
-	return capnp.Struct(s).EncodeAsPtr(seg)
+func (s Conmon_LogDriver) SetPipeLabel(v Conmon_LogDriver_PipeLabel) {
+	capnp.Struct(s).SetUint16(58, uint16(v))
 }
 
-func (Conmon_ExecSyncContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ExecSyncContainerRequest {
-	return Conmon_ExecSyncContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+func (s Conmon_LogDriver) WatchPath() bool {
+	return capnp.Struct(s).Bit(35)
 }
 
-func (s Conmon_ExecSyncContainerRequest) ToPtr() capnp.Ptr {
-	return capnp.Struct(s).ToPtr()
+func (s Conmon_LogDriver) SetWatchPath(v bool) {
+	capnp.Struct(s).SetBit(35, v)
 }
-func (s Conmon_ExecSyncContainerRequest) IsValid() bool {
-	return capnp.Struct(s).IsValid()
+
+func (s Conmon_LogDriver) Sequence() bool {
+	return capnp.Struct(s).Bit(36)
 }
 
-func (s Conmon_ExecSyncContainerRequest) Message() *capnp.Message {
-	return capnp.Struct(s).Message()
+func (s Conmon_LogDriver) SetSequence(v bool) {
+	capnp.Struct(s).SetBit(36, v)
 }
 
-func (s Conmon_ExecSyncContainerRequest) Segment() *capnp.Segment {
-	return capnp.Struct(s).Segment()
+func (s Conmon_LogDriver) Compression() Conmon_LogDriver_Compression {
+	return Conmon_LogDriver_Compression(capnp.Struct(s).Uint16(60))
 }
-func (s Conmon_ExecSyncContainerRequest) Id() (string, error) {
-	p, err := capnp.Struct(s).Ptr(0)
-	return p.Text(), err
+
+func (s Conmon_LogDriver) SetCompression(v Conmon_LogDriver_Compression) {
+	capnp.Struct(s).SetUint16(60, uint16(v))
 }
 
-func (s Conmon_ExecSyncContainerRequest) HasId() bool {
-	return capnp.Struct(s).HasPtr(0)
+func (s Conmon_LogDriver) BufferSize() uint64 {
+	return capnp.Struct(s).Uint64(72)
 }
 
-func (s Conmon_ExecSyncContainerRequest) IdBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
-	return p.TextBytes(), err
+func (s Conmon_LogDriver) SetBufferSize(v uint64) {
+	capnp.Struct(s).SetUint64(72, v)
 }
 
-func (s Conmon_ExecSyncContainerRequest) SetId(v string) error {
-	return capnp.Struct(s).SetText(0, v)
+func (s Conmon_LogDriver) BatchSize() uint64 {
+	return capnp.Struct(s).Uint64(80)
 }
 
-func (s Conmon_ExecSyncContainerRequest) TimeoutSec() uint64 {
-	return capnp.Struct(s).Uint64(0)
+func (s Conmon_LogDriver) SetBatchSize(v uint64) {
+	capnp.Struct(s).SetUint64(80, v)
 }
 
-func (s Conmon_ExecSyncContainerRequest) SetTimeoutSec(v uint64) {
-	capnp.Struct(s).SetUint64(0, v)
+func (s Conmon_LogDriver) MaxBufferedLines() uint64 {
+	return capnp.Struct(s).Uint64(88)
 }
 
-func (s Conmon_ExecSyncContainerRequest) Command() (capnp.TextList, error) {
-	p, err := capnp.Struct(s).Ptr(1)
+func (s Conmon_LogDriver) SetMaxBufferedLines(v uint64) {
+	capnp.Struct(s).SetUint64(88, v)
+}
+
+func (s Conmon_LogDriver) RepeatWindow() uint64 {
+	return capnp.Struct(s).Uint64(96)
+}
+
+func (s Conmon_LogDriver) SetRepeatWindow(v uint64) {
+	capnp.Struct(s).SetUint64(96, v)
+}
+
+func (s Conmon_LogDriver) RedactPatterns() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(5)
 	return capnp.TextList(p.List()), err
 }
 
-func (s Conmon_ExecSyncContainerRequest) HasCommand() bool {
-	return capnp.Struct(s).HasPtr(1)
+func (s Conmon_LogDriver) HasRedactPatterns() bool {
+	return capnp.Struct(s).HasPtr(5)
 }
 
-func (s Conmon_ExecSyncContainerRequest) SetCommand(v capnp.TextList) error {
-	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+func (s Conmon_LogDriver) SetRedactPatterns(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(5, v.ToPtr())
 }
 
-// NewCommand sets the command field to a newly
+// NewRedactPatterns sets the redactPatterns field to a newly
 // allocated capnp.TextList, preferring placement in s's segment.
-func (s Conmon_ExecSyncContainerRequest) NewCommand(n int32) (capnp.TextList, error) {
+func (s Conmon_LogDriver) NewRedactPatterns(n int32) (capnp.TextList, error) {
 	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
 	if err != nil {
 		return capnp.TextList{}, err
 	}
-	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	err = capnp.Struct(s).SetPtr(5, l.ToPtr())
 	return l, err
 }
-func (s Conmon_ExecSyncContainerRequest) Terminal() bool {
-	return capnp.Struct(s).Bit(64)
+func (s Conmon_LogDriver) AddHostname() bool {
+	return capnp.Struct(s).Bit(37)
 }
 
-func (s Conmon_ExecSyncContainerRequest) SetTerminal(v bool) {
-	capnp.Struct(s).SetBit(64, v)
+func (s Conmon_LogDriver) SetAddHostname(v bool) {
+	capnp.Struct(s).SetBit(37, v)
 }
 
-func (s Conmon_ExecSyncContainerRequest) MetadataOld() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(2)
-	return []byte(p.Data()), err
+func (s Conmon_LogDriver) Hostname() (string, error) {
+	p, err := capnp.Struct(s).Ptr(6)
+	return p.Text(), err
 }
 
-func (s Conmon_ExecSyncContainerRequest) HasMetadataOld() bool {
-	return capnp.Struct(s).HasPtr(2)
+func (s Conmon_LogDriver) HasHostname() bool {
+	return capnp.Struct(s).HasPtr(6)
 }
 
-func (s Conmon_ExecSyncContainerRequest) SetMetadataOld(v []byte) error {
-	return capnp.Struct(s).SetData(2, v)
+func (s Conmon_LogDriver) HostnameBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(6)
+	return p.TextBytes(), err
 }
 
-func (s Conmon_ExecSyncContainerRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
-	p, err := capnp.Struct(s).Ptr(3)
-	return Conmon_TextTextMapEntry_List(p.List()), err
+func (s Conmon_LogDriver) SetHostname(v string) error {
+	return capnp.Struct(s).SetText(6, v)
 }
 
-func (s Conmon_ExecSyncContainerRequest) HasMetadata() bool {
-	return capnp.Struct(s).HasPtr(3)
+func (s Conmon_LogDriver) FileMode() uint32 {
+	return capnp.Struct(s).Uint32(104)
 }
 
-func (s Conmon_ExecSyncContainerRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
-	return capnp.Struct(s).SetPtr(3, v.ToPtr())
+func (s Conmon_LogDriver) SetFileMode(v uint32) {
+	capnp.Struct(s).SetUint32(104, v)
 }
 
-// NewMetadata sets the metadata field to a newly
-// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
-func (s Conmon_ExecSyncContainerRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
-	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
-	if err != nil {
-		return Conmon_TextTextMapEntry_List{}, err
-	}
-	err = capnp.Struct(s).SetPtr(3, l.ToPtr())
-	return l, err
-}
-func (s Conmon_ExecSyncContainerRequest) EnvVars() (Conmon_TextTextMapEntry_List, error) {
-	p, err := capnp.Struct(s).Ptr(4)
-	return Conmon_TextTextMapEntry_List(p.List()), err
+func (s Conmon_LogDriver) FileUid() int64 {
+	return int64(capnp.Struct(s).Uint64(112) ^ 18446744073709551615)
 }
 
-func (s Conmon_ExecSyncContainerRequest) HasEnvVars() bool {
-	return capnp.Struct(s).HasPtr(4)
+func (s Conmon_LogDriver) SetFileUid(v int64) {
+	capnp.Struct(s).SetUint64(112, uint64(v)^18446744073709551615)
 }
 
-func (s Conmon_ExecSyncContainerRequest) SetEnvVars(v Conmon_TextTextMapEntry_List) error {
-	return capnp.Struct(s).SetPtr(4, v.ToPtr())
+func (s Conmon_LogDriver) FileGid() int64 {
+	return int64(capnp.Struct(s).Uint64(120) ^ 18446744073709551615)
 }
 
-// NewEnvVars sets the envVars field to a newly
-// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
-func (s Conmon_ExecSyncContainerRequest) NewEnvVars(n int32) (Conmon_TextTextMapEntry_List, error) {
-	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
-	if err != nil {
-		return Conmon_TextTextMapEntry_List{}, err
-	}
-	err = capnp.Struct(s).SetPtr(4, l.ToPtr())
-	return l, err
-}
-func (s Conmon_ExecSyncContainerRequest) CgroupManager() Conmon_CgroupManager {
-	return Conmon_CgroupManager(capnp.Struct(s).Uint16(10))
+func (s Conmon_LogDriver) SetFileGid(v int64) {
+	capnp.Struct(s).SetUint64(120, uint64(v)^18446744073709551615)
 }
 
-func (s Conmon_ExecSyncContainerRequest) SetCgroupManager(v Conmon_CgroupManager) {
-	capnp.Struct(s).SetUint16(10, uint16(v))
+func (s Conmon_LogDriver) MaxSizeMode() Conmon_LogDriver_MaxSizeMode {
+	return Conmon_LogDriver_MaxSizeMode(capnp.Struct(s).Uint16(62))
 }
 
-// Conmon_ExecSyncContainerRequest_List is a list of Conmon_ExecSyncContainerRequest.
-type Conmon_ExecSyncContainerRequest_List = capnp.StructList[Conmon_ExecSyncContainerRequest]
-
-// NewConmon_ExecSyncContainerRequest creates a new list of Conmon_ExecSyncContainerRequest.
-func NewConmon_ExecSyncContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_ExecSyncContainerRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 5}, sz)
-	return capnp.StructList[Conmon_ExecSyncContainerRequest](l), err
+func (s Conmon_LogDriver) SetMaxSizeMode(v Conmon_LogDriver_MaxSizeMode) {
+	capnp.Struct(s).SetUint16(62, uint16(v))
 }
 
-// Conmon_ExecSyncContainerRequest_Future is a wrapper for a Conmon_ExecSyncContainerRequest promised by a client call.
-type Conmon_ExecSyncContainerRequest_Future struct{ *capnp.Future }
+func (s Conmon_LogDriver) DiskFullRetries() uint32 {
+	return capnp.Struct(s).Uint32(108)
+}
 
-func (f Conmon_ExecSyncContainerRequest_Future) Struct() (Conmon_ExecSyncContainerRequest, error) {
-	p, err := f.Future.Ptr()
-	return Conmon_ExecSyncContainerRequest(p.Struct()), err
+func (s Conmon_LogDriver) SetDiskFullRetries(v uint32) {
+	capnp.Struct(s).SetUint32(108, v)
 }
 
-type Conmon_ExecSyncContainerResponse capnp.Struct
+func (s Conmon_LogDriver) MessageKey() (string, error) {
+	p, err := capnp.Struct(s).Ptr(7)
+	return p.Text(), err
+}
 
-// Conmon_ExecSyncContainerResponse_TypeID is the unique identifier for the type Conmon_ExecSyncContainerResponse.
-const Conmon_ExecSyncContainerResponse_TypeID = 0xd9d61d1d803c85fc
+func (s Conmon_LogDriver) HasMessageKey() bool {
+	return capnp.Struct(s).HasPtr(7)
+}
 
-func NewConmon_ExecSyncContainerResponse(s *capnp.Segment) (Conmon_ExecSyncContainerResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
-	return Conmon_ExecSyncContainerResponse(st), err
+func (s Conmon_LogDriver) MessageKeyBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(7)
+	return p.TextBytes(), err
 }
 
-func NewRootConmon_ExecSyncContainerResponse(s *capnp.Segment) (Conmon_ExecSyncContainerResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
-	return Conmon_ExecSyncContainerResponse(st), err
+func (s Conmon_LogDriver) SetMessageKey(v string) error {
+	return capnp.Struct(s).SetText(7, v)
 }
 
-func ReadRootConmon_ExecSyncContainerResponse(msg *capnp.Message) (Conmon_ExecSyncContainerResponse, error) {
-	root, err := msg.Root()
-	return Conmon_ExecSyncContainerResponse(root.Struct()), err
+func (s Conmon_LogDriver) TimestampKey() (string, error) {
+	p, err := capnp.Struct(s).Ptr(8)
+	return p.Text(), err
 }
 
-func (s Conmon_ExecSyncContainerResponse) String() string {
-	str, _ := text.Marshal(0xd9d61d1d803c85fc, capnp.Struct(s))
-	return str
+func (s Conmon_LogDriver) HasTimestampKey() bool {
+	return capnp.Struct(s).HasPtr(8)
 }
 
-func (s Conmon_ExecSyncContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
-	return capnp.Struct(s).EncodeAsPtr(seg)
+func (s Conmon_LogDriver) TimestampKeyBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(8)
+	return p.TextBytes(), err
 }
 
-func (Conmon_ExecSyncContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ExecSyncContainerResponse {
-	return Conmon_ExecSyncContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+func (s Conmon_LogDriver) SetTimestampKey(v string) error {
+	return capnp.Struct(s).SetText(8, v)
 }
 
-func (s Conmon_ExecSyncContainerResponse) ToPtr() capnp.Ptr {
-	return capnp.Struct(s).ToPtr()
+func (s Conmon_LogDriver) PipeKey() (string, error) {
+	p, err := capnp.Struct(s).Ptr(9)
+	return p.Text(), err
 }
-func (s Conmon_ExecSyncContainerResponse) IsValid() bool {
-	return capnp.Struct(s).IsValid()
+
+func (s Conmon_LogDriver) HasPipeKey() bool {
+	return capnp.Struct(s).HasPtr(9)
 }
 
-func (s Conmon_ExecSyncContainerResponse) Message() *capnp.Message {
-	return capnp.Struct(s).Message()
+func (s Conmon_LogDriver) PipeKeyBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(9)
+	return p.TextBytes(), err
 }
 
-func (s Conmon_ExecSyncContainerResponse) Segment() *capnp.Segment {
-	return capnp.Struct(s).Segment()
+func (s Conmon_LogDriver) SetPipeKey(v string) error {
+	return capnp.Struct(s).SetText(9, v)
 }
-func (s Conmon_ExecSyncContainerResponse) ExitCode() int32 {
-	return int32(capnp.Struct(s).Uint32(0))
+
+func (s Conmon_LogDriver) Required() bool {
+	return !capnp.Struct(s).Bit(38)
 }
 
-func (s Conmon_ExecSyncContainerResponse) SetExitCode(v int32) {
-	capnp.Struct(s).SetUint32(0, uint32(v))
+func (s Conmon_LogDriver) SetRequired(v bool) {
+	capnp.Struct(s).SetBit(38, !v)
 }
 
-func (s Conmon_ExecSyncContainerResponse) Stdout() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
-	return []byte(p.Data()), err
+func (s Conmon_LogDriver) FlushEveryLines() uint64 {
+	return capnp.Struct(s).Uint64(128)
 }
 
-func (s Conmon_ExecSyncContainerResponse) HasStdout() bool {
-	return capnp.Struct(s).HasPtr(0)
+func (s Conmon_LogDriver) SetFlushEveryLines(v uint64) {
+	capnp.Struct(s).SetUint64(128, v)
 }
 
-func (s Conmon_ExecSyncContainerResponse) SetStdout(v []byte) error {
-	return capnp.Struct(s).SetData(0, v)
+func (s Conmon_LogDriver) FlushEveryBytes() uint64 {
+	return capnp.Struct(s).Uint64(136)
 }
 
-func (s Conmon_ExecSyncContainerResponse) Stderr() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(1)
-	return []byte(p.Data()), err
+func (s Conmon_LogDriver) SetFlushEveryBytes(v uint64) {
+	capnp.Struct(s).SetUint64(136, v)
 }
 
-func (s Conmon_ExecSyncContainerResponse) HasStderr() bool {
-	return capnp.Struct(s).HasPtr(1)
+func (s Conmon_LogDriver) EmptyLines() Conmon_LogDriver_EmptyLines {
+	return Conmon_LogDriver_EmptyLines(capnp.Struct(s).Uint16(144))
 }
 
-func (s Conmon_ExecSyncContainerResponse) SetStderr(v []byte) error {
-	return capnp.Struct(s).SetData(1, v)
+func (s Conmon_LogDriver) SetEmptyLines(v Conmon_LogDriver_EmptyLines) {
+	capnp.Struct(s).SetUint16(144, uint16(v))
 }
 
-func (s Conmon_ExecSyncContainerResponse) TimedOut() bool {
-	return capnp.Struct(s).Bit(32)
+func (s Conmon_LogDriver) FallbackPath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(10)
+	return p.Text(), err
 }
 
-func (s Conmon_ExecSyncContainerResponse) SetTimedOut(v bool) {
-	capnp.Struct(s).SetBit(32, v)
+func (s Conmon_LogDriver) HasFallbackPath() bool {
+	return capnp.Struct(s).HasPtr(10)
 }
 
-// Conmon_ExecSyncContainerResponse_List is a list of Conmon_ExecSyncContainerResponse.
-type Conmon_ExecSyncContainerResponse_List = capnp.StructList[Conmon_ExecSyncContainerResponse]
+func (s Conmon_LogDriver) FallbackPathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(10)
+	return p.TextBytes(), err
+}
 
-// NewConmon_ExecSyncContainerResponse creates a new list of Conmon_ExecSyncContainerResponse.
-func NewConmon_ExecSyncContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_ExecSyncContainerResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2}, sz)
-	return capnp.StructList[Conmon_ExecSyncContainerResponse](l), err
+func (s Conmon_LogDriver) SetFallbackPath(v string) error {
+	return capnp.Struct(s).SetText(10, v)
 }
 
-// Conmon_ExecSyncContainerResponse_Future is a wrapper for a Conmon_ExecSyncContainerResponse promised by a client call.
-type Conmon_ExecSyncContainerResponse_Future struct{ *capnp.Future }
+func (s Conmon_LogDriver) TraceContext() bool {
+	return capnp.Struct(s).Bit(39)
+}
 
-func (f Conmon_ExecSyncContainerResponse_Future) Struct() (Conmon_ExecSyncContainerResponse, error) {
-	p, err := f.Future.Ptr()
-	return Conmon_ExecSyncContainerResponse(p.Struct()), err
+func (s Conmon_LogDriver) SetTraceContext(v bool) {
+	capnp.Struct(s).SetBit(39, v)
 }
 
-type Conmon_AttachRequest capnp.Struct
+func (s Conmon_LogDriver) BackupFormat() (string, error) {
+	p, err := capnp.Struct(s).Ptr(11)
+	return p.Text(), err
+}
 
-// Conmon_AttachRequest_TypeID is the unique identifier for the type Conmon_AttachRequest.
-const Conmon_AttachRequest_TypeID = 0xdf703ca0befc3afc
+func (s Conmon_LogDriver) HasBackupFormat() bool {
+	return capnp.Struct(s).HasPtr(11)
+}
 
-func NewConmon_AttachRequest(s *capnp.Segment) (Conmon_AttachRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5})
-	return Conmon_AttachRequest(st), err
+func (s Conmon_LogDriver) BackupFormatBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(11)
+	return p.TextBytes(), err
 }
 
-func NewRootConmon_AttachRequest(s *capnp.Segment) (Conmon_AttachRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5})
-	return Conmon_AttachRequest(st), err
+func (s Conmon_LogDriver) SetBackupFormat(v string) error {
+	return capnp.Struct(s).SetText(11, v)
 }
 
-func ReadRootConmon_AttachRequest(msg *capnp.Message) (Conmon_AttachRequest, error) {
-	root, err := msg.Root()
-	return Conmon_AttachRequest(root.Struct()), err
+func (s Conmon_LogDriver) Timezone() (string, error) {
+	p, err := capnp.Struct(s).Ptr(12)
+	return p.Text(), err
 }
 
-func (s Conmon_AttachRequest) String() string {
-	str, _ := text.Marshal(0xdf703ca0befc3afc, capnp.Struct(s))
-	return str
+func (s Conmon_LogDriver) HasTimezone() bool {
+	return capnp.Struct(s).HasPtr(12)
 }
 
-func (s Conmon_AttachRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
-	return capnp.Struct(s).EncodeAsPtr(seg)
+func (s Conmon_LogDriver) TimezoneBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(12)
+	return p.TextBytes(), err
 }
 
-func (Conmon_AttachRequest) DecodeFromPtr(p capnp.Ptr) Conmon_AttachRequest {
-	return Conmon_AttachRequest(capnp.Struct{}.DecodeFromPtr(p))
+func (s Conmon_LogDriver) SetTimezone(v string) error {
+	return capnp.Struct(s).SetText(12, v)
 }
 
-func (s Conmon_AttachRequest) ToPtr() capnp.Ptr {
-	return capnp.Struct(s).ToPtr()
+func (s Conmon_LogDriver) ContainerName() (string, error) {
+	p, err := capnp.Struct(s).Ptr(13)
+	return p.Text(), err
 }
-func (s Conmon_AttachRequest) IsValid() bool {
-	return capnp.Struct(s).IsValid()
+
+func (s Conmon_LogDriver) HasContainerName() bool {
+	return capnp.Struct(s).HasPtr(13)
 }
 
-func (s Conmon_AttachRequest) Message() *capnp.Message {
-	return capnp.Struct(s).Message()
+func (s Conmon_LogDriver) ContainerNameBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(13)
+	return p.TextBytes(), err
 }
 
-func (s Conmon_AttachRequest) Segment() *capnp.Segment {
-	return capnp.Struct(s).Segment()
+func (s Conmon_LogDriver) SetContainerName(v string) error {
+	return capnp.Struct(s).SetText(13, v)
 }
-func (s Conmon_AttachRequest) Id() (string, error) {
-	p, err := capnp.Struct(s).Ptr(0)
+
+func (s Conmon_LogDriver) Image() (string, error) {
+	p, err := capnp.Struct(s).Ptr(14)
 	return p.Text(), err
 }
 
-func (s Conmon_AttachRequest) HasId() bool {
-	return capnp.Struct(s).HasPtr(0)
+func (s Conmon_LogDriver) HasImage() bool {
+	return capnp.Struct(s).HasPtr(14)
 }
 
-func (s Conmon_AttachRequest) IdBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
+func (s Conmon_LogDriver) ImageBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(14)
 	return p.TextBytes(), err
 }
 
-func (s Conmon_AttachRequest) SetId(v string) error {
-	return capnp.Struct(s).SetText(0, v)
+func (s Conmon_LogDriver) SetImage(v string) error {
+	return capnp.Struct(s).SetText(14, v)
 }
 
-func (s Conmon_AttachRequest) SocketPath() (string, error) {
-	p, err := capnp.Struct(s).Ptr(1)
+func (s Conmon_LogDriver) PartialTag() (string, error) {
+	p, err := capnp.Struct(s).Ptr(15)
 	return p.Text(), err
 }
 
-func (s Conmon_AttachRequest) HasSocketPath() bool {
-	return capnp.Struct(s).HasPtr(1)
+func (s Conmon_LogDriver) HasPartialTag() bool {
+	return capnp.Struct(s).HasPtr(15)
 }
 
-func (s Conmon_AttachRequest) SocketPathBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(1)
+func (s Conmon_LogDriver) PartialTagBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(15)
 	return p.TextBytes(), err
 }
 
-func (s Conmon_AttachRequest) SetSocketPath(v string) error {
-	return capnp.Struct(s).SetText(1, v)
+func (s Conmon_LogDriver) SetPartialTag(v string) error {
+	return capnp.Struct(s).SetText(15, v)
 }
 
-func (s Conmon_AttachRequest) ExecSessionId() (string, error) {
-	p, err := capnp.Struct(s).Ptr(2)
+func (s Conmon_LogDriver) FullTag() (string, error) {
+	p, err := capnp.Struct(s).Ptr(16)
 	return p.Text(), err
 }
 
-func (s Conmon_AttachRequest) HasExecSessionId() bool {
-	return capnp.Struct(s).HasPtr(2)
+func (s Conmon_LogDriver) HasFullTag() bool {
+	return capnp.Struct(s).HasPtr(16)
 }
 
-func (s Conmon_AttachRequest) ExecSessionIdBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(2)
+func (s Conmon_LogDriver) FullTagBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(16)
 	return p.TextBytes(), err
 }
 
-func (s Conmon_AttachRequest) SetExecSessionId(v string) error {
-	return capnp.Struct(s).SetText(2, v)
+func (s Conmon_LogDriver) SetFullTag(v string) error {
+	return capnp.Struct(s).SetText(16, v)
 }
 
-func (s Conmon_AttachRequest) StopAfterStdinEof() bool {
-	return capnp.Struct(s).Bit(0)
+func (s Conmon_LogDriver) NoFollow() bool {
+	return capnp.Struct(s).Bit(40)
 }
 
-func (s Conmon_AttachRequest) SetStopAfterStdinEof(v bool) {
-	capnp.Struct(s).SetBit(0, v)
+func (s Conmon_LogDriver) SetNoFollow(v bool) {
+	capnp.Struct(s).SetBit(40, v)
 }
 
-func (s Conmon_AttachRequest) MetadataOld() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(3)
-	return []byte(p.Data()), err
+func (s Conmon_LogDriver) ReconcileInterval() uint64 {
+	return capnp.Struct(s).Uint64(152)
 }
 
-func (s Conmon_AttachRequest) HasMetadataOld() bool {
-	return capnp.Struct(s).HasPtr(3)
+func (s Conmon_LogDriver) SetReconcileInterval(v uint64) {
+	capnp.Struct(s).SetUint64(152, v)
 }
 
-func (s Conmon_AttachRequest) SetMetadataOld(v []byte) error {
-	return capnp.Struct(s).SetData(3, v)
+func (s Conmon_LogDriver) LinkPath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(17)
+	return p.Text(), err
 }
 
-func (s Conmon_AttachRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
-	p, err := capnp.Struct(s).Ptr(4)
-	return Conmon_TextTextMapEntry_List(p.List()), err
+func (s Conmon_LogDriver) HasLinkPath() bool {
+	return capnp.Struct(s).HasPtr(17)
 }
 
-func (s Conmon_AttachRequest) HasMetadata() bool {
-	return capnp.Struct(s).HasPtr(4)
+func (s Conmon_LogDriver) LinkPathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(17)
+	return p.TextBytes(), err
 }
 
-func (s Conmon_AttachRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
-	return capnp.Struct(s).SetPtr(4, v.ToPtr())
+func (s Conmon_LogDriver) SetLinkPath(v string) error {
+	return capnp.Struct(s).SetText(17, v)
 }
 
-// NewMetadata sets the metadata field to a newly
-// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
-func (s Conmon_AttachRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
-	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
-	if err != nil {
-		return Conmon_TextTextMapEntry_List{}, err
-	}
-	err = capnp.Struct(s).SetPtr(4, l.ToPtr())
-	return l, err
+func (s Conmon_LogDriver) WriteTimeout() uint64 {
+	return capnp.Struct(s).Uint64(160)
 }
 
-// Conmon_AttachRequest_List is a list of Conmon_AttachRequest.
-type Conmon_AttachRequest_List = capnp.StructList[Conmon_AttachRequest]
+func (s Conmon_LogDriver) SetWriteTimeout(v uint64) {
+	capnp.Struct(s).SetUint64(160, v)
+}
 
-// NewConmon_AttachRequest creates a new list of Conmon_AttachRequest.
-func NewConmon_AttachRequest_List(s *capnp.Segment, sz int32) (Conmon_AttachRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5}, sz)
-	return capnp.StructList[Conmon_AttachRequest](l), err
+func (s Conmon_LogDriver) DockerCompat() bool {
+	return capnp.Struct(s).Bit(41)
 }
 
-// Conmon_AttachRequest_Future is a wrapper for a Conmon_AttachRequest promised by a client call.
-type Conmon_AttachRequest_Future struct{ *capnp.Future }
+func (s Conmon_LogDriver) SetDockerCompat(v bool) {
+	capnp.Struct(s).SetBit(41, v)
+}
 
-func (f Conmon_AttachRequest_Future) Struct() (Conmon_AttachRequest, error) {
-	p, err := f.Future.Ptr()
-	return Conmon_AttachRequest(p.Struct()), err
+func (s Conmon_LogDriver) Checksum() bool {
+	return capnp.Struct(s).Bit(42)
 }
 
-type Conmon_AttachResponse capnp.Struct
+func (s Conmon_LogDriver) SetChecksum(v bool) {
+	capnp.Struct(s).SetBit(42, v)
+}
 
-// Conmon_AttachResponse_TypeID is the unique identifier for the type Conmon_AttachResponse.
-const Conmon_AttachResponse_TypeID = 0xace5517aafc86077
+func (s Conmon_LogDriver) Multiline() bool {
+	return capnp.Struct(s).Bit(43)
+}
 
-func NewConmon_AttachResponse(s *capnp.Segment) (Conmon_AttachResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_AttachResponse(st), err
+func (s Conmon_LogDriver) SetMultiline(v bool) {
+	capnp.Struct(s).SetBit(43, v)
 }
 
-func NewRootConmon_AttachResponse(s *capnp.Segment) (Conmon_AttachResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_AttachResponse(st), err
+func (s Conmon_LogDriver) MultilinePattern() (string, error) {
+	p, err := capnp.Struct(s).Ptr(18)
+	return p.Text(), err
 }
 
-func ReadRootConmon_AttachResponse(msg *capnp.Message) (Conmon_AttachResponse, error) {
-	root, err := msg.Root()
-	return Conmon_AttachResponse(root.Struct()), err
+func (s Conmon_LogDriver) HasMultilinePattern() bool {
+	return capnp.Struct(s).HasPtr(18)
 }
 
-func (s Conmon_AttachResponse) String() string {
-	str, _ := text.Marshal(0xace5517aafc86077, capnp.Struct(s))
-	return str
+func (s Conmon_LogDriver) MultilinePatternBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(18)
+	return p.TextBytes(), err
 }
 
-func (s Conmon_AttachResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
-	return capnp.Struct(s).EncodeAsPtr(seg)
+func (s Conmon_LogDriver) SetMultilinePattern(v string) error {
+	return capnp.Struct(s).SetText(18, v)
 }
 
-func (Conmon_AttachResponse) DecodeFromPtr(p capnp.Ptr) Conmon_AttachResponse {
-	return Conmon_AttachResponse(capnp.Struct{}.DecodeFromPtr(p))
+func (s Conmon_LogDriver) MultilineTimeout() uint64 {
+	return capnp.Struct(s).Uint64(168)
 }
 
-func (s Conmon_AttachResponse) ToPtr() capnp.Ptr {
-	return capnp.Struct(s).ToPtr()
-}
-func (s Conmon_AttachResponse) IsValid() bool {
-	return capnp.Struct(s).IsValid()
+func (s Conmon_LogDriver) SetMultilineTimeout(v uint64) {
+	capnp.Struct(s).SetUint64(168, v)
 }
 
-func (s Conmon_AttachResponse) Message() *capnp.Message {
-	return capnp.Struct(s).Message()
+func (s Conmon_LogDriver) UploadEndpoint() (string, error) {
+	p, err := capnp.Struct(s).Ptr(19)
+	return p.Text(), err
 }
 
-func (s Conmon_AttachResponse) Segment() *capnp.Segment {
-	return capnp.Struct(s).Segment()
+func (s Conmon_LogDriver) HasUploadEndpoint() bool {
+	return capnp.Struct(s).HasPtr(19)
 }
 
-// Conmon_AttachResponse_List is a list of Conmon_AttachResponse.
-type Conmon_AttachResponse_List = capnp.StructList[Conmon_AttachResponse]
-
-// NewConmon_AttachResponse creates a new list of Conmon_AttachResponse.
-func NewConmon_AttachResponse_List(s *capnp.Segment, sz int32) (Conmon_AttachResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
-	return capnp.StructList[Conmon_AttachResponse](l), err
+func (s Conmon_LogDriver) UploadEndpointBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(19)
+	return p.TextBytes(), err
 }
 
-// Conmon_AttachResponse_Future is a wrapper for a Conmon_AttachResponse promised by a client call.
-type Conmon_AttachResponse_Future struct{ *capnp.Future }
-
-func (f Conmon_AttachResponse_Future) Struct() (Conmon_AttachResponse, error) {
-	p, err := f.Future.Ptr()
-	return Conmon_AttachResponse(p.Struct()), err
+func (s Conmon_LogDriver) SetUploadEndpoint(v string) error {
+	return capnp.Struct(s).SetText(19, v)
 }
 
-type Conmon_ReopenLogRequest capnp.Struct
+func (s Conmon_LogDriver) UploadBucket() (string, error) {
+	p, err := capnp.Struct(s).Ptr(20)
+	return p.Text(), err
+}
 
-// Conmon_ReopenLogRequest_TypeID is the unique identifier for the type Conmon_ReopenLogRequest.
-const Conmon_ReopenLogRequest_TypeID = 0xd0476e0f34d1411a
+func (s Conmon_LogDriver) HasUploadBucket() bool {
+	return capnp.Struct(s).HasPtr(20)
+}
 
-func NewConmon_ReopenLogRequest(s *capnp.Segment) (Conmon_ReopenLogRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
-	return Conmon_ReopenLogRequest(st), err
+func (s Conmon_LogDriver) UploadBucketBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(20)
+	return p.TextBytes(), err
 }
 
-func NewRootConmon_ReopenLogRequest(s *capnp.Segment) (Conmon_ReopenLogRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
-	return Conmon_ReopenLogRequest(st), err
+func (s Conmon_LogDriver) SetUploadBucket(v string) error {
+	return capnp.Struct(s).SetText(20, v)
 }
 
-func ReadRootConmon_ReopenLogRequest(msg *capnp.Message) (Conmon_ReopenLogRequest, error) {
-	root, err := msg.Root()
-	return Conmon_ReopenLogRequest(root.Struct()), err
+func (s Conmon_LogDriver) UploadPrefix() (string, error) {
+	p, err := capnp.Struct(s).Ptr(21)
+	return p.Text(), err
 }
 
-func (s Conmon_ReopenLogRequest) String() string {
-	str, _ := text.Marshal(0xd0476e0f34d1411a, capnp.Struct(s))
-	return str
+func (s Conmon_LogDriver) HasUploadPrefix() bool {
+	return capnp.Struct(s).HasPtr(21)
 }
 
-func (s Conmon_ReopenLogRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
-	return capnp.Struct(s).EncodeAsPtr(seg)
+func (s Conmon_LogDriver) UploadPrefixBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(21)
+	return p.TextBytes(), err
 }
 
-func (Conmon_ReopenLogRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ReopenLogRequest {
-	return Conmon_ReopenLogRequest(capnp.Struct{}.DecodeFromPtr(p))
+func (s Conmon_LogDriver) SetUploadPrefix(v string) error {
+	return capnp.Struct(s).SetText(21, v)
 }
 
-func (s Conmon_ReopenLogRequest) ToPtr() capnp.Ptr {
-	return capnp.Struct(s).ToPtr()
+func (s Conmon_LogDriver) UploadRegion() (string, error) {
+	p, err := capnp.Struct(s).Ptr(22)
+	return p.Text(), err
 }
-func (s Conmon_ReopenLogRequest) IsValid() bool {
-	return capnp.Struct(s).IsValid()
+
+func (s Conmon_LogDriver) HasUploadRegion() bool {
+	return capnp.Struct(s).HasPtr(22)
 }
 
-func (s Conmon_ReopenLogRequest) Message() *capnp.Message {
-	return capnp.Struct(s).Message()
+func (s Conmon_LogDriver) UploadRegionBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(22)
+	return p.TextBytes(), err
 }
 
-func (s Conmon_ReopenLogRequest) Segment() *capnp.Segment {
-	return capnp.Struct(s).Segment()
+func (s Conmon_LogDriver) SetUploadRegion(v string) error {
+	return capnp.Struct(s).SetText(22, v)
 }
-func (s Conmon_ReopenLogRequest) Id() (string, error) {
-	p, err := capnp.Struct(s).Ptr(0)
+
+func (s Conmon_LogDriver) UploadAccessKeyId() (string, error) {
+	p, err := capnp.Struct(s).Ptr(23)
 	return p.Text(), err
 }
 
-func (s Conmon_ReopenLogRequest) HasId() bool {
-	return capnp.Struct(s).HasPtr(0)
+func (s Conmon_LogDriver) HasUploadAccessKeyId() bool {
+	return capnp.Struct(s).HasPtr(23)
 }
 
-func (s Conmon_ReopenLogRequest) IdBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
+func (s Conmon_LogDriver) UploadAccessKeyIdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(23)
 	return p.TextBytes(), err
 }
 
-func (s Conmon_ReopenLogRequest) SetId(v string) error {
-	return capnp.Struct(s).SetText(0, v)
+func (s Conmon_LogDriver) SetUploadAccessKeyId(v string) error {
+	return capnp.Struct(s).SetText(23, v)
 }
 
-func (s Conmon_ReopenLogRequest) MetadataOld() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(1)
-	return []byte(p.Data()), err
+func (s Conmon_LogDriver) UploadSecretAccessKey() (string, error) {
+	p, err := capnp.Struct(s).Ptr(24)
+	return p.Text(), err
 }
 
-func (s Conmon_ReopenLogRequest) HasMetadataOld() bool {
-	return capnp.Struct(s).HasPtr(1)
+func (s Conmon_LogDriver) HasUploadSecretAccessKey() bool {
+	return capnp.Struct(s).HasPtr(24)
 }
 
-func (s Conmon_ReopenLogRequest) SetMetadataOld(v []byte) error {
-	return capnp.Struct(s).SetData(1, v)
+func (s Conmon_LogDriver) UploadSecretAccessKeyBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(24)
+	return p.TextBytes(), err
 }
 
-func (s Conmon_ReopenLogRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
-	p, err := capnp.Struct(s).Ptr(2)
-	return Conmon_TextTextMapEntry_List(p.List()), err
+func (s Conmon_LogDriver) SetUploadSecretAccessKey(v string) error {
+	return capnp.Struct(s).SetText(24, v)
 }
 
-func (s Conmon_ReopenLogRequest) HasMetadata() bool {
-	return capnp.Struct(s).HasPtr(2)
+func (s Conmon_LogDriver) UploadDelete() bool {
+	return capnp.Struct(s).Bit(44)
 }
 
-func (s Conmon_ReopenLogRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
-	return capnp.Struct(s).SetPtr(2, v.ToPtr())
+func (s Conmon_LogDriver) SetUploadDelete(v bool) {
+	capnp.Struct(s).SetBit(44, v)
 }
 
-// NewMetadata sets the metadata field to a newly
-// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
-func (s Conmon_ReopenLogRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
-	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
-	if err != nil {
-		return Conmon_TextTextMapEntry_List{}, err
-	}
-	err = capnp.Struct(s).SetPtr(2, l.ToPtr())
-	return l, err
+func (s Conmon_LogDriver) PreserveRaw() bool {
+	return capnp.Struct(s).Bit(45)
 }
 
-// Conmon_ReopenLogRequest_List is a list of Conmon_ReopenLogRequest.
-type Conmon_ReopenLogRequest_List = capnp.StructList[Conmon_ReopenLogRequest]
+func (s Conmon_LogDriver) SetPreserveRaw(v bool) {
+	capnp.Struct(s).SetBit(45, v)
+}
 
-// NewConmon_ReopenLogRequest creates a new list of Conmon_ReopenLogRequest.
-func NewConmon_ReopenLogRequest_List(s *capnp.Segment, sz int32) (Conmon_ReopenLogRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3}, sz)
-	return capnp.StructList[Conmon_ReopenLogRequest](l), err
+// Conmon_LogDriver_List is a list of Conmon_LogDriver.
+type Conmon_LogDriver_List = capnp.StructList[Conmon_LogDriver]
+
+// NewConmon_LogDriver creates a new list of Conmon_LogDriver.
+func NewConmon_LogDriver_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 176, PointerCount: 25}, sz)
+	return capnp.StructList[Conmon_LogDriver](l), err
 }
 
-// Conmon_ReopenLogRequest_Future is a wrapper for a Conmon_ReopenLogRequest promised by a client call.
-type Conmon_ReopenLogRequest_Future struct{ *capnp.Future }
+// Conmon_LogDriver_Future is a wrapper for a Conmon_LogDriver promised by a client call.
+type Conmon_LogDriver_Future struct{ *capnp.Future }
 
-func (f Conmon_ReopenLogRequest_Future) Struct() (Conmon_ReopenLogRequest, error) {
+func (f Conmon_LogDriver_Future) Struct() (Conmon_LogDriver, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_ReopenLogRequest(p.Struct()), err
+	return Conmon_LogDriver(p.Struct()), err
 }
 
-type Conmon_ReopenLogResponse capnp.Struct
+type Conmon_LogDriver_Type uint16
 
-// Conmon_ReopenLogResponse_TypeID is the unique identifier for the type Conmon_ReopenLogResponse.
-const Conmon_ReopenLogResponse_TypeID = 0xa20f49456be85b99
+// Conmon_LogDriver_Type_TypeID is the unique identifier for the type Conmon_LogDriver_Type.
+const Conmon_LogDriver_Type_TypeID = 0xf026e3d750335bc1
 
-func NewConmon_ReopenLogResponse(s *capnp.Segment) (Conmon_ReopenLogResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_ReopenLogResponse(st), err
-}
+// Values of Conmon_LogDriver_Type.
+const (
+	Conmon_LogDriver_Type_containerRuntimeInterface Conmon_LogDriver_Type = 0
+	Conmon_LogDriver_Type_json                      Conmon_LogDriver_Type = 1
+	Conmon_LogDriver_Type_syslog                    Conmon_LogDriver_Type = 2
+	Conmon_LogDriver_Type_journald                  Conmon_LogDriver_Type = 3
+	Conmon_LogDriver_Type_command                   Conmon_LogDriver_Type = 4
+	Conmon_LogDriver_Type_null                      Conmon_LogDriver_Type = 5
+	Conmon_LogDriver_Type_otlp                      Conmon_LogDriver_Type = 6
+	Conmon_LogDriver_Type_memory                    Conmon_LogDriver_Type = 7
+	Conmon_LogDriver_Type_gelf                      Conmon_LogDriver_Type = 8
+)
 
-func NewRootConmon_ReopenLogResponse(s *capnp.Segment) (Conmon_ReopenLogResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_ReopenLogResponse(st), err
-}
+// String returns the enum's constant name.
+func (c Conmon_LogDriver_Type) String() string {
+	switch c {
+	case Conmon_LogDriver_Type_containerRuntimeInterface:
+		return "containerRuntimeInterface"
+	case Conmon_LogDriver_Type_json:
+		return "json"
+	case Conmon_LogDriver_Type_syslog:
+		return "syslog"
+	case Conmon_LogDriver_Type_journald:
+		return "journald"
+	case Conmon_LogDriver_Type_command:
+		return "command"
+	case Conmon_LogDriver_Type_null:
+		return "null"
+	case Conmon_LogDriver_Type_otlp:
+		return "otlp"
+	case Conmon_LogDriver_Type_memory:
+		return "memory"
+	case Conmon_LogDriver_Type_gelf:
+		return "gelf"
 
-func ReadRootConmon_ReopenLogResponse(msg *capnp.Message) (Conmon_ReopenLogResponse, error) {
-	root, err := msg.Root()
-	return Conmon_ReopenLogResponse(root.Struct()), err
+	default:
+		return ""
+	}
 }
 
-func (s Conmon_ReopenLogResponse) String() string {
-	str, _ := text.Marshal(0xa20f49456be85b99, capnp.Struct(s))
-	return str
-}
+// Conmon_LogDriver_TypeFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_LogDriver_TypeFromString(c string) Conmon_LogDriver_Type {
+	switch c {
+	case "containerRuntimeInterface":
+		return Conmon_LogDriver_Type_containerRuntimeInterface
+	case "json":
+		return Conmon_LogDriver_Type_json
+	case "syslog":
+		return Conmon_LogDriver_Type_syslog
+	case "journald":
+		return Conmon_LogDriver_Type_journald
+	case "command":
+		return Conmon_LogDriver_Type_command
+	case "null":
+		return Conmon_LogDriver_Type_null
+	case "otlp":
+		return Conmon_LogDriver_Type_otlp
+	case "memory":
+		return Conmon_LogDriver_Type_memory
+	case "gelf":
+		return Conmon_LogDriver_Type_gelf
 
-func (s Conmon_ReopenLogResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
-	return capnp.Struct(s).EncodeAsPtr(seg)
+	default:
+		return 0
+	}
 }
 
-func (Conmon_ReopenLogResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ReopenLogResponse {
-	return Conmon_ReopenLogResponse(capnp.Struct{}.DecodeFromPtr(p))
-}
+type Conmon_LogDriver_Type_List = capnp.EnumList[Conmon_LogDriver_Type]
 
-func (s Conmon_ReopenLogResponse) ToPtr() capnp.Ptr {
-	return capnp.Struct(s).ToPtr()
-}
-func (s Conmon_ReopenLogResponse) IsValid() bool {
-	return capnp.Struct(s).IsValid()
+func NewConmon_LogDriver_Type_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_Type_List, error) {
+	return capnp.NewEnumList[Conmon_LogDriver_Type](s, sz)
 }
 
-func (s Conmon_ReopenLogResponse) Message() *capnp.Message {
-	return capnp.Struct(s).Message()
-}
+type Conmon_LogDriver_Rotation uint16
 
-func (s Conmon_ReopenLogResponse) Segment() *capnp.Segment {
-	return capnp.Struct(s).Segment()
+// Conmon_LogDriver_Rotation_TypeID is the unique identifier for the type Conmon_LogDriver_Rotation.
+const Conmon_LogDriver_Rotation_TypeID = 0xffe010df085b0c55
+
+// Values of Conmon_LogDriver_Rotation.
+const (
+	Conmon_LogDriver_Rotation_truncate  Conmon_LogDriver_Rotation = 0
+	Conmon_LogDriver_Rotation_rename    Conmon_LogDriver_Rotation = 1
+	Conmon_LogDriver_Rotation_timestamp Conmon_LogDriver_Rotation = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_LogDriver_Rotation) String() string {
+	switch c {
+	case Conmon_LogDriver_Rotation_truncate:
+		return "truncate"
+	case Conmon_LogDriver_Rotation_rename:
+		return "rename"
+	case Conmon_LogDriver_Rotation_timestamp:
+		return "timestamp"
+
+	default:
+		return ""
+	}
 }
 
-// Conmon_ReopenLogResponse_List is a list of Conmon_ReopenLogResponse.
-type Conmon_ReopenLogResponse_List = capnp.StructList[Conmon_ReopenLogResponse]
+// Conmon_LogDriver_RotationFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_LogDriver_RotationFromString(c string) Conmon_LogDriver_Rotation {
+	switch c {
+	case "truncate":
+		return Conmon_LogDriver_Rotation_truncate
+	case "rename":
+		return Conmon_LogDriver_Rotation_rename
+	case "timestamp":
+		return Conmon_LogDriver_Rotation_timestamp
 
-// NewConmon_ReopenLogResponse creates a new list of Conmon_ReopenLogResponse.
-func NewConmon_ReopenLogResponse_List(s *capnp.Segment, sz int32) (Conmon_ReopenLogResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
-	return capnp.StructList[Conmon_ReopenLogResponse](l), err
+	default:
+		return 0
+	}
 }
 
-// Conmon_ReopenLogResponse_Future is a wrapper for a Conmon_ReopenLogResponse promised by a client call.
-type Conmon_ReopenLogResponse_Future struct{ *capnp.Future }
+type Conmon_LogDriver_Rotation_List = capnp.EnumList[Conmon_LogDriver_Rotation]
 
-func (f Conmon_ReopenLogResponse_Future) Struct() (Conmon_ReopenLogResponse, error) {
-	p, err := f.Future.Ptr()
-	return Conmon_ReopenLogResponse(p.Struct()), err
+func NewConmon_LogDriver_Rotation_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_Rotation_List, error) {
+	return capnp.NewEnumList[Conmon_LogDriver_Rotation](s, sz)
 }
 
-type Conmon_SetWindowSizeRequest capnp.Struct
+type Conmon_LogDriver_Durability uint16
 
-// Conmon_SetWindowSizeRequest_TypeID is the unique identifier for the type Conmon_SetWindowSizeRequest.
-const Conmon_SetWindowSizeRequest_TypeID = 0xb5418b8ea8ead17b
+// Conmon_LogDriver_Durability_TypeID is the unique identifier for the type Conmon_LogDriver_Durability.
+const Conmon_LogDriver_Durability_TypeID = 0xeffbded8dd651be8
 
-func NewConmon_SetWindowSizeRequest(s *capnp.Segment) (Conmon_SetWindowSizeRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3})
-	return Conmon_SetWindowSizeRequest(st), err
-}
+// Values of Conmon_LogDriver_Durability.
+const (
+	Conmon_LogDriver_Durability_none       Conmon_LogDriver_Durability = 0
+	Conmon_LogDriver_Durability_everyWrite Conmon_LogDriver_Durability = 1
+	Conmon_LogDriver_Durability_interval   Conmon_LogDriver_Durability = 2
+	Conmon_LogDriver_Durability_dsync      Conmon_LogDriver_Durability = 3
+)
 
-func NewRootConmon_SetWindowSizeRequest(s *capnp.Segment) (Conmon_SetWindowSizeRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3})
-	return Conmon_SetWindowSizeRequest(st), err
-}
+// String returns the enum's constant name.
+func (c Conmon_LogDriver_Durability) String() string {
+	switch c {
+	case Conmon_LogDriver_Durability_none:
+		return "none"
+	case Conmon_LogDriver_Durability_everyWrite:
+		return "everyWrite"
+	case Conmon_LogDriver_Durability_interval:
+		return "interval"
+	case Conmon_LogDriver_Durability_dsync:
+		return "dsync"
 
-func ReadRootConmon_SetWindowSizeRequest(msg *capnp.Message) (Conmon_SetWindowSizeRequest, error) {
-	root, err := msg.Root()
-	return Conmon_SetWindowSizeRequest(root.Struct()), err
+	default:
+		return ""
+	}
 }
 
-func (s Conmon_SetWindowSizeRequest) String() string {
-	str, _ := text.Marshal(0xb5418b8ea8ead17b, capnp.Struct(s))
-	return str
-}
+// Conmon_LogDriver_DurabilityFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_LogDriver_DurabilityFromString(c string) Conmon_LogDriver_Durability {
+	switch c {
+	case "none":
+		return Conmon_LogDriver_Durability_none
+	case "everyWrite":
+		return Conmon_LogDriver_Durability_everyWrite
+	case "interval":
+		return Conmon_LogDriver_Durability_interval
+	case "dsync":
+		return Conmon_LogDriver_Durability_dsync
 
-func (s Conmon_SetWindowSizeRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
-	return capnp.Struct(s).EncodeAsPtr(seg)
+	default:
+		return 0
+	}
 }
 
-func (Conmon_SetWindowSizeRequest) DecodeFromPtr(p capnp.Ptr) Conmon_SetWindowSizeRequest {
-	return Conmon_SetWindowSizeRequest(capnp.Struct{}.DecodeFromPtr(p))
-}
+type Conmon_LogDriver_Durability_List = capnp.EnumList[Conmon_LogDriver_Durability]
 
-func (s Conmon_SetWindowSizeRequest) ToPtr() capnp.Ptr {
-	return capnp.Struct(s).ToPtr()
-}
-func (s Conmon_SetWindowSizeRequest) IsValid() bool {
-	return capnp.Struct(s).IsValid()
+func NewConmon_LogDriver_Durability_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_Durability_List, error) {
+	return capnp.NewEnumList[Conmon_LogDriver_Durability](s, sz)
 }
 
-func (s Conmon_SetWindowSizeRequest) Message() *capnp.Message {
-	return capnp.Struct(s).Message()
-}
+type Conmon_LogDriver_Backpressure uint16
+
+// Conmon_LogDriver_Backpressure_TypeID is the unique identifier for the type Conmon_LogDriver_Backpressure.
+const Conmon_LogDriver_Backpressure_TypeID = 0xe5aca9f6f318460e
+
+// Values of Conmon_LogDriver_Backpressure.
+const (
+	Conmon_LogDriver_Backpressure_block Conmon_LogDriver_Backpressure = 0
+	Conmon_LogDriver_Backpressure_drop  Conmon_LogDriver_Backpressure = 1
+)
+
+// String returns the enum's constant name.
+func (c Conmon_LogDriver_Backpressure) String() string {
+	switch c {
+	case Conmon_LogDriver_Backpressure_block:
+		return "block"
+	case Conmon_LogDriver_Backpressure_drop:
+		return "drop"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_LogDriver_BackpressureFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_LogDriver_BackpressureFromString(c string) Conmon_LogDriver_Backpressure {
+	switch c {
+	case "block":
+		return Conmon_LogDriver_Backpressure_block
+	case "drop":
+		return Conmon_LogDriver_Backpressure_drop
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_LogDriver_Backpressure_List = capnp.EnumList[Conmon_LogDriver_Backpressure]
+
+func NewConmon_LogDriver_Backpressure_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_Backpressure_List, error) {
+	return capnp.NewEnumList[Conmon_LogDriver_Backpressure](s, sz)
+}
+
+type Conmon_LogDriver_QueuePolicy uint16
+
+// Conmon_LogDriver_QueuePolicy_TypeID is the unique identifier for the type Conmon_LogDriver_QueuePolicy.
+const Conmon_LogDriver_QueuePolicy_TypeID = 0x9ae8f79260e0a4a7
+
+// Values of Conmon_LogDriver_QueuePolicy.
+const (
+	Conmon_LogDriver_QueuePolicy_block      Conmon_LogDriver_QueuePolicy = 0
+	Conmon_LogDriver_QueuePolicy_dropOldest Conmon_LogDriver_QueuePolicy = 1
+	Conmon_LogDriver_QueuePolicy_dropNewest Conmon_LogDriver_QueuePolicy = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_LogDriver_QueuePolicy) String() string {
+	switch c {
+	case Conmon_LogDriver_QueuePolicy_block:
+		return "block"
+	case Conmon_LogDriver_QueuePolicy_dropOldest:
+		return "dropOldest"
+	case Conmon_LogDriver_QueuePolicy_dropNewest:
+		return "dropNewest"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_LogDriver_QueuePolicyFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_LogDriver_QueuePolicyFromString(c string) Conmon_LogDriver_QueuePolicy {
+	switch c {
+	case "block":
+		return Conmon_LogDriver_QueuePolicy_block
+	case "dropOldest":
+		return Conmon_LogDriver_QueuePolicy_dropOldest
+	case "dropNewest":
+		return Conmon_LogDriver_QueuePolicy_dropNewest
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_LogDriver_QueuePolicy_List = capnp.EnumList[Conmon_LogDriver_QueuePolicy]
+
+func NewConmon_LogDriver_QueuePolicy_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_QueuePolicy_List, error) {
+	return capnp.NewEnumList[Conmon_LogDriver_QueuePolicy](s, sz)
+}
+
+type Conmon_LogDriver_TimestampSource uint16
+
+// Conmon_LogDriver_TimestampSource_TypeID is the unique identifier for the type Conmon_LogDriver_TimestampSource.
+const Conmon_LogDriver_TimestampSource_TypeID = 0x81cf83061cb9d611
+
+// Values of Conmon_LogDriver_TimestampSource.
+const (
+	Conmon_LogDriver_TimestampSource_wallClock Conmon_LogDriver_TimestampSource = 0
+	Conmon_LogDriver_TimestampSource_monotonic Conmon_LogDriver_TimestampSource = 1
+)
+
+// String returns the enum's constant name.
+func (c Conmon_LogDriver_TimestampSource) String() string {
+	switch c {
+	case Conmon_LogDriver_TimestampSource_wallClock:
+		return "wallClock"
+	case Conmon_LogDriver_TimestampSource_monotonic:
+		return "monotonic"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_LogDriver_TimestampSourceFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_LogDriver_TimestampSourceFromString(c string) Conmon_LogDriver_TimestampSource {
+	switch c {
+	case "wallClock":
+		return Conmon_LogDriver_TimestampSource_wallClock
+	case "monotonic":
+		return Conmon_LogDriver_TimestampSource_monotonic
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_LogDriver_TimestampSource_List = capnp.EnumList[Conmon_LogDriver_TimestampSource]
+
+func NewConmon_LogDriver_TimestampSource_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_TimestampSource_List, error) {
+	return capnp.NewEnumList[Conmon_LogDriver_TimestampSource](s, sz)
+}
+
+type Conmon_LogDriver_Stream uint16
+
+// Conmon_LogDriver_Stream_TypeID is the unique identifier for the type Conmon_LogDriver_Stream.
+const Conmon_LogDriver_Stream_TypeID = 0xa1a81a51f2e6d925
+
+// Values of Conmon_LogDriver_Stream.
+const (
+	Conmon_LogDriver_Stream_all    Conmon_LogDriver_Stream = 0
+	Conmon_LogDriver_Stream_stdout Conmon_LogDriver_Stream = 1
+	Conmon_LogDriver_Stream_stderr Conmon_LogDriver_Stream = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_LogDriver_Stream) String() string {
+	switch c {
+	case Conmon_LogDriver_Stream_all:
+		return "all"
+	case Conmon_LogDriver_Stream_stdout:
+		return "stdout"
+	case Conmon_LogDriver_Stream_stderr:
+		return "stderr"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_LogDriver_StreamFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_LogDriver_StreamFromString(c string) Conmon_LogDriver_Stream {
+	switch c {
+	case "all":
+		return Conmon_LogDriver_Stream_all
+	case "stdout":
+		return Conmon_LogDriver_Stream_stdout
+	case "stderr":
+		return Conmon_LogDriver_Stream_stderr
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_LogDriver_Stream_List = capnp.EnumList[Conmon_LogDriver_Stream]
+
+func NewConmon_LogDriver_Stream_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_Stream_List, error) {
+	return capnp.NewEnumList[Conmon_LogDriver_Stream](s, sz)
+}
+
+type Conmon_LogDriver_EmptyLines uint16
+
+// Conmon_LogDriver_EmptyLines_TypeID is the unique identifier for the type Conmon_LogDriver_EmptyLines.
+const Conmon_LogDriver_EmptyLines_TypeID = 0x9d8e0a5b2890efca
+
+// Values of Conmon_LogDriver_EmptyLines.
+const (
+	Conmon_LogDriver_EmptyLines_keep Conmon_LogDriver_EmptyLines = 0
+	Conmon_LogDriver_EmptyLines_drop Conmon_LogDriver_EmptyLines = 1
+)
+
+// String returns the enum's constant name.
+func (c Conmon_LogDriver_EmptyLines) String() string {
+	switch c {
+	case Conmon_LogDriver_EmptyLines_keep:
+		return "keep"
+	case Conmon_LogDriver_EmptyLines_drop:
+		return "drop"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_LogDriver_EmptyLinesFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_LogDriver_EmptyLinesFromString(c string) Conmon_LogDriver_EmptyLines {
+	switch c {
+	case "keep":
+		return Conmon_LogDriver_EmptyLines_keep
+	case "drop":
+		return Conmon_LogDriver_EmptyLines_drop
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_LogDriver_EmptyLines_List = capnp.EnumList[Conmon_LogDriver_EmptyLines]
+
+func NewConmon_LogDriver_EmptyLines_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_EmptyLines_List, error) {
+	return capnp.NewEnumList[Conmon_LogDriver_EmptyLines](s, sz)
+}
+
+type Conmon_LogDriver_MaxSizeMode uint16
+
+// Conmon_LogDriver_MaxSizeMode_TypeID is the unique identifier for the type Conmon_LogDriver_MaxSizeMode.
+const Conmon_LogDriver_MaxSizeMode_TypeID = 0xa3ce5d4aa318e87a
+
+// Values of Conmon_LogDriver_MaxSizeMode.
+const (
+	Conmon_LogDriver_MaxSizeMode_inherit   Conmon_LogDriver_MaxSizeMode = 0
+	Conmon_LogDriver_MaxSizeMode_unlimited Conmon_LogDriver_MaxSizeMode = 1
+	Conmon_LogDriver_MaxSizeMode_limited   Conmon_LogDriver_MaxSizeMode = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_LogDriver_MaxSizeMode) String() string {
+	switch c {
+	case Conmon_LogDriver_MaxSizeMode_inherit:
+		return "inherit"
+	case Conmon_LogDriver_MaxSizeMode_unlimited:
+		return "unlimited"
+	case Conmon_LogDriver_MaxSizeMode_limited:
+		return "limited"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_LogDriver_MaxSizeModeFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_LogDriver_MaxSizeModeFromString(c string) Conmon_LogDriver_MaxSizeMode {
+	switch c {
+	case "inherit":
+		return Conmon_LogDriver_MaxSizeMode_inherit
+	case "unlimited":
+		return Conmon_LogDriver_MaxSizeMode_unlimited
+	case "limited":
+		return Conmon_LogDriver_MaxSizeMode_limited
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_LogDriver_MaxSizeMode_List = capnp.EnumList[Conmon_LogDriver_MaxSizeMode]
+
+func NewConmon_LogDriver_MaxSizeMode_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_MaxSizeMode_List, error) {
+	return capnp.NewEnumList[Conmon_LogDriver_MaxSizeMode](s, sz)
+}
+
+type Conmon_LogDriver_Framing uint16
+
+// Conmon_LogDriver_Framing_TypeID is the unique identifier for the type Conmon_LogDriver_Framing.
+const Conmon_LogDriver_Framing_TypeID = 0x81af26d8deca1c10
+
+// Values of Conmon_LogDriver_Framing.
+const (
+	Conmon_LogDriver_Framing_ndjson  Conmon_LogDriver_Framing = 0
+	Conmon_LogDriver_Framing_jsonSeq Conmon_LogDriver_Framing = 1
+	Conmon_LogDriver_Framing_array   Conmon_LogDriver_Framing = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_LogDriver_Framing) String() string {
+	switch c {
+	case Conmon_LogDriver_Framing_ndjson:
+		return "ndjson"
+	case Conmon_LogDriver_Framing_jsonSeq:
+		return "jsonSeq"
+	case Conmon_LogDriver_Framing_array:
+		return "array"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_LogDriver_FramingFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_LogDriver_FramingFromString(c string) Conmon_LogDriver_Framing {
+	switch c {
+	case "ndjson":
+		return Conmon_LogDriver_Framing_ndjson
+	case "jsonSeq":
+		return Conmon_LogDriver_Framing_jsonSeq
+	case "array":
+		return Conmon_LogDriver_Framing_array
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_LogDriver_Framing_List = capnp.EnumList[Conmon_LogDriver_Framing]
+
+func NewConmon_LogDriver_Framing_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_Framing_List, error) {
+	return capnp.NewEnumList[Conmon_LogDriver_Framing](s, sz)
+}
+
+type Conmon_LogDriver_PipeLabel uint16
+
+// Conmon_LogDriver_PipeLabel_TypeID is the unique identifier for the type Conmon_LogDriver_PipeLabel.
+const Conmon_LogDriver_PipeLabel_TypeID = 0x9d0da23d405b48b7
+
+// Values of Conmon_LogDriver_PipeLabel.
+const (
+	Conmon_LogDriver_PipeLabel_name  Conmon_LogDriver_PipeLabel = 0
+	Conmon_LogDriver_PipeLabel_short Conmon_LogDriver_PipeLabel = 1
+	Conmon_LogDriver_PipeLabel_fd    Conmon_LogDriver_PipeLabel = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_LogDriver_PipeLabel) String() string {
+	switch c {
+	case Conmon_LogDriver_PipeLabel_name:
+		return "name"
+	case Conmon_LogDriver_PipeLabel_short:
+		return "short"
+	case Conmon_LogDriver_PipeLabel_fd:
+		return "fd"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_LogDriver_PipeLabelFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_LogDriver_PipeLabelFromString(c string) Conmon_LogDriver_PipeLabel {
+	switch c {
+	case "name":
+		return Conmon_LogDriver_PipeLabel_name
+	case "short":
+		return Conmon_LogDriver_PipeLabel_short
+	case "fd":
+		return Conmon_LogDriver_PipeLabel_fd
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_LogDriver_PipeLabel_List = capnp.EnumList[Conmon_LogDriver_PipeLabel]
+
+func NewConmon_LogDriver_PipeLabel_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_PipeLabel_List, error) {
+	return capnp.NewEnumList[Conmon_LogDriver_PipeLabel](s, sz)
+}
+
+type Conmon_LogDriver_Compression uint16
+
+// Conmon_LogDriver_Compression_TypeID is the unique identifier for the type Conmon_LogDriver_Compression.
+const Conmon_LogDriver_Compression_TypeID = 0xe377a0cceeec274b
+
+// Values of Conmon_LogDriver_Compression.
+const (
+	Conmon_LogDriver_Compression_none Conmon_LogDriver_Compression = 0
+	Conmon_LogDriver_Compression_gzip Conmon_LogDriver_Compression = 1
+	Conmon_LogDriver_Compression_zstd Conmon_LogDriver_Compression = 2
+)
+
+// String returns the enum's constant name.
+func (c Conmon_LogDriver_Compression) String() string {
+	switch c {
+	case Conmon_LogDriver_Compression_none:
+		return "none"
+	case Conmon_LogDriver_Compression_gzip:
+		return "gzip"
+	case Conmon_LogDriver_Compression_zstd:
+		return "zstd"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_LogDriver_CompressionFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_LogDriver_CompressionFromString(c string) Conmon_LogDriver_Compression {
+	switch c {
+	case "none":
+		return Conmon_LogDriver_Compression_none
+	case "gzip":
+		return Conmon_LogDriver_Compression_gzip
+	case "zstd":
+		return Conmon_LogDriver_Compression_zstd
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_LogDriver_Compression_List = capnp.EnumList[Conmon_LogDriver_Compression]
+
+func NewConmon_LogDriver_Compression_List(s *capnp.Segment, sz int32) (Conmon_LogDriver_Compression_List, error) {
+	return capnp.NewEnumList[Conmon_LogDriver_Compression](s, sz)
+}
+
+type Conmon_CgroupManager uint16
+
+// Conmon_CgroupManager_TypeID is the unique identifier for the type Conmon_CgroupManager.
+const Conmon_CgroupManager_TypeID = 0xaa4bbac12765a78a
+
+// Values of Conmon_CgroupManager.
+const (
+	Conmon_CgroupManager_systemd  Conmon_CgroupManager = 0
+	Conmon_CgroupManager_cgroupfs Conmon_CgroupManager = 1
+)
+
+// String returns the enum's constant name.
+func (c Conmon_CgroupManager) String() string {
+	switch c {
+	case Conmon_CgroupManager_systemd:
+		return "systemd"
+	case Conmon_CgroupManager_cgroupfs:
+		return "cgroupfs"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_CgroupManagerFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_CgroupManagerFromString(c string) Conmon_CgroupManager {
+	switch c {
+	case "systemd":
+		return Conmon_CgroupManager_systemd
+	case "cgroupfs":
+		return Conmon_CgroupManager_cgroupfs
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_CgroupManager_List = capnp.EnumList[Conmon_CgroupManager]
+
+func NewConmon_CgroupManager_List(s *capnp.Segment, sz int32) (Conmon_CgroupManager_List, error) {
+	return capnp.NewEnumList[Conmon_CgroupManager](s, sz)
+}
+
+type Conmon_CreateContainerResponse capnp.Struct
+
+// Conmon_CreateContainerResponse_TypeID is the unique identifier for the type Conmon_CreateContainerResponse.
+const Conmon_CreateContainerResponse_TypeID = 0xde3a625e70772b9a
+
+func NewConmon_CreateContainerResponse(s *capnp.Segment) (Conmon_CreateContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_CreateContainerResponse(st), err
+}
+
+func NewRootConmon_CreateContainerResponse(s *capnp.Segment) (Conmon_CreateContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_CreateContainerResponse(st), err
+}
+
+func ReadRootConmon_CreateContainerResponse(msg *capnp.Message) (Conmon_CreateContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_CreateContainerResponse(root.Struct()), err
+}
+
+func (s Conmon_CreateContainerResponse) String() string {
+	str, _ := text.Marshal(0xde3a625e70772b9a, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_CreateContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_CreateContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_CreateContainerResponse {
+	return Conmon_CreateContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_CreateContainerResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_CreateContainerResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_CreateContainerResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_CreateContainerResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_CreateContainerResponse) ContainerPid() uint32 {
+	return capnp.Struct(s).Uint32(0)
+}
+
+func (s Conmon_CreateContainerResponse) SetContainerPid(v uint32) {
+	capnp.Struct(s).SetUint32(0, v)
+}
+
+// Conmon_CreateContainerResponse_List is a list of Conmon_CreateContainerResponse.
+type Conmon_CreateContainerResponse_List = capnp.StructList[Conmon_CreateContainerResponse]
+
+// NewConmon_CreateContainerResponse creates a new list of Conmon_CreateContainerResponse.
+func NewConmon_CreateContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_CreateContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_CreateContainerResponse](l), err
+}
+
+// Conmon_CreateContainerResponse_Future is a wrapper for a Conmon_CreateContainerResponse promised by a client call.
+type Conmon_CreateContainerResponse_Future struct{ *capnp.Future }
+
+func (f Conmon_CreateContainerResponse_Future) Struct() (Conmon_CreateContainerResponse, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_CreateContainerResponse(p.Struct()), err
+}
+
+type Conmon_ExecSyncContainerRequest capnp.Struct
+
+// Conmon_ExecSyncContainerRequest_TypeID is the unique identifier for the type Conmon_ExecSyncContainerRequest.
+const Conmon_ExecSyncContainerRequest_TypeID = 0xf41122f890a371a6
+
+func NewConmon_ExecSyncContainerRequest(s *capnp.Segment) (Conmon_ExecSyncContainerRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 5})
+	return Conmon_ExecSyncContainerRequest(st), err
+}
+
+func NewRootConmon_ExecSyncContainerRequest(s *capnp.Segment) (Conmon_ExecSyncContainerRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 16, PointerCount: 5})
+	return Conmon_ExecSyncContainerRequest(st), err
+}
+
+func ReadRootConmon_ExecSyncContainerRequest(msg *capnp.Message) (Conmon_ExecSyncContainerRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ExecSyncContainerRequest(root.Struct()), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) String() string {
+	str, _ := text.Marshal(0xf41122f890a371a6, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ExecSyncContainerRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ExecSyncContainerRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ExecSyncContainerRequest {
+	return Conmon_ExecSyncContainerRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ExecSyncContainerRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ExecSyncContainerRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ExecSyncContainerRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ExecSyncContainerRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ExecSyncContainerRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ExecSyncContainerRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_ExecSyncContainerRequest) TimeoutSec() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetTimeoutSec(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+func (s Conmon_ExecSyncContainerRequest) Command() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) HasCommand() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetCommand(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+}
+
+// NewCommand sets the command field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_ExecSyncContainerRequest) NewCommand(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	return l, err
+}
+func (s Conmon_ExecSyncContainerRequest) Terminal() bool {
+	return capnp.Struct(s).Bit(64)
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetTerminal(v bool) {
+	capnp.Struct(s).SetBit(64, v)
+}
+
+func (s Conmon_ExecSyncContainerRequest) MetadataOld() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return []byte(p.Data()), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) HasMetadataOld() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetMetadataOld(v []byte) error {
+	return capnp.Struct(s).SetData(2, v)
+}
+
+func (s Conmon_ExecSyncContainerRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
+	p, err := capnp.Struct(s).Ptr(3)
+	return Conmon_TextTextMapEntry_List(p.List()), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) HasMetadata() bool {
+	return capnp.Struct(s).HasPtr(3)
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
+	return capnp.Struct(s).SetPtr(3, v.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
+func (s Conmon_ExecSyncContainerRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_TextTextMapEntry_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(3, l.ToPtr())
+	return l, err
+}
+func (s Conmon_ExecSyncContainerRequest) EnvVars() (Conmon_TextTextMapEntry_List, error) {
+	p, err := capnp.Struct(s).Ptr(4)
+	return Conmon_TextTextMapEntry_List(p.List()), err
+}
+
+func (s Conmon_ExecSyncContainerRequest) HasEnvVars() bool {
+	return capnp.Struct(s).HasPtr(4)
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetEnvVars(v Conmon_TextTextMapEntry_List) error {
+	return capnp.Struct(s).SetPtr(4, v.ToPtr())
+}
+
+// NewEnvVars sets the envVars field to a newly
+// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
+func (s Conmon_ExecSyncContainerRequest) NewEnvVars(n int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_TextTextMapEntry_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(4, l.ToPtr())
+	return l, err
+}
+func (s Conmon_ExecSyncContainerRequest) CgroupManager() Conmon_CgroupManager {
+	return Conmon_CgroupManager(capnp.Struct(s).Uint16(10))
+}
+
+func (s Conmon_ExecSyncContainerRequest) SetCgroupManager(v Conmon_CgroupManager) {
+	capnp.Struct(s).SetUint16(10, uint16(v))
+}
+
+// Conmon_ExecSyncContainerRequest_List is a list of Conmon_ExecSyncContainerRequest.
+type Conmon_ExecSyncContainerRequest_List = capnp.StructList[Conmon_ExecSyncContainerRequest]
+
+// NewConmon_ExecSyncContainerRequest creates a new list of Conmon_ExecSyncContainerRequest.
+func NewConmon_ExecSyncContainerRequest_List(s *capnp.Segment, sz int32) (Conmon_ExecSyncContainerRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 16, PointerCount: 5}, sz)
+	return capnp.StructList[Conmon_ExecSyncContainerRequest](l), err
+}
+
+// Conmon_ExecSyncContainerRequest_Future is a wrapper for a Conmon_ExecSyncContainerRequest promised by a client call.
+type Conmon_ExecSyncContainerRequest_Future struct{ *capnp.Future }
+
+func (f Conmon_ExecSyncContainerRequest_Future) Struct() (Conmon_ExecSyncContainerRequest, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_ExecSyncContainerRequest(p.Struct()), err
+}
+
+type Conmon_ExecSyncContainerResponse capnp.Struct
+
+// Conmon_ExecSyncContainerResponse_TypeID is the unique identifier for the type Conmon_ExecSyncContainerResponse.
+const Conmon_ExecSyncContainerResponse_TypeID = 0xd9d61d1d803c85fc
+
+func NewConmon_ExecSyncContainerResponse(s *capnp.Segment) (Conmon_ExecSyncContainerResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
+	return Conmon_ExecSyncContainerResponse(st), err
+}
+
+func NewRootConmon_ExecSyncContainerResponse(s *capnp.Segment) (Conmon_ExecSyncContainerResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
+	return Conmon_ExecSyncContainerResponse(st), err
+}
+
+func ReadRootConmon_ExecSyncContainerResponse(msg *capnp.Message) (Conmon_ExecSyncContainerResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ExecSyncContainerResponse(root.Struct()), err
+}
+
+func (s Conmon_ExecSyncContainerResponse) String() string {
+	str, _ := text.Marshal(0xd9d61d1d803c85fc, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ExecSyncContainerResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ExecSyncContainerResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ExecSyncContainerResponse {
+	return Conmon_ExecSyncContainerResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ExecSyncContainerResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ExecSyncContainerResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ExecSyncContainerResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ExecSyncContainerResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ExecSyncContainerResponse) ExitCode() int32 {
+	return int32(capnp.Struct(s).Uint32(0))
+}
+
+func (s Conmon_ExecSyncContainerResponse) SetExitCode(v int32) {
+	capnp.Struct(s).SetUint32(0, uint32(v))
+}
+
+func (s Conmon_ExecSyncContainerResponse) Stdout() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return []byte(p.Data()), err
+}
+
+func (s Conmon_ExecSyncContainerResponse) HasStdout() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ExecSyncContainerResponse) SetStdout(v []byte) error {
+	return capnp.Struct(s).SetData(0, v)
+}
+
+func (s Conmon_ExecSyncContainerResponse) Stderr() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return []byte(p.Data()), err
+}
+
+func (s Conmon_ExecSyncContainerResponse) HasStderr() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ExecSyncContainerResponse) SetStderr(v []byte) error {
+	return capnp.Struct(s).SetData(1, v)
+}
+
+func (s Conmon_ExecSyncContainerResponse) TimedOut() bool {
+	return capnp.Struct(s).Bit(32)
+}
+
+func (s Conmon_ExecSyncContainerResponse) SetTimedOut(v bool) {
+	capnp.Struct(s).SetBit(32, v)
+}
+
+// Conmon_ExecSyncContainerResponse_List is a list of Conmon_ExecSyncContainerResponse.
+type Conmon_ExecSyncContainerResponse_List = capnp.StructList[Conmon_ExecSyncContainerResponse]
+
+// NewConmon_ExecSyncContainerResponse creates a new list of Conmon_ExecSyncContainerResponse.
+func NewConmon_ExecSyncContainerResponse_List(s *capnp.Segment, sz int32) (Conmon_ExecSyncContainerResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_ExecSyncContainerResponse](l), err
+}
+
+// Conmon_ExecSyncContainerResponse_Future is a wrapper for a Conmon_ExecSyncContainerResponse promised by a client call.
+type Conmon_ExecSyncContainerResponse_Future struct{ *capnp.Future }
+
+func (f Conmon_ExecSyncContainerResponse_Future) Struct() (Conmon_ExecSyncContainerResponse, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_ExecSyncContainerResponse(p.Struct()), err
+}
+
+type Conmon_AttachRequest capnp.Struct
+
+// Conmon_AttachRequest_TypeID is the unique identifier for the type Conmon_AttachRequest.
+const Conmon_AttachRequest_TypeID = 0xdf703ca0befc3afc
+
+func NewConmon_AttachRequest(s *capnp.Segment) (Conmon_AttachRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5})
+	return Conmon_AttachRequest(st), err
+}
+
+func NewRootConmon_AttachRequest(s *capnp.Segment) (Conmon_AttachRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5})
+	return Conmon_AttachRequest(st), err
+}
+
+func ReadRootConmon_AttachRequest(msg *capnp.Message) (Conmon_AttachRequest, error) {
+	root, err := msg.Root()
+	return Conmon_AttachRequest(root.Struct()), err
+}
+
+func (s Conmon_AttachRequest) String() string {
+	str, _ := text.Marshal(0xdf703ca0befc3afc, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_AttachRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_AttachRequest) DecodeFromPtr(p capnp.Ptr) Conmon_AttachRequest {
+	return Conmon_AttachRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_AttachRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_AttachRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_AttachRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_AttachRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_AttachRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_AttachRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_AttachRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_AttachRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_AttachRequest) SocketPath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_AttachRequest) HasSocketPath() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_AttachRequest) SocketPathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_AttachRequest) SetSocketPath(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+func (s Conmon_AttachRequest) ExecSessionId() (string, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.Text(), err
+}
+
+func (s Conmon_AttachRequest) HasExecSessionId() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_AttachRequest) ExecSessionIdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_AttachRequest) SetExecSessionId(v string) error {
+	return capnp.Struct(s).SetText(2, v)
+}
+
+func (s Conmon_AttachRequest) StopAfterStdinEof() bool {
+	return capnp.Struct(s).Bit(0)
+}
+
+func (s Conmon_AttachRequest) SetStopAfterStdinEof(v bool) {
+	capnp.Struct(s).SetBit(0, v)
+}
+
+func (s Conmon_AttachRequest) MetadataOld() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(3)
+	return []byte(p.Data()), err
+}
+
+func (s Conmon_AttachRequest) HasMetadataOld() bool {
+	return capnp.Struct(s).HasPtr(3)
+}
+
+func (s Conmon_AttachRequest) SetMetadataOld(v []byte) error {
+	return capnp.Struct(s).SetData(3, v)
+}
+
+func (s Conmon_AttachRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
+	p, err := capnp.Struct(s).Ptr(4)
+	return Conmon_TextTextMapEntry_List(p.List()), err
+}
+
+func (s Conmon_AttachRequest) HasMetadata() bool {
+	return capnp.Struct(s).HasPtr(4)
+}
+
+func (s Conmon_AttachRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
+	return capnp.Struct(s).SetPtr(4, v.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
+func (s Conmon_AttachRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_TextTextMapEntry_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(4, l.ToPtr())
+	return l, err
+}
+
+// Conmon_AttachRequest_List is a list of Conmon_AttachRequest.
+type Conmon_AttachRequest_List = capnp.StructList[Conmon_AttachRequest]
+
+// NewConmon_AttachRequest creates a new list of Conmon_AttachRequest.
+func NewConmon_AttachRequest_List(s *capnp.Segment, sz int32) (Conmon_AttachRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 5}, sz)
+	return capnp.StructList[Conmon_AttachRequest](l), err
+}
+
+// Conmon_AttachRequest_Future is a wrapper for a Conmon_AttachRequest promised by a client call.
+type Conmon_AttachRequest_Future struct{ *capnp.Future }
+
+func (f Conmon_AttachRequest_Future) Struct() (Conmon_AttachRequest, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_AttachRequest(p.Struct()), err
+}
+
+type Conmon_AttachResponse capnp.Struct
+
+// Conmon_AttachResponse_TypeID is the unique identifier for the type Conmon_AttachResponse.
+const Conmon_AttachResponse_TypeID = 0xace5517aafc86077
+
+func NewConmon_AttachResponse(s *capnp.Segment) (Conmon_AttachResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_AttachResponse(st), err
+}
+
+func NewRootConmon_AttachResponse(s *capnp.Segment) (Conmon_AttachResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_AttachResponse(st), err
+}
+
+func ReadRootConmon_AttachResponse(msg *capnp.Message) (Conmon_AttachResponse, error) {
+	root, err := msg.Root()
+	return Conmon_AttachResponse(root.Struct()), err
+}
+
+func (s Conmon_AttachResponse) String() string {
+	str, _ := text.Marshal(0xace5517aafc86077, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_AttachResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_AttachResponse) DecodeFromPtr(p capnp.Ptr) Conmon_AttachResponse {
+	return Conmon_AttachResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_AttachResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_AttachResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_AttachResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_AttachResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_AttachResponse_List is a list of Conmon_AttachResponse.
+type Conmon_AttachResponse_List = capnp.StructList[Conmon_AttachResponse]
+
+// NewConmon_AttachResponse creates a new list of Conmon_AttachResponse.
+func NewConmon_AttachResponse_List(s *capnp.Segment, sz int32) (Conmon_AttachResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_AttachResponse](l), err
+}
+
+// Conmon_AttachResponse_Future is a wrapper for a Conmon_AttachResponse promised by a client call.
+type Conmon_AttachResponse_Future struct{ *capnp.Future }
+
+func (f Conmon_AttachResponse_Future) Struct() (Conmon_AttachResponse, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_AttachResponse(p.Struct()), err
+}
+
+type Conmon_ReopenLogRequest capnp.Struct
+
+// Conmon_ReopenLogRequest_TypeID is the unique identifier for the type Conmon_ReopenLogRequest.
+const Conmon_ReopenLogRequest_TypeID = 0xd0476e0f34d1411a
+
+func NewConmon_ReopenLogRequest(s *capnp.Segment) (Conmon_ReopenLogRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3})
+	return Conmon_ReopenLogRequest(st), err
+}
+
+func NewRootConmon_ReopenLogRequest(s *capnp.Segment) (Conmon_ReopenLogRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3})
+	return Conmon_ReopenLogRequest(st), err
+}
+
+func ReadRootConmon_ReopenLogRequest(msg *capnp.Message) (Conmon_ReopenLogRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ReopenLogRequest(root.Struct()), err
+}
+
+func (s Conmon_ReopenLogRequest) String() string {
+	str, _ := text.Marshal(0xd0476e0f34d1411a, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ReopenLogRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ReopenLogRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ReopenLogRequest {
+	return Conmon_ReopenLogRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ReopenLogRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ReopenLogRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ReopenLogRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ReopenLogRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ReopenLogRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ReopenLogRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ReopenLogRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ReopenLogRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_ReopenLogRequest) MetadataOld() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return []byte(p.Data()), err
+}
+
+func (s Conmon_ReopenLogRequest) HasMetadataOld() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ReopenLogRequest) SetMetadataOld(v []byte) error {
+	return capnp.Struct(s).SetData(1, v)
+}
+
+func (s Conmon_ReopenLogRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return Conmon_TextTextMapEntry_List(p.List()), err
+}
+
+func (s Conmon_ReopenLogRequest) HasMetadata() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_ReopenLogRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
+	return capnp.Struct(s).SetPtr(2, v.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
+func (s Conmon_ReopenLogRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_TextTextMapEntry_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(2, l.ToPtr())
+	return l, err
+}
+func (s Conmon_ReopenLogRequest) All() bool {
+	return capnp.Struct(s).Bit(0)
+}
+
+func (s Conmon_ReopenLogRequest) SetAll(v bool) {
+	capnp.Struct(s).SetBit(0, v)
+}
+
+// Conmon_ReopenLogRequest_List is a list of Conmon_ReopenLogRequest.
+type Conmon_ReopenLogRequest_List = capnp.StructList[Conmon_ReopenLogRequest]
+
+// NewConmon_ReopenLogRequest creates a new list of Conmon_ReopenLogRequest.
+func NewConmon_ReopenLogRequest_List(s *capnp.Segment, sz int32) (Conmon_ReopenLogRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_ReopenLogRequest](l), err
+}
+
+// Conmon_ReopenLogRequest_Future is a wrapper for a Conmon_ReopenLogRequest promised by a client call.
+type Conmon_ReopenLogRequest_Future struct{ *capnp.Future }
+
+func (f Conmon_ReopenLogRequest_Future) Struct() (Conmon_ReopenLogRequest, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_ReopenLogRequest(p.Struct()), err
+}
+
+type Conmon_ReopenLogResponse capnp.Struct
+
+// Conmon_ReopenLogResponse_TypeID is the unique identifier for the type Conmon_ReopenLogResponse.
+const Conmon_ReopenLogResponse_TypeID = 0xa20f49456be85b99
+
+func NewConmon_ReopenLogResponse(s *capnp.Segment) (Conmon_ReopenLogResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ReopenLogResponse(st), err
+}
+
+func NewRootConmon_ReopenLogResponse(s *capnp.Segment) (Conmon_ReopenLogResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ReopenLogResponse(st), err
+}
+
+func ReadRootConmon_ReopenLogResponse(msg *capnp.Message) (Conmon_ReopenLogResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ReopenLogResponse(root.Struct()), err
+}
+
+func (s Conmon_ReopenLogResponse) String() string {
+	str, _ := text.Marshal(0xa20f49456be85b99, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ReopenLogResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ReopenLogResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ReopenLogResponse {
+	return Conmon_ReopenLogResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ReopenLogResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ReopenLogResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ReopenLogResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ReopenLogResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_ReopenLogResponse_List is a list of Conmon_ReopenLogResponse.
+type Conmon_ReopenLogResponse_List = capnp.StructList[Conmon_ReopenLogResponse]
+
+// NewConmon_ReopenLogResponse creates a new list of Conmon_ReopenLogResponse.
+func NewConmon_ReopenLogResponse_List(s *capnp.Segment, sz int32) (Conmon_ReopenLogResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_ReopenLogResponse](l), err
+}
+
+// Conmon_ReopenLogResponse_Future is a wrapper for a Conmon_ReopenLogResponse promised by a client call.
+type Conmon_ReopenLogResponse_Future struct{ *capnp.Future }
+
+func (f Conmon_ReopenLogResponse_Future) Struct() (Conmon_ReopenLogResponse, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_ReopenLogResponse(p.Struct()), err
+}
+
+type Conmon_SetWindowSizeRequest capnp.Struct
+
+// Conmon_SetWindowSizeRequest_TypeID is the unique identifier for the type Conmon_SetWindowSizeRequest.
+const Conmon_SetWindowSizeRequest_TypeID = 0xb5418b8ea8ead17b
+
+func NewConmon_SetWindowSizeRequest(s *capnp.Segment) (Conmon_SetWindowSizeRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3})
+	return Conmon_SetWindowSizeRequest(st), err
+}
+
+func NewRootConmon_SetWindowSizeRequest(s *capnp.Segment) (Conmon_SetWindowSizeRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3})
+	return Conmon_SetWindowSizeRequest(st), err
+}
+
+func ReadRootConmon_SetWindowSizeRequest(msg *capnp.Message) (Conmon_SetWindowSizeRequest, error) {
+	root, err := msg.Root()
+	return Conmon_SetWindowSizeRequest(root.Struct()), err
+}
+
+func (s Conmon_SetWindowSizeRequest) String() string {
+	str, _ := text.Marshal(0xb5418b8ea8ead17b, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_SetWindowSizeRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_SetWindowSizeRequest) DecodeFromPtr(p capnp.Ptr) Conmon_SetWindowSizeRequest {
+	return Conmon_SetWindowSizeRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_SetWindowSizeRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_SetWindowSizeRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_SetWindowSizeRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
 
 func (s Conmon_SetWindowSizeRequest) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_SetWindowSizeRequest) Id() (string, error) {
+func (s Conmon_SetWindowSizeRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_SetWindowSizeRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_SetWindowSizeRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_SetWindowSizeRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_SetWindowSizeRequest) Width() uint16 {
+	return capnp.Struct(s).Uint16(0)
+}
+
+func (s Conmon_SetWindowSizeRequest) SetWidth(v uint16) {
+	capnp.Struct(s).SetUint16(0, v)
+}
+
+func (s Conmon_SetWindowSizeRequest) Height() uint16 {
+	return capnp.Struct(s).Uint16(2)
+}
+
+func (s Conmon_SetWindowSizeRequest) SetHeight(v uint16) {
+	capnp.Struct(s).SetUint16(2, v)
+}
+
+func (s Conmon_SetWindowSizeRequest) MetadataOld() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return []byte(p.Data()), err
+}
+
+func (s Conmon_SetWindowSizeRequest) HasMetadataOld() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_SetWindowSizeRequest) SetMetadataOld(v []byte) error {
+	return capnp.Struct(s).SetData(1, v)
+}
+
+func (s Conmon_SetWindowSizeRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return Conmon_TextTextMapEntry_List(p.List()), err
+}
+
+func (s Conmon_SetWindowSizeRequest) HasMetadata() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_SetWindowSizeRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
+	return capnp.Struct(s).SetPtr(2, v.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
+func (s Conmon_SetWindowSizeRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_TextTextMapEntry_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(2, l.ToPtr())
+	return l, err
+}
+
+// Conmon_SetWindowSizeRequest_List is a list of Conmon_SetWindowSizeRequest.
+type Conmon_SetWindowSizeRequest_List = capnp.StructList[Conmon_SetWindowSizeRequest]
+
+// NewConmon_SetWindowSizeRequest creates a new list of Conmon_SetWindowSizeRequest.
+func NewConmon_SetWindowSizeRequest_List(s *capnp.Segment, sz int32) (Conmon_SetWindowSizeRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_SetWindowSizeRequest](l), err
+}
+
+// Conmon_SetWindowSizeRequest_Future is a wrapper for a Conmon_SetWindowSizeRequest promised by a client call.
+type Conmon_SetWindowSizeRequest_Future struct{ *capnp.Future }
+
+func (f Conmon_SetWindowSizeRequest_Future) Struct() (Conmon_SetWindowSizeRequest, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_SetWindowSizeRequest(p.Struct()), err
+}
+
+type Conmon_SetWindowSizeResponse capnp.Struct
+
+// Conmon_SetWindowSizeResponse_TypeID is the unique identifier for the type Conmon_SetWindowSizeResponse.
+const Conmon_SetWindowSizeResponse_TypeID = 0xf9b3cd8033aba1f8
+
+func NewConmon_SetWindowSizeResponse(s *capnp.Segment) (Conmon_SetWindowSizeResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_SetWindowSizeResponse(st), err
+}
+
+func NewRootConmon_SetWindowSizeResponse(s *capnp.Segment) (Conmon_SetWindowSizeResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_SetWindowSizeResponse(st), err
+}
+
+func ReadRootConmon_SetWindowSizeResponse(msg *capnp.Message) (Conmon_SetWindowSizeResponse, error) {
+	root, err := msg.Root()
+	return Conmon_SetWindowSizeResponse(root.Struct()), err
+}
+
+func (s Conmon_SetWindowSizeResponse) String() string {
+	str, _ := text.Marshal(0xf9b3cd8033aba1f8, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_SetWindowSizeResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_SetWindowSizeResponse) DecodeFromPtr(p capnp.Ptr) Conmon_SetWindowSizeResponse {
+	return Conmon_SetWindowSizeResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_SetWindowSizeResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_SetWindowSizeResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_SetWindowSizeResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_SetWindowSizeResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_SetWindowSizeResponse_List is a list of Conmon_SetWindowSizeResponse.
+type Conmon_SetWindowSizeResponse_List = capnp.StructList[Conmon_SetWindowSizeResponse]
+
+// NewConmon_SetWindowSizeResponse creates a new list of Conmon_SetWindowSizeResponse.
+func NewConmon_SetWindowSizeResponse_List(s *capnp.Segment, sz int32) (Conmon_SetWindowSizeResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_SetWindowSizeResponse](l), err
+}
+
+// Conmon_SetWindowSizeResponse_Future is a wrapper for a Conmon_SetWindowSizeResponse promised by a client call.
+type Conmon_SetWindowSizeResponse_Future struct{ *capnp.Future }
+
+func (f Conmon_SetWindowSizeResponse_Future) Struct() (Conmon_SetWindowSizeResponse, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_SetWindowSizeResponse(p.Struct()), err
+}
+
+type Conmon_CreateNamespacesRequest capnp.Struct
+
+// Conmon_CreateNamespacesRequest_TypeID is the unique identifier for the type Conmon_CreateNamespacesRequest.
+const Conmon_CreateNamespacesRequest_TypeID = 0x8b5b1693940f607e
+
+func NewConmon_CreateNamespacesRequest(s *capnp.Segment) (Conmon_CreateNamespacesRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 7})
+	return Conmon_CreateNamespacesRequest(st), err
+}
+
+func NewRootConmon_CreateNamespacesRequest(s *capnp.Segment) (Conmon_CreateNamespacesRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 7})
+	return Conmon_CreateNamespacesRequest(st), err
+}
+
+func ReadRootConmon_CreateNamespacesRequest(msg *capnp.Message) (Conmon_CreateNamespacesRequest, error) {
+	root, err := msg.Root()
+	return Conmon_CreateNamespacesRequest(root.Struct()), err
+}
+
+func (s Conmon_CreateNamespacesRequest) String() string {
+	str, _ := text.Marshal(0x8b5b1693940f607e, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_CreateNamespacesRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_CreateNamespacesRequest) DecodeFromPtr(p capnp.Ptr) Conmon_CreateNamespacesRequest {
+	return Conmon_CreateNamespacesRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_CreateNamespacesRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_CreateNamespacesRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_CreateNamespacesRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_CreateNamespacesRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_CreateNamespacesRequest) MetadataOld() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return []byte(p.Data()), err
+}
+
+func (s Conmon_CreateNamespacesRequest) HasMetadataOld() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_CreateNamespacesRequest) SetMetadataOld(v []byte) error {
+	return capnp.Struct(s).SetData(0, v)
+}
+
+func (s Conmon_CreateNamespacesRequest) Namespaces() (Conmon_Namespace_List, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return Conmon_Namespace_List(p.List()), err
+}
+
+func (s Conmon_CreateNamespacesRequest) HasNamespaces() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_CreateNamespacesRequest) SetNamespaces(v Conmon_Namespace_List) error {
+	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+}
+
+// NewNamespaces sets the namespaces field to a newly
+// allocated Conmon_Namespace_List, preferring placement in s's segment.
+func (s Conmon_CreateNamespacesRequest) NewNamespaces(n int32) (Conmon_Namespace_List, error) {
+	l, err := NewConmon_Namespace_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_Namespace_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	return l, err
+}
+func (s Conmon_CreateNamespacesRequest) UidMappings() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_CreateNamespacesRequest) HasUidMappings() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_CreateNamespacesRequest) SetUidMappings(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(2, v.ToPtr())
+}
+
+// NewUidMappings sets the uidMappings field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_CreateNamespacesRequest) NewUidMappings(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(2, l.ToPtr())
+	return l, err
+}
+func (s Conmon_CreateNamespacesRequest) GidMappings() (capnp.TextList, error) {
+	p, err := capnp.Struct(s).Ptr(3)
+	return capnp.TextList(p.List()), err
+}
+
+func (s Conmon_CreateNamespacesRequest) HasGidMappings() bool {
+	return capnp.Struct(s).HasPtr(3)
+}
+
+func (s Conmon_CreateNamespacesRequest) SetGidMappings(v capnp.TextList) error {
+	return capnp.Struct(s).SetPtr(3, v.ToPtr())
+}
+
+// NewGidMappings sets the gidMappings field to a newly
+// allocated capnp.TextList, preferring placement in s's segment.
+func (s Conmon_CreateNamespacesRequest) NewGidMappings(n int32) (capnp.TextList, error) {
+	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return capnp.TextList{}, err
+	}
+	err = capnp.Struct(s).SetPtr(3, l.ToPtr())
+	return l, err
+}
+func (s Conmon_CreateNamespacesRequest) BasePath() (string, error) {
+	p, err := capnp.Struct(s).Ptr(4)
+	return p.Text(), err
+}
+
+func (s Conmon_CreateNamespacesRequest) HasBasePath() bool {
+	return capnp.Struct(s).HasPtr(4)
+}
+
+func (s Conmon_CreateNamespacesRequest) BasePathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(4)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_CreateNamespacesRequest) SetBasePath(v string) error {
+	return capnp.Struct(s).SetText(4, v)
+}
+
+func (s Conmon_CreateNamespacesRequest) PodId() (string, error) {
+	p, err := capnp.Struct(s).Ptr(5)
+	return p.Text(), err
+}
+
+func (s Conmon_CreateNamespacesRequest) HasPodId() bool {
+	return capnp.Struct(s).HasPtr(5)
+}
+
+func (s Conmon_CreateNamespacesRequest) PodIdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(5)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_CreateNamespacesRequest) SetPodId(v string) error {
+	return capnp.Struct(s).SetText(5, v)
+}
+
+func (s Conmon_CreateNamespacesRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
+	p, err := capnp.Struct(s).Ptr(6)
+	return Conmon_TextTextMapEntry_List(p.List()), err
+}
+
+func (s Conmon_CreateNamespacesRequest) HasMetadata() bool {
+	return capnp.Struct(s).HasPtr(6)
+}
+
+func (s Conmon_CreateNamespacesRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
+	return capnp.Struct(s).SetPtr(6, v.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
+func (s Conmon_CreateNamespacesRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_TextTextMapEntry_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(6, l.ToPtr())
+	return l, err
+}
+
+// Conmon_CreateNamespacesRequest_List is a list of Conmon_CreateNamespacesRequest.
+type Conmon_CreateNamespacesRequest_List = capnp.StructList[Conmon_CreateNamespacesRequest]
+
+// NewConmon_CreateNamespacesRequest creates a new list of Conmon_CreateNamespacesRequest.
+func NewConmon_CreateNamespacesRequest_List(s *capnp.Segment, sz int32) (Conmon_CreateNamespacesRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 7}, sz)
+	return capnp.StructList[Conmon_CreateNamespacesRequest](l), err
+}
+
+// Conmon_CreateNamespacesRequest_Future is a wrapper for a Conmon_CreateNamespacesRequest promised by a client call.
+type Conmon_CreateNamespacesRequest_Future struct{ *capnp.Future }
+
+func (f Conmon_CreateNamespacesRequest_Future) Struct() (Conmon_CreateNamespacesRequest, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_CreateNamespacesRequest(p.Struct()), err
+}
+
+type Conmon_Namespace uint16
+
+// Conmon_Namespace_TypeID is the unique identifier for the type Conmon_Namespace.
+const Conmon_Namespace_TypeID = 0xd61491b560a8f3a3
+
+// Values of Conmon_Namespace.
+const (
+	Conmon_Namespace_ipc  Conmon_Namespace = 0
+	Conmon_Namespace_net  Conmon_Namespace = 1
+	Conmon_Namespace_pid  Conmon_Namespace = 2
+	Conmon_Namespace_user Conmon_Namespace = 3
+	Conmon_Namespace_uts  Conmon_Namespace = 4
+)
+
+// String returns the enum's constant name.
+func (c Conmon_Namespace) String() string {
+	switch c {
+	case Conmon_Namespace_ipc:
+		return "ipc"
+	case Conmon_Namespace_net:
+		return "net"
+	case Conmon_Namespace_pid:
+		return "pid"
+	case Conmon_Namespace_user:
+		return "user"
+	case Conmon_Namespace_uts:
+		return "uts"
+
+	default:
+		return ""
+	}
+}
+
+// Conmon_NamespaceFromString returns the enum value with a name,
+// or the zero value if there's no such value.
+func Conmon_NamespaceFromString(c string) Conmon_Namespace {
+	switch c {
+	case "ipc":
+		return Conmon_Namespace_ipc
+	case "net":
+		return Conmon_Namespace_net
+	case "pid":
+		return Conmon_Namespace_pid
+	case "user":
+		return Conmon_Namespace_user
+	case "uts":
+		return Conmon_Namespace_uts
+
+	default:
+		return 0
+	}
+}
+
+type Conmon_Namespace_List = capnp.EnumList[Conmon_Namespace]
+
+func NewConmon_Namespace_List(s *capnp.Segment, sz int32) (Conmon_Namespace_List, error) {
+	return capnp.NewEnumList[Conmon_Namespace](s, sz)
+}
+
+type Conmon_CreateNamespacesResponse capnp.Struct
+
+// Conmon_CreateNamespacesResponse_TypeID is the unique identifier for the type Conmon_CreateNamespacesResponse.
+const Conmon_CreateNamespacesResponse_TypeID = 0x9887a60f577a1ecb
+
+func NewConmon_CreateNamespacesResponse(s *capnp.Segment) (Conmon_CreateNamespacesResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_CreateNamespacesResponse(st), err
+}
+
+func NewRootConmon_CreateNamespacesResponse(s *capnp.Segment) (Conmon_CreateNamespacesResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_CreateNamespacesResponse(st), err
+}
+
+func ReadRootConmon_CreateNamespacesResponse(msg *capnp.Message) (Conmon_CreateNamespacesResponse, error) {
+	root, err := msg.Root()
+	return Conmon_CreateNamespacesResponse(root.Struct()), err
+}
+
+func (s Conmon_CreateNamespacesResponse) String() string {
+	str, _ := text.Marshal(0x9887a60f577a1ecb, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_CreateNamespacesResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_CreateNamespacesResponse) DecodeFromPtr(p capnp.Ptr) Conmon_CreateNamespacesResponse {
+	return Conmon_CreateNamespacesResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_CreateNamespacesResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_CreateNamespacesResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_CreateNamespacesResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_CreateNamespacesResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_CreateNamespacesResponse) Namespaces() (Conmon_NamespaceResponse_List, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_NamespaceResponse_List(p.List()), err
+}
+
+func (s Conmon_CreateNamespacesResponse) HasNamespaces() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_CreateNamespacesResponse) SetNamespaces(v Conmon_NamespaceResponse_List) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewNamespaces sets the namespaces field to a newly
+// allocated Conmon_NamespaceResponse_List, preferring placement in s's segment.
+func (s Conmon_CreateNamespacesResponse) NewNamespaces(n int32) (Conmon_NamespaceResponse_List, error) {
+	l, err := NewConmon_NamespaceResponse_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_NamespaceResponse_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+// Conmon_CreateNamespacesResponse_List is a list of Conmon_CreateNamespacesResponse.
+type Conmon_CreateNamespacesResponse_List = capnp.StructList[Conmon_CreateNamespacesResponse]
+
+// NewConmon_CreateNamespacesResponse creates a new list of Conmon_CreateNamespacesResponse.
+func NewConmon_CreateNamespacesResponse_List(s *capnp.Segment, sz int32) (Conmon_CreateNamespacesResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_CreateNamespacesResponse](l), err
+}
+
+// Conmon_CreateNamespacesResponse_Future is a wrapper for a Conmon_CreateNamespacesResponse promised by a client call.
+type Conmon_CreateNamespacesResponse_Future struct{ *capnp.Future }
+
+func (f Conmon_CreateNamespacesResponse_Future) Struct() (Conmon_CreateNamespacesResponse, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_CreateNamespacesResponse(p.Struct()), err
+}
+
+type Conmon_NamespaceResponse capnp.Struct
+
+// Conmon_NamespaceResponse_TypeID is the unique identifier for the type Conmon_NamespaceResponse.
+const Conmon_NamespaceResponse_TypeID = 0xa93853d6a4e3fa16
+
+func NewConmon_NamespaceResponse(s *capnp.Segment) (Conmon_NamespaceResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_NamespaceResponse(st), err
+}
+
+func NewRootConmon_NamespaceResponse(s *capnp.Segment) (Conmon_NamespaceResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_NamespaceResponse(st), err
+}
+
+func ReadRootConmon_NamespaceResponse(msg *capnp.Message) (Conmon_NamespaceResponse, error) {
+	root, err := msg.Root()
+	return Conmon_NamespaceResponse(root.Struct()), err
+}
+
+func (s Conmon_NamespaceResponse) String() string {
+	str, _ := text.Marshal(0xa93853d6a4e3fa16, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_NamespaceResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_NamespaceResponse) DecodeFromPtr(p capnp.Ptr) Conmon_NamespaceResponse {
+	return Conmon_NamespaceResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_NamespaceResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_NamespaceResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_NamespaceResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_NamespaceResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_NamespaceResponse) Type() Conmon_Namespace {
+	return Conmon_Namespace(capnp.Struct(s).Uint16(0))
+}
+
+func (s Conmon_NamespaceResponse) SetType(v Conmon_Namespace) {
+	capnp.Struct(s).SetUint16(0, uint16(v))
+}
+
+func (s Conmon_NamespaceResponse) Path() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_NamespaceResponse) HasPath() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_NamespaceResponse) PathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_NamespaceResponse) SetPath(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_NamespaceResponse_List is a list of Conmon_NamespaceResponse.
+type Conmon_NamespaceResponse_List = capnp.StructList[Conmon_NamespaceResponse]
+
+// NewConmon_NamespaceResponse creates a new list of Conmon_NamespaceResponse.
+func NewConmon_NamespaceResponse_List(s *capnp.Segment, sz int32) (Conmon_NamespaceResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_NamespaceResponse](l), err
+}
+
+// Conmon_NamespaceResponse_Future is a wrapper for a Conmon_NamespaceResponse promised by a client call.
+type Conmon_NamespaceResponse_Future struct{ *capnp.Future }
+
+func (f Conmon_NamespaceResponse_Future) Struct() (Conmon_NamespaceResponse, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_NamespaceResponse(p.Struct()), err
+}
+
+type Conmon_StartFdSocketRequest capnp.Struct
+
+// Conmon_StartFdSocketRequest_TypeID is the unique identifier for the type Conmon_StartFdSocketRequest.
+const Conmon_StartFdSocketRequest_TypeID = 0xba53ab87a688ec29
+
+func NewConmon_StartFdSocketRequest(s *capnp.Segment) (Conmon_StartFdSocketRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_StartFdSocketRequest(st), err
+}
+
+func NewRootConmon_StartFdSocketRequest(s *capnp.Segment) (Conmon_StartFdSocketRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_StartFdSocketRequest(st), err
+}
+
+func ReadRootConmon_StartFdSocketRequest(msg *capnp.Message) (Conmon_StartFdSocketRequest, error) {
+	root, err := msg.Root()
+	return Conmon_StartFdSocketRequest(root.Struct()), err
+}
+
+func (s Conmon_StartFdSocketRequest) String() string {
+	str, _ := text.Marshal(0xba53ab87a688ec29, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_StartFdSocketRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_StartFdSocketRequest) DecodeFromPtr(p capnp.Ptr) Conmon_StartFdSocketRequest {
+	return Conmon_StartFdSocketRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_StartFdSocketRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_StartFdSocketRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_StartFdSocketRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_StartFdSocketRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_StartFdSocketRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_TextTextMapEntry_List(p.List()), err
+}
+
+func (s Conmon_StartFdSocketRequest) HasMetadata() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_StartFdSocketRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
+func (s Conmon_StartFdSocketRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_TextTextMapEntry_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+
+// Conmon_StartFdSocketRequest_List is a list of Conmon_StartFdSocketRequest.
+type Conmon_StartFdSocketRequest_List = capnp.StructList[Conmon_StartFdSocketRequest]
+
+// NewConmon_StartFdSocketRequest creates a new list of Conmon_StartFdSocketRequest.
+func NewConmon_StartFdSocketRequest_List(s *capnp.Segment, sz int32) (Conmon_StartFdSocketRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_StartFdSocketRequest](l), err
+}
+
+// Conmon_StartFdSocketRequest_Future is a wrapper for a Conmon_StartFdSocketRequest promised by a client call.
+type Conmon_StartFdSocketRequest_Future struct{ *capnp.Future }
+
+func (f Conmon_StartFdSocketRequest_Future) Struct() (Conmon_StartFdSocketRequest, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_StartFdSocketRequest(p.Struct()), err
+}
+
+type Conmon_StartFdSocketResponse capnp.Struct
+
+// Conmon_StartFdSocketResponse_TypeID is the unique identifier for the type Conmon_StartFdSocketResponse.
+const Conmon_StartFdSocketResponse_TypeID = 0xb62f418e0ae4e003
+
+func NewConmon_StartFdSocketResponse(s *capnp.Segment) (Conmon_StartFdSocketResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_StartFdSocketResponse(st), err
+}
+
+func NewRootConmon_StartFdSocketResponse(s *capnp.Segment) (Conmon_StartFdSocketResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_StartFdSocketResponse(st), err
+}
+
+func ReadRootConmon_StartFdSocketResponse(msg *capnp.Message) (Conmon_StartFdSocketResponse, error) {
+	root, err := msg.Root()
+	return Conmon_StartFdSocketResponse(root.Struct()), err
+}
+
+func (s Conmon_StartFdSocketResponse) String() string {
+	str, _ := text.Marshal(0xb62f418e0ae4e003, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_StartFdSocketResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_StartFdSocketResponse) DecodeFromPtr(p capnp.Ptr) Conmon_StartFdSocketResponse {
+	return Conmon_StartFdSocketResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_StartFdSocketResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_StartFdSocketResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_StartFdSocketResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_StartFdSocketResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_StartFdSocketResponse) Path() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_StartFdSocketResponse) HasPath() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_StartFdSocketResponse) PathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_StartFdSocketResponse) SetPath(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+// Conmon_StartFdSocketResponse_List is a list of Conmon_StartFdSocketResponse.
+type Conmon_StartFdSocketResponse_List = capnp.StructList[Conmon_StartFdSocketResponse]
+
+// NewConmon_StartFdSocketResponse creates a new list of Conmon_StartFdSocketResponse.
+func NewConmon_StartFdSocketResponse_List(s *capnp.Segment, sz int32) (Conmon_StartFdSocketResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_StartFdSocketResponse](l), err
+}
+
+// Conmon_StartFdSocketResponse_Future is a wrapper for a Conmon_StartFdSocketResponse promised by a client call.
+type Conmon_StartFdSocketResponse_Future struct{ *capnp.Future }
+
+func (f Conmon_StartFdSocketResponse_Future) Struct() (Conmon_StartFdSocketResponse, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_StartFdSocketResponse(p.Struct()), err
+}
+
+type Conmon_TailLogRequest capnp.Struct
+
+// Conmon_TailLogRequest_TypeID is the unique identifier for the type Conmon_TailLogRequest.
+const Conmon_TailLogRequest_TypeID = 0xff1fe278c0cfa678
+
+func NewConmon_TailLogRequest(s *capnp.Segment) (Conmon_TailLogRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 32, PointerCount: 2})
+	return Conmon_TailLogRequest(st), err
+}
+
+func NewRootConmon_TailLogRequest(s *capnp.Segment) (Conmon_TailLogRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 32, PointerCount: 2})
+	return Conmon_TailLogRequest(st), err
+}
+
+func ReadRootConmon_TailLogRequest(msg *capnp.Message) (Conmon_TailLogRequest, error) {
+	root, err := msg.Root()
+	return Conmon_TailLogRequest(root.Struct()), err
+}
+
+func (s Conmon_TailLogRequest) String() string {
+	str, _ := text.Marshal(0xff1fe278c0cfa678, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_TailLogRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_TailLogRequest) DecodeFromPtr(p capnp.Ptr) Conmon_TailLogRequest {
+	return Conmon_TailLogRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_TailLogRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_TailLogRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_TailLogRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_TailLogRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_TailLogRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_TailLogRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_TailLogRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_TailLogRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_TailLogRequest) Lines() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_TailLogRequest) SetLines(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+func (s Conmon_TailLogRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return Conmon_TextTextMapEntry_List(p.List()), err
+}
+
+func (s Conmon_TailLogRequest) HasMetadata() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_TailLogRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
+	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
+func (s Conmon_TailLogRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_TextTextMapEntry_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	return l, err
+}
+func (s Conmon_TailLogRequest) Since() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_TailLogRequest) SetSince(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+func (s Conmon_TailLogRequest) Until() uint64 {
+	return capnp.Struct(s).Uint64(16)
+}
+
+func (s Conmon_TailLogRequest) SetUntil(v uint64) {
+	capnp.Struct(s).SetUint64(16, v)
+}
+
+func (s Conmon_TailLogRequest) SkipInvalidTimestamps() bool {
+	return capnp.Struct(s).Bit(192)
+}
+
+func (s Conmon_TailLogRequest) SetSkipInvalidTimestamps(v bool) {
+	capnp.Struct(s).SetBit(192, v)
+}
+
+func (s Conmon_TailLogRequest) Stream() Conmon_LogDriver_Stream {
+	return Conmon_LogDriver_Stream(capnp.Struct(s).Uint16(26))
+}
+
+func (s Conmon_TailLogRequest) SetStream(v Conmon_LogDriver_Stream) {
+	capnp.Struct(s).SetUint16(26, uint16(v))
+}
+
+// Conmon_TailLogRequest_List is a list of Conmon_TailLogRequest.
+type Conmon_TailLogRequest_List = capnp.StructList[Conmon_TailLogRequest]
+
+// NewConmon_TailLogRequest creates a new list of Conmon_TailLogRequest.
+func NewConmon_TailLogRequest_List(s *capnp.Segment, sz int32) (Conmon_TailLogRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 32, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_TailLogRequest](l), err
+}
+
+// Conmon_TailLogRequest_Future is a wrapper for a Conmon_TailLogRequest promised by a client call.
+type Conmon_TailLogRequest_Future struct{ *capnp.Future }
+
+func (f Conmon_TailLogRequest_Future) Struct() (Conmon_TailLogRequest, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_TailLogRequest(p.Struct()), err
+}
+
+type Conmon_TailLogResponse capnp.Struct
+
+// Conmon_TailLogResponse_TypeID is the unique identifier for the type Conmon_TailLogResponse.
+const Conmon_TailLogResponse_TypeID = 0x90dd84f8954874b4
+
+func NewConmon_TailLogResponse(s *capnp.Segment) (Conmon_TailLogResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_TailLogResponse(st), err
+}
+
+func NewRootConmon_TailLogResponse(s *capnp.Segment) (Conmon_TailLogResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
+	return Conmon_TailLogResponse(st), err
+}
+
+func ReadRootConmon_TailLogResponse(msg *capnp.Message) (Conmon_TailLogResponse, error) {
+	root, err := msg.Root()
+	return Conmon_TailLogResponse(root.Struct()), err
+}
+
+func (s Conmon_TailLogResponse) String() string {
+	str, _ := text.Marshal(0x90dd84f8954874b4, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_TailLogResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_TailLogResponse) DecodeFromPtr(p capnp.Ptr) Conmon_TailLogResponse {
+	return Conmon_TailLogResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_TailLogResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_TailLogResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_TailLogResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_TailLogResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_TailLogResponse) Lines() (Conmon_LogLine_List, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_LogLine_List(p.List()), err
+}
+
+func (s Conmon_TailLogResponse) HasLines() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_TailLogResponse) SetLines(v Conmon_LogLine_List) error {
+	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+}
+
+// NewLines sets the lines field to a newly
+// allocated Conmon_LogLine_List, preferring placement in s's segment.
+func (s Conmon_TailLogResponse) NewLines(n int32) (Conmon_LogLine_List, error) {
+	l, err := NewConmon_LogLine_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_LogLine_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
+	return l, err
+}
+func (s Conmon_TailLogResponse) LastWrite() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_TailLogResponse) SetLastWrite(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+// Conmon_TailLogResponse_List is a list of Conmon_TailLogResponse.
+type Conmon_TailLogResponse_List = capnp.StructList[Conmon_TailLogResponse]
+
+// NewConmon_TailLogResponse creates a new list of Conmon_TailLogResponse.
+func NewConmon_TailLogResponse_List(s *capnp.Segment, sz int32) (Conmon_TailLogResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_TailLogResponse](l), err
+}
+
+// Conmon_TailLogResponse_Future is a wrapper for a Conmon_TailLogResponse promised by a client call.
+type Conmon_TailLogResponse_Future struct{ *capnp.Future }
+
+func (f Conmon_TailLogResponse_Future) Struct() (Conmon_TailLogResponse, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_TailLogResponse(p.Struct()), err
+}
+
+type Conmon_LogLine capnp.Struct
+
+// Conmon_LogLine_TypeID is the unique identifier for the type Conmon_LogLine.
+const Conmon_LogLine_TypeID = 0xf45b380214ff8477
+
+func NewConmon_LogLine(s *capnp.Segment) (Conmon_LogLine, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
+	return Conmon_LogLine(st), err
+}
+
+func NewRootConmon_LogLine(s *capnp.Segment) (Conmon_LogLine, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
+	return Conmon_LogLine(st), err
+}
+
+func ReadRootConmon_LogLine(msg *capnp.Message) (Conmon_LogLine, error) {
+	root, err := msg.Root()
+	return Conmon_LogLine(root.Struct()), err
+}
+
+func (s Conmon_LogLine) String() string {
+	str, _ := text.Marshal(0xf45b380214ff8477, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_LogLine) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_LogLine) DecodeFromPtr(p capnp.Ptr) Conmon_LogLine {
+	return Conmon_LogLine(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_LogLine) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_LogLine) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_LogLine) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_LogLine) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_LogLine) Timestamp() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_LogLine) HasTimestamp() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_LogLine) TimestampBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_LogLine) SetTimestamp(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_LogLine) Pipe() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_LogLine) HasPipe() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_LogLine) PipeBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_LogLine) SetPipe(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+func (s Conmon_LogLine) Message() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return []byte(p.Data()), err
+}
+
+func (s Conmon_LogLine) HasMessage() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_LogLine) SetMessage(v []byte) error {
+	return capnp.Struct(s).SetData(2, v)
+}
+
+// Conmon_LogLine_List is a list of Conmon_LogLine.
+type Conmon_LogLine_List = capnp.StructList[Conmon_LogLine]
+
+// NewConmon_LogLine creates a new list of Conmon_LogLine.
+func NewConmon_LogLine_List(s *capnp.Segment, sz int32) (Conmon_LogLine_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_LogLine](l), err
+}
+
+// Conmon_LogLine_Future is a wrapper for a Conmon_LogLine promised by a client call.
+type Conmon_LogLine_Future struct{ *capnp.Future }
+
+func (f Conmon_LogLine_Future) Struct() (Conmon_LogLine, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_LogLine(p.Struct()), err
+}
+
+type Conmon_PauseLogRequest capnp.Struct
+
+// Conmon_PauseLogRequest_TypeID is the unique identifier for the type Conmon_PauseLogRequest.
+const Conmon_PauseLogRequest_TypeID = 0xbf8bf393a5b0605f
+
+func NewConmon_PauseLogRequest(s *capnp.Segment) (Conmon_PauseLogRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
+	return Conmon_PauseLogRequest(st), err
+}
+
+func NewRootConmon_PauseLogRequest(s *capnp.Segment) (Conmon_PauseLogRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2})
+	return Conmon_PauseLogRequest(st), err
+}
+
+func ReadRootConmon_PauseLogRequest(msg *capnp.Message) (Conmon_PauseLogRequest, error) {
+	root, err := msg.Root()
+	return Conmon_PauseLogRequest(root.Struct()), err
+}
+
+func (s Conmon_PauseLogRequest) String() string {
+	str, _ := text.Marshal(0xbf8bf393a5b0605f, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_PauseLogRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_PauseLogRequest) DecodeFromPtr(p capnp.Ptr) Conmon_PauseLogRequest {
+	return Conmon_PauseLogRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_PauseLogRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_PauseLogRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_PauseLogRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_PauseLogRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_PauseLogRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_PauseLogRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_PauseLogRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_PauseLogRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_PauseLogRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return Conmon_TextTextMapEntry_List(p.List()), err
+}
+
+func (s Conmon_PauseLogRequest) HasMetadata() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_PauseLogRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
+	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
+func (s Conmon_PauseLogRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_TextTextMapEntry_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	return l, err
+}
+func (s Conmon_PauseLogRequest) BufferSize() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_PauseLogRequest) SetBufferSize(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+// Conmon_PauseLogRequest_List is a list of Conmon_PauseLogRequest.
+type Conmon_PauseLogRequest_List = capnp.StructList[Conmon_PauseLogRequest]
+
+// NewConmon_PauseLogRequest creates a new list of Conmon_PauseLogRequest.
+func NewConmon_PauseLogRequest_List(s *capnp.Segment, sz int32) (Conmon_PauseLogRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_PauseLogRequest](l), err
+}
+
+// Conmon_PauseLogRequest_Future is a wrapper for a Conmon_PauseLogRequest promised by a client call.
+type Conmon_PauseLogRequest_Future struct{ *capnp.Future }
+
+func (f Conmon_PauseLogRequest_Future) Struct() (Conmon_PauseLogRequest, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_PauseLogRequest(p.Struct()), err
+}
+
+type Conmon_PauseLogResponse capnp.Struct
+
+// Conmon_PauseLogResponse_TypeID is the unique identifier for the type Conmon_PauseLogResponse.
+const Conmon_PauseLogResponse_TypeID = 0xb4e9d49db5875667
+
+func NewConmon_PauseLogResponse(s *capnp.Segment) (Conmon_PauseLogResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_PauseLogResponse(st), err
+}
+
+func NewRootConmon_PauseLogResponse(s *capnp.Segment) (Conmon_PauseLogResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_PauseLogResponse(st), err
+}
+
+func ReadRootConmon_PauseLogResponse(msg *capnp.Message) (Conmon_PauseLogResponse, error) {
+	root, err := msg.Root()
+	return Conmon_PauseLogResponse(root.Struct()), err
+}
+
+func (s Conmon_PauseLogResponse) String() string {
+	str, _ := text.Marshal(0xb4e9d49db5875667, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_PauseLogResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_PauseLogResponse) DecodeFromPtr(p capnp.Ptr) Conmon_PauseLogResponse {
+	return Conmon_PauseLogResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_PauseLogResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_PauseLogResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_PauseLogResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_PauseLogResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_PauseLogResponse) AlreadyPaused() bool {
+	return capnp.Struct(s).Bit(0)
+}
+
+func (s Conmon_PauseLogResponse) SetAlreadyPaused(v bool) {
+	capnp.Struct(s).SetBit(0, v)
+}
+
+// Conmon_PauseLogResponse_List is a list of Conmon_PauseLogResponse.
+type Conmon_PauseLogResponse_List = capnp.StructList[Conmon_PauseLogResponse]
+
+// NewConmon_PauseLogResponse creates a new list of Conmon_PauseLogResponse.
+func NewConmon_PauseLogResponse_List(s *capnp.Segment, sz int32) (Conmon_PauseLogResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_PauseLogResponse](l), err
+}
+
+// Conmon_PauseLogResponse_Future is a wrapper for a Conmon_PauseLogResponse promised by a client call.
+type Conmon_PauseLogResponse_Future struct{ *capnp.Future }
+
+func (f Conmon_PauseLogResponse_Future) Struct() (Conmon_PauseLogResponse, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_PauseLogResponse(p.Struct()), err
+}
+
+type Conmon_ResumeLogRequest capnp.Struct
+
+// Conmon_ResumeLogRequest_TypeID is the unique identifier for the type Conmon_ResumeLogRequest.
+const Conmon_ResumeLogRequest_TypeID = 0xbdf982a1bf936157
+
+func NewConmon_ResumeLogRequest(s *capnp.Segment) (Conmon_ResumeLogRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_ResumeLogRequest(st), err
+}
+
+func NewRootConmon_ResumeLogRequest(s *capnp.Segment) (Conmon_ResumeLogRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_ResumeLogRequest(st), err
+}
+
+func ReadRootConmon_ResumeLogRequest(msg *capnp.Message) (Conmon_ResumeLogRequest, error) {
+	root, err := msg.Root()
+	return Conmon_ResumeLogRequest(root.Struct()), err
+}
+
+func (s Conmon_ResumeLogRequest) String() string {
+	str, _ := text.Marshal(0xbdf982a1bf936157, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ResumeLogRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ResumeLogRequest) DecodeFromPtr(p capnp.Ptr) Conmon_ResumeLogRequest {
+	return Conmon_ResumeLogRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ResumeLogRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ResumeLogRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ResumeLogRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ResumeLogRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_ResumeLogRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_ResumeLogRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_ResumeLogRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_ResumeLogRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_ResumeLogRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return Conmon_TextTextMapEntry_List(p.List()), err
+}
+
+func (s Conmon_ResumeLogRequest) HasMetadata() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_ResumeLogRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
+	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
+func (s Conmon_ResumeLogRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_TextTextMapEntry_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	return l, err
+}
+
+// Conmon_ResumeLogRequest_List is a list of Conmon_ResumeLogRequest.
+type Conmon_ResumeLogRequest_List = capnp.StructList[Conmon_ResumeLogRequest]
+
+// NewConmon_ResumeLogRequest creates a new list of Conmon_ResumeLogRequest.
+func NewConmon_ResumeLogRequest_List(s *capnp.Segment, sz int32) (Conmon_ResumeLogRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_ResumeLogRequest](l), err
+}
+
+// Conmon_ResumeLogRequest_Future is a wrapper for a Conmon_ResumeLogRequest promised by a client call.
+type Conmon_ResumeLogRequest_Future struct{ *capnp.Future }
+
+func (f Conmon_ResumeLogRequest_Future) Struct() (Conmon_ResumeLogRequest, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_ResumeLogRequest(p.Struct()), err
+}
+
+type Conmon_ResumeLogResponse capnp.Struct
+
+// Conmon_ResumeLogResponse_TypeID is the unique identifier for the type Conmon_ResumeLogResponse.
+const Conmon_ResumeLogResponse_TypeID = 0xd9d185299a8131ea
+
+func NewConmon_ResumeLogResponse(s *capnp.Segment) (Conmon_ResumeLogResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ResumeLogResponse(st), err
+}
+
+func NewRootConmon_ResumeLogResponse(s *capnp.Segment) (Conmon_ResumeLogResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_ResumeLogResponse(st), err
+}
+
+func ReadRootConmon_ResumeLogResponse(msg *capnp.Message) (Conmon_ResumeLogResponse, error) {
+	root, err := msg.Root()
+	return Conmon_ResumeLogResponse(root.Struct()), err
+}
+
+func (s Conmon_ResumeLogResponse) String() string {
+	str, _ := text.Marshal(0xd9d185299a8131ea, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_ResumeLogResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_ResumeLogResponse) DecodeFromPtr(p capnp.Ptr) Conmon_ResumeLogResponse {
+	return Conmon_ResumeLogResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_ResumeLogResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_ResumeLogResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_ResumeLogResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_ResumeLogResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_ResumeLogResponse_List is a list of Conmon_ResumeLogResponse.
+type Conmon_ResumeLogResponse_List = capnp.StructList[Conmon_ResumeLogResponse]
+
+// NewConmon_ResumeLogResponse creates a new list of Conmon_ResumeLogResponse.
+func NewConmon_ResumeLogResponse_List(s *capnp.Segment, sz int32) (Conmon_ResumeLogResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_ResumeLogResponse](l), err
+}
+
+// Conmon_ResumeLogResponse_Future is a wrapper for a Conmon_ResumeLogResponse promised by a client call.
+type Conmon_ResumeLogResponse_Future struct{ *capnp.Future }
+
+func (f Conmon_ResumeLogResponse_Future) Struct() (Conmon_ResumeLogResponse, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_ResumeLogResponse(p.Struct()), err
+}
+
+type Conmon_AddLogDriverRequest capnp.Struct
+
+// Conmon_AddLogDriverRequest_TypeID is the unique identifier for the type Conmon_AddLogDriverRequest.
+const Conmon_AddLogDriverRequest_TypeID = 0xeecb8f2cf4fdde21
+
+func NewConmon_AddLogDriverRequest(s *capnp.Segment) (Conmon_AddLogDriverRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
+	return Conmon_AddLogDriverRequest(st), err
+}
+
+func NewRootConmon_AddLogDriverRequest(s *capnp.Segment) (Conmon_AddLogDriverRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
+	return Conmon_AddLogDriverRequest(st), err
+}
+
+func ReadRootConmon_AddLogDriverRequest(msg *capnp.Message) (Conmon_AddLogDriverRequest, error) {
+	root, err := msg.Root()
+	return Conmon_AddLogDriverRequest(root.Struct()), err
+}
+
+func (s Conmon_AddLogDriverRequest) String() string {
+	str, _ := text.Marshal(0xeecb8f2cf4fdde21, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_AddLogDriverRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_AddLogDriverRequest) DecodeFromPtr(p capnp.Ptr) Conmon_AddLogDriverRequest {
+	return Conmon_AddLogDriverRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_AddLogDriverRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_AddLogDriverRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_AddLogDriverRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_AddLogDriverRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_AddLogDriverRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_AddLogDriverRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_AddLogDriverRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_AddLogDriverRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_AddLogDriverRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return Conmon_TextTextMapEntry_List(p.List()), err
+}
+
+func (s Conmon_AddLogDriverRequest) HasMetadata() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_AddLogDriverRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
+	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
+func (s Conmon_AddLogDriverRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_TextTextMapEntry_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	return l, err
+}
+func (s Conmon_AddLogDriverRequest) Driver() (Conmon_LogDriver, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return Conmon_LogDriver(p.Struct()), err
+}
+
+func (s Conmon_AddLogDriverRequest) HasDriver() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_AddLogDriverRequest) SetDriver(v Conmon_LogDriver) error {
+	return capnp.Struct(s).SetPtr(2, capnp.Struct(v).ToPtr())
+}
+
+// NewDriver sets the driver field to a newly
+// allocated Conmon_LogDriver struct, preferring placement in s's segment.
+func (s Conmon_AddLogDriverRequest) NewDriver() (Conmon_LogDriver, error) {
+	ss, err := NewConmon_LogDriver(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_LogDriver{}, err
+	}
+	err = capnp.Struct(s).SetPtr(2, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_AddLogDriverRequest_List is a list of Conmon_AddLogDriverRequest.
+type Conmon_AddLogDriverRequest_List = capnp.StructList[Conmon_AddLogDriverRequest]
+
+// NewConmon_AddLogDriverRequest creates a new list of Conmon_AddLogDriverRequest.
+func NewConmon_AddLogDriverRequest_List(s *capnp.Segment, sz int32) (Conmon_AddLogDriverRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_AddLogDriverRequest](l), err
+}
+
+// Conmon_AddLogDriverRequest_Future is a wrapper for a Conmon_AddLogDriverRequest promised by a client call.
+type Conmon_AddLogDriverRequest_Future struct{ *capnp.Future }
+
+func (f Conmon_AddLogDriverRequest_Future) Struct() (Conmon_AddLogDriverRequest, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_AddLogDriverRequest(p.Struct()), err
+}
+func (p Conmon_AddLogDriverRequest_Future) Driver() Conmon_LogDriver_Future {
+	return Conmon_LogDriver_Future{Future: p.Future.Field(2, nil)}
+}
+
+type Conmon_AddLogDriverResponse capnp.Struct
+
+// Conmon_AddLogDriverResponse_TypeID is the unique identifier for the type Conmon_AddLogDriverResponse.
+const Conmon_AddLogDriverResponse_TypeID = 0xabfd7291f61ff659
+
+func NewConmon_AddLogDriverResponse(s *capnp.Segment) (Conmon_AddLogDriverResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_AddLogDriverResponse(st), err
+}
+
+func NewRootConmon_AddLogDriverResponse(s *capnp.Segment) (Conmon_AddLogDriverResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_AddLogDriverResponse(st), err
+}
+
+func ReadRootConmon_AddLogDriverResponse(msg *capnp.Message) (Conmon_AddLogDriverResponse, error) {
+	root, err := msg.Root()
+	return Conmon_AddLogDriverResponse(root.Struct()), err
+}
+
+func (s Conmon_AddLogDriverResponse) String() string {
+	str, _ := text.Marshal(0xabfd7291f61ff659, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_AddLogDriverResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_AddLogDriverResponse) DecodeFromPtr(p capnp.Ptr) Conmon_AddLogDriverResponse {
+	return Conmon_AddLogDriverResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_AddLogDriverResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_AddLogDriverResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_AddLogDriverResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_AddLogDriverResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_AddLogDriverResponse_List is a list of Conmon_AddLogDriverResponse.
+type Conmon_AddLogDriverResponse_List = capnp.StructList[Conmon_AddLogDriverResponse]
+
+// NewConmon_AddLogDriverResponse creates a new list of Conmon_AddLogDriverResponse.
+func NewConmon_AddLogDriverResponse_List(s *capnp.Segment, sz int32) (Conmon_AddLogDriverResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_AddLogDriverResponse](l), err
+}
+
+// Conmon_AddLogDriverResponse_Future is a wrapper for a Conmon_AddLogDriverResponse promised by a client call.
+type Conmon_AddLogDriverResponse_Future struct{ *capnp.Future }
+
+func (f Conmon_AddLogDriverResponse_Future) Struct() (Conmon_AddLogDriverResponse, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_AddLogDriverResponse(p.Struct()), err
+}
+
+type Conmon_RemoveLogDriverRequest capnp.Struct
+
+// Conmon_RemoveLogDriverRequest_TypeID is the unique identifier for the type Conmon_RemoveLogDriverRequest.
+const Conmon_RemoveLogDriverRequest_TypeID = 0xec285699f2c21582
+
+func NewConmon_RemoveLogDriverRequest(s *capnp.Segment) (Conmon_RemoveLogDriverRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
+	return Conmon_RemoveLogDriverRequest(st), err
+}
+
+func NewRootConmon_RemoveLogDriverRequest(s *capnp.Segment) (Conmon_RemoveLogDriverRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3})
+	return Conmon_RemoveLogDriverRequest(st), err
+}
+
+func ReadRootConmon_RemoveLogDriverRequest(msg *capnp.Message) (Conmon_RemoveLogDriverRequest, error) {
+	root, err := msg.Root()
+	return Conmon_RemoveLogDriverRequest(root.Struct()), err
+}
+
+func (s Conmon_RemoveLogDriverRequest) String() string {
+	str, _ := text.Marshal(0xec285699f2c21582, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_RemoveLogDriverRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_RemoveLogDriverRequest) DecodeFromPtr(p capnp.Ptr) Conmon_RemoveLogDriverRequest {
+	return Conmon_RemoveLogDriverRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_RemoveLogDriverRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_RemoveLogDriverRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_RemoveLogDriverRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_RemoveLogDriverRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_RemoveLogDriverRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_RemoveLogDriverRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_RemoveLogDriverRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_RemoveLogDriverRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_RemoveLogDriverRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return Conmon_TextTextMapEntry_List(p.List()), err
+}
+
+func (s Conmon_RemoveLogDriverRequest) HasMetadata() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_RemoveLogDriverRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
+	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
+func (s Conmon_RemoveLogDriverRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_TextTextMapEntry_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	return l, err
+}
+func (s Conmon_RemoveLogDriverRequest) Path() (string, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.Text(), err
+}
+
+func (s Conmon_RemoveLogDriverRequest) HasPath() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_RemoveLogDriverRequest) PathBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(2)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_RemoveLogDriverRequest) SetPath(v string) error {
+	return capnp.Struct(s).SetText(2, v)
+}
+
+// Conmon_RemoveLogDriverRequest_List is a list of Conmon_RemoveLogDriverRequest.
+type Conmon_RemoveLogDriverRequest_List = capnp.StructList[Conmon_RemoveLogDriverRequest]
+
+// NewConmon_RemoveLogDriverRequest creates a new list of Conmon_RemoveLogDriverRequest.
+func NewConmon_RemoveLogDriverRequest_List(s *capnp.Segment, sz int32) (Conmon_RemoveLogDriverRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_RemoveLogDriverRequest](l), err
+}
+
+// Conmon_RemoveLogDriverRequest_Future is a wrapper for a Conmon_RemoveLogDriverRequest promised by a client call.
+type Conmon_RemoveLogDriverRequest_Future struct{ *capnp.Future }
+
+func (f Conmon_RemoveLogDriverRequest_Future) Struct() (Conmon_RemoveLogDriverRequest, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_RemoveLogDriverRequest(p.Struct()), err
+}
+
+type Conmon_RemoveLogDriverResponse capnp.Struct
+
+// Conmon_RemoveLogDriverResponse_TypeID is the unique identifier for the type Conmon_RemoveLogDriverResponse.
+const Conmon_RemoveLogDriverResponse_TypeID = 0xe1405aa7f55a9152
+
+func NewConmon_RemoveLogDriverResponse(s *capnp.Segment) (Conmon_RemoveLogDriverResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_RemoveLogDriverResponse(st), err
+}
+
+func NewRootConmon_RemoveLogDriverResponse(s *capnp.Segment) (Conmon_RemoveLogDriverResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0})
+	return Conmon_RemoveLogDriverResponse(st), err
+}
+
+func ReadRootConmon_RemoveLogDriverResponse(msg *capnp.Message) (Conmon_RemoveLogDriverResponse, error) {
+	root, err := msg.Root()
+	return Conmon_RemoveLogDriverResponse(root.Struct()), err
+}
+
+func (s Conmon_RemoveLogDriverResponse) String() string {
+	str, _ := text.Marshal(0xe1405aa7f55a9152, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_RemoveLogDriverResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_RemoveLogDriverResponse) DecodeFromPtr(p capnp.Ptr) Conmon_RemoveLogDriverResponse {
+	return Conmon_RemoveLogDriverResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_RemoveLogDriverResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_RemoveLogDriverResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_RemoveLogDriverResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_RemoveLogDriverResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_RemoveLogDriverResponse) Removed() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_RemoveLogDriverResponse) SetRemoved(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+// Conmon_RemoveLogDriverResponse_List is a list of Conmon_RemoveLogDriverResponse.
+type Conmon_RemoveLogDriverResponse_List = capnp.StructList[Conmon_RemoveLogDriverResponse]
+
+// NewConmon_RemoveLogDriverResponse creates a new list of Conmon_RemoveLogDriverResponse.
+func NewConmon_RemoveLogDriverResponse_List(s *capnp.Segment, sz int32) (Conmon_RemoveLogDriverResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_RemoveLogDriverResponse](l), err
+}
+
+// Conmon_RemoveLogDriverResponse_Future is a wrapper for a Conmon_RemoveLogDriverResponse promised by a client call.
+type Conmon_RemoveLogDriverResponse_Future struct{ *capnp.Future }
+
+func (f Conmon_RemoveLogDriverResponse_Future) Struct() (Conmon_RemoveLogDriverResponse, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_RemoveLogDriverResponse(p.Struct()), err
+}
+
+type Conmon_FollowLogRequest capnp.Struct
+
+// Conmon_FollowLogRequest_TypeID is the unique identifier for the type Conmon_FollowLogRequest.
+const Conmon_FollowLogRequest_TypeID = 0xd6b52319bc826f96
+
+func NewConmon_FollowLogRequest(s *capnp.Segment) (Conmon_FollowLogRequest, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 3})
+	return Conmon_FollowLogRequest(st), err
+}
+
+func NewRootConmon_FollowLogRequest(s *capnp.Segment) (Conmon_FollowLogRequest, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 24, PointerCount: 3})
+	return Conmon_FollowLogRequest(st), err
+}
+
+func ReadRootConmon_FollowLogRequest(msg *capnp.Message) (Conmon_FollowLogRequest, error) {
+	root, err := msg.Root()
+	return Conmon_FollowLogRequest(root.Struct()), err
+}
+
+func (s Conmon_FollowLogRequest) String() string {
+	str, _ := text.Marshal(0xd6b52319bc826f96, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_FollowLogRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_FollowLogRequest) DecodeFromPtr(p capnp.Ptr) Conmon_FollowLogRequest {
+	return Conmon_FollowLogRequest(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_FollowLogRequest) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_FollowLogRequest) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_FollowLogRequest) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_FollowLogRequest) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_FollowLogRequest) Id() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_FollowLogRequest) HasId() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_FollowLogRequest) IdBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_FollowLogRequest) SetId(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_FollowLogRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return Conmon_TextTextMapEntry_List(p.List()), err
+}
+
+func (s Conmon_FollowLogRequest) HasMetadata() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_FollowLogRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
+	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+}
+
+// NewMetadata sets the metadata field to a newly
+// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
+func (s Conmon_FollowLogRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+	if err != nil {
+		return Conmon_TextTextMapEntry_List{}, err
+	}
+	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
+	return l, err
+}
+func (s Conmon_FollowLogRequest) Since() uint64 {
+	return capnp.Struct(s).Uint64(0)
+}
+
+func (s Conmon_FollowLogRequest) SetSince(v uint64) {
+	capnp.Struct(s).SetUint64(0, v)
+}
+
+func (s Conmon_FollowLogRequest) Until() uint64 {
+	return capnp.Struct(s).Uint64(8)
+}
+
+func (s Conmon_FollowLogRequest) SetUntil(v uint64) {
+	capnp.Struct(s).SetUint64(8, v)
+}
+
+func (s Conmon_FollowLogRequest) SkipInvalidTimestamps() bool {
+	return capnp.Struct(s).Bit(128)
+}
+
+func (s Conmon_FollowLogRequest) SetSkipInvalidTimestamps(v bool) {
+	capnp.Struct(s).SetBit(128, v)
+}
+
+func (s Conmon_FollowLogRequest) Stream() Conmon_LogDriver_Stream {
+	return Conmon_LogDriver_Stream(capnp.Struct(s).Uint16(18))
+}
+
+func (s Conmon_FollowLogRequest) SetStream(v Conmon_LogDriver_Stream) {
+	capnp.Struct(s).SetUint16(18, uint16(v))
+}
+
+func (s Conmon_FollowLogRequest) Listener() Conmon_LogListener {
+	p, _ := capnp.Struct(s).Ptr(2)
+	return Conmon_LogListener(p.Interface().Client())
+}
+
+func (s Conmon_FollowLogRequest) HasListener() bool {
+	return capnp.Struct(s).HasPtr(2)
+}
+
+func (s Conmon_FollowLogRequest) SetListener(v Conmon_LogListener) error {
+	if !v.IsValid() {
+		return capnp.Struct(s).SetPtr(2, capnp.Ptr{})
+	}
+	seg := s.Segment()
+	in := capnp.NewInterface(seg, seg.Message().CapTable().Add(capnp.Client(v)))
+	return capnp.Struct(s).SetPtr(2, in.ToPtr())
+}
+
+// Conmon_FollowLogRequest_List is a list of Conmon_FollowLogRequest.
+type Conmon_FollowLogRequest_List = capnp.StructList[Conmon_FollowLogRequest]
+
+// NewConmon_FollowLogRequest creates a new list of Conmon_FollowLogRequest.
+func NewConmon_FollowLogRequest_List(s *capnp.Segment, sz int32) (Conmon_FollowLogRequest_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 24, PointerCount: 3}, sz)
+	return capnp.StructList[Conmon_FollowLogRequest](l), err
+}
+
+// Conmon_FollowLogRequest_Future is a wrapper for a Conmon_FollowLogRequest promised by a client call.
+type Conmon_FollowLogRequest_Future struct{ *capnp.Future }
+
+func (f Conmon_FollowLogRequest_Future) Struct() (Conmon_FollowLogRequest, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_FollowLogRequest(p.Struct()), err
+}
+func (p Conmon_FollowLogRequest_Future) Listener() Conmon_LogListener {
+	return Conmon_LogListener(p.Future.Field(2, nil).Client())
+}
+
+type Conmon_FollowLogResponse capnp.Struct
+
+// Conmon_FollowLogResponse_TypeID is the unique identifier for the type Conmon_FollowLogResponse.
+const Conmon_FollowLogResponse_TypeID = 0xf9cd7b95c38bfa8e
+
+func NewConmon_FollowLogResponse(s *capnp.Segment) (Conmon_FollowLogResponse, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_FollowLogResponse(st), err
+}
+
+func NewRootConmon_FollowLogResponse(s *capnp.Segment) (Conmon_FollowLogResponse, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_FollowLogResponse(st), err
+}
+
+func ReadRootConmon_FollowLogResponse(msg *capnp.Message) (Conmon_FollowLogResponse, error) {
+	root, err := msg.Root()
+	return Conmon_FollowLogResponse(root.Struct()), err
+}
+
+func (s Conmon_FollowLogResponse) String() string {
+	str, _ := text.Marshal(0xf9cd7b95c38bfa8e, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_FollowLogResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_FollowLogResponse) DecodeFromPtr(p capnp.Ptr) Conmon_FollowLogResponse {
+	return Conmon_FollowLogResponse(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_FollowLogResponse) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_FollowLogResponse) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_FollowLogResponse) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_FollowLogResponse) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_FollowLogResponse_List is a list of Conmon_FollowLogResponse.
+type Conmon_FollowLogResponse_List = capnp.StructList[Conmon_FollowLogResponse]
+
+// NewConmon_FollowLogResponse creates a new list of Conmon_FollowLogResponse.
+func NewConmon_FollowLogResponse_List(s *capnp.Segment, sz int32) (Conmon_FollowLogResponse_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_FollowLogResponse](l), err
+}
+
+// Conmon_FollowLogResponse_Future is a wrapper for a Conmon_FollowLogResponse promised by a client call.
+type Conmon_FollowLogResponse_Future struct{ *capnp.Future }
+
+func (f Conmon_FollowLogResponse_Future) Struct() (Conmon_FollowLogResponse, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_FollowLogResponse(p.Struct()), err
+}
+
+type Conmon_LogListener capnp.Client
+
+// Conmon_LogListener_TypeID is the unique identifier for the type Conmon_LogListener.
+const Conmon_LogListener_TypeID = 0xbab00e2a7d787530
+
+func (c Conmon_LogListener) Line(ctx context.Context, params func(Conmon_LogListener_line_Params) error) (Conmon_LogListener_line_Results_Future, capnp.ReleaseFunc) {
+
+	s := capnp.Send{
+		Method: capnp.Method{
+			InterfaceID:   0xbab00e2a7d787530,
+			MethodID:      0,
+			InterfaceName: "internal/proto/conmon.capnp:Conmon.LogListener",
+			MethodName:    "line",
+		},
+	}
+	if params != nil {
+		s.ArgsSize = capnp.ObjectSize{DataSize: 0, PointerCount: 1}
+		s.PlaceArgs = func(s capnp.Struct) error { return params(Conmon_LogListener_line_Params(s)) }
+	}
+
+	ans, release := capnp.Client(c).SendCall(ctx, s)
+	return Conmon_LogListener_line_Results_Future{Future: ans.Future()}, release
+
+}
+
+func (c Conmon_LogListener) WaitStreaming() error {
+	return capnp.Client(c).WaitStreaming()
+}
+
+// String returns a string that identifies this capability for debugging
+// purposes.  Its format should not be depended on: in particular, it
+// should not be used to compare clients.  Use IsSame to compare clients
+// for equality.
+func (c Conmon_LogListener) String() string {
+	return "Conmon_LogListener(" + capnp.Client(c).String() + ")"
+}
+
+// AddRef creates a new Client that refers to the same capability as c.
+// If c is nil or has resolved to null, then AddRef returns nil.
+func (c Conmon_LogListener) AddRef() Conmon_LogListener {
+	return Conmon_LogListener(capnp.Client(c).AddRef())
+}
+
+// Release releases a capability reference.  If this is the last
+// reference to the capability, then the underlying resources associated
+// with the capability will be released.
+//
+// Release will panic if c has already been released, but not if c is
+// nil or resolved to null.
+func (c Conmon_LogListener) Release() {
+	capnp.Client(c).Release()
+}
+
+// Resolve blocks until the capability is fully resolved or the Context
+// expires.
+func (c Conmon_LogListener) Resolve(ctx context.Context) error {
+	return capnp.Client(c).Resolve(ctx)
+}
+
+func (c Conmon_LogListener) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Client(c).EncodeAsPtr(seg)
+}
+
+func (Conmon_LogListener) DecodeFromPtr(p capnp.Ptr) Conmon_LogListener {
+	return Conmon_LogListener(capnp.Client{}.DecodeFromPtr(p))
+}
+
+// IsValid reports whether c is a valid reference to a capability.
+// A reference is invalid if it is nil, has resolved to null, or has
+// been released.
+func (c Conmon_LogListener) IsValid() bool {
+	return capnp.Client(c).IsValid()
+}
+
+// IsSame reports whether c and other refer to a capability created by the
+// same call to NewClient.  This can return false negatives if c or other
+// are not fully resolved: use Resolve if this is an issue.  If either
+// c or other are released, then IsSame panics.
+func (c Conmon_LogListener) IsSame(other Conmon_LogListener) bool {
+	return capnp.Client(c).IsSame(capnp.Client(other))
+}
+
+// Update the flowcontrol.FlowLimiter used to manage flow control for
+// this client. This affects all future calls, but not calls already
+// waiting to send. Passing nil sets the value to flowcontrol.NopLimiter,
+// which is also the default.
+func (c Conmon_LogListener) SetFlowLimiter(lim fc.FlowLimiter) {
+	capnp.Client(c).SetFlowLimiter(lim)
+}
+
+// Get the current flowcontrol.FlowLimiter used to manage flow control
+// for this client.
+func (c Conmon_LogListener) GetFlowLimiter() fc.FlowLimiter {
+	return capnp.Client(c).GetFlowLimiter()
+}
+
+// A Conmon_LogListener_Server is a Conmon_LogListener with a local implementation.
+type Conmon_LogListener_Server interface {
+	Line(context.Context, Conmon_LogListener_line) error
+}
+
+// Conmon_LogListener_NewServer creates a new Server from an implementation of Conmon_LogListener_Server.
+func Conmon_LogListener_NewServer(s Conmon_LogListener_Server) *server.Server {
+	c, _ := s.(server.Shutdowner)
+	return server.New(Conmon_LogListener_Methods(nil, s), s, c)
+}
+
+// Conmon_LogListener_ServerToClient creates a new Client from an implementation of Conmon_LogListener_Server.
+// The caller is responsible for calling Release on the returned Client.
+func Conmon_LogListener_ServerToClient(s Conmon_LogListener_Server) Conmon_LogListener {
+	return Conmon_LogListener(capnp.NewClient(Conmon_LogListener_NewServer(s)))
+}
+
+// Conmon_LogListener_Methods appends Methods to a slice that invoke the methods on s.
+// This can be used to create a more complicated Server.
+func Conmon_LogListener_Methods(methods []server.Method, s Conmon_LogListener_Server) []server.Method {
+	if cap(methods) == 0 {
+		methods = make([]server.Method, 0, 1)
+	}
+
+	methods = append(methods, server.Method{
+		Method: capnp.Method{
+			InterfaceID:   0xbab00e2a7d787530,
+			MethodID:      0,
+			InterfaceName: "internal/proto/conmon.capnp:Conmon.LogListener",
+			MethodName:    "line",
+		},
+		Impl: func(ctx context.Context, call *server.Call) error {
+			return s.Line(ctx, Conmon_LogListener_line{call})
+		},
+	})
+
+	return methods
+}
+
+// Conmon_LogListener_line holds the state for a server call to Conmon_LogListener.line.
+// See server.Call for documentation.
+type Conmon_LogListener_line struct {
+	*server.Call
+}
+
+// Args returns the call's arguments.
+func (c Conmon_LogListener_line) Args() Conmon_LogListener_line_Params {
+	return Conmon_LogListener_line_Params(c.Call.Args())
+}
+
+// AllocResults allocates the results struct.
+func (c Conmon_LogListener_line) AllocResults() (Conmon_LogListener_line_Results, error) {
+	r, err := c.Call.AllocResults(capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_LogListener_line_Results(r), err
+}
+
+// Conmon_LogListener_List is a list of Conmon_LogListener.
+type Conmon_LogListener_List = capnp.CapList[Conmon_LogListener]
+
+// NewConmon_LogListener creates a new list of Conmon_LogListener.
+func NewConmon_LogListener_List(s *capnp.Segment, sz int32) (Conmon_LogListener_List, error) {
+	l, err := capnp.NewPointerList(s, sz)
+	return capnp.CapList[Conmon_LogListener](l), err
+}
+
+type Conmon_LogListener_line_Params capnp.Struct
+
+// Conmon_LogListener_line_Params_TypeID is the unique identifier for the type Conmon_LogListener_line_Params.
+const Conmon_LogListener_line_Params_TypeID = 0xc3e0cf14ec9bf330
+
+func NewConmon_LogListener_line_Params(s *capnp.Segment) (Conmon_LogListener_line_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_LogListener_line_Params(st), err
+}
+
+func NewRootConmon_LogListener_line_Params(s *capnp.Segment) (Conmon_LogListener_line_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_LogListener_line_Params(st), err
+}
+
+func ReadRootConmon_LogListener_line_Params(msg *capnp.Message) (Conmon_LogListener_line_Params, error) {
+	root, err := msg.Root()
+	return Conmon_LogListener_line_Params(root.Struct()), err
+}
+
+func (s Conmon_LogListener_line_Params) String() string {
+	str, _ := text.Marshal(0xc3e0cf14ec9bf330, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_LogListener_line_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_LogListener_line_Params) DecodeFromPtr(p capnp.Ptr) Conmon_LogListener_line_Params {
+	return Conmon_LogListener_line_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_LogListener_line_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_LogListener_line_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_LogListener_line_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_LogListener_line_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_LogListener_line_Params) Line() (Conmon_LogLine, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_LogLine(p.Struct()), err
+}
+
+func (s Conmon_LogListener_line_Params) HasLine() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_LogListener_line_Params) SetLine(v Conmon_LogLine) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewLine sets the line field to a newly
+// allocated Conmon_LogLine struct, preferring placement in s's segment.
+func (s Conmon_LogListener_line_Params) NewLine() (Conmon_LogLine, error) {
+	ss, err := NewConmon_LogLine(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_LogLine{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_LogListener_line_Params_List is a list of Conmon_LogListener_line_Params.
+type Conmon_LogListener_line_Params_List = capnp.StructList[Conmon_LogListener_line_Params]
+
+// NewConmon_LogListener_line_Params creates a new list of Conmon_LogListener_line_Params.
+func NewConmon_LogListener_line_Params_List(s *capnp.Segment, sz int32) (Conmon_LogListener_line_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_LogListener_line_Params](l), err
+}
+
+// Conmon_LogListener_line_Params_Future is a wrapper for a Conmon_LogListener_line_Params promised by a client call.
+type Conmon_LogListener_line_Params_Future struct{ *capnp.Future }
+
+func (f Conmon_LogListener_line_Params_Future) Struct() (Conmon_LogListener_line_Params, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_LogListener_line_Params(p.Struct()), err
+}
+func (p Conmon_LogListener_line_Params_Future) Line() Conmon_LogLine_Future {
+	return Conmon_LogLine_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_LogListener_line_Results capnp.Struct
+
+// Conmon_LogListener_line_Results_TypeID is the unique identifier for the type Conmon_LogListener_line_Results.
+const Conmon_LogListener_line_Results_TypeID = 0x96e541d34ba6c497
+
+func NewConmon_LogListener_line_Results(s *capnp.Segment) (Conmon_LogListener_line_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_LogListener_line_Results(st), err
+}
+
+func NewRootConmon_LogListener_line_Results(s *capnp.Segment) (Conmon_LogListener_line_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
+	return Conmon_LogListener_line_Results(st), err
+}
+
+func ReadRootConmon_LogListener_line_Results(msg *capnp.Message) (Conmon_LogListener_line_Results, error) {
+	root, err := msg.Root()
+	return Conmon_LogListener_line_Results(root.Struct()), err
+}
+
+func (s Conmon_LogListener_line_Results) String() string {
+	str, _ := text.Marshal(0x96e541d34ba6c497, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_LogListener_line_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_LogListener_line_Results) DecodeFromPtr(p capnp.Ptr) Conmon_LogListener_line_Results {
+	return Conmon_LogListener_line_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_LogListener_line_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_LogListener_line_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_LogListener_line_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_LogListener_line_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+
+// Conmon_LogListener_line_Results_List is a list of Conmon_LogListener_line_Results.
+type Conmon_LogListener_line_Results_List = capnp.StructList[Conmon_LogListener_line_Results]
+
+// NewConmon_LogListener_line_Results creates a new list of Conmon_LogListener_line_Results.
+func NewConmon_LogListener_line_Results_List(s *capnp.Segment, sz int32) (Conmon_LogListener_line_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
+	return capnp.StructList[Conmon_LogListener_line_Results](l), err
+}
+
+// Conmon_LogListener_line_Results_Future is a wrapper for a Conmon_LogListener_line_Results promised by a client call.
+type Conmon_LogListener_line_Results_Future struct{ *capnp.Future }
+
+func (f Conmon_LogListener_line_Results_Future) Struct() (Conmon_LogListener_line_Results, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_LogListener_line_Results(p.Struct()), err
+}
+
+type Conmon_TextTextMapEntry capnp.Struct
+
+// Conmon_TextTextMapEntry_TypeID is the unique identifier for the type Conmon_TextTextMapEntry.
+const Conmon_TextTextMapEntry_TypeID = 0xfabbfdde6d4ad392
+
+func NewConmon_TextTextMapEntry(s *capnp.Segment) (Conmon_TextTextMapEntry, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_TextTextMapEntry(st), err
+}
+
+func NewRootConmon_TextTextMapEntry(s *capnp.Segment) (Conmon_TextTextMapEntry, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
+	return Conmon_TextTextMapEntry(st), err
+}
+
+func ReadRootConmon_TextTextMapEntry(msg *capnp.Message) (Conmon_TextTextMapEntry, error) {
+	root, err := msg.Root()
+	return Conmon_TextTextMapEntry(root.Struct()), err
+}
+
+func (s Conmon_TextTextMapEntry) String() string {
+	str, _ := text.Marshal(0xfabbfdde6d4ad392, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_TextTextMapEntry) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_TextTextMapEntry) DecodeFromPtr(p capnp.Ptr) Conmon_TextTextMapEntry {
+	return Conmon_TextTextMapEntry(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_TextTextMapEntry) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_TextTextMapEntry) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_TextTextMapEntry) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_TextTextMapEntry) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_TextTextMapEntry) Key() (string, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.Text(), err
+}
+
+func (s Conmon_TextTextMapEntry) HasKey() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_TextTextMapEntry) KeyBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_TextTextMapEntry) SetKey(v string) error {
+	return capnp.Struct(s).SetText(0, v)
+}
+
+func (s Conmon_TextTextMapEntry) Value() (string, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.Text(), err
+}
+
+func (s Conmon_TextTextMapEntry) HasValue() bool {
+	return capnp.Struct(s).HasPtr(1)
+}
+
+func (s Conmon_TextTextMapEntry) ValueBytes() ([]byte, error) {
+	p, err := capnp.Struct(s).Ptr(1)
+	return p.TextBytes(), err
+}
+
+func (s Conmon_TextTextMapEntry) SetValue(v string) error {
+	return capnp.Struct(s).SetText(1, v)
+}
+
+// Conmon_TextTextMapEntry_List is a list of Conmon_TextTextMapEntry.
+type Conmon_TextTextMapEntry_List = capnp.StructList[Conmon_TextTextMapEntry]
+
+// NewConmon_TextTextMapEntry creates a new list of Conmon_TextTextMapEntry.
+func NewConmon_TextTextMapEntry_List(s *capnp.Segment, sz int32) (Conmon_TextTextMapEntry_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2}, sz)
+	return capnp.StructList[Conmon_TextTextMapEntry](l), err
+}
+
+// Conmon_TextTextMapEntry_Future is a wrapper for a Conmon_TextTextMapEntry promised by a client call.
+type Conmon_TextTextMapEntry_Future struct{ *capnp.Future }
+
+func (f Conmon_TextTextMapEntry_Future) Struct() (Conmon_TextTextMapEntry, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_TextTextMapEntry(p.Struct()), err
+}
+
+type Conmon_version_Params capnp.Struct
+
+// Conmon_version_Params_TypeID is the unique identifier for the type Conmon_version_Params.
+const Conmon_version_Params_TypeID = 0xcc2f70676afee4e7
+
+func NewConmon_version_Params(s *capnp.Segment) (Conmon_version_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_version_Params(st), err
+}
+
+func NewRootConmon_version_Params(s *capnp.Segment) (Conmon_version_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_version_Params(st), err
+}
+
+func ReadRootConmon_version_Params(msg *capnp.Message) (Conmon_version_Params, error) {
+	root, err := msg.Root()
+	return Conmon_version_Params(root.Struct()), err
+}
+
+func (s Conmon_version_Params) String() string {
+	str, _ := text.Marshal(0xcc2f70676afee4e7, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_version_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_version_Params) DecodeFromPtr(p capnp.Ptr) Conmon_version_Params {
+	return Conmon_version_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_version_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_version_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_version_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_version_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_version_Params) Request() (Conmon_VersionRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_VersionRequest(p.Struct()), err
+}
+
+func (s Conmon_version_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_version_Params) SetRequest(v Conmon_VersionRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_VersionRequest struct, preferring placement in s's segment.
+func (s Conmon_version_Params) NewRequest() (Conmon_VersionRequest, error) {
+	ss, err := NewConmon_VersionRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_VersionRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_version_Params_List is a list of Conmon_version_Params.
+type Conmon_version_Params_List = capnp.StructList[Conmon_version_Params]
+
+// NewConmon_version_Params creates a new list of Conmon_version_Params.
+func NewConmon_version_Params_List(s *capnp.Segment, sz int32) (Conmon_version_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_version_Params](l), err
+}
+
+// Conmon_version_Params_Future is a wrapper for a Conmon_version_Params promised by a client call.
+type Conmon_version_Params_Future struct{ *capnp.Future }
+
+func (f Conmon_version_Params_Future) Struct() (Conmon_version_Params, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_version_Params(p.Struct()), err
+}
+func (p Conmon_version_Params_Future) Request() Conmon_VersionRequest_Future {
+	return Conmon_VersionRequest_Future{Future: p.Future.Field(0, nil)}
+}
+
+type Conmon_version_Results capnp.Struct
+
+// Conmon_version_Results_TypeID is the unique identifier for the type Conmon_version_Results.
+const Conmon_version_Results_TypeID = 0xe313695ea9477b30
+
+func NewConmon_version_Results(s *capnp.Segment) (Conmon_version_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_version_Results(st), err
+}
+
+func NewRootConmon_version_Results(s *capnp.Segment) (Conmon_version_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_version_Results(st), err
+}
+
+func ReadRootConmon_version_Results(msg *capnp.Message) (Conmon_version_Results, error) {
+	root, err := msg.Root()
+	return Conmon_version_Results(root.Struct()), err
+}
+
+func (s Conmon_version_Results) String() string {
+	str, _ := text.Marshal(0xe313695ea9477b30, capnp.Struct(s))
+	return str
+}
+
+func (s Conmon_version_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
+}
+
+func (Conmon_version_Results) DecodeFromPtr(p capnp.Ptr) Conmon_version_Results {
+	return Conmon_version_Results(capnp.Struct{}.DecodeFromPtr(p))
+}
+
+func (s Conmon_version_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_version_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
+}
+
+func (s Conmon_version_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
+
+func (s Conmon_version_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_version_Results) Response() (Conmon_VersionResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return p.Text(), err
+	return Conmon_VersionResponse(p.Struct()), err
 }
 
-func (s Conmon_SetWindowSizeRequest) HasId() bool {
+func (s Conmon_version_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_SetWindowSizeRequest) IdBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
-	return p.TextBytes(), err
+func (s Conmon_version_Results) SetResponse(v Conmon_VersionResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_SetWindowSizeRequest) SetId(v string) error {
-	return capnp.Struct(s).SetText(0, v)
+// NewResponse sets the response field to a newly
+// allocated Conmon_VersionResponse struct, preferring placement in s's segment.
+func (s Conmon_version_Results) NewResponse() (Conmon_VersionResponse, error) {
+	ss, err := NewConmon_VersionResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_VersionResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-func (s Conmon_SetWindowSizeRequest) Width() uint16 {
-	return capnp.Struct(s).Uint16(0)
+// Conmon_version_Results_List is a list of Conmon_version_Results.
+type Conmon_version_Results_List = capnp.StructList[Conmon_version_Results]
+
+// NewConmon_version_Results creates a new list of Conmon_version_Results.
+func NewConmon_version_Results_List(s *capnp.Segment, sz int32) (Conmon_version_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_version_Results](l), err
 }
 
-func (s Conmon_SetWindowSizeRequest) SetWidth(v uint16) {
-	capnp.Struct(s).SetUint16(0, v)
+// Conmon_version_Results_Future is a wrapper for a Conmon_version_Results promised by a client call.
+type Conmon_version_Results_Future struct{ *capnp.Future }
+
+func (f Conmon_version_Results_Future) Struct() (Conmon_version_Results, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_version_Results(p.Struct()), err
+}
+func (p Conmon_version_Results_Future) Response() Conmon_VersionResponse_Future {
+	return Conmon_VersionResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-func (s Conmon_SetWindowSizeRequest) Height() uint16 {
-	return capnp.Struct(s).Uint16(2)
+type Conmon_createContainer_Params capnp.Struct
+
+// Conmon_createContainer_Params_TypeID is the unique identifier for the type Conmon_createContainer_Params.
+const Conmon_createContainer_Params_TypeID = 0xf44732c48f949ab8
+
+func NewConmon_createContainer_Params(s *capnp.Segment) (Conmon_createContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_createContainer_Params(st), err
 }
 
-func (s Conmon_SetWindowSizeRequest) SetHeight(v uint16) {
-	capnp.Struct(s).SetUint16(2, v)
+func NewRootConmon_createContainer_Params(s *capnp.Segment) (Conmon_createContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_createContainer_Params(st), err
 }
 
-func (s Conmon_SetWindowSizeRequest) MetadataOld() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(1)
-	return []byte(p.Data()), err
+func ReadRootConmon_createContainer_Params(msg *capnp.Message) (Conmon_createContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_createContainer_Params(root.Struct()), err
 }
 
-func (s Conmon_SetWindowSizeRequest) HasMetadataOld() bool {
-	return capnp.Struct(s).HasPtr(1)
+func (s Conmon_createContainer_Params) String() string {
+	str, _ := text.Marshal(0xf44732c48f949ab8, capnp.Struct(s))
+	return str
 }
 
-func (s Conmon_SetWindowSizeRequest) SetMetadataOld(v []byte) error {
-	return capnp.Struct(s).SetData(1, v)
+func (s Conmon_createContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (s Conmon_SetWindowSizeRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
-	p, err := capnp.Struct(s).Ptr(2)
-	return Conmon_TextTextMapEntry_List(p.List()), err
+func (Conmon_createContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_createContainer_Params {
+	return Conmon_createContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_SetWindowSizeRequest) HasMetadata() bool {
-	return capnp.Struct(s).HasPtr(2)
+func (s Conmon_createContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_createContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_SetWindowSizeRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
-	return capnp.Struct(s).SetPtr(2, v.ToPtr())
+func (s Conmon_createContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
 }
 
-// NewMetadata sets the metadata field to a newly
-// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
-func (s Conmon_SetWindowSizeRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
-	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+func (s Conmon_createContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_createContainer_Params) Request() (Conmon_CreateContainerRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_CreateContainerRequest(p.Struct()), err
+}
+
+func (s Conmon_createContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
+
+func (s Conmon_createContainer_Params) SetRequest(v Conmon_CreateContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
+
+// NewRequest sets the request field to a newly
+// allocated Conmon_CreateContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_createContainer_Params) NewRequest() (Conmon_CreateContainerRequest, error) {
+	ss, err := NewConmon_CreateContainerRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_TextTextMapEntry_List{}, err
+		return Conmon_CreateContainerRequest{}, err
 	}
-	err = capnp.Struct(s).SetPtr(2, l.ToPtr())
-	return l, err
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_SetWindowSizeRequest_List is a list of Conmon_SetWindowSizeRequest.
-type Conmon_SetWindowSizeRequest_List = capnp.StructList[Conmon_SetWindowSizeRequest]
+// Conmon_createContainer_Params_List is a list of Conmon_createContainer_Params.
+type Conmon_createContainer_Params_List = capnp.StructList[Conmon_createContainer_Params]
 
-// NewConmon_SetWindowSizeRequest creates a new list of Conmon_SetWindowSizeRequest.
-func NewConmon_SetWindowSizeRequest_List(s *capnp.Segment, sz int32) (Conmon_SetWindowSizeRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 3}, sz)
-	return capnp.StructList[Conmon_SetWindowSizeRequest](l), err
+// NewConmon_createContainer_Params creates a new list of Conmon_createContainer_Params.
+func NewConmon_createContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_createContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_createContainer_Params](l), err
 }
 
-// Conmon_SetWindowSizeRequest_Future is a wrapper for a Conmon_SetWindowSizeRequest promised by a client call.
-type Conmon_SetWindowSizeRequest_Future struct{ *capnp.Future }
+// Conmon_createContainer_Params_Future is a wrapper for a Conmon_createContainer_Params promised by a client call.
+type Conmon_createContainer_Params_Future struct{ *capnp.Future }
 
-func (f Conmon_SetWindowSizeRequest_Future) Struct() (Conmon_SetWindowSizeRequest, error) {
+func (f Conmon_createContainer_Params_Future) Struct() (Conmon_createContainer_Params, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_SetWindowSizeRequest(p.Struct()), err
+	return Conmon_createContainer_Params(p.Struct()), err
+}
+func (p Conmon_createContainer_Params_Future) Request() Conmon_CreateContainerRequest_Future {
+	return Conmon_CreateContainerRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_SetWindowSizeResponse capnp.Struct
+type Conmon_createContainer_Results capnp.Struct
 
-// Conmon_SetWindowSizeResponse_TypeID is the unique identifier for the type Conmon_SetWindowSizeResponse.
-const Conmon_SetWindowSizeResponse_TypeID = 0xf9b3cd8033aba1f8
+// Conmon_createContainer_Results_TypeID is the unique identifier for the type Conmon_createContainer_Results.
+const Conmon_createContainer_Results_TypeID = 0xceba3c1a97be15f8
 
-func NewConmon_SetWindowSizeResponse(s *capnp.Segment) (Conmon_SetWindowSizeResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_SetWindowSizeResponse(st), err
+func NewConmon_createContainer_Results(s *capnp.Segment) (Conmon_createContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_createContainer_Results(st), err
 }
 
-func NewRootConmon_SetWindowSizeResponse(s *capnp.Segment) (Conmon_SetWindowSizeResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0})
-	return Conmon_SetWindowSizeResponse(st), err
+func NewRootConmon_createContainer_Results(s *capnp.Segment) (Conmon_createContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_createContainer_Results(st), err
 }
 
-func ReadRootConmon_SetWindowSizeResponse(msg *capnp.Message) (Conmon_SetWindowSizeResponse, error) {
+func ReadRootConmon_createContainer_Results(msg *capnp.Message) (Conmon_createContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_SetWindowSizeResponse(root.Struct()), err
+	return Conmon_createContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_SetWindowSizeResponse) String() string {
-	str, _ := text.Marshal(0xf9b3cd8033aba1f8, capnp.Struct(s))
+func (s Conmon_createContainer_Results) String() string {
+	str, _ := text.Marshal(0xceba3c1a97be15f8, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_SetWindowSizeResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_createContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_SetWindowSizeResponse) DecodeFromPtr(p capnp.Ptr) Conmon_SetWindowSizeResponse {
-	return Conmon_SetWindowSizeResponse(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_createContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_createContainer_Results {
+	return Conmon_createContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_SetWindowSizeResponse) ToPtr() capnp.Ptr {
+func (s Conmon_createContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_SetWindowSizeResponse) IsValid() bool {
+func (s Conmon_createContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_SetWindowSizeResponse) Message() *capnp.Message {
+func (s Conmon_createContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_SetWindowSizeResponse) Segment() *capnp.Segment {
+func (s Conmon_createContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
+func (s Conmon_createContainer_Results) Response() (Conmon_CreateContainerResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_CreateContainerResponse(p.Struct()), err
+}
 
-// Conmon_SetWindowSizeResponse_List is a list of Conmon_SetWindowSizeResponse.
-type Conmon_SetWindowSizeResponse_List = capnp.StructList[Conmon_SetWindowSizeResponse]
+func (s Conmon_createContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
 
-// NewConmon_SetWindowSizeResponse creates a new list of Conmon_SetWindowSizeResponse.
-func NewConmon_SetWindowSizeResponse_List(s *capnp.Segment, sz int32) (Conmon_SetWindowSizeResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 0}, sz)
-	return capnp.StructList[Conmon_SetWindowSizeResponse](l), err
+func (s Conmon_createContainer_Results) SetResponse(v Conmon_CreateContainerResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-// Conmon_SetWindowSizeResponse_Future is a wrapper for a Conmon_SetWindowSizeResponse promised by a client call.
-type Conmon_SetWindowSizeResponse_Future struct{ *capnp.Future }
+// NewResponse sets the response field to a newly
+// allocated Conmon_CreateContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_createContainer_Results) NewResponse() (Conmon_CreateContainerResponse, error) {
+	ss, err := NewConmon_CreateContainerResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_CreateContainerResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
+}
+
+// Conmon_createContainer_Results_List is a list of Conmon_createContainer_Results.
+type Conmon_createContainer_Results_List = capnp.StructList[Conmon_createContainer_Results]
+
+// NewConmon_createContainer_Results creates a new list of Conmon_createContainer_Results.
+func NewConmon_createContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_createContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_createContainer_Results](l), err
+}
 
-func (f Conmon_SetWindowSizeResponse_Future) Struct() (Conmon_SetWindowSizeResponse, error) {
+// Conmon_createContainer_Results_Future is a wrapper for a Conmon_createContainer_Results promised by a client call.
+type Conmon_createContainer_Results_Future struct{ *capnp.Future }
+
+func (f Conmon_createContainer_Results_Future) Struct() (Conmon_createContainer_Results, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_SetWindowSizeResponse(p.Struct()), err
+	return Conmon_createContainer_Results(p.Struct()), err
+}
+func (p Conmon_createContainer_Results_Future) Response() Conmon_CreateContainerResponse_Future {
+	return Conmon_CreateContainerResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_CreateNamespacesRequest capnp.Struct
+type Conmon_execSyncContainer_Params capnp.Struct
 
-// Conmon_CreateNamespacesRequest_TypeID is the unique identifier for the type Conmon_CreateNamespacesRequest.
-const Conmon_CreateNamespacesRequest_TypeID = 0x8b5b1693940f607e
+// Conmon_execSyncContainer_Params_TypeID is the unique identifier for the type Conmon_execSyncContainer_Params.
+const Conmon_execSyncContainer_Params_TypeID = 0x83479da67279e173
 
-func NewConmon_CreateNamespacesRequest(s *capnp.Segment) (Conmon_CreateNamespacesRequest, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 7})
-	return Conmon_CreateNamespacesRequest(st), err
+func NewConmon_execSyncContainer_Params(s *capnp.Segment) (Conmon_execSyncContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execSyncContainer_Params(st), err
 }
 
-func NewRootConmon_CreateNamespacesRequest(s *capnp.Segment) (Conmon_CreateNamespacesRequest, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 7})
-	return Conmon_CreateNamespacesRequest(st), err
+func NewRootConmon_execSyncContainer_Params(s *capnp.Segment) (Conmon_execSyncContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execSyncContainer_Params(st), err
 }
 
-func ReadRootConmon_CreateNamespacesRequest(msg *capnp.Message) (Conmon_CreateNamespacesRequest, error) {
+func ReadRootConmon_execSyncContainer_Params(msg *capnp.Message) (Conmon_execSyncContainer_Params, error) {
 	root, err := msg.Root()
-	return Conmon_CreateNamespacesRequest(root.Struct()), err
+	return Conmon_execSyncContainer_Params(root.Struct()), err
 }
 
-func (s Conmon_CreateNamespacesRequest) String() string {
-	str, _ := text.Marshal(0x8b5b1693940f607e, capnp.Struct(s))
+func (s Conmon_execSyncContainer_Params) String() string {
+	str, _ := text.Marshal(0x83479da67279e173, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_CreateNamespacesRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_execSyncContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_CreateNamespacesRequest) DecodeFromPtr(p capnp.Ptr) Conmon_CreateNamespacesRequest {
-	return Conmon_CreateNamespacesRequest(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_execSyncContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_execSyncContainer_Params {
+	return Conmon_execSyncContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_CreateNamespacesRequest) ToPtr() capnp.Ptr {
+func (s Conmon_execSyncContainer_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_CreateNamespacesRequest) IsValid() bool {
+func (s Conmon_execSyncContainer_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_CreateNamespacesRequest) Message() *capnp.Message {
+func (s Conmon_execSyncContainer_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_CreateNamespacesRequest) Segment() *capnp.Segment {
+func (s Conmon_execSyncContainer_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_CreateNamespacesRequest) MetadataOld() ([]byte, error) {
+func (s Conmon_execSyncContainer_Params) Request() (Conmon_ExecSyncContainerRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return []byte(p.Data()), err
+	return Conmon_ExecSyncContainerRequest(p.Struct()), err
 }
 
-func (s Conmon_CreateNamespacesRequest) HasMetadataOld() bool {
+func (s Conmon_execSyncContainer_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_CreateNamespacesRequest) SetMetadataOld(v []byte) error {
-	return capnp.Struct(s).SetData(0, v)
+func (s Conmon_execSyncContainer_Params) SetRequest(v Conmon_ExecSyncContainerRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_CreateNamespacesRequest) Namespaces() (Conmon_Namespace_List, error) {
-	p, err := capnp.Struct(s).Ptr(1)
-	return Conmon_Namespace_List(p.List()), err
+// NewRequest sets the request field to a newly
+// allocated Conmon_ExecSyncContainerRequest struct, preferring placement in s's segment.
+func (s Conmon_execSyncContainer_Params) NewRequest() (Conmon_ExecSyncContainerRequest, error) {
+	ss, err := NewConmon_ExecSyncContainerRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ExecSyncContainerRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-func (s Conmon_CreateNamespacesRequest) HasNamespaces() bool {
-	return capnp.Struct(s).HasPtr(1)
-}
+// Conmon_execSyncContainer_Params_List is a list of Conmon_execSyncContainer_Params.
+type Conmon_execSyncContainer_Params_List = capnp.StructList[Conmon_execSyncContainer_Params]
 
-func (s Conmon_CreateNamespacesRequest) SetNamespaces(v Conmon_Namespace_List) error {
-	return capnp.Struct(s).SetPtr(1, v.ToPtr())
+// NewConmon_execSyncContainer_Params creates a new list of Conmon_execSyncContainer_Params.
+func NewConmon_execSyncContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_execSyncContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_execSyncContainer_Params](l), err
 }
 
-// NewNamespaces sets the namespaces field to a newly
-// allocated Conmon_Namespace_List, preferring placement in s's segment.
-func (s Conmon_CreateNamespacesRequest) NewNamespaces(n int32) (Conmon_Namespace_List, error) {
-	l, err := NewConmon_Namespace_List(capnp.Struct(s).Segment(), n)
-	if err != nil {
-		return Conmon_Namespace_List{}, err
-	}
-	err = capnp.Struct(s).SetPtr(1, l.ToPtr())
-	return l, err
+// Conmon_execSyncContainer_Params_Future is a wrapper for a Conmon_execSyncContainer_Params promised by a client call.
+type Conmon_execSyncContainer_Params_Future struct{ *capnp.Future }
+
+func (f Conmon_execSyncContainer_Params_Future) Struct() (Conmon_execSyncContainer_Params, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_execSyncContainer_Params(p.Struct()), err
 }
-func (s Conmon_CreateNamespacesRequest) UidMappings() (capnp.TextList, error) {
-	p, err := capnp.Struct(s).Ptr(2)
-	return capnp.TextList(p.List()), err
+func (p Conmon_execSyncContainer_Params_Future) Request() Conmon_ExecSyncContainerRequest_Future {
+	return Conmon_ExecSyncContainerRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-func (s Conmon_CreateNamespacesRequest) HasUidMappings() bool {
-	return capnp.Struct(s).HasPtr(2)
+type Conmon_execSyncContainer_Results capnp.Struct
+
+// Conmon_execSyncContainer_Results_TypeID is the unique identifier for the type Conmon_execSyncContainer_Results.
+const Conmon_execSyncContainer_Results_TypeID = 0xf8e86a5c0baa01bc
+
+func NewConmon_execSyncContainer_Results(s *capnp.Segment) (Conmon_execSyncContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execSyncContainer_Results(st), err
 }
 
-func (s Conmon_CreateNamespacesRequest) SetUidMappings(v capnp.TextList) error {
-	return capnp.Struct(s).SetPtr(2, v.ToPtr())
+func NewRootConmon_execSyncContainer_Results(s *capnp.Segment) (Conmon_execSyncContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_execSyncContainer_Results(st), err
 }
 
-// NewUidMappings sets the uidMappings field to a newly
-// allocated capnp.TextList, preferring placement in s's segment.
-func (s Conmon_CreateNamespacesRequest) NewUidMappings(n int32) (capnp.TextList, error) {
-	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
-	if err != nil {
-		return capnp.TextList{}, err
-	}
-	err = capnp.Struct(s).SetPtr(2, l.ToPtr())
-	return l, err
+func ReadRootConmon_execSyncContainer_Results(msg *capnp.Message) (Conmon_execSyncContainer_Results, error) {
+	root, err := msg.Root()
+	return Conmon_execSyncContainer_Results(root.Struct()), err
 }
-func (s Conmon_CreateNamespacesRequest) GidMappings() (capnp.TextList, error) {
-	p, err := capnp.Struct(s).Ptr(3)
-	return capnp.TextList(p.List()), err
+
+func (s Conmon_execSyncContainer_Results) String() string {
+	str, _ := text.Marshal(0xf8e86a5c0baa01bc, capnp.Struct(s))
+	return str
 }
 
-func (s Conmon_CreateNamespacesRequest) HasGidMappings() bool {
-	return capnp.Struct(s).HasPtr(3)
+func (s Conmon_execSyncContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (s Conmon_CreateNamespacesRequest) SetGidMappings(v capnp.TextList) error {
-	return capnp.Struct(s).SetPtr(3, v.ToPtr())
+func (Conmon_execSyncContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_execSyncContainer_Results {
+	return Conmon_execSyncContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-// NewGidMappings sets the gidMappings field to a newly
-// allocated capnp.TextList, preferring placement in s's segment.
-func (s Conmon_CreateNamespacesRequest) NewGidMappings(n int32) (capnp.TextList, error) {
-	l, err := capnp.NewTextList(capnp.Struct(s).Segment(), n)
-	if err != nil {
-		return capnp.TextList{}, err
-	}
-	err = capnp.Struct(s).SetPtr(3, l.ToPtr())
-	return l, err
+func (s Conmon_execSyncContainer_Results) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_CreateNamespacesRequest) BasePath() (string, error) {
-	p, err := capnp.Struct(s).Ptr(4)
-	return p.Text(), err
+func (s Conmon_execSyncContainer_Results) IsValid() bool {
+	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_CreateNamespacesRequest) HasBasePath() bool {
-	return capnp.Struct(s).HasPtr(4)
+func (s Conmon_execSyncContainer_Results) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_CreateNamespacesRequest) BasePathBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(4)
-	return p.TextBytes(), err
+func (s Conmon_execSyncContainer_Results) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
 }
-
-func (s Conmon_CreateNamespacesRequest) SetBasePath(v string) error {
-	return capnp.Struct(s).SetText(4, v)
+func (s Conmon_execSyncContainer_Results) Response() (Conmon_ExecSyncContainerResponse, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_ExecSyncContainerResponse(p.Struct()), err
 }
 
-func (s Conmon_CreateNamespacesRequest) PodId() (string, error) {
-	p, err := capnp.Struct(s).Ptr(5)
-	return p.Text(), err
+func (s Conmon_execSyncContainer_Results) HasResponse() bool {
+	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_CreateNamespacesRequest) HasPodId() bool {
-	return capnp.Struct(s).HasPtr(5)
+func (s Conmon_execSyncContainer_Results) SetResponse(v Conmon_ExecSyncContainerResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_CreateNamespacesRequest) PodIdBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(5)
-	return p.TextBytes(), err
+// NewResponse sets the response field to a newly
+// allocated Conmon_ExecSyncContainerResponse struct, preferring placement in s's segment.
+func (s Conmon_execSyncContainer_Results) NewResponse() (Conmon_ExecSyncContainerResponse, error) {
+	ss, err := NewConmon_ExecSyncContainerResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ExecSyncContainerResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-func (s Conmon_CreateNamespacesRequest) SetPodId(v string) error {
-	return capnp.Struct(s).SetText(5, v)
+// Conmon_execSyncContainer_Results_List is a list of Conmon_execSyncContainer_Results.
+type Conmon_execSyncContainer_Results_List = capnp.StructList[Conmon_execSyncContainer_Results]
+
+// NewConmon_execSyncContainer_Results creates a new list of Conmon_execSyncContainer_Results.
+func NewConmon_execSyncContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_execSyncContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_execSyncContainer_Results](l), err
 }
 
-func (s Conmon_CreateNamespacesRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
-	p, err := capnp.Struct(s).Ptr(6)
-	return Conmon_TextTextMapEntry_List(p.List()), err
+// Conmon_execSyncContainer_Results_Future is a wrapper for a Conmon_execSyncContainer_Results promised by a client call.
+type Conmon_execSyncContainer_Results_Future struct{ *capnp.Future }
+
+func (f Conmon_execSyncContainer_Results_Future) Struct() (Conmon_execSyncContainer_Results, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_execSyncContainer_Results(p.Struct()), err
+}
+func (p Conmon_execSyncContainer_Results_Future) Response() Conmon_ExecSyncContainerResponse_Future {
+	return Conmon_ExecSyncContainerResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-func (s Conmon_CreateNamespacesRequest) HasMetadata() bool {
-	return capnp.Struct(s).HasPtr(6)
+type Conmon_attachContainer_Params capnp.Struct
+
+// Conmon_attachContainer_Params_TypeID is the unique identifier for the type Conmon_attachContainer_Params.
+const Conmon_attachContainer_Params_TypeID = 0xaa2f3c8ad1c3af24
+
+func NewConmon_attachContainer_Params(s *capnp.Segment) (Conmon_attachContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachContainer_Params(st), err
 }
 
-func (s Conmon_CreateNamespacesRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
-	return capnp.Struct(s).SetPtr(6, v.ToPtr())
+func NewRootConmon_attachContainer_Params(s *capnp.Segment) (Conmon_attachContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_attachContainer_Params(st), err
 }
 
-// NewMetadata sets the metadata field to a newly
-// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
-func (s Conmon_CreateNamespacesRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
-	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
-	if err != nil {
-		return Conmon_TextTextMapEntry_List{}, err
-	}
-	err = capnp.Struct(s).SetPtr(6, l.ToPtr())
-	return l, err
+func ReadRootConmon_attachContainer_Params(msg *capnp.Message) (Conmon_attachContainer_Params, error) {
+	root, err := msg.Root()
+	return Conmon_attachContainer_Params(root.Struct()), err
 }
 
-// Conmon_CreateNamespacesRequest_List is a list of Conmon_CreateNamespacesRequest.
-type Conmon_CreateNamespacesRequest_List = capnp.StructList[Conmon_CreateNamespacesRequest]
+func (s Conmon_attachContainer_Params) String() string {
+	str, _ := text.Marshal(0xaa2f3c8ad1c3af24, capnp.Struct(s))
+	return str
+}
 
-// NewConmon_CreateNamespacesRequest creates a new list of Conmon_CreateNamespacesRequest.
-func NewConmon_CreateNamespacesRequest_List(s *capnp.Segment, sz int32) (Conmon_CreateNamespacesRequest_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 7}, sz)
-	return capnp.StructList[Conmon_CreateNamespacesRequest](l), err
+func (s Conmon_attachContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-// Conmon_CreateNamespacesRequest_Future is a wrapper for a Conmon_CreateNamespacesRequest promised by a client call.
-type Conmon_CreateNamespacesRequest_Future struct{ *capnp.Future }
+func (Conmon_attachContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_attachContainer_Params {
+	return Conmon_attachContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+}
 
-func (f Conmon_CreateNamespacesRequest_Future) Struct() (Conmon_CreateNamespacesRequest, error) {
-	p, err := f.Future.Ptr()
-	return Conmon_CreateNamespacesRequest(p.Struct()), err
+func (s Conmon_attachContainer_Params) ToPtr() capnp.Ptr {
+	return capnp.Struct(s).ToPtr()
+}
+func (s Conmon_attachContainer_Params) IsValid() bool {
+	return capnp.Struct(s).IsValid()
 }
 
-type Conmon_Namespace uint16
+func (s Conmon_attachContainer_Params) Message() *capnp.Message {
+	return capnp.Struct(s).Message()
+}
 
-// Conmon_Namespace_TypeID is the unique identifier for the type Conmon_Namespace.
-const Conmon_Namespace_TypeID = 0xd61491b560a8f3a3
+func (s Conmon_attachContainer_Params) Segment() *capnp.Segment {
+	return capnp.Struct(s).Segment()
+}
+func (s Conmon_attachContainer_Params) Request() (Conmon_AttachRequest, error) {
+	p, err := capnp.Struct(s).Ptr(0)
+	return Conmon_AttachRequest(p.Struct()), err
+}
 
-// Values of Conmon_Namespace.
-const (
-	Conmon_Namespace_ipc  Conmon_Namespace = 0
-	Conmon_Namespace_net  Conmon_Namespace = 1
-	Conmon_Namespace_pid  Conmon_Namespace = 2
-	Conmon_Namespace_user Conmon_Namespace = 3
-	Conmon_Namespace_uts  Conmon_Namespace = 4
-)
+func (s Conmon_attachContainer_Params) HasRequest() bool {
+	return capnp.Struct(s).HasPtr(0)
+}
 
-// String returns the enum's constant name.
-func (c Conmon_Namespace) String() string {
-	switch c {
-	case Conmon_Namespace_ipc:
-		return "ipc"
-	case Conmon_Namespace_net:
-		return "net"
-	case Conmon_Namespace_pid:
-		return "pid"
-	case Conmon_Namespace_user:
-		return "user"
-	case Conmon_Namespace_uts:
-		return "uts"
+func (s Conmon_attachContainer_Params) SetRequest(v Conmon_AttachRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
+}
 
-	default:
-		return ""
+// NewRequest sets the request field to a newly
+// allocated Conmon_AttachRequest struct, preferring placement in s's segment.
+func (s Conmon_attachContainer_Params) NewRequest() (Conmon_AttachRequest, error) {
+	ss, err := NewConmon_AttachRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_AttachRequest{}, err
 	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_NamespaceFromString returns the enum value with a name,
-// or the zero value if there's no such value.
-func Conmon_NamespaceFromString(c string) Conmon_Namespace {
-	switch c {
-	case "ipc":
-		return Conmon_Namespace_ipc
-	case "net":
-		return Conmon_Namespace_net
-	case "pid":
-		return Conmon_Namespace_pid
-	case "user":
-		return Conmon_Namespace_user
-	case "uts":
-		return Conmon_Namespace_uts
+// Conmon_attachContainer_Params_List is a list of Conmon_attachContainer_Params.
+type Conmon_attachContainer_Params_List = capnp.StructList[Conmon_attachContainer_Params]
 
-	default:
-		return 0
-	}
+// NewConmon_attachContainer_Params creates a new list of Conmon_attachContainer_Params.
+func NewConmon_attachContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_attachContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_attachContainer_Params](l), err
 }
 
-type Conmon_Namespace_List = capnp.EnumList[Conmon_Namespace]
+// Conmon_attachContainer_Params_Future is a wrapper for a Conmon_attachContainer_Params promised by a client call.
+type Conmon_attachContainer_Params_Future struct{ *capnp.Future }
 
-func NewConmon_Namespace_List(s *capnp.Segment, sz int32) (Conmon_Namespace_List, error) {
-	return capnp.NewEnumList[Conmon_Namespace](s, sz)
+func (f Conmon_attachContainer_Params_Future) Struct() (Conmon_attachContainer_Params, error) {
+	p, err := f.Future.Ptr()
+	return Conmon_attachContainer_Params(p.Struct()), err
+}
+func (p Conmon_attachContainer_Params_Future) Request() Conmon_AttachRequest_Future {
+	return Conmon_AttachRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_CreateNamespacesResponse capnp.Struct
+type Conmon_attachContainer_Results capnp.Struct
 
-// Conmon_CreateNamespacesResponse_TypeID is the unique identifier for the type Conmon_CreateNamespacesResponse.
-const Conmon_CreateNamespacesResponse_TypeID = 0x9887a60f577a1ecb
+// Conmon_attachContainer_Results_TypeID is the unique identifier for the type Conmon_attachContainer_Results.
+const Conmon_attachContainer_Results_TypeID = 0xc5e65eec3dcf5b10
 
-func NewConmon_CreateNamespacesResponse(s *capnp.Segment) (Conmon_CreateNamespacesResponse, error) {
+func NewConmon_attachContainer_Results(s *capnp.Segment) (Conmon_attachContainer_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_CreateNamespacesResponse(st), err
+	return Conmon_attachContainer_Results(st), err
 }
 
-func NewRootConmon_CreateNamespacesResponse(s *capnp.Segment) (Conmon_CreateNamespacesResponse, error) {
+func NewRootConmon_attachContainer_Results(s *capnp.Segment) (Conmon_attachContainer_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_CreateNamespacesResponse(st), err
+	return Conmon_attachContainer_Results(st), err
 }
 
-func ReadRootConmon_CreateNamespacesResponse(msg *capnp.Message) (Conmon_CreateNamespacesResponse, error) {
+func ReadRootConmon_attachContainer_Results(msg *capnp.Message) (Conmon_attachContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_CreateNamespacesResponse(root.Struct()), err
+	return Conmon_attachContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_CreateNamespacesResponse) String() string {
-	str, _ := text.Marshal(0x9887a60f577a1ecb, capnp.Struct(s))
+func (s Conmon_attachContainer_Results) String() string {
+	str, _ := text.Marshal(0xc5e65eec3dcf5b10, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_CreateNamespacesResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_attachContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_CreateNamespacesResponse) DecodeFromPtr(p capnp.Ptr) Conmon_CreateNamespacesResponse {
-	return Conmon_CreateNamespacesResponse(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_attachContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_attachContainer_Results {
+	return Conmon_attachContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_CreateNamespacesResponse) ToPtr() capnp.Ptr {
+func (s Conmon_attachContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_CreateNamespacesResponse) IsValid() bool {
+func (s Conmon_attachContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_CreateNamespacesResponse) Message() *capnp.Message {
+func (s Conmon_attachContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_CreateNamespacesResponse) Segment() *capnp.Segment {
+func (s Conmon_attachContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_CreateNamespacesResponse) Namespaces() (Conmon_NamespaceResponse_List, error) {
+func (s Conmon_attachContainer_Results) Response() (Conmon_AttachResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_NamespaceResponse_List(p.List()), err
+	return Conmon_AttachResponse(p.Struct()), err
 }
 
-func (s Conmon_CreateNamespacesResponse) HasNamespaces() bool {
+func (s Conmon_attachContainer_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_CreateNamespacesResponse) SetNamespaces(v Conmon_NamespaceResponse_List) error {
-	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+func (s Conmon_attachContainer_Results) SetResponse(v Conmon_AttachResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-// NewNamespaces sets the namespaces field to a newly
-// allocated Conmon_NamespaceResponse_List, preferring placement in s's segment.
-func (s Conmon_CreateNamespacesResponse) NewNamespaces(n int32) (Conmon_NamespaceResponse_List, error) {
-	l, err := NewConmon_NamespaceResponse_List(capnp.Struct(s).Segment(), n)
+// NewResponse sets the response field to a newly
+// allocated Conmon_AttachResponse struct, preferring placement in s's segment.
+func (s Conmon_attachContainer_Results) NewResponse() (Conmon_AttachResponse, error) {
+	ss, err := NewConmon_AttachResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_NamespaceResponse_List{}, err
+		return Conmon_AttachResponse{}, err
 	}
-	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
-	return l, err
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_CreateNamespacesResponse_List is a list of Conmon_CreateNamespacesResponse.
-type Conmon_CreateNamespacesResponse_List = capnp.StructList[Conmon_CreateNamespacesResponse]
+// Conmon_attachContainer_Results_List is a list of Conmon_attachContainer_Results.
+type Conmon_attachContainer_Results_List = capnp.StructList[Conmon_attachContainer_Results]
 
-// NewConmon_CreateNamespacesResponse creates a new list of Conmon_CreateNamespacesResponse.
-func NewConmon_CreateNamespacesResponse_List(s *capnp.Segment, sz int32) (Conmon_CreateNamespacesResponse_List, error) {
+// NewConmon_attachContainer_Results creates a new list of Conmon_attachContainer_Results.
+func NewConmon_attachContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_attachContainer_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_CreateNamespacesResponse](l), err
+	return capnp.StructList[Conmon_attachContainer_Results](l), err
 }
 
-// Conmon_CreateNamespacesResponse_Future is a wrapper for a Conmon_CreateNamespacesResponse promised by a client call.
-type Conmon_CreateNamespacesResponse_Future struct{ *capnp.Future }
+// Conmon_attachContainer_Results_Future is a wrapper for a Conmon_attachContainer_Results promised by a client call.
+type Conmon_attachContainer_Results_Future struct{ *capnp.Future }
 
-func (f Conmon_CreateNamespacesResponse_Future) Struct() (Conmon_CreateNamespacesResponse, error) {
+func (f Conmon_attachContainer_Results_Future) Struct() (Conmon_attachContainer_Results, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_CreateNamespacesResponse(p.Struct()), err
+	return Conmon_attachContainer_Results(p.Struct()), err
+}
+func (p Conmon_attachContainer_Results_Future) Response() Conmon_AttachResponse_Future {
+	return Conmon_AttachResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_NamespaceResponse capnp.Struct
+type Conmon_reopenLogContainer_Params capnp.Struct
 
-// Conmon_NamespaceResponse_TypeID is the unique identifier for the type Conmon_NamespaceResponse.
-const Conmon_NamespaceResponse_TypeID = 0xa93853d6a4e3fa16
+// Conmon_reopenLogContainer_Params_TypeID is the unique identifier for the type Conmon_reopenLogContainer_Params.
+const Conmon_reopenLogContainer_Params_TypeID = 0xe5ea916eb0c31336
 
-func NewConmon_NamespaceResponse(s *capnp.Segment) (Conmon_NamespaceResponse, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
-	return Conmon_NamespaceResponse(st), err
+func NewConmon_reopenLogContainer_Params(s *capnp.Segment) (Conmon_reopenLogContainer_Params, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_reopenLogContainer_Params(st), err
 }
 
-func NewRootConmon_NamespaceResponse(s *capnp.Segment) (Conmon_NamespaceResponse, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1})
-	return Conmon_NamespaceResponse(st), err
+func NewRootConmon_reopenLogContainer_Params(s *capnp.Segment) (Conmon_reopenLogContainer_Params, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_reopenLogContainer_Params(st), err
 }
 
-func ReadRootConmon_NamespaceResponse(msg *capnp.Message) (Conmon_NamespaceResponse, error) {
+func ReadRootConmon_reopenLogContainer_Params(msg *capnp.Message) (Conmon_reopenLogContainer_Params, error) {
 	root, err := msg.Root()
-	return Conmon_NamespaceResponse(root.Struct()), err
+	return Conmon_reopenLogContainer_Params(root.Struct()), err
 }
 
-func (s Conmon_NamespaceResponse) String() string {
-	str, _ := text.Marshal(0xa93853d6a4e3fa16, capnp.Struct(s))
+func (s Conmon_reopenLogContainer_Params) String() string {
+	str, _ := text.Marshal(0xe5ea916eb0c31336, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_NamespaceResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_reopenLogContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_NamespaceResponse) DecodeFromPtr(p capnp.Ptr) Conmon_NamespaceResponse {
-	return Conmon_NamespaceResponse(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_reopenLogContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_reopenLogContainer_Params {
+	return Conmon_reopenLogContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_NamespaceResponse) ToPtr() capnp.Ptr {
+func (s Conmon_reopenLogContainer_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_NamespaceResponse) IsValid() bool {
+func (s Conmon_reopenLogContainer_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_NamespaceResponse) Message() *capnp.Message {
+func (s Conmon_reopenLogContainer_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_NamespaceResponse) Segment() *capnp.Segment {
+func (s Conmon_reopenLogContainer_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_NamespaceResponse) Type() Conmon_Namespace {
-	return Conmon_Namespace(capnp.Struct(s).Uint16(0))
-}
-
-func (s Conmon_NamespaceResponse) SetType(v Conmon_Namespace) {
-	capnp.Struct(s).SetUint16(0, uint16(v))
-}
-
-func (s Conmon_NamespaceResponse) Path() (string, error) {
+func (s Conmon_reopenLogContainer_Params) Request() (Conmon_ReopenLogRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return p.Text(), err
+	return Conmon_ReopenLogRequest(p.Struct()), err
 }
 
-func (s Conmon_NamespaceResponse) HasPath() bool {
+func (s Conmon_reopenLogContainer_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_NamespaceResponse) PathBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
-	return p.TextBytes(), err
+func (s Conmon_reopenLogContainer_Params) SetRequest(v Conmon_ReopenLogRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_NamespaceResponse) SetPath(v string) error {
-	return capnp.Struct(s).SetText(0, v)
+// NewRequest sets the request field to a newly
+// allocated Conmon_ReopenLogRequest struct, preferring placement in s's segment.
+func (s Conmon_reopenLogContainer_Params) NewRequest() (Conmon_ReopenLogRequest, error) {
+	ss, err := NewConmon_ReopenLogRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_ReopenLogRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_NamespaceResponse_List is a list of Conmon_NamespaceResponse.
-type Conmon_NamespaceResponse_List = capnp.StructList[Conmon_NamespaceResponse]
-
-// NewConmon_NamespaceResponse creates a new list of Conmon_NamespaceResponse.
-func NewConmon_NamespaceResponse_List(s *capnp.Segment, sz int32) (Conmon_NamespaceResponse_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 8, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_NamespaceResponse](l), err
+// Conmon_reopenLogContainer_Params_List is a list of Conmon_reopenLogContainer_Params.
+type Conmon_reopenLogContainer_Params_List = capnp.StructList[Conmon_reopenLogContainer_Params]
+
+// NewConmon_reopenLogContainer_Params creates a new list of Conmon_reopenLogContainer_Params.
+func NewConmon_reopenLogContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_reopenLogContainer_Params_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_reopenLogContainer_Params](l), err
 }
 
-// Conmon_NamespaceResponse_Future is a wrapper for a Conmon_NamespaceResponse promised by a client call.
-type Conmon_NamespaceResponse_Future struct{ *capnp.Future }
+// Conmon_reopenLogContainer_Params_Future is a wrapper for a Conmon_reopenLogContainer_Params promised by a client call.
+type Conmon_reopenLogContainer_Params_Future struct{ *capnp.Future }
 
-func (f Conmon_NamespaceResponse_Future) Struct() (Conmon_NamespaceResponse, error) {
+func (f Conmon_reopenLogContainer_Params_Future) Struct() (Conmon_reopenLogContainer_Params, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_NamespaceResponse(p.Struct()), err
+	return Conmon_reopenLogContainer_Params(p.Struct()), err
+}
+func (p Conmon_reopenLogContainer_Params_Future) Request() Conmon_ReopenLogRequest_Future {
+	return Conmon_ReopenLogRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_StartFdSocketRequest capnp.Struct
+type Conmon_reopenLogContainer_Results capnp.Struct
 
-// Conmon_StartFdSocketRequest_TypeID is the unique identifier for the type Conmon_StartFdSocketRequest.
-const Conmon_StartFdSocketRequest_TypeID = 0xba53ab87a688ec29
+// Conmon_reopenLogContainer_Results_TypeID is the unique identifier for the type Conmon_reopenLogContainer_Results.
+const Conmon_reopenLogContainer_Results_TypeID = 0xa0ef8355b64ee985
 
-func NewConmon_StartFdSocketRequest(s *capnp.Segment) (Conmon_StartFdSocketRequest, error) {
+func NewConmon_reopenLogContainer_Results(s *capnp.Segment) (Conmon_reopenLogContainer_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_StartFdSocketRequest(st), err
+	return Conmon_reopenLogContainer_Results(st), err
 }
 
-func NewRootConmon_StartFdSocketRequest(s *capnp.Segment) (Conmon_StartFdSocketRequest, error) {
+func NewRootConmon_reopenLogContainer_Results(s *capnp.Segment) (Conmon_reopenLogContainer_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_StartFdSocketRequest(st), err
+	return Conmon_reopenLogContainer_Results(st), err
 }
 
-func ReadRootConmon_StartFdSocketRequest(msg *capnp.Message) (Conmon_StartFdSocketRequest, error) {
+func ReadRootConmon_reopenLogContainer_Results(msg *capnp.Message) (Conmon_reopenLogContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_StartFdSocketRequest(root.Struct()), err
+	return Conmon_reopenLogContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_StartFdSocketRequest) String() string {
-	str, _ := text.Marshal(0xba53ab87a688ec29, capnp.Struct(s))
+func (s Conmon_reopenLogContainer_Results) String() string {
+	str, _ := text.Marshal(0xa0ef8355b64ee985, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_StartFdSocketRequest) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_reopenLogContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_StartFdSocketRequest) DecodeFromPtr(p capnp.Ptr) Conmon_StartFdSocketRequest {
-	return Conmon_StartFdSocketRequest(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_reopenLogContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_reopenLogContainer_Results {
+	return Conmon_reopenLogContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_StartFdSocketRequest) ToPtr() capnp.Ptr {
+func (s Conmon_reopenLogContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_StartFdSocketRequest) IsValid() bool {
+func (s Conmon_reopenLogContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_StartFdSocketRequest) Message() *capnp.Message {
+func (s Conmon_reopenLogContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_StartFdSocketRequest) Segment() *capnp.Segment {
+func (s Conmon_reopenLogContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_StartFdSocketRequest) Metadata() (Conmon_TextTextMapEntry_List, error) {
+func (s Conmon_reopenLogContainer_Results) Response() (Conmon_ReopenLogResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_TextTextMapEntry_List(p.List()), err
+	return Conmon_ReopenLogResponse(p.Struct()), err
 }
 
-func (s Conmon_StartFdSocketRequest) HasMetadata() bool {
+func (s Conmon_reopenLogContainer_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_StartFdSocketRequest) SetMetadata(v Conmon_TextTextMapEntry_List) error {
-	return capnp.Struct(s).SetPtr(0, v.ToPtr())
+func (s Conmon_reopenLogContainer_Results) SetResponse(v Conmon_ReopenLogResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-// NewMetadata sets the metadata field to a newly
-// allocated Conmon_TextTextMapEntry_List, preferring placement in s's segment.
-func (s Conmon_StartFdSocketRequest) NewMetadata(n int32) (Conmon_TextTextMapEntry_List, error) {
-	l, err := NewConmon_TextTextMapEntry_List(capnp.Struct(s).Segment(), n)
+// NewResponse sets the response field to a newly
+// allocated Conmon_ReopenLogResponse struct, preferring placement in s's segment.
+func (s Conmon_reopenLogContainer_Results) NewResponse() (Conmon_ReopenLogResponse, error) {
+	ss, err := NewConmon_ReopenLogResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_TextTextMapEntry_List{}, err
+		return Conmon_ReopenLogResponse{}, err
 	}
-	err = capnp.Struct(s).SetPtr(0, l.ToPtr())
-	return l, err
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_StartFdSocketRequest_List is a list of Conmon_StartFdSocketRequest.
-type Conmon_StartFdSocketRequest_List = capnp.StructList[Conmon_StartFdSocketRequest]
+// Conmon_reopenLogContainer_Results_List is a list of Conmon_reopenLogContainer_Results.
+type Conmon_reopenLogContainer_Results_List = capnp.StructList[Conmon_reopenLogContainer_Results]
 
-// NewConmon_StartFdSocketRequest creates a new list of Conmon_StartFdSocketRequest.
-func NewConmon_StartFdSocketRequest_List(s *capnp.Segment, sz int32) (Conmon_StartFdSocketRequest_List, error) {
+// NewConmon_reopenLogContainer_Results creates a new list of Conmon_reopenLogContainer_Results.
+func NewConmon_reopenLogContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_reopenLogContainer_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_StartFdSocketRequest](l), err
+	return capnp.StructList[Conmon_reopenLogContainer_Results](l), err
 }
 
-// Conmon_StartFdSocketRequest_Future is a wrapper for a Conmon_StartFdSocketRequest promised by a client call.
-type Conmon_StartFdSocketRequest_Future struct{ *capnp.Future }
+// Conmon_reopenLogContainer_Results_Future is a wrapper for a Conmon_reopenLogContainer_Results promised by a client call.
+type Conmon_reopenLogContainer_Results_Future struct{ *capnp.Future }
 
-func (f Conmon_StartFdSocketRequest_Future) Struct() (Conmon_StartFdSocketRequest, error) {
+func (f Conmon_reopenLogContainer_Results_Future) Struct() (Conmon_reopenLogContainer_Results, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_StartFdSocketRequest(p.Struct()), err
+	return Conmon_reopenLogContainer_Results(p.Struct()), err
+}
+func (p Conmon_reopenLogContainer_Results_Future) Response() Conmon_ReopenLogResponse_Future {
+	return Conmon_ReopenLogResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_StartFdSocketResponse capnp.Struct
+type Conmon_setWindowSizeContainer_Params capnp.Struct
 
-// Conmon_StartFdSocketResponse_TypeID is the unique identifier for the type Conmon_StartFdSocketResponse.
-const Conmon_StartFdSocketResponse_TypeID = 0xb62f418e0ae4e003
+// Conmon_setWindowSizeContainer_Params_TypeID is the unique identifier for the type Conmon_setWindowSizeContainer_Params.
+const Conmon_setWindowSizeContainer_Params_TypeID = 0xc76ccd4502bb61e7
 
-func NewConmon_StartFdSocketResponse(s *capnp.Segment) (Conmon_StartFdSocketResponse, error) {
+func NewConmon_setWindowSizeContainer_Params(s *capnp.Segment) (Conmon_setWindowSizeContainer_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_StartFdSocketResponse(st), err
+	return Conmon_setWindowSizeContainer_Params(st), err
 }
 
-func NewRootConmon_StartFdSocketResponse(s *capnp.Segment) (Conmon_StartFdSocketResponse, error) {
+func NewRootConmon_setWindowSizeContainer_Params(s *capnp.Segment) (Conmon_setWindowSizeContainer_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_StartFdSocketResponse(st), err
+	return Conmon_setWindowSizeContainer_Params(st), err
 }
 
-func ReadRootConmon_StartFdSocketResponse(msg *capnp.Message) (Conmon_StartFdSocketResponse, error) {
+func ReadRootConmon_setWindowSizeContainer_Params(msg *capnp.Message) (Conmon_setWindowSizeContainer_Params, error) {
 	root, err := msg.Root()
-	return Conmon_StartFdSocketResponse(root.Struct()), err
+	return Conmon_setWindowSizeContainer_Params(root.Struct()), err
 }
 
-func (s Conmon_StartFdSocketResponse) String() string {
-	str, _ := text.Marshal(0xb62f418e0ae4e003, capnp.Struct(s))
+func (s Conmon_setWindowSizeContainer_Params) String() string {
+	str, _ := text.Marshal(0xc76ccd4502bb61e7, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_StartFdSocketResponse) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_setWindowSizeContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_StartFdSocketResponse) DecodeFromPtr(p capnp.Ptr) Conmon_StartFdSocketResponse {
-	return Conmon_StartFdSocketResponse(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_setWindowSizeContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_setWindowSizeContainer_Params {
+	return Conmon_setWindowSizeContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_StartFdSocketResponse) ToPtr() capnp.Ptr {
+func (s Conmon_setWindowSizeContainer_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_StartFdSocketResponse) IsValid() bool {
+func (s Conmon_setWindowSizeContainer_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_StartFdSocketResponse) Message() *capnp.Message {
+func (s Conmon_setWindowSizeContainer_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_StartFdSocketResponse) Segment() *capnp.Segment {
+func (s Conmon_setWindowSizeContainer_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_StartFdSocketResponse) Path() (string, error) {
+func (s Conmon_setWindowSizeContainer_Params) Request() (Conmon_SetWindowSizeRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return p.Text(), err
+	return Conmon_SetWindowSizeRequest(p.Struct()), err
 }
 
-func (s Conmon_StartFdSocketResponse) HasPath() bool {
+func (s Conmon_setWindowSizeContainer_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_StartFdSocketResponse) PathBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
-	return p.TextBytes(), err
+func (s Conmon_setWindowSizeContainer_Params) SetRequest(v Conmon_SetWindowSizeRequest) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_StartFdSocketResponse) SetPath(v string) error {
-	return capnp.Struct(s).SetText(0, v)
+// NewRequest sets the request field to a newly
+// allocated Conmon_SetWindowSizeRequest struct, preferring placement in s's segment.
+func (s Conmon_setWindowSizeContainer_Params) NewRequest() (Conmon_SetWindowSizeRequest, error) {
+	ss, err := NewConmon_SetWindowSizeRequest(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_SetWindowSizeRequest{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_StartFdSocketResponse_List is a list of Conmon_StartFdSocketResponse.
-type Conmon_StartFdSocketResponse_List = capnp.StructList[Conmon_StartFdSocketResponse]
+// Conmon_setWindowSizeContainer_Params_List is a list of Conmon_setWindowSizeContainer_Params.
+type Conmon_setWindowSizeContainer_Params_List = capnp.StructList[Conmon_setWindowSizeContainer_Params]
 
-// NewConmon_StartFdSocketResponse creates a new list of Conmon_StartFdSocketResponse.
-func NewConmon_StartFdSocketResponse_List(s *capnp.Segment, sz int32) (Conmon_StartFdSocketResponse_List, error) {
+// NewConmon_setWindowSizeContainer_Params creates a new list of Conmon_setWindowSizeContainer_Params.
+func NewConmon_setWindowSizeContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_setWindowSizeContainer_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_StartFdSocketResponse](l), err
+	return capnp.StructList[Conmon_setWindowSizeContainer_Params](l), err
 }
 
-// Conmon_StartFdSocketResponse_Future is a wrapper for a Conmon_StartFdSocketResponse promised by a client call.
-type Conmon_StartFdSocketResponse_Future struct{ *capnp.Future }
+// Conmon_setWindowSizeContainer_Params_Future is a wrapper for a Conmon_setWindowSizeContainer_Params promised by a client call.
+type Conmon_setWindowSizeContainer_Params_Future struct{ *capnp.Future }
 
-func (f Conmon_StartFdSocketResponse_Future) Struct() (Conmon_StartFdSocketResponse, error) {
+func (f Conmon_setWindowSizeContainer_Params_Future) Struct() (Conmon_setWindowSizeContainer_Params, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_StartFdSocketResponse(p.Struct()), err
+	return Conmon_setWindowSizeContainer_Params(p.Struct()), err
+}
+func (p Conmon_setWindowSizeContainer_Params_Future) Request() Conmon_SetWindowSizeRequest_Future {
+	return Conmon_SetWindowSizeRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_TextTextMapEntry capnp.Struct
+type Conmon_setWindowSizeContainer_Results capnp.Struct
 
-// Conmon_TextTextMapEntry_TypeID is the unique identifier for the type Conmon_TextTextMapEntry.
-const Conmon_TextTextMapEntry_TypeID = 0xfabbfdde6d4ad392
+// Conmon_setWindowSizeContainer_Results_TypeID is the unique identifier for the type Conmon_setWindowSizeContainer_Results.
+const Conmon_setWindowSizeContainer_Results_TypeID = 0xe00e522611477055
 
-func NewConmon_TextTextMapEntry(s *capnp.Segment) (Conmon_TextTextMapEntry, error) {
-	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
-	return Conmon_TextTextMapEntry(st), err
+func NewConmon_setWindowSizeContainer_Results(s *capnp.Segment) (Conmon_setWindowSizeContainer_Results, error) {
+	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setWindowSizeContainer_Results(st), err
 }
 
-func NewRootConmon_TextTextMapEntry(s *capnp.Segment) (Conmon_TextTextMapEntry, error) {
-	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2})
-	return Conmon_TextTextMapEntry(st), err
+func NewRootConmon_setWindowSizeContainer_Results(s *capnp.Segment) (Conmon_setWindowSizeContainer_Results, error) {
+	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
+	return Conmon_setWindowSizeContainer_Results(st), err
 }
 
-func ReadRootConmon_TextTextMapEntry(msg *capnp.Message) (Conmon_TextTextMapEntry, error) {
+func ReadRootConmon_setWindowSizeContainer_Results(msg *capnp.Message) (Conmon_setWindowSizeContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_TextTextMapEntry(root.Struct()), err
+	return Conmon_setWindowSizeContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_TextTextMapEntry) String() string {
-	str, _ := text.Marshal(0xfabbfdde6d4ad392, capnp.Struct(s))
+func (s Conmon_setWindowSizeContainer_Results) String() string {
+	str, _ := text.Marshal(0xe00e522611477055, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_TextTextMapEntry) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_setWindowSizeContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_TextTextMapEntry) DecodeFromPtr(p capnp.Ptr) Conmon_TextTextMapEntry {
-	return Conmon_TextTextMapEntry(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_setWindowSizeContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_setWindowSizeContainer_Results {
+	return Conmon_setWindowSizeContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_TextTextMapEntry) ToPtr() capnp.Ptr {
+func (s Conmon_setWindowSizeContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_TextTextMapEntry) IsValid() bool {
+func (s Conmon_setWindowSizeContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_TextTextMapEntry) Message() *capnp.Message {
+func (s Conmon_setWindowSizeContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_TextTextMapEntry) Segment() *capnp.Segment {
+func (s Conmon_setWindowSizeContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_TextTextMapEntry) Key() (string, error) {
+func (s Conmon_setWindowSizeContainer_Results) Response() (Conmon_SetWindowSizeResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return p.Text(), err
+	return Conmon_SetWindowSizeResponse(p.Struct()), err
 }
 
-func (s Conmon_TextTextMapEntry) HasKey() bool {
+func (s Conmon_setWindowSizeContainer_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_TextTextMapEntry) KeyBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(0)
-	return p.TextBytes(), err
-}
-
-func (s Conmon_TextTextMapEntry) SetKey(v string) error {
-	return capnp.Struct(s).SetText(0, v)
-}
-
-func (s Conmon_TextTextMapEntry) Value() (string, error) {
-	p, err := capnp.Struct(s).Ptr(1)
-	return p.Text(), err
-}
-
-func (s Conmon_TextTextMapEntry) HasValue() bool {
-	return capnp.Struct(s).HasPtr(1)
-}
-
-func (s Conmon_TextTextMapEntry) ValueBytes() ([]byte, error) {
-	p, err := capnp.Struct(s).Ptr(1)
-	return p.TextBytes(), err
+func (s Conmon_setWindowSizeContainer_Results) SetResponse(v Conmon_SetWindowSizeResponse) error {
+	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
-func (s Conmon_TextTextMapEntry) SetValue(v string) error {
-	return capnp.Struct(s).SetText(1, v)
+// NewResponse sets the response field to a newly
+// allocated Conmon_SetWindowSizeResponse struct, preferring placement in s's segment.
+func (s Conmon_setWindowSizeContainer_Results) NewResponse() (Conmon_SetWindowSizeResponse, error) {
+	ss, err := NewConmon_SetWindowSizeResponse(capnp.Struct(s).Segment())
+	if err != nil {
+		return Conmon_SetWindowSizeResponse{}, err
+	}
+	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
+	return ss, err
 }
 
-// Conmon_TextTextMapEntry_List is a list of Conmon_TextTextMapEntry.
-type Conmon_TextTextMapEntry_List = capnp.StructList[Conmon_TextTextMapEntry]
+// Conmon_setWindowSizeContainer_Results_List is a list of Conmon_setWindowSizeContainer_Results.
+type Conmon_setWindowSizeContainer_Results_List = capnp.StructList[Conmon_setWindowSizeContainer_Results]
 
-// NewConmon_TextTextMapEntry creates a new list of Conmon_TextTextMapEntry.
-func NewConmon_TextTextMapEntry_List(s *capnp.Segment, sz int32) (Conmon_TextTextMapEntry_List, error) {
-	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 2}, sz)
-	return capnp.StructList[Conmon_TextTextMapEntry](l), err
+// NewConmon_setWindowSizeContainer_Results creates a new list of Conmon_setWindowSizeContainer_Results.
+func NewConmon_setWindowSizeContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_setWindowSizeContainer_Results_List, error) {
+	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
+	return capnp.StructList[Conmon_setWindowSizeContainer_Results](l), err
 }
 
-// Conmon_TextTextMapEntry_Future is a wrapper for a Conmon_TextTextMapEntry promised by a client call.
-type Conmon_TextTextMapEntry_Future struct{ *capnp.Future }
+// Conmon_setWindowSizeContainer_Results_Future is a wrapper for a Conmon_setWindowSizeContainer_Results promised by a client call.
+type Conmon_setWindowSizeContainer_Results_Future struct{ *capnp.Future }
 
-func (f Conmon_TextTextMapEntry_Future) Struct() (Conmon_TextTextMapEntry, error) {
+func (f Conmon_setWindowSizeContainer_Results_Future) Struct() (Conmon_setWindowSizeContainer_Results, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_TextTextMapEntry(p.Struct()), err
+	return Conmon_setWindowSizeContainer_Results(p.Struct()), err
+}
+func (p Conmon_setWindowSizeContainer_Results_Future) Response() Conmon_SetWindowSizeResponse_Future {
+	return Conmon_SetWindowSizeResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_version_Params capnp.Struct
+type Conmon_createNamespaces_Params capnp.Struct
 
-// Conmon_version_Params_TypeID is the unique identifier for the type Conmon_version_Params.
-const Conmon_version_Params_TypeID = 0xcc2f70676afee4e7
+// Conmon_createNamespaces_Params_TypeID is the unique identifier for the type Conmon_createNamespaces_Params.
+const Conmon_createNamespaces_Params_TypeID = 0x8b4c03a0662a38dc
 
-func NewConmon_version_Params(s *capnp.Segment) (Conmon_version_Params, error) {
+func NewConmon_createNamespaces_Params(s *capnp.Segment) (Conmon_createNamespaces_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_version_Params(st), err
+	return Conmon_createNamespaces_Params(st), err
 }
 
-func NewRootConmon_version_Params(s *capnp.Segment) (Conmon_version_Params, error) {
+func NewRootConmon_createNamespaces_Params(s *capnp.Segment) (Conmon_createNamespaces_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_version_Params(st), err
+	return Conmon_createNamespaces_Params(st), err
 }
 
-func ReadRootConmon_version_Params(msg *capnp.Message) (Conmon_version_Params, error) {
+func ReadRootConmon_createNamespaces_Params(msg *capnp.Message) (Conmon_createNamespaces_Params, error) {
 	root, err := msg.Root()
-	return Conmon_version_Params(root.Struct()), err
+	return Conmon_createNamespaces_Params(root.Struct()), err
 }
 
-func (s Conmon_version_Params) String() string {
-	str, _ := text.Marshal(0xcc2f70676afee4e7, capnp.Struct(s))
+func (s Conmon_createNamespaces_Params) String() string {
+	str, _ := text.Marshal(0x8b4c03a0662a38dc, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_version_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_createNamespaces_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_version_Params) DecodeFromPtr(p capnp.Ptr) Conmon_version_Params {
-	return Conmon_version_Params(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_createNamespaces_Params) DecodeFromPtr(p capnp.Ptr) Conmon_createNamespaces_Params {
+	return Conmon_createNamespaces_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_version_Params) ToPtr() capnp.Ptr {
+func (s Conmon_createNamespaces_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_version_Params) IsValid() bool {
+func (s Conmon_createNamespaces_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_version_Params) Message() *capnp.Message {
+func (s Conmon_createNamespaces_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_version_Params) Segment() *capnp.Segment {
+func (s Conmon_createNamespaces_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_version_Params) Request() (Conmon_VersionRequest, error) {
+func (s Conmon_createNamespaces_Params) Request() (Conmon_CreateNamespacesRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_VersionRequest(p.Struct()), err
+	return Conmon_CreateNamespacesRequest(p.Struct()), err
 }
 
-func (s Conmon_version_Params) HasRequest() bool {
+func (s Conmon_createNamespaces_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_version_Params) SetRequest(v Conmon_VersionRequest) error {
+func (s Conmon_createNamespaces_Params) SetRequest(v Conmon_CreateNamespacesRequest) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_VersionRequest struct, preferring placement in s's segment.
-func (s Conmon_version_Params) NewRequest() (Conmon_VersionRequest, error) {
-	ss, err := NewConmon_VersionRequest(capnp.Struct(s).Segment())
+// allocated Conmon_CreateNamespacesRequest struct, preferring placement in s's segment.
+func (s Conmon_createNamespaces_Params) NewRequest() (Conmon_CreateNamespacesRequest, error) {
+	ss, err := NewConmon_CreateNamespacesRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_VersionRequest{}, err
+		return Conmon_CreateNamespacesRequest{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_version_Params_List is a list of Conmon_version_Params.
-type Conmon_version_Params_List = capnp.StructList[Conmon_version_Params]
+// Conmon_createNamespaces_Params_List is a list of Conmon_createNamespaces_Params.
+type Conmon_createNamespaces_Params_List = capnp.StructList[Conmon_createNamespaces_Params]
 
-// NewConmon_version_Params creates a new list of Conmon_version_Params.
-func NewConmon_version_Params_List(s *capnp.Segment, sz int32) (Conmon_version_Params_List, error) {
+// NewConmon_createNamespaces_Params creates a new list of Conmon_createNamespaces_Params.
+func NewConmon_createNamespaces_Params_List(s *capnp.Segment, sz int32) (Conmon_createNamespaces_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_version_Params](l), err
+	return capnp.StructList[Conmon_createNamespaces_Params](l), err
 }
 
-// Conmon_version_Params_Future is a wrapper for a Conmon_version_Params promised by a client call.
-type Conmon_version_Params_Future struct{ *capnp.Future }
+// Conmon_createNamespaces_Params_Future is a wrapper for a Conmon_createNamespaces_Params promised by a client call.
+type Conmon_createNamespaces_Params_Future struct{ *capnp.Future }
 
-func (f Conmon_version_Params_Future) Struct() (Conmon_version_Params, error) {
+func (f Conmon_createNamespaces_Params_Future) Struct() (Conmon_createNamespaces_Params, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_version_Params(p.Struct()), err
+	return Conmon_createNamespaces_Params(p.Struct()), err
 }
-func (p Conmon_version_Params_Future) Request() Conmon_VersionRequest_Future {
-	return Conmon_VersionRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_createNamespaces_Params_Future) Request() Conmon_CreateNamespacesRequest_Future {
+	return Conmon_CreateNamespacesRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_version_Results capnp.Struct
+type Conmon_createNamespaces_Results capnp.Struct
 
-// Conmon_version_Results_TypeID is the unique identifier for the type Conmon_version_Results.
-const Conmon_version_Results_TypeID = 0xe313695ea9477b30
+// Conmon_createNamespaces_Results_TypeID is the unique identifier for the type Conmon_createNamespaces_Results.
+const Conmon_createNamespaces_Results_TypeID = 0x8aef91973dc8a4f5
 
-func NewConmon_version_Results(s *capnp.Segment) (Conmon_version_Results, error) {
+func NewConmon_createNamespaces_Results(s *capnp.Segment) (Conmon_createNamespaces_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_version_Results(st), err
+	return Conmon_createNamespaces_Results(st), err
 }
 
-func NewRootConmon_version_Results(s *capnp.Segment) (Conmon_version_Results, error) {
+func NewRootConmon_createNamespaces_Results(s *capnp.Segment) (Conmon_createNamespaces_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_version_Results(st), err
+	return Conmon_createNamespaces_Results(st), err
 }
 
-func ReadRootConmon_version_Results(msg *capnp.Message) (Conmon_version_Results, error) {
+func ReadRootConmon_createNamespaces_Results(msg *capnp.Message) (Conmon_createNamespaces_Results, error) {
 	root, err := msg.Root()
-	return Conmon_version_Results(root.Struct()), err
+	return Conmon_createNamespaces_Results(root.Struct()), err
 }
 
-func (s Conmon_version_Results) String() string {
-	str, _ := text.Marshal(0xe313695ea9477b30, capnp.Struct(s))
+func (s Conmon_createNamespaces_Results) String() string {
+	str, _ := text.Marshal(0x8aef91973dc8a4f5, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_version_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_createNamespaces_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_version_Results) DecodeFromPtr(p capnp.Ptr) Conmon_version_Results {
-	return Conmon_version_Results(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_createNamespaces_Results) DecodeFromPtr(p capnp.Ptr) Conmon_createNamespaces_Results {
+	return Conmon_createNamespaces_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_version_Results) ToPtr() capnp.Ptr {
+func (s Conmon_createNamespaces_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_version_Results) IsValid() bool {
+func (s Conmon_createNamespaces_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_version_Results) Message() *capnp.Message {
+func (s Conmon_createNamespaces_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_version_Results) Segment() *capnp.Segment {
+func (s Conmon_createNamespaces_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_version_Results) Response() (Conmon_VersionResponse, error) {
+func (s Conmon_createNamespaces_Results) Response() (Conmon_CreateNamespacesResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_VersionResponse(p.Struct()), err
+	return Conmon_CreateNamespacesResponse(p.Struct()), err
 }
 
-func (s Conmon_version_Results) HasResponse() bool {
+func (s Conmon_createNamespaces_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_version_Results) SetResponse(v Conmon_VersionResponse) error {
+func (s Conmon_createNamespaces_Results) SetResponse(v Conmon_CreateNamespacesResponse) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_VersionResponse struct, preferring placement in s's segment.
-func (s Conmon_version_Results) NewResponse() (Conmon_VersionResponse, error) {
-	ss, err := NewConmon_VersionResponse(capnp.Struct(s).Segment())
+// allocated Conmon_CreateNamespacesResponse struct, preferring placement in s's segment.
+func (s Conmon_createNamespaces_Results) NewResponse() (Conmon_CreateNamespacesResponse, error) {
+	ss, err := NewConmon_CreateNamespacesResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_VersionResponse{}, err
+		return Conmon_CreateNamespacesResponse{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_version_Results_List is a list of Conmon_version_Results.
-type Conmon_version_Results_List = capnp.StructList[Conmon_version_Results]
+// Conmon_createNamespaces_Results_List is a list of Conmon_createNamespaces_Results.
+type Conmon_createNamespaces_Results_List = capnp.StructList[Conmon_createNamespaces_Results]
 
-// NewConmon_version_Results creates a new list of Conmon_version_Results.
-func NewConmon_version_Results_List(s *capnp.Segment, sz int32) (Conmon_version_Results_List, error) {
+// NewConmon_createNamespaces_Results creates a new list of Conmon_createNamespaces_Results.
+func NewConmon_createNamespaces_Results_List(s *capnp.Segment, sz int32) (Conmon_createNamespaces_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_version_Results](l), err
+	return capnp.StructList[Conmon_createNamespaces_Results](l), err
 }
 
-// Conmon_version_Results_Future is a wrapper for a Conmon_version_Results promised by a client call.
-type Conmon_version_Results_Future struct{ *capnp.Future }
+// Conmon_createNamespaces_Results_Future is a wrapper for a Conmon_createNamespaces_Results promised by a client call.
+type Conmon_createNamespaces_Results_Future struct{ *capnp.Future }
 
-func (f Conmon_version_Results_Future) Struct() (Conmon_version_Results, error) {
+func (f Conmon_createNamespaces_Results_Future) Struct() (Conmon_createNamespaces_Results, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_version_Results(p.Struct()), err
+	return Conmon_createNamespaces_Results(p.Struct()), err
 }
-func (p Conmon_version_Results_Future) Response() Conmon_VersionResponse_Future {
-	return Conmon_VersionResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_createNamespaces_Results_Future) Response() Conmon_CreateNamespacesResponse_Future {
+	return Conmon_CreateNamespacesResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_createContainer_Params capnp.Struct
+type Conmon_startFdSocket_Params capnp.Struct
 
-// Conmon_createContainer_Params_TypeID is the unique identifier for the type Conmon_createContainer_Params.
-const Conmon_createContainer_Params_TypeID = 0xf44732c48f949ab8
+// Conmon_startFdSocket_Params_TypeID is the unique identifier for the type Conmon_startFdSocket_Params.
+const Conmon_startFdSocket_Params_TypeID = 0xce733f0914c80b6b
 
-func NewConmon_createContainer_Params(s *capnp.Segment) (Conmon_createContainer_Params, error) {
+func NewConmon_startFdSocket_Params(s *capnp.Segment) (Conmon_startFdSocket_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createContainer_Params(st), err
+	return Conmon_startFdSocket_Params(st), err
 }
 
-func NewRootConmon_createContainer_Params(s *capnp.Segment) (Conmon_createContainer_Params, error) {
+func NewRootConmon_startFdSocket_Params(s *capnp.Segment) (Conmon_startFdSocket_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createContainer_Params(st), err
+	return Conmon_startFdSocket_Params(st), err
 }
 
-func ReadRootConmon_createContainer_Params(msg *capnp.Message) (Conmon_createContainer_Params, error) {
+func ReadRootConmon_startFdSocket_Params(msg *capnp.Message) (Conmon_startFdSocket_Params, error) {
 	root, err := msg.Root()
-	return Conmon_createContainer_Params(root.Struct()), err
+	return Conmon_startFdSocket_Params(root.Struct()), err
 }
 
-func (s Conmon_createContainer_Params) String() string {
-	str, _ := text.Marshal(0xf44732c48f949ab8, capnp.Struct(s))
+func (s Conmon_startFdSocket_Params) String() string {
+	str, _ := text.Marshal(0xce733f0914c80b6b, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_createContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_startFdSocket_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_createContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_createContainer_Params {
-	return Conmon_createContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_startFdSocket_Params) DecodeFromPtr(p capnp.Ptr) Conmon_startFdSocket_Params {
+	return Conmon_startFdSocket_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_createContainer_Params) ToPtr() capnp.Ptr {
+func (s Conmon_startFdSocket_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_createContainer_Params) IsValid() bool {
+func (s Conmon_startFdSocket_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_createContainer_Params) Message() *capnp.Message {
+func (s Conmon_startFdSocket_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_createContainer_Params) Segment() *capnp.Segment {
+func (s Conmon_startFdSocket_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_createContainer_Params) Request() (Conmon_CreateContainerRequest, error) {
+func (s Conmon_startFdSocket_Params) Request() (Conmon_StartFdSocketRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_CreateContainerRequest(p.Struct()), err
+	return Conmon_StartFdSocketRequest(p.Struct()), err
 }
 
-func (s Conmon_createContainer_Params) HasRequest() bool {
+func (s Conmon_startFdSocket_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_createContainer_Params) SetRequest(v Conmon_CreateContainerRequest) error {
+func (s Conmon_startFdSocket_Params) SetRequest(v Conmon_StartFdSocketRequest) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_CreateContainerRequest struct, preferring placement in s's segment.
-func (s Conmon_createContainer_Params) NewRequest() (Conmon_CreateContainerRequest, error) {
-	ss, err := NewConmon_CreateContainerRequest(capnp.Struct(s).Segment())
+// allocated Conmon_StartFdSocketRequest struct, preferring placement in s's segment.
+func (s Conmon_startFdSocket_Params) NewRequest() (Conmon_StartFdSocketRequest, error) {
+	ss, err := NewConmon_StartFdSocketRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_CreateContainerRequest{}, err
+		return Conmon_StartFdSocketRequest{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_createContainer_Params_List is a list of Conmon_createContainer_Params.
-type Conmon_createContainer_Params_List = capnp.StructList[Conmon_createContainer_Params]
+// Conmon_startFdSocket_Params_List is a list of Conmon_startFdSocket_Params.
+type Conmon_startFdSocket_Params_List = capnp.StructList[Conmon_startFdSocket_Params]
 
-// NewConmon_createContainer_Params creates a new list of Conmon_createContainer_Params.
-func NewConmon_createContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_createContainer_Params_List, error) {
+// NewConmon_startFdSocket_Params creates a new list of Conmon_startFdSocket_Params.
+func NewConmon_startFdSocket_Params_List(s *capnp.Segment, sz int32) (Conmon_startFdSocket_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_createContainer_Params](l), err
+	return capnp.StructList[Conmon_startFdSocket_Params](l), err
 }
 
-// Conmon_createContainer_Params_Future is a wrapper for a Conmon_createContainer_Params promised by a client call.
-type Conmon_createContainer_Params_Future struct{ *capnp.Future }
+// Conmon_startFdSocket_Params_Future is a wrapper for a Conmon_startFdSocket_Params promised by a client call.
+type Conmon_startFdSocket_Params_Future struct{ *capnp.Future }
 
-func (f Conmon_createContainer_Params_Future) Struct() (Conmon_createContainer_Params, error) {
+func (f Conmon_startFdSocket_Params_Future) Struct() (Conmon_startFdSocket_Params, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_createContainer_Params(p.Struct()), err
+	return Conmon_startFdSocket_Params(p.Struct()), err
 }
-func (p Conmon_createContainer_Params_Future) Request() Conmon_CreateContainerRequest_Future {
-	return Conmon_CreateContainerRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_startFdSocket_Params_Future) Request() Conmon_StartFdSocketRequest_Future {
+	return Conmon_StartFdSocketRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_createContainer_Results capnp.Struct
+type Conmon_startFdSocket_Results capnp.Struct
 
-// Conmon_createContainer_Results_TypeID is the unique identifier for the type Conmon_createContainer_Results.
-const Conmon_createContainer_Results_TypeID = 0xceba3c1a97be15f8
+// Conmon_startFdSocket_Results_TypeID is the unique identifier for the type Conmon_startFdSocket_Results.
+const Conmon_startFdSocket_Results_TypeID = 0xf4e3e92ae0815f15
 
-func NewConmon_createContainer_Results(s *capnp.Segment) (Conmon_createContainer_Results, error) {
+func NewConmon_startFdSocket_Results(s *capnp.Segment) (Conmon_startFdSocket_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createContainer_Results(st), err
+	return Conmon_startFdSocket_Results(st), err
 }
 
-func NewRootConmon_createContainer_Results(s *capnp.Segment) (Conmon_createContainer_Results, error) {
+func NewRootConmon_startFdSocket_Results(s *capnp.Segment) (Conmon_startFdSocket_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createContainer_Results(st), err
+	return Conmon_startFdSocket_Results(st), err
 }
 
-func ReadRootConmon_createContainer_Results(msg *capnp.Message) (Conmon_createContainer_Results, error) {
+func ReadRootConmon_startFdSocket_Results(msg *capnp.Message) (Conmon_startFdSocket_Results, error) {
 	root, err := msg.Root()
-	return Conmon_createContainer_Results(root.Struct()), err
+	return Conmon_startFdSocket_Results(root.Struct()), err
 }
 
-func (s Conmon_createContainer_Results) String() string {
-	str, _ := text.Marshal(0xceba3c1a97be15f8, capnp.Struct(s))
+func (s Conmon_startFdSocket_Results) String() string {
+	str, _ := text.Marshal(0xf4e3e92ae0815f15, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_createContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_startFdSocket_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_createContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_createContainer_Results {
-	return Conmon_createContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_startFdSocket_Results) DecodeFromPtr(p capnp.Ptr) Conmon_startFdSocket_Results {
+	return Conmon_startFdSocket_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_createContainer_Results) ToPtr() capnp.Ptr {
+func (s Conmon_startFdSocket_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_createContainer_Results) IsValid() bool {
+func (s Conmon_startFdSocket_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_createContainer_Results) Message() *capnp.Message {
+func (s Conmon_startFdSocket_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_createContainer_Results) Segment() *capnp.Segment {
+func (s Conmon_startFdSocket_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_createContainer_Results) Response() (Conmon_CreateContainerResponse, error) {
+func (s Conmon_startFdSocket_Results) Response() (Conmon_StartFdSocketResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_CreateContainerResponse(p.Struct()), err
+	return Conmon_StartFdSocketResponse(p.Struct()), err
 }
 
-func (s Conmon_createContainer_Results) HasResponse() bool {
+func (s Conmon_startFdSocket_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_createContainer_Results) SetResponse(v Conmon_CreateContainerResponse) error {
+func (s Conmon_startFdSocket_Results) SetResponse(v Conmon_StartFdSocketResponse) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_CreateContainerResponse struct, preferring placement in s's segment.
-func (s Conmon_createContainer_Results) NewResponse() (Conmon_CreateContainerResponse, error) {
-	ss, err := NewConmon_CreateContainerResponse(capnp.Struct(s).Segment())
+// allocated Conmon_StartFdSocketResponse struct, preferring placement in s's segment.
+func (s Conmon_startFdSocket_Results) NewResponse() (Conmon_StartFdSocketResponse, error) {
+	ss, err := NewConmon_StartFdSocketResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_CreateContainerResponse{}, err
+		return Conmon_StartFdSocketResponse{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_createContainer_Results_List is a list of Conmon_createContainer_Results.
-type Conmon_createContainer_Results_List = capnp.StructList[Conmon_createContainer_Results]
+// Conmon_startFdSocket_Results_List is a list of Conmon_startFdSocket_Results.
+type Conmon_startFdSocket_Results_List = capnp.StructList[Conmon_startFdSocket_Results]
 
-// NewConmon_createContainer_Results creates a new list of Conmon_createContainer_Results.
-func NewConmon_createContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_createContainer_Results_List, error) {
+// NewConmon_startFdSocket_Results creates a new list of Conmon_startFdSocket_Results.
+func NewConmon_startFdSocket_Results_List(s *capnp.Segment, sz int32) (Conmon_startFdSocket_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_createContainer_Results](l), err
+	return capnp.StructList[Conmon_startFdSocket_Results](l), err
 }
 
-// Conmon_createContainer_Results_Future is a wrapper for a Conmon_createContainer_Results promised by a client call.
-type Conmon_createContainer_Results_Future struct{ *capnp.Future }
+// Conmon_startFdSocket_Results_Future is a wrapper for a Conmon_startFdSocket_Results promised by a client call.
+type Conmon_startFdSocket_Results_Future struct{ *capnp.Future }
 
-func (f Conmon_createContainer_Results_Future) Struct() (Conmon_createContainer_Results, error) {
+func (f Conmon_startFdSocket_Results_Future) Struct() (Conmon_startFdSocket_Results, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_createContainer_Results(p.Struct()), err
+	return Conmon_startFdSocket_Results(p.Struct()), err
 }
-func (p Conmon_createContainer_Results_Future) Response() Conmon_CreateContainerResponse_Future {
-	return Conmon_CreateContainerResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_startFdSocket_Results_Future) Response() Conmon_StartFdSocketResponse_Future {
+	return Conmon_StartFdSocketResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_execSyncContainer_Params capnp.Struct
+type Conmon_tailLogContainer_Params capnp.Struct
 
-// Conmon_execSyncContainer_Params_TypeID is the unique identifier for the type Conmon_execSyncContainer_Params.
-const Conmon_execSyncContainer_Params_TypeID = 0x83479da67279e173
+// Conmon_tailLogContainer_Params_TypeID is the unique identifier for the type Conmon_tailLogContainer_Params.
+const Conmon_tailLogContainer_Params_TypeID = 0x90a3950a51412b8b
 
-func NewConmon_execSyncContainer_Params(s *capnp.Segment) (Conmon_execSyncContainer_Params, error) {
+func NewConmon_tailLogContainer_Params(s *capnp.Segment) (Conmon_tailLogContainer_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_execSyncContainer_Params(st), err
+	return Conmon_tailLogContainer_Params(st), err
 }
 
-func NewRootConmon_execSyncContainer_Params(s *capnp.Segment) (Conmon_execSyncContainer_Params, error) {
+func NewRootConmon_tailLogContainer_Params(s *capnp.Segment) (Conmon_tailLogContainer_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_execSyncContainer_Params(st), err
+	return Conmon_tailLogContainer_Params(st), err
 }
 
-func ReadRootConmon_execSyncContainer_Params(msg *capnp.Message) (Conmon_execSyncContainer_Params, error) {
+func ReadRootConmon_tailLogContainer_Params(msg *capnp.Message) (Conmon_tailLogContainer_Params, error) {
 	root, err := msg.Root()
-	return Conmon_execSyncContainer_Params(root.Struct()), err
+	return Conmon_tailLogContainer_Params(root.Struct()), err
 }
 
-func (s Conmon_execSyncContainer_Params) String() string {
-	str, _ := text.Marshal(0x83479da67279e173, capnp.Struct(s))
+func (s Conmon_tailLogContainer_Params) String() string {
+	str, _ := text.Marshal(0x90a3950a51412b8b, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_execSyncContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_tailLogContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_execSyncContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_execSyncContainer_Params {
-	return Conmon_execSyncContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_tailLogContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_tailLogContainer_Params {
+	return Conmon_tailLogContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_execSyncContainer_Params) ToPtr() capnp.Ptr {
+func (s Conmon_tailLogContainer_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_execSyncContainer_Params) IsValid() bool {
+func (s Conmon_tailLogContainer_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_execSyncContainer_Params) Message() *capnp.Message {
+func (s Conmon_tailLogContainer_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_execSyncContainer_Params) Segment() *capnp.Segment {
+func (s Conmon_tailLogContainer_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_execSyncContainer_Params) Request() (Conmon_ExecSyncContainerRequest, error) {
+func (s Conmon_tailLogContainer_Params) Request() (Conmon_TailLogRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_ExecSyncContainerRequest(p.Struct()), err
+	return Conmon_TailLogRequest(p.Struct()), err
 }
 
-func (s Conmon_execSyncContainer_Params) HasRequest() bool {
+func (s Conmon_tailLogContainer_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_execSyncContainer_Params) SetRequest(v Conmon_ExecSyncContainerRequest) error {
+func (s Conmon_tailLogContainer_Params) SetRequest(v Conmon_TailLogRequest) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_ExecSyncContainerRequest struct, preferring placement in s's segment.
-func (s Conmon_execSyncContainer_Params) NewRequest() (Conmon_ExecSyncContainerRequest, error) {
-	ss, err := NewConmon_ExecSyncContainerRequest(capnp.Struct(s).Segment())
+// allocated Conmon_TailLogRequest struct, preferring placement in s's segment.
+func (s Conmon_tailLogContainer_Params) NewRequest() (Conmon_TailLogRequest, error) {
+	ss, err := NewConmon_TailLogRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_ExecSyncContainerRequest{}, err
+		return Conmon_TailLogRequest{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_execSyncContainer_Params_List is a list of Conmon_execSyncContainer_Params.
-type Conmon_execSyncContainer_Params_List = capnp.StructList[Conmon_execSyncContainer_Params]
+// Conmon_tailLogContainer_Params_List is a list of Conmon_tailLogContainer_Params.
+type Conmon_tailLogContainer_Params_List = capnp.StructList[Conmon_tailLogContainer_Params]
 
-// NewConmon_execSyncContainer_Params creates a new list of Conmon_execSyncContainer_Params.
-func NewConmon_execSyncContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_execSyncContainer_Params_List, error) {
+// NewConmon_tailLogContainer_Params creates a new list of Conmon_tailLogContainer_Params.
+func NewConmon_tailLogContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_tailLogContainer_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_execSyncContainer_Params](l), err
+	return capnp.StructList[Conmon_tailLogContainer_Params](l), err
 }
 
-// Conmon_execSyncContainer_Params_Future is a wrapper for a Conmon_execSyncContainer_Params promised by a client call.
-type Conmon_execSyncContainer_Params_Future struct{ *capnp.Future }
+// Conmon_tailLogContainer_Params_Future is a wrapper for a Conmon_tailLogContainer_Params promised by a client call.
+type Conmon_tailLogContainer_Params_Future struct{ *capnp.Future }
 
-func (f Conmon_execSyncContainer_Params_Future) Struct() (Conmon_execSyncContainer_Params, error) {
+func (f Conmon_tailLogContainer_Params_Future) Struct() (Conmon_tailLogContainer_Params, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_execSyncContainer_Params(p.Struct()), err
+	return Conmon_tailLogContainer_Params(p.Struct()), err
 }
-func (p Conmon_execSyncContainer_Params_Future) Request() Conmon_ExecSyncContainerRequest_Future {
-	return Conmon_ExecSyncContainerRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_tailLogContainer_Params_Future) Request() Conmon_TailLogRequest_Future {
+	return Conmon_TailLogRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_execSyncContainer_Results capnp.Struct
+type Conmon_tailLogContainer_Results capnp.Struct
 
-// Conmon_execSyncContainer_Results_TypeID is the unique identifier for the type Conmon_execSyncContainer_Results.
-const Conmon_execSyncContainer_Results_TypeID = 0xf8e86a5c0baa01bc
+// Conmon_tailLogContainer_Results_TypeID is the unique identifier for the type Conmon_tailLogContainer_Results.
+const Conmon_tailLogContainer_Results_TypeID = 0xdebaeed2a782ac80
 
-func NewConmon_execSyncContainer_Results(s *capnp.Segment) (Conmon_execSyncContainer_Results, error) {
+func NewConmon_tailLogContainer_Results(s *capnp.Segment) (Conmon_tailLogContainer_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_execSyncContainer_Results(st), err
+	return Conmon_tailLogContainer_Results(st), err
 }
 
-func NewRootConmon_execSyncContainer_Results(s *capnp.Segment) (Conmon_execSyncContainer_Results, error) {
+func NewRootConmon_tailLogContainer_Results(s *capnp.Segment) (Conmon_tailLogContainer_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_execSyncContainer_Results(st), err
+	return Conmon_tailLogContainer_Results(st), err
 }
 
-func ReadRootConmon_execSyncContainer_Results(msg *capnp.Message) (Conmon_execSyncContainer_Results, error) {
+func ReadRootConmon_tailLogContainer_Results(msg *capnp.Message) (Conmon_tailLogContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_execSyncContainer_Results(root.Struct()), err
+	return Conmon_tailLogContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_execSyncContainer_Results) String() string {
-	str, _ := text.Marshal(0xf8e86a5c0baa01bc, capnp.Struct(s))
+func (s Conmon_tailLogContainer_Results) String() string {
+	str, _ := text.Marshal(0xdebaeed2a782ac80, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_execSyncContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_tailLogContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_execSyncContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_execSyncContainer_Results {
-	return Conmon_execSyncContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_tailLogContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_tailLogContainer_Results {
+	return Conmon_tailLogContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_execSyncContainer_Results) ToPtr() capnp.Ptr {
+func (s Conmon_tailLogContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_execSyncContainer_Results) IsValid() bool {
+func (s Conmon_tailLogContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_execSyncContainer_Results) Message() *capnp.Message {
+func (s Conmon_tailLogContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_execSyncContainer_Results) Segment() *capnp.Segment {
+func (s Conmon_tailLogContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_execSyncContainer_Results) Response() (Conmon_ExecSyncContainerResponse, error) {
+func (s Conmon_tailLogContainer_Results) Response() (Conmon_TailLogResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_ExecSyncContainerResponse(p.Struct()), err
+	return Conmon_TailLogResponse(p.Struct()), err
 }
 
-func (s Conmon_execSyncContainer_Results) HasResponse() bool {
+func (s Conmon_tailLogContainer_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_execSyncContainer_Results) SetResponse(v Conmon_ExecSyncContainerResponse) error {
+func (s Conmon_tailLogContainer_Results) SetResponse(v Conmon_TailLogResponse) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_ExecSyncContainerResponse struct, preferring placement in s's segment.
-func (s Conmon_execSyncContainer_Results) NewResponse() (Conmon_ExecSyncContainerResponse, error) {
-	ss, err := NewConmon_ExecSyncContainerResponse(capnp.Struct(s).Segment())
+// allocated Conmon_TailLogResponse struct, preferring placement in s's segment.
+func (s Conmon_tailLogContainer_Results) NewResponse() (Conmon_TailLogResponse, error) {
+	ss, err := NewConmon_TailLogResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_ExecSyncContainerResponse{}, err
+		return Conmon_TailLogResponse{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_execSyncContainer_Results_List is a list of Conmon_execSyncContainer_Results.
-type Conmon_execSyncContainer_Results_List = capnp.StructList[Conmon_execSyncContainer_Results]
+// Conmon_tailLogContainer_Results_List is a list of Conmon_tailLogContainer_Results.
+type Conmon_tailLogContainer_Results_List = capnp.StructList[Conmon_tailLogContainer_Results]
 
-// NewConmon_execSyncContainer_Results creates a new list of Conmon_execSyncContainer_Results.
-func NewConmon_execSyncContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_execSyncContainer_Results_List, error) {
+// NewConmon_tailLogContainer_Results creates a new list of Conmon_tailLogContainer_Results.
+func NewConmon_tailLogContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_tailLogContainer_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_execSyncContainer_Results](l), err
+	return capnp.StructList[Conmon_tailLogContainer_Results](l), err
 }
 
-// Conmon_execSyncContainer_Results_Future is a wrapper for a Conmon_execSyncContainer_Results promised by a client call.
-type Conmon_execSyncContainer_Results_Future struct{ *capnp.Future }
+// Conmon_tailLogContainer_Results_Future is a wrapper for a Conmon_tailLogContainer_Results promised by a client call.
+type Conmon_tailLogContainer_Results_Future struct{ *capnp.Future }
 
-func (f Conmon_execSyncContainer_Results_Future) Struct() (Conmon_execSyncContainer_Results, error) {
+func (f Conmon_tailLogContainer_Results_Future) Struct() (Conmon_tailLogContainer_Results, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_execSyncContainer_Results(p.Struct()), err
+	return Conmon_tailLogContainer_Results(p.Struct()), err
 }
-func (p Conmon_execSyncContainer_Results_Future) Response() Conmon_ExecSyncContainerResponse_Future {
-	return Conmon_ExecSyncContainerResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_tailLogContainer_Results_Future) Response() Conmon_TailLogResponse_Future {
+	return Conmon_TailLogResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_attachContainer_Params capnp.Struct
+type Conmon_pauseLogContainer_Params capnp.Struct
 
-// Conmon_attachContainer_Params_TypeID is the unique identifier for the type Conmon_attachContainer_Params.
-const Conmon_attachContainer_Params_TypeID = 0xaa2f3c8ad1c3af24
+// Conmon_pauseLogContainer_Params_TypeID is the unique identifier for the type Conmon_pauseLogContainer_Params.
+const Conmon_pauseLogContainer_Params_TypeID = 0xa3cb406c522dcab1
 
-func NewConmon_attachContainer_Params(s *capnp.Segment) (Conmon_attachContainer_Params, error) {
+func NewConmon_pauseLogContainer_Params(s *capnp.Segment) (Conmon_pauseLogContainer_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_attachContainer_Params(st), err
+	return Conmon_pauseLogContainer_Params(st), err
 }
 
-func NewRootConmon_attachContainer_Params(s *capnp.Segment) (Conmon_attachContainer_Params, error) {
+func NewRootConmon_pauseLogContainer_Params(s *capnp.Segment) (Conmon_pauseLogContainer_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_attachContainer_Params(st), err
+	return Conmon_pauseLogContainer_Params(st), err
 }
 
-func ReadRootConmon_attachContainer_Params(msg *capnp.Message) (Conmon_attachContainer_Params, error) {
+func ReadRootConmon_pauseLogContainer_Params(msg *capnp.Message) (Conmon_pauseLogContainer_Params, error) {
 	root, err := msg.Root()
-	return Conmon_attachContainer_Params(root.Struct()), err
+	return Conmon_pauseLogContainer_Params(root.Struct()), err
 }
 
-func (s Conmon_attachContainer_Params) String() string {
-	str, _ := text.Marshal(0xaa2f3c8ad1c3af24, capnp.Struct(s))
+func (s Conmon_pauseLogContainer_Params) String() string {
+	str, _ := text.Marshal(0xa3cb406c522dcab1, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_attachContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_pauseLogContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_attachContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_attachContainer_Params {
-	return Conmon_attachContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_pauseLogContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_pauseLogContainer_Params {
+	return Conmon_pauseLogContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_attachContainer_Params) ToPtr() capnp.Ptr {
+func (s Conmon_pauseLogContainer_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_attachContainer_Params) IsValid() bool {
+func (s Conmon_pauseLogContainer_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_attachContainer_Params) Message() *capnp.Message {
+func (s Conmon_pauseLogContainer_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_attachContainer_Params) Segment() *capnp.Segment {
+func (s Conmon_pauseLogContainer_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_attachContainer_Params) Request() (Conmon_AttachRequest, error) {
+func (s Conmon_pauseLogContainer_Params) Request() (Conmon_PauseLogRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_AttachRequest(p.Struct()), err
+	return Conmon_PauseLogRequest(p.Struct()), err
 }
 
-func (s Conmon_attachContainer_Params) HasRequest() bool {
+func (s Conmon_pauseLogContainer_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_attachContainer_Params) SetRequest(v Conmon_AttachRequest) error {
+func (s Conmon_pauseLogContainer_Params) SetRequest(v Conmon_PauseLogRequest) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_AttachRequest struct, preferring placement in s's segment.
-func (s Conmon_attachContainer_Params) NewRequest() (Conmon_AttachRequest, error) {
-	ss, err := NewConmon_AttachRequest(capnp.Struct(s).Segment())
+// allocated Conmon_PauseLogRequest struct, preferring placement in s's segment.
+func (s Conmon_pauseLogContainer_Params) NewRequest() (Conmon_PauseLogRequest, error) {
+	ss, err := NewConmon_PauseLogRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_AttachRequest{}, err
+		return Conmon_PauseLogRequest{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_attachContainer_Params_List is a list of Conmon_attachContainer_Params.
-type Conmon_attachContainer_Params_List = capnp.StructList[Conmon_attachContainer_Params]
+// Conmon_pauseLogContainer_Params_List is a list of Conmon_pauseLogContainer_Params.
+type Conmon_pauseLogContainer_Params_List = capnp.StructList[Conmon_pauseLogContainer_Params]
 
-// NewConmon_attachContainer_Params creates a new list of Conmon_attachContainer_Params.
-func NewConmon_attachContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_attachContainer_Params_List, error) {
+// NewConmon_pauseLogContainer_Params creates a new list of Conmon_pauseLogContainer_Params.
+func NewConmon_pauseLogContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_pauseLogContainer_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_attachContainer_Params](l), err
+	return capnp.StructList[Conmon_pauseLogContainer_Params](l), err
 }
 
-// Conmon_attachContainer_Params_Future is a wrapper for a Conmon_attachContainer_Params promised by a client call.
-type Conmon_attachContainer_Params_Future struct{ *capnp.Future }
+// Conmon_pauseLogContainer_Params_Future is a wrapper for a Conmon_pauseLogContainer_Params promised by a client call.
+type Conmon_pauseLogContainer_Params_Future struct{ *capnp.Future }
 
-func (f Conmon_attachContainer_Params_Future) Struct() (Conmon_attachContainer_Params, error) {
+func (f Conmon_pauseLogContainer_Params_Future) Struct() (Conmon_pauseLogContainer_Params, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_attachContainer_Params(p.Struct()), err
+	return Conmon_pauseLogContainer_Params(p.Struct()), err
 }
-func (p Conmon_attachContainer_Params_Future) Request() Conmon_AttachRequest_Future {
-	return Conmon_AttachRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_pauseLogContainer_Params_Future) Request() Conmon_PauseLogRequest_Future {
+	return Conmon_PauseLogRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_attachContainer_Results capnp.Struct
+type Conmon_pauseLogContainer_Results capnp.Struct
 
-// Conmon_attachContainer_Results_TypeID is the unique identifier for the type Conmon_attachContainer_Results.
-const Conmon_attachContainer_Results_TypeID = 0xc5e65eec3dcf5b10
+// Conmon_pauseLogContainer_Results_TypeID is the unique identifier for the type Conmon_pauseLogContainer_Results.
+const Conmon_pauseLogContainer_Results_TypeID = 0xedd2e5b018f17bbb
 
-func NewConmon_attachContainer_Results(s *capnp.Segment) (Conmon_attachContainer_Results, error) {
+func NewConmon_pauseLogContainer_Results(s *capnp.Segment) (Conmon_pauseLogContainer_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_attachContainer_Results(st), err
+	return Conmon_pauseLogContainer_Results(st), err
 }
 
-func NewRootConmon_attachContainer_Results(s *capnp.Segment) (Conmon_attachContainer_Results, error) {
+func NewRootConmon_pauseLogContainer_Results(s *capnp.Segment) (Conmon_pauseLogContainer_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_attachContainer_Results(st), err
+	return Conmon_pauseLogContainer_Results(st), err
 }
 
-func ReadRootConmon_attachContainer_Results(msg *capnp.Message) (Conmon_attachContainer_Results, error) {
+func ReadRootConmon_pauseLogContainer_Results(msg *capnp.Message) (Conmon_pauseLogContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_attachContainer_Results(root.Struct()), err
+	return Conmon_pauseLogContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_attachContainer_Results) String() string {
-	str, _ := text.Marshal(0xc5e65eec3dcf5b10, capnp.Struct(s))
+func (s Conmon_pauseLogContainer_Results) String() string {
+	str, _ := text.Marshal(0xedd2e5b018f17bbb, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_attachContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_pauseLogContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_attachContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_attachContainer_Results {
-	return Conmon_attachContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_pauseLogContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_pauseLogContainer_Results {
+	return Conmon_pauseLogContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_attachContainer_Results) ToPtr() capnp.Ptr {
+func (s Conmon_pauseLogContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_attachContainer_Results) IsValid() bool {
+func (s Conmon_pauseLogContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_attachContainer_Results) Message() *capnp.Message {
+func (s Conmon_pauseLogContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_attachContainer_Results) Segment() *capnp.Segment {
+func (s Conmon_pauseLogContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_attachContainer_Results) Response() (Conmon_AttachResponse, error) {
+func (s Conmon_pauseLogContainer_Results) Response() (Conmon_PauseLogResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_AttachResponse(p.Struct()), err
+	return Conmon_PauseLogResponse(p.Struct()), err
 }
 
-func (s Conmon_attachContainer_Results) HasResponse() bool {
+func (s Conmon_pauseLogContainer_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_attachContainer_Results) SetResponse(v Conmon_AttachResponse) error {
+func (s Conmon_pauseLogContainer_Results) SetResponse(v Conmon_PauseLogResponse) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_AttachResponse struct, preferring placement in s's segment.
-func (s Conmon_attachContainer_Results) NewResponse() (Conmon_AttachResponse, error) {
-	ss, err := NewConmon_AttachResponse(capnp.Struct(s).Segment())
+// allocated Conmon_PauseLogResponse struct, preferring placement in s's segment.
+func (s Conmon_pauseLogContainer_Results) NewResponse() (Conmon_PauseLogResponse, error) {
+	ss, err := NewConmon_PauseLogResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_AttachResponse{}, err
+		return Conmon_PauseLogResponse{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_attachContainer_Results_List is a list of Conmon_attachContainer_Results.
-type Conmon_attachContainer_Results_List = capnp.StructList[Conmon_attachContainer_Results]
+// Conmon_pauseLogContainer_Results_List is a list of Conmon_pauseLogContainer_Results.
+type Conmon_pauseLogContainer_Results_List = capnp.StructList[Conmon_pauseLogContainer_Results]
 
-// NewConmon_attachContainer_Results creates a new list of Conmon_attachContainer_Results.
-func NewConmon_attachContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_attachContainer_Results_List, error) {
+// NewConmon_pauseLogContainer_Results creates a new list of Conmon_pauseLogContainer_Results.
+func NewConmon_pauseLogContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_pauseLogContainer_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_attachContainer_Results](l), err
+	return capnp.StructList[Conmon_pauseLogContainer_Results](l), err
 }
 
-// Conmon_attachContainer_Results_Future is a wrapper for a Conmon_attachContainer_Results promised by a client call.
-type Conmon_attachContainer_Results_Future struct{ *capnp.Future }
+// Conmon_pauseLogContainer_Results_Future is a wrapper for a Conmon_pauseLogContainer_Results promised by a client call.
+type Conmon_pauseLogContainer_Results_Future struct{ *capnp.Future }
 
-func (f Conmon_attachContainer_Results_Future) Struct() (Conmon_attachContainer_Results, error) {
+func (f Conmon_pauseLogContainer_Results_Future) Struct() (Conmon_pauseLogContainer_Results, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_attachContainer_Results(p.Struct()), err
+	return Conmon_pauseLogContainer_Results(p.Struct()), err
 }
-func (p Conmon_attachContainer_Results_Future) Response() Conmon_AttachResponse_Future {
-	return Conmon_AttachResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_pauseLogContainer_Results_Future) Response() Conmon_PauseLogResponse_Future {
+	return Conmon_PauseLogResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_reopenLogContainer_Params capnp.Struct
+type Conmon_resumeLogContainer_Params capnp.Struct
 
-// Conmon_reopenLogContainer_Params_TypeID is the unique identifier for the type Conmon_reopenLogContainer_Params.
-const Conmon_reopenLogContainer_Params_TypeID = 0xe5ea916eb0c31336
+// Conmon_resumeLogContainer_Params_TypeID is the unique identifier for the type Conmon_resumeLogContainer_Params.
+const Conmon_resumeLogContainer_Params_TypeID = 0x9d82529754851252
 
-func NewConmon_reopenLogContainer_Params(s *capnp.Segment) (Conmon_reopenLogContainer_Params, error) {
+func NewConmon_resumeLogContainer_Params(s *capnp.Segment) (Conmon_resumeLogContainer_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_reopenLogContainer_Params(st), err
+	return Conmon_resumeLogContainer_Params(st), err
 }
 
-func NewRootConmon_reopenLogContainer_Params(s *capnp.Segment) (Conmon_reopenLogContainer_Params, error) {
+func NewRootConmon_resumeLogContainer_Params(s *capnp.Segment) (Conmon_resumeLogContainer_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_reopenLogContainer_Params(st), err
+	return Conmon_resumeLogContainer_Params(st), err
 }
 
-func ReadRootConmon_reopenLogContainer_Params(msg *capnp.Message) (Conmon_reopenLogContainer_Params, error) {
+func ReadRootConmon_resumeLogContainer_Params(msg *capnp.Message) (Conmon_resumeLogContainer_Params, error) {
 	root, err := msg.Root()
-	return Conmon_reopenLogContainer_Params(root.Struct()), err
+	return Conmon_resumeLogContainer_Params(root.Struct()), err
 }
 
-func (s Conmon_reopenLogContainer_Params) String() string {
-	str, _ := text.Marshal(0xe5ea916eb0c31336, capnp.Struct(s))
+func (s Conmon_resumeLogContainer_Params) String() string {
+	str, _ := text.Marshal(0x9d82529754851252, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_reopenLogContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_resumeLogContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_reopenLogContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_reopenLogContainer_Params {
-	return Conmon_reopenLogContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_resumeLogContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_resumeLogContainer_Params {
+	return Conmon_resumeLogContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_reopenLogContainer_Params) ToPtr() capnp.Ptr {
+func (s Conmon_resumeLogContainer_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_reopenLogContainer_Params) IsValid() bool {
+func (s Conmon_resumeLogContainer_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_reopenLogContainer_Params) Message() *capnp.Message {
+func (s Conmon_resumeLogContainer_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_reopenLogContainer_Params) Segment() *capnp.Segment {
+func (s Conmon_resumeLogContainer_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_reopenLogContainer_Params) Request() (Conmon_ReopenLogRequest, error) {
+func (s Conmon_resumeLogContainer_Params) Request() (Conmon_ResumeLogRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_ReopenLogRequest(p.Struct()), err
+	return Conmon_ResumeLogRequest(p.Struct()), err
 }
 
-func (s Conmon_reopenLogContainer_Params) HasRequest() bool {
+func (s Conmon_resumeLogContainer_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_reopenLogContainer_Params) SetRequest(v Conmon_ReopenLogRequest) error {
+func (s Conmon_resumeLogContainer_Params) SetRequest(v Conmon_ResumeLogRequest) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_ReopenLogRequest struct, preferring placement in s's segment.
-func (s Conmon_reopenLogContainer_Params) NewRequest() (Conmon_ReopenLogRequest, error) {
-	ss, err := NewConmon_ReopenLogRequest(capnp.Struct(s).Segment())
+// allocated Conmon_ResumeLogRequest struct, preferring placement in s's segment.
+func (s Conmon_resumeLogContainer_Params) NewRequest() (Conmon_ResumeLogRequest, error) {
+	ss, err := NewConmon_ResumeLogRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_ReopenLogRequest{}, err
+		return Conmon_ResumeLogRequest{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_reopenLogContainer_Params_List is a list of Conmon_reopenLogContainer_Params.
-type Conmon_reopenLogContainer_Params_List = capnp.StructList[Conmon_reopenLogContainer_Params]
+// Conmon_resumeLogContainer_Params_List is a list of Conmon_resumeLogContainer_Params.
+type Conmon_resumeLogContainer_Params_List = capnp.StructList[Conmon_resumeLogContainer_Params]
 
-// NewConmon_reopenLogContainer_Params creates a new list of Conmon_reopenLogContainer_Params.
-func NewConmon_reopenLogContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_reopenLogContainer_Params_List, error) {
+// NewConmon_resumeLogContainer_Params creates a new list of Conmon_resumeLogContainer_Params.
+func NewConmon_resumeLogContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_resumeLogContainer_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_reopenLogContainer_Params](l), err
+	return capnp.StructList[Conmon_resumeLogContainer_Params](l), err
 }
 
-// Conmon_reopenLogContainer_Params_Future is a wrapper for a Conmon_reopenLogContainer_Params promised by a client call.
-type Conmon_reopenLogContainer_Params_Future struct{ *capnp.Future }
+// Conmon_resumeLogContainer_Params_Future is a wrapper for a Conmon_resumeLogContainer_Params promised by a client call.
+type Conmon_resumeLogContainer_Params_Future struct{ *capnp.Future }
 
-func (f Conmon_reopenLogContainer_Params_Future) Struct() (Conmon_reopenLogContainer_Params, error) {
+func (f Conmon_resumeLogContainer_Params_Future) Struct() (Conmon_resumeLogContainer_Params, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_reopenLogContainer_Params(p.Struct()), err
+	return Conmon_resumeLogContainer_Params(p.Struct()), err
 }
-func (p Conmon_reopenLogContainer_Params_Future) Request() Conmon_ReopenLogRequest_Future {
-	return Conmon_ReopenLogRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_resumeLogContainer_Params_Future) Request() Conmon_ResumeLogRequest_Future {
+	return Conmon_ResumeLogRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_reopenLogContainer_Results capnp.Struct
+type Conmon_resumeLogContainer_Results capnp.Struct
 
-// Conmon_reopenLogContainer_Results_TypeID is the unique identifier for the type Conmon_reopenLogContainer_Results.
-const Conmon_reopenLogContainer_Results_TypeID = 0xa0ef8355b64ee985
+// Conmon_resumeLogContainer_Results_TypeID is the unique identifier for the type Conmon_resumeLogContainer_Results.
+const Conmon_resumeLogContainer_Results_TypeID = 0xae5e0ae5001ebdfe
 
-func NewConmon_reopenLogContainer_Results(s *capnp.Segment) (Conmon_reopenLogContainer_Results, error) {
+func NewConmon_resumeLogContainer_Results(s *capnp.Segment) (Conmon_resumeLogContainer_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_reopenLogContainer_Results(st), err
+	return Conmon_resumeLogContainer_Results(st), err
 }
 
-func NewRootConmon_reopenLogContainer_Results(s *capnp.Segment) (Conmon_reopenLogContainer_Results, error) {
+func NewRootConmon_resumeLogContainer_Results(s *capnp.Segment) (Conmon_resumeLogContainer_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_reopenLogContainer_Results(st), err
+	return Conmon_resumeLogContainer_Results(st), err
 }
 
-func ReadRootConmon_reopenLogContainer_Results(msg *capnp.Message) (Conmon_reopenLogContainer_Results, error) {
+func ReadRootConmon_resumeLogContainer_Results(msg *capnp.Message) (Conmon_resumeLogContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_reopenLogContainer_Results(root.Struct()), err
+	return Conmon_resumeLogContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_reopenLogContainer_Results) String() string {
-	str, _ := text.Marshal(0xa0ef8355b64ee985, capnp.Struct(s))
+func (s Conmon_resumeLogContainer_Results) String() string {
+	str, _ := text.Marshal(0xae5e0ae5001ebdfe, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_reopenLogContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_resumeLogContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_reopenLogContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_reopenLogContainer_Results {
-	return Conmon_reopenLogContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_resumeLogContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_resumeLogContainer_Results {
+	return Conmon_resumeLogContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_reopenLogContainer_Results) ToPtr() capnp.Ptr {
+func (s Conmon_resumeLogContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_reopenLogContainer_Results) IsValid() bool {
+func (s Conmon_resumeLogContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_reopenLogContainer_Results) Message() *capnp.Message {
+func (s Conmon_resumeLogContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_reopenLogContainer_Results) Segment() *capnp.Segment {
+func (s Conmon_resumeLogContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_reopenLogContainer_Results) Response() (Conmon_ReopenLogResponse, error) {
+func (s Conmon_resumeLogContainer_Results) Response() (Conmon_ResumeLogResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_ReopenLogResponse(p.Struct()), err
+	return Conmon_ResumeLogResponse(p.Struct()), err
 }
 
-func (s Conmon_reopenLogContainer_Results) HasResponse() bool {
+func (s Conmon_resumeLogContainer_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_reopenLogContainer_Results) SetResponse(v Conmon_ReopenLogResponse) error {
+func (s Conmon_resumeLogContainer_Results) SetResponse(v Conmon_ResumeLogResponse) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_ReopenLogResponse struct, preferring placement in s's segment.
-func (s Conmon_reopenLogContainer_Results) NewResponse() (Conmon_ReopenLogResponse, error) {
-	ss, err := NewConmon_ReopenLogResponse(capnp.Struct(s).Segment())
+// allocated Conmon_ResumeLogResponse struct, preferring placement in s's segment.
+func (s Conmon_resumeLogContainer_Results) NewResponse() (Conmon_ResumeLogResponse, error) {
+	ss, err := NewConmon_ResumeLogResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_ReopenLogResponse{}, err
+		return Conmon_ResumeLogResponse{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_reopenLogContainer_Results_List is a list of Conmon_reopenLogContainer_Results.
-type Conmon_reopenLogContainer_Results_List = capnp.StructList[Conmon_reopenLogContainer_Results]
+// Conmon_resumeLogContainer_Results_List is a list of Conmon_resumeLogContainer_Results.
+type Conmon_resumeLogContainer_Results_List = capnp.StructList[Conmon_resumeLogContainer_Results]
 
-// NewConmon_reopenLogContainer_Results creates a new list of Conmon_reopenLogContainer_Results.
-func NewConmon_reopenLogContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_reopenLogContainer_Results_List, error) {
+// NewConmon_resumeLogContainer_Results creates a new list of Conmon_resumeLogContainer_Results.
+func NewConmon_resumeLogContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_resumeLogContainer_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_reopenLogContainer_Results](l), err
+	return capnp.StructList[Conmon_resumeLogContainer_Results](l), err
 }
 
-// Conmon_reopenLogContainer_Results_Future is a wrapper for a Conmon_reopenLogContainer_Results promised by a client call.
-type Conmon_reopenLogContainer_Results_Future struct{ *capnp.Future }
+// Conmon_resumeLogContainer_Results_Future is a wrapper for a Conmon_resumeLogContainer_Results promised by a client call.
+type Conmon_resumeLogContainer_Results_Future struct{ *capnp.Future }
 
-func (f Conmon_reopenLogContainer_Results_Future) Struct() (Conmon_reopenLogContainer_Results, error) {
+func (f Conmon_resumeLogContainer_Results_Future) Struct() (Conmon_resumeLogContainer_Results, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_reopenLogContainer_Results(p.Struct()), err
+	return Conmon_resumeLogContainer_Results(p.Struct()), err
 }
-func (p Conmon_reopenLogContainer_Results_Future) Response() Conmon_ReopenLogResponse_Future {
-	return Conmon_ReopenLogResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_resumeLogContainer_Results_Future) Response() Conmon_ResumeLogResponse_Future {
+	return Conmon_ResumeLogResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_setWindowSizeContainer_Params capnp.Struct
+type Conmon_addLogDriver_Params capnp.Struct
 
-// Conmon_setWindowSizeContainer_Params_TypeID is the unique identifier for the type Conmon_setWindowSizeContainer_Params.
-const Conmon_setWindowSizeContainer_Params_TypeID = 0xc76ccd4502bb61e7
+// Conmon_addLogDriver_Params_TypeID is the unique identifier for the type Conmon_addLogDriver_Params.
+const Conmon_addLogDriver_Params_TypeID = 0xa6d76ce69f13a816
 
-func NewConmon_setWindowSizeContainer_Params(s *capnp.Segment) (Conmon_setWindowSizeContainer_Params, error) {
+func NewConmon_addLogDriver_Params(s *capnp.Segment) (Conmon_addLogDriver_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_setWindowSizeContainer_Params(st), err
+	return Conmon_addLogDriver_Params(st), err
 }
 
-func NewRootConmon_setWindowSizeContainer_Params(s *capnp.Segment) (Conmon_setWindowSizeContainer_Params, error) {
+func NewRootConmon_addLogDriver_Params(s *capnp.Segment) (Conmon_addLogDriver_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_setWindowSizeContainer_Params(st), err
+	return Conmon_addLogDriver_Params(st), err
 }
 
-func ReadRootConmon_setWindowSizeContainer_Params(msg *capnp.Message) (Conmon_setWindowSizeContainer_Params, error) {
+func ReadRootConmon_addLogDriver_Params(msg *capnp.Message) (Conmon_addLogDriver_Params, error) {
 	root, err := msg.Root()
-	return Conmon_setWindowSizeContainer_Params(root.Struct()), err
+	return Conmon_addLogDriver_Params(root.Struct()), err
 }
 
-func (s Conmon_setWindowSizeContainer_Params) String() string {
-	str, _ := text.Marshal(0xc76ccd4502bb61e7, capnp.Struct(s))
+func (s Conmon_addLogDriver_Params) String() string {
+	str, _ := text.Marshal(0xa6d76ce69f13a816, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_setWindowSizeContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_addLogDriver_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_setWindowSizeContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_setWindowSizeContainer_Params {
-	return Conmon_setWindowSizeContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_addLogDriver_Params) DecodeFromPtr(p capnp.Ptr) Conmon_addLogDriver_Params {
+	return Conmon_addLogDriver_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_setWindowSizeContainer_Params) ToPtr() capnp.Ptr {
+func (s Conmon_addLogDriver_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_setWindowSizeContainer_Params) IsValid() bool {
+func (s Conmon_addLogDriver_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_setWindowSizeContainer_Params) Message() *capnp.Message {
+func (s Conmon_addLogDriver_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_setWindowSizeContainer_Params) Segment() *capnp.Segment {
+func (s Conmon_addLogDriver_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_setWindowSizeContainer_Params) Request() (Conmon_SetWindowSizeRequest, error) {
+func (s Conmon_addLogDriver_Params) Request() (Conmon_AddLogDriverRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_SetWindowSizeRequest(p.Struct()), err
+	return Conmon_AddLogDriverRequest(p.Struct()), err
 }
 
-func (s Conmon_setWindowSizeContainer_Params) HasRequest() bool {
+func (s Conmon_addLogDriver_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_setWindowSizeContainer_Params) SetRequest(v Conmon_SetWindowSizeRequest) error {
+func (s Conmon_addLogDriver_Params) SetRequest(v Conmon_AddLogDriverRequest) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_SetWindowSizeRequest struct, preferring placement in s's segment.
-func (s Conmon_setWindowSizeContainer_Params) NewRequest() (Conmon_SetWindowSizeRequest, error) {
-	ss, err := NewConmon_SetWindowSizeRequest(capnp.Struct(s).Segment())
+// allocated Conmon_AddLogDriverRequest struct, preferring placement in s's segment.
+func (s Conmon_addLogDriver_Params) NewRequest() (Conmon_AddLogDriverRequest, error) {
+	ss, err := NewConmon_AddLogDriverRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_SetWindowSizeRequest{}, err
+		return Conmon_AddLogDriverRequest{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_setWindowSizeContainer_Params_List is a list of Conmon_setWindowSizeContainer_Params.
-type Conmon_setWindowSizeContainer_Params_List = capnp.StructList[Conmon_setWindowSizeContainer_Params]
+// Conmon_addLogDriver_Params_List is a list of Conmon_addLogDriver_Params.
+type Conmon_addLogDriver_Params_List = capnp.StructList[Conmon_addLogDriver_Params]
 
-// NewConmon_setWindowSizeContainer_Params creates a new list of Conmon_setWindowSizeContainer_Params.
-func NewConmon_setWindowSizeContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_setWindowSizeContainer_Params_List, error) {
+// NewConmon_addLogDriver_Params creates a new list of Conmon_addLogDriver_Params.
+func NewConmon_addLogDriver_Params_List(s *capnp.Segment, sz int32) (Conmon_addLogDriver_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_setWindowSizeContainer_Params](l), err
+	return capnp.StructList[Conmon_addLogDriver_Params](l), err
 }
 
-// Conmon_setWindowSizeContainer_Params_Future is a wrapper for a Conmon_setWindowSizeContainer_Params promised by a client call.
-type Conmon_setWindowSizeContainer_Params_Future struct{ *capnp.Future }
+// Conmon_addLogDriver_Params_Future is a wrapper for a Conmon_addLogDriver_Params promised by a client call.
+type Conmon_addLogDriver_Params_Future struct{ *capnp.Future }
 
-func (f Conmon_setWindowSizeContainer_Params_Future) Struct() (Conmon_setWindowSizeContainer_Params, error) {
+func (f Conmon_addLogDriver_Params_Future) Struct() (Conmon_addLogDriver_Params, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_setWindowSizeContainer_Params(p.Struct()), err
+	return Conmon_addLogDriver_Params(p.Struct()), err
 }
-func (p Conmon_setWindowSizeContainer_Params_Future) Request() Conmon_SetWindowSizeRequest_Future {
-	return Conmon_SetWindowSizeRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_addLogDriver_Params_Future) Request() Conmon_AddLogDriverRequest_Future {
+	return Conmon_AddLogDriverRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_setWindowSizeContainer_Results capnp.Struct
+type Conmon_addLogDriver_Results capnp.Struct
 
-// Conmon_setWindowSizeContainer_Results_TypeID is the unique identifier for the type Conmon_setWindowSizeContainer_Results.
-const Conmon_setWindowSizeContainer_Results_TypeID = 0xe00e522611477055
+// Conmon_addLogDriver_Results_TypeID is the unique identifier for the type Conmon_addLogDriver_Results.
+const Conmon_addLogDriver_Results_TypeID = 0xaaa69aebe451afba
 
-func NewConmon_setWindowSizeContainer_Results(s *capnp.Segment) (Conmon_setWindowSizeContainer_Results, error) {
+func NewConmon_addLogDriver_Results(s *capnp.Segment) (Conmon_addLogDriver_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_setWindowSizeContainer_Results(st), err
+	return Conmon_addLogDriver_Results(st), err
 }
 
-func NewRootConmon_setWindowSizeContainer_Results(s *capnp.Segment) (Conmon_setWindowSizeContainer_Results, error) {
+func NewRootConmon_addLogDriver_Results(s *capnp.Segment) (Conmon_addLogDriver_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_setWindowSizeContainer_Results(st), err
+	return Conmon_addLogDriver_Results(st), err
 }
 
-func ReadRootConmon_setWindowSizeContainer_Results(msg *capnp.Message) (Conmon_setWindowSizeContainer_Results, error) {
+func ReadRootConmon_addLogDriver_Results(msg *capnp.Message) (Conmon_addLogDriver_Results, error) {
 	root, err := msg.Root()
-	return Conmon_setWindowSizeContainer_Results(root.Struct()), err
+	return Conmon_addLogDriver_Results(root.Struct()), err
 }
 
-func (s Conmon_setWindowSizeContainer_Results) String() string {
-	str, _ := text.Marshal(0xe00e522611477055, capnp.Struct(s))
+func (s Conmon_addLogDriver_Results) String() string {
+	str, _ := text.Marshal(0xaaa69aebe451afba, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_setWindowSizeContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_addLogDriver_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_setWindowSizeContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_setWindowSizeContainer_Results {
-	return Conmon_setWindowSizeContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_addLogDriver_Results) DecodeFromPtr(p capnp.Ptr) Conmon_addLogDriver_Results {
+	return Conmon_addLogDriver_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_setWindowSizeContainer_Results) ToPtr() capnp.Ptr {
+func (s Conmon_addLogDriver_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_setWindowSizeContainer_Results) IsValid() bool {
+func (s Conmon_addLogDriver_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_setWindowSizeContainer_Results) Message() *capnp.Message {
+func (s Conmon_addLogDriver_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_setWindowSizeContainer_Results) Segment() *capnp.Segment {
+func (s Conmon_addLogDriver_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_setWindowSizeContainer_Results) Response() (Conmon_SetWindowSizeResponse, error) {
+func (s Conmon_addLogDriver_Results) Response() (Conmon_AddLogDriverResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_SetWindowSizeResponse(p.Struct()), err
+	return Conmon_AddLogDriverResponse(p.Struct()), err
 }
 
-func (s Conmon_setWindowSizeContainer_Results) HasResponse() bool {
+func (s Conmon_addLogDriver_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_setWindowSizeContainer_Results) SetResponse(v Conmon_SetWindowSizeResponse) error {
+func (s Conmon_addLogDriver_Results) SetResponse(v Conmon_AddLogDriverResponse) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_SetWindowSizeResponse struct, preferring placement in s's segment.
-func (s Conmon_setWindowSizeContainer_Results) NewResponse() (Conmon_SetWindowSizeResponse, error) {
-	ss, err := NewConmon_SetWindowSizeResponse(capnp.Struct(s).Segment())
+// allocated Conmon_AddLogDriverResponse struct, preferring placement in s's segment.
+func (s Conmon_addLogDriver_Results) NewResponse() (Conmon_AddLogDriverResponse, error) {
+	ss, err := NewConmon_AddLogDriverResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_SetWindowSizeResponse{}, err
+		return Conmon_AddLogDriverResponse{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_setWindowSizeContainer_Results_List is a list of Conmon_setWindowSizeContainer_Results.
-type Conmon_setWindowSizeContainer_Results_List = capnp.StructList[Conmon_setWindowSizeContainer_Results]
+// Conmon_addLogDriver_Results_List is a list of Conmon_addLogDriver_Results.
+type Conmon_addLogDriver_Results_List = capnp.StructList[Conmon_addLogDriver_Results]
 
-// NewConmon_setWindowSizeContainer_Results creates a new list of Conmon_setWindowSizeContainer_Results.
-func NewConmon_setWindowSizeContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_setWindowSizeContainer_Results_List, error) {
+// NewConmon_addLogDriver_Results creates a new list of Conmon_addLogDriver_Results.
+func NewConmon_addLogDriver_Results_List(s *capnp.Segment, sz int32) (Conmon_addLogDriver_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_setWindowSizeContainer_Results](l), err
+	return capnp.StructList[Conmon_addLogDriver_Results](l), err
 }
 
-// Conmon_setWindowSizeContainer_Results_Future is a wrapper for a Conmon_setWindowSizeContainer_Results promised by a client call.
-type Conmon_setWindowSizeContainer_Results_Future struct{ *capnp.Future }
+// Conmon_addLogDriver_Results_Future is a wrapper for a Conmon_addLogDriver_Results promised by a client call.
+type Conmon_addLogDriver_Results_Future struct{ *capnp.Future }
 
-func (f Conmon_setWindowSizeContainer_Results_Future) Struct() (Conmon_setWindowSizeContainer_Results, error) {
+func (f Conmon_addLogDriver_Results_Future) Struct() (Conmon_addLogDriver_Results, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_setWindowSizeContainer_Results(p.Struct()), err
+	return Conmon_addLogDriver_Results(p.Struct()), err
 }
-func (p Conmon_setWindowSizeContainer_Results_Future) Response() Conmon_SetWindowSizeResponse_Future {
-	return Conmon_SetWindowSizeResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_addLogDriver_Results_Future) Response() Conmon_AddLogDriverResponse_Future {
+	return Conmon_AddLogDriverResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_createNamespaces_Params capnp.Struct
+type Conmon_removeLogDriver_Params capnp.Struct
 
-// Conmon_createNamespaces_Params_TypeID is the unique identifier for the type Conmon_createNamespaces_Params.
-const Conmon_createNamespaces_Params_TypeID = 0x8b4c03a0662a38dc
+// Conmon_removeLogDriver_Params_TypeID is the unique identifier for the type Conmon_removeLogDriver_Params.
+const Conmon_removeLogDriver_Params_TypeID = 0xe989fde14d6e82dd
 
-func NewConmon_createNamespaces_Params(s *capnp.Segment) (Conmon_createNamespaces_Params, error) {
+func NewConmon_removeLogDriver_Params(s *capnp.Segment) (Conmon_removeLogDriver_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createNamespaces_Params(st), err
+	return Conmon_removeLogDriver_Params(st), err
 }
 
-func NewRootConmon_createNamespaces_Params(s *capnp.Segment) (Conmon_createNamespaces_Params, error) {
+func NewRootConmon_removeLogDriver_Params(s *capnp.Segment) (Conmon_removeLogDriver_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createNamespaces_Params(st), err
+	return Conmon_removeLogDriver_Params(st), err
 }
 
-func ReadRootConmon_createNamespaces_Params(msg *capnp.Message) (Conmon_createNamespaces_Params, error) {
+func ReadRootConmon_removeLogDriver_Params(msg *capnp.Message) (Conmon_removeLogDriver_Params, error) {
 	root, err := msg.Root()
-	return Conmon_createNamespaces_Params(root.Struct()), err
+	return Conmon_removeLogDriver_Params(root.Struct()), err
 }
 
-func (s Conmon_createNamespaces_Params) String() string {
-	str, _ := text.Marshal(0x8b4c03a0662a38dc, capnp.Struct(s))
+func (s Conmon_removeLogDriver_Params) String() string {
+	str, _ := text.Marshal(0xe989fde14d6e82dd, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_createNamespaces_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_removeLogDriver_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_createNamespaces_Params) DecodeFromPtr(p capnp.Ptr) Conmon_createNamespaces_Params {
-	return Conmon_createNamespaces_Params(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_removeLogDriver_Params) DecodeFromPtr(p capnp.Ptr) Conmon_removeLogDriver_Params {
+	return Conmon_removeLogDriver_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_createNamespaces_Params) ToPtr() capnp.Ptr {
+func (s Conmon_removeLogDriver_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_createNamespaces_Params) IsValid() bool {
+func (s Conmon_removeLogDriver_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_createNamespaces_Params) Message() *capnp.Message {
+func (s Conmon_removeLogDriver_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_createNamespaces_Params) Segment() *capnp.Segment {
+func (s Conmon_removeLogDriver_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_createNamespaces_Params) Request() (Conmon_CreateNamespacesRequest, error) {
+func (s Conmon_removeLogDriver_Params) Request() (Conmon_RemoveLogDriverRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_CreateNamespacesRequest(p.Struct()), err
+	return Conmon_RemoveLogDriverRequest(p.Struct()), err
 }
 
-func (s Conmon_createNamespaces_Params) HasRequest() bool {
+func (s Conmon_removeLogDriver_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_createNamespaces_Params) SetRequest(v Conmon_CreateNamespacesRequest) error {
+func (s Conmon_removeLogDriver_Params) SetRequest(v Conmon_RemoveLogDriverRequest) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_CreateNamespacesRequest struct, preferring placement in s's segment.
-func (s Conmon_createNamespaces_Params) NewRequest() (Conmon_CreateNamespacesRequest, error) {
-	ss, err := NewConmon_CreateNamespacesRequest(capnp.Struct(s).Segment())
+// allocated Conmon_RemoveLogDriverRequest struct, preferring placement in s's segment.
+func (s Conmon_removeLogDriver_Params) NewRequest() (Conmon_RemoveLogDriverRequest, error) {
+	ss, err := NewConmon_RemoveLogDriverRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_CreateNamespacesRequest{}, err
+		return Conmon_RemoveLogDriverRequest{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_createNamespaces_Params_List is a list of Conmon_createNamespaces_Params.
-type Conmon_createNamespaces_Params_List = capnp.StructList[Conmon_createNamespaces_Params]
+// Conmon_removeLogDriver_Params_List is a list of Conmon_removeLogDriver_Params.
+type Conmon_removeLogDriver_Params_List = capnp.StructList[Conmon_removeLogDriver_Params]
 
-// NewConmon_createNamespaces_Params creates a new list of Conmon_createNamespaces_Params.
-func NewConmon_createNamespaces_Params_List(s *capnp.Segment, sz int32) (Conmon_createNamespaces_Params_List, error) {
+// NewConmon_removeLogDriver_Params creates a new list of Conmon_removeLogDriver_Params.
+func NewConmon_removeLogDriver_Params_List(s *capnp.Segment, sz int32) (Conmon_removeLogDriver_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_createNamespaces_Params](l), err
+	return capnp.StructList[Conmon_removeLogDriver_Params](l), err
 }
 
-// Conmon_createNamespaces_Params_Future is a wrapper for a Conmon_createNamespaces_Params promised by a client call.
-type Conmon_createNamespaces_Params_Future struct{ *capnp.Future }
+// Conmon_removeLogDriver_Params_Future is a wrapper for a Conmon_removeLogDriver_Params promised by a client call.
+type Conmon_removeLogDriver_Params_Future struct{ *capnp.Future }
 
-func (f Conmon_createNamespaces_Params_Future) Struct() (Conmon_createNamespaces_Params, error) {
+func (f Conmon_removeLogDriver_Params_Future) Struct() (Conmon_removeLogDriver_Params, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_createNamespaces_Params(p.Struct()), err
+	return Conmon_removeLogDriver_Params(p.Struct()), err
 }
-func (p Conmon_createNamespaces_Params_Future) Request() Conmon_CreateNamespacesRequest_Future {
-	return Conmon_CreateNamespacesRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_removeLogDriver_Params_Future) Request() Conmon_RemoveLogDriverRequest_Future {
+	return Conmon_RemoveLogDriverRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_createNamespaces_Results capnp.Struct
+type Conmon_removeLogDriver_Results capnp.Struct
 
-// Conmon_createNamespaces_Results_TypeID is the unique identifier for the type Conmon_createNamespaces_Results.
-const Conmon_createNamespaces_Results_TypeID = 0x8aef91973dc8a4f5
+// Conmon_removeLogDriver_Results_TypeID is the unique identifier for the type Conmon_removeLogDriver_Results.
+const Conmon_removeLogDriver_Results_TypeID = 0x9488d71c49c86c29
 
-func NewConmon_createNamespaces_Results(s *capnp.Segment) (Conmon_createNamespaces_Results, error) {
+func NewConmon_removeLogDriver_Results(s *capnp.Segment) (Conmon_removeLogDriver_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createNamespaces_Results(st), err
+	return Conmon_removeLogDriver_Results(st), err
 }
 
-func NewRootConmon_createNamespaces_Results(s *capnp.Segment) (Conmon_createNamespaces_Results, error) {
+func NewRootConmon_removeLogDriver_Results(s *capnp.Segment) (Conmon_removeLogDriver_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_createNamespaces_Results(st), err
+	return Conmon_removeLogDriver_Results(st), err
 }
 
-func ReadRootConmon_createNamespaces_Results(msg *capnp.Message) (Conmon_createNamespaces_Results, error) {
+func ReadRootConmon_removeLogDriver_Results(msg *capnp.Message) (Conmon_removeLogDriver_Results, error) {
 	root, err := msg.Root()
-	return Conmon_createNamespaces_Results(root.Struct()), err
+	return Conmon_removeLogDriver_Results(root.Struct()), err
 }
 
-func (s Conmon_createNamespaces_Results) String() string {
-	str, _ := text.Marshal(0x8aef91973dc8a4f5, capnp.Struct(s))
+func (s Conmon_removeLogDriver_Results) String() string {
+	str, _ := text.Marshal(0x9488d71c49c86c29, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_createNamespaces_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_removeLogDriver_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_createNamespaces_Results) DecodeFromPtr(p capnp.Ptr) Conmon_createNamespaces_Results {
-	return Conmon_createNamespaces_Results(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_removeLogDriver_Results) DecodeFromPtr(p capnp.Ptr) Conmon_removeLogDriver_Results {
+	return Conmon_removeLogDriver_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_createNamespaces_Results) ToPtr() capnp.Ptr {
+func (s Conmon_removeLogDriver_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_createNamespaces_Results) IsValid() bool {
+func (s Conmon_removeLogDriver_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_createNamespaces_Results) Message() *capnp.Message {
+func (s Conmon_removeLogDriver_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_createNamespaces_Results) Segment() *capnp.Segment {
+func (s Conmon_removeLogDriver_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_createNamespaces_Results) Response() (Conmon_CreateNamespacesResponse, error) {
+func (s Conmon_removeLogDriver_Results) Response() (Conmon_RemoveLogDriverResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_CreateNamespacesResponse(p.Struct()), err
+	return Conmon_RemoveLogDriverResponse(p.Struct()), err
 }
 
-func (s Conmon_createNamespaces_Results) HasResponse() bool {
+func (s Conmon_removeLogDriver_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_createNamespaces_Results) SetResponse(v Conmon_CreateNamespacesResponse) error {
+func (s Conmon_removeLogDriver_Results) SetResponse(v Conmon_RemoveLogDriverResponse) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_CreateNamespacesResponse struct, preferring placement in s's segment.
-func (s Conmon_createNamespaces_Results) NewResponse() (Conmon_CreateNamespacesResponse, error) {
-	ss, err := NewConmon_CreateNamespacesResponse(capnp.Struct(s).Segment())
+// allocated Conmon_RemoveLogDriverResponse struct, preferring placement in s's segment.
+func (s Conmon_removeLogDriver_Results) NewResponse() (Conmon_RemoveLogDriverResponse, error) {
+	ss, err := NewConmon_RemoveLogDriverResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_CreateNamespacesResponse{}, err
+		return Conmon_RemoveLogDriverResponse{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_createNamespaces_Results_List is a list of Conmon_createNamespaces_Results.
-type Conmon_createNamespaces_Results_List = capnp.StructList[Conmon_createNamespaces_Results]
+// Conmon_removeLogDriver_Results_List is a list of Conmon_removeLogDriver_Results.
+type Conmon_removeLogDriver_Results_List = capnp.StructList[Conmon_removeLogDriver_Results]
 
-// NewConmon_createNamespaces_Results creates a new list of Conmon_createNamespaces_Results.
-func NewConmon_createNamespaces_Results_List(s *capnp.Segment, sz int32) (Conmon_createNamespaces_Results_List, error) {
+// NewConmon_removeLogDriver_Results creates a new list of Conmon_removeLogDriver_Results.
+func NewConmon_removeLogDriver_Results_List(s *capnp.Segment, sz int32) (Conmon_removeLogDriver_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_createNamespaces_Results](l), err
+	return capnp.StructList[Conmon_removeLogDriver_Results](l), err
 }
 
-// Conmon_createNamespaces_Results_Future is a wrapper for a Conmon_createNamespaces_Results promised by a client call.
-type Conmon_createNamespaces_Results_Future struct{ *capnp.Future }
+// Conmon_removeLogDriver_Results_Future is a wrapper for a Conmon_removeLogDriver_Results promised by a client call.
+type Conmon_removeLogDriver_Results_Future struct{ *capnp.Future }
 
-func (f Conmon_createNamespaces_Results_Future) Struct() (Conmon_createNamespaces_Results, error) {
+func (f Conmon_removeLogDriver_Results_Future) Struct() (Conmon_removeLogDriver_Results, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_createNamespaces_Results(p.Struct()), err
+	return Conmon_removeLogDriver_Results(p.Struct()), err
 }
-func (p Conmon_createNamespaces_Results_Future) Response() Conmon_CreateNamespacesResponse_Future {
-	return Conmon_CreateNamespacesResponse_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_removeLogDriver_Results_Future) Response() Conmon_RemoveLogDriverResponse_Future {
+	return Conmon_RemoveLogDriverResponse_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_startFdSocket_Params capnp.Struct
+type Conmon_followLogContainer_Params capnp.Struct
 
-// Conmon_startFdSocket_Params_TypeID is the unique identifier for the type Conmon_startFdSocket_Params.
-const Conmon_startFdSocket_Params_TypeID = 0xce733f0914c80b6b
+// Conmon_followLogContainer_Params_TypeID is the unique identifier for the type Conmon_followLogContainer_Params.
+const Conmon_followLogContainer_Params_TypeID = 0xad5e6e3b177fdffd
 
-func NewConmon_startFdSocket_Params(s *capnp.Segment) (Conmon_startFdSocket_Params, error) {
+func NewConmon_followLogContainer_Params(s *capnp.Segment) (Conmon_followLogContainer_Params, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_startFdSocket_Params(st), err
+	return Conmon_followLogContainer_Params(st), err
 }
 
-func NewRootConmon_startFdSocket_Params(s *capnp.Segment) (Conmon_startFdSocket_Params, error) {
+func NewRootConmon_followLogContainer_Params(s *capnp.Segment) (Conmon_followLogContainer_Params, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_startFdSocket_Params(st), err
+	return Conmon_followLogContainer_Params(st), err
 }
 
-func ReadRootConmon_startFdSocket_Params(msg *capnp.Message) (Conmon_startFdSocket_Params, error) {
+func ReadRootConmon_followLogContainer_Params(msg *capnp.Message) (Conmon_followLogContainer_Params, error) {
 	root, err := msg.Root()
-	return Conmon_startFdSocket_Params(root.Struct()), err
+	return Conmon_followLogContainer_Params(root.Struct()), err
 }
 
-func (s Conmon_startFdSocket_Params) String() string {
-	str, _ := text.Marshal(0xce733f0914c80b6b, capnp.Struct(s))
+func (s Conmon_followLogContainer_Params) String() string {
+	str, _ := text.Marshal(0xad5e6e3b177fdffd, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_startFdSocket_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_followLogContainer_Params) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_startFdSocket_Params) DecodeFromPtr(p capnp.Ptr) Conmon_startFdSocket_Params {
-	return Conmon_startFdSocket_Params(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_followLogContainer_Params) DecodeFromPtr(p capnp.Ptr) Conmon_followLogContainer_Params {
+	return Conmon_followLogContainer_Params(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_startFdSocket_Params) ToPtr() capnp.Ptr {
+func (s Conmon_followLogContainer_Params) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_startFdSocket_Params) IsValid() bool {
+func (s Conmon_followLogContainer_Params) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_startFdSocket_Params) Message() *capnp.Message {
+func (s Conmon_followLogContainer_Params) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_startFdSocket_Params) Segment() *capnp.Segment {
+func (s Conmon_followLogContainer_Params) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_startFdSocket_Params) Request() (Conmon_StartFdSocketRequest, error) {
+func (s Conmon_followLogContainer_Params) Request() (Conmon_FollowLogRequest, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_StartFdSocketRequest(p.Struct()), err
+	return Conmon_FollowLogRequest(p.Struct()), err
 }
 
-func (s Conmon_startFdSocket_Params) HasRequest() bool {
+func (s Conmon_followLogContainer_Params) HasRequest() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_startFdSocket_Params) SetRequest(v Conmon_StartFdSocketRequest) error {
+func (s Conmon_followLogContainer_Params) SetRequest(v Conmon_FollowLogRequest) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewRequest sets the request field to a newly
-// allocated Conmon_StartFdSocketRequest struct, preferring placement in s's segment.
-func (s Conmon_startFdSocket_Params) NewRequest() (Conmon_StartFdSocketRequest, error) {
-	ss, err := NewConmon_StartFdSocketRequest(capnp.Struct(s).Segment())
+// allocated Conmon_FollowLogRequest struct, preferring placement in s's segment.
+func (s Conmon_followLogContainer_Params) NewRequest() (Conmon_FollowLogRequest, error) {
+	ss, err := NewConmon_FollowLogRequest(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_StartFdSocketRequest{}, err
+		return Conmon_FollowLogRequest{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_startFdSocket_Params_List is a list of Conmon_startFdSocket_Params.
-type Conmon_startFdSocket_Params_List = capnp.StructList[Conmon_startFdSocket_Params]
+// Conmon_followLogContainer_Params_List is a list of Conmon_followLogContainer_Params.
+type Conmon_followLogContainer_Params_List = capnp.StructList[Conmon_followLogContainer_Params]
 
-// NewConmon_startFdSocket_Params creates a new list of Conmon_startFdSocket_Params.
-func NewConmon_startFdSocket_Params_List(s *capnp.Segment, sz int32) (Conmon_startFdSocket_Params_List, error) {
+// NewConmon_followLogContainer_Params creates a new list of Conmon_followLogContainer_Params.
+func NewConmon_followLogContainer_Params_List(s *capnp.Segment, sz int32) (Conmon_followLogContainer_Params_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_startFdSocket_Params](l), err
+	return capnp.StructList[Conmon_followLogContainer_Params](l), err
 }
 
-// Conmon_startFdSocket_Params_Future is a wrapper for a Conmon_startFdSocket_Params promised by a client call.
-type Conmon_startFdSocket_Params_Future struct{ *capnp.Future }
+// Conmon_followLogContainer_Params_Future is a wrapper for a Conmon_followLogContainer_Params promised by a client call.
+type Conmon_followLogContainer_Params_Future struct{ *capnp.Future }
 
-func (f Conmon_startFdSocket_Params_Future) Struct() (Conmon_startFdSocket_Params, error) {
+func (f Conmon_followLogContainer_Params_Future) Struct() (Conmon_followLogContainer_Params, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_startFdSocket_Params(p.Struct()), err
+	return Conmon_followLogContainer_Params(p.Struct()), err
 }
-func (p Conmon_startFdSocket_Params_Future) Request() Conmon_StartFdSocketRequest_Future {
-	return Conmon_StartFdSocketRequest_Future{Future: p.Future.Field(0, nil)}
+func (p Conmon_followLogContainer_Params_Future) Request() Conmon_FollowLogRequest_Future {
+	return Conmon_FollowLogRequest_Future{Future: p.Future.Field(0, nil)}
 }
 
-type Conmon_startFdSocket_Results capnp.Struct
+type Conmon_followLogContainer_Results capnp.Struct
 
-// Conmon_startFdSocket_Results_TypeID is the unique identifier for the type Conmon_startFdSocket_Results.
-const Conmon_startFdSocket_Results_TypeID = 0xf4e3e92ae0815f15
+// Conmon_followLogContainer_Results_TypeID is the unique identifier for the type Conmon_followLogContainer_Results.
+const Conmon_followLogContainer_Results_TypeID = 0xc9701dd28ecc4dec
 
-func NewConmon_startFdSocket_Results(s *capnp.Segment) (Conmon_startFdSocket_Results, error) {
+func NewConmon_followLogContainer_Results(s *capnp.Segment) (Conmon_followLogContainer_Results, error) {
 	st, err := capnp.NewStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_startFdSocket_Results(st), err
+	return Conmon_followLogContainer_Results(st), err
 }
 
-func NewRootConmon_startFdSocket_Results(s *capnp.Segment) (Conmon_startFdSocket_Results, error) {
+func NewRootConmon_followLogContainer_Results(s *capnp.Segment) (Conmon_followLogContainer_Results, error) {
 	st, err := capnp.NewRootStruct(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1})
-	return Conmon_startFdSocket_Results(st), err
+	return Conmon_followLogContainer_Results(st), err
 }
 
-func ReadRootConmon_startFdSocket_Results(msg *capnp.Message) (Conmon_startFdSocket_Results, error) {
+func ReadRootConmon_followLogContainer_Results(msg *capnp.Message) (Conmon_followLogContainer_Results, error) {
 	root, err := msg.Root()
-	return Conmon_startFdSocket_Results(root.Struct()), err
+	return Conmon_followLogContainer_Results(root.Struct()), err
 }
 
-func (s Conmon_startFdSocket_Results) String() string {
-	str, _ := text.Marshal(0xf4e3e92ae0815f15, capnp.Struct(s))
+func (s Conmon_followLogContainer_Results) String() string {
+	str, _ := text.Marshal(0xc9701dd28ecc4dec, capnp.Struct(s))
 	return str
 }
 
-func (s Conmon_startFdSocket_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
+func (s Conmon_followLogContainer_Results) EncodeAsPtr(seg *capnp.Segment) capnp.Ptr {
 	return capnp.Struct(s).EncodeAsPtr(seg)
 }
 
-func (Conmon_startFdSocket_Results) DecodeFromPtr(p capnp.Ptr) Conmon_startFdSocket_Results {
-	return Conmon_startFdSocket_Results(capnp.Struct{}.DecodeFromPtr(p))
+func (Conmon_followLogContainer_Results) DecodeFromPtr(p capnp.Ptr) Conmon_followLogContainer_Results {
+	return Conmon_followLogContainer_Results(capnp.Struct{}.DecodeFromPtr(p))
 }
 
-func (s Conmon_startFdSocket_Results) ToPtr() capnp.Ptr {
+func (s Conmon_followLogContainer_Results) ToPtr() capnp.Ptr {
 	return capnp.Struct(s).ToPtr()
 }
-func (s Conmon_startFdSocket_Results) IsValid() bool {
+func (s Conmon_followLogContainer_Results) IsValid() bool {
 	return capnp.Struct(s).IsValid()
 }
 
-func (s Conmon_startFdSocket_Results) Message() *capnp.Message {
+func (s Conmon_followLogContainer_Results) Message() *capnp.Message {
 	return capnp.Struct(s).Message()
 }
 
-func (s Conmon_startFdSocket_Results) Segment() *capnp.Segment {
+func (s Conmon_followLogContainer_Results) Segment() *capnp.Segment {
 	return capnp.Struct(s).Segment()
 }
-func (s Conmon_startFdSocket_Results) Response() (Conmon_StartFdSocketResponse, error) {
+func (s Conmon_followLogContainer_Results) Response() (Conmon_FollowLogResponse, error) {
 	p, err := capnp.Struct(s).Ptr(0)
-	return Conmon_StartFdSocketResponse(p.Struct()), err
+	return Conmon_FollowLogResponse(p.Struct()), err
 }
 
-func (s Conmon_startFdSocket_Results) HasResponse() bool {
+func (s Conmon_followLogContainer_Results) HasResponse() bool {
 	return capnp.Struct(s).HasPtr(0)
 }
 
-func (s Conmon_startFdSocket_Results) SetResponse(v Conmon_StartFdSocketResponse) error {
+func (s Conmon_followLogContainer_Results) SetResponse(v Conmon_FollowLogResponse) error {
 	return capnp.Struct(s).SetPtr(0, capnp.Struct(v).ToPtr())
 }
 
 // NewResponse sets the response field to a newly
-// allocated Conmon_StartFdSocketResponse struct, preferring placement in s's segment.
-func (s Conmon_startFdSocket_Results) NewResponse() (Conmon_StartFdSocketResponse, error) {
-	ss, err := NewConmon_StartFdSocketResponse(capnp.Struct(s).Segment())
+// allocated Conmon_FollowLogResponse struct, preferring placement in s's segment.
+func (s Conmon_followLogContainer_Results) NewResponse() (Conmon_FollowLogResponse, error) {
+	ss, err := NewConmon_FollowLogResponse(capnp.Struct(s).Segment())
 	if err != nil {
-		return Conmon_StartFdSocketResponse{}, err
+		return Conmon_FollowLogResponse{}, err
 	}
 	err = capnp.Struct(s).SetPtr(0, capnp.Struct(ss).ToPtr())
 	return ss, err
 }
 
-// Conmon_startFdSocket_Results_List is a list of Conmon_startFdSocket_Results.
-type Conmon_startFdSocket_Results_List = capnp.StructList[Conmon_startFdSocket_Results]
+// Conmon_followLogContainer_Results_List is a list of Conmon_followLogContainer_Results.
+type Conmon_followLogContainer_Results_List = capnp.StructList[Conmon_followLogContainer_Results]
 
-// NewConmon_startFdSocket_Results creates a new list of Conmon_startFdSocket_Results.
-func NewConmon_startFdSocket_Results_List(s *capnp.Segment, sz int32) (Conmon_startFdSocket_Results_List, error) {
+// NewConmon_followLogContainer_Results creates a new list of Conmon_followLogContainer_Results.
+func NewConmon_followLogContainer_Results_List(s *capnp.Segment, sz int32) (Conmon_followLogContainer_Results_List, error) {
 	l, err := capnp.NewCompositeList(s, capnp.ObjectSize{DataSize: 0, PointerCount: 1}, sz)
-	return capnp.StructList[Conmon_startFdSocket_Results](l), err
+	return capnp.StructList[Conmon_followLogContainer_Results](l), err
 }
 
-// Conmon_startFdSocket_Results_Future is a wrapper for a Conmon_startFdSocket_Results promised by a client call.
-type Conmon_startFdSocket_Results_Future struct{ *capnp.Future }
+// Conmon_followLogContainer_Results_Future is a wrapper for a Conmon_followLogContainer_Results promised by a client call.
+type Conmon_followLogContainer_Results_Future struct{ *capnp.Future }
 
-func (f Conmon_startFdSocket_Results_Future) Struct() (Conmon_startFdSocket_Results, error) {
+func (f Conmon_followLogContainer_Results_Future) Struct() (Conmon_followLogContainer_Results, error) {
 	p, err := f.Future.Ptr()
-	return Conmon_startFdSocket_Results(p.Struct()), err
-}
-func (p Conmon_startFdSocket_Results_Future) Response() Conmon_StartFdSocketResponse_Future {
-	return Conmon_StartFdSocketResponse_Future{Future: p.Future.Field(0, nil)}
-}
-
-const schema_ffaaf7385bc4adad = "x\xda\xc4Y}p\x14\xe7y\x7f\x9e\xdd\x93V\xa7\x0f" +
-	"N\xdb=\x09\x10\xa8\xd2\x10HA\x0d\xe1C\xb8&\x0c" +
-	"\x19I\x80B!\xe0hu`ZH\\\x96\xbbE:" +
-	"\xb8\xdb=v\xf7\x00\x11\xa72I=v\x94\xd81*" +
-	"\x9e\xd8L\x99A\xb6\xa1\x80\xa1\xc6\x1fP\x83\xc1\x03\x18" +
-	"\xc6\x80M]h\xa1\xc6c\\0\xa6\xb6\x19\x7fQ\xbb" +
-	"S\xec\x81n\xe7y\xef\xf6C'\x19\x9fTw\xf2\x07" +
-	"\x83\xee\xd9\xdf>\xef\xc7\xf3\xf9{v\xe2\xe7\xc1\xc6\xc0" +
-	"\xa4\xb27\x86\x00'\xef*(\xb4\xcd\xcb\x1d\xc6\xb6\xcd" +
-	"\xb3\x7f\x05\xe2w\x10\xa0\x00\x05\x80\xfa\xf9\xc2\xbb\x08(" +
-	")B\x03\xa0\xfd_O\x9d\xf8\xe1\xef7|\xda\xe5\x07" +
-	"\xac\xcf\x00\x1ee\x80\xb7\xa7\xd6-\xdf\xc2\xcf\xfb\x8d\x1f" +
-	"\xb0_x\x8b\x00\xa7\x19\xe0\xaf\x97\x866\xfem\xe5\x12" +
-	"\x06\xb0\xaf\xd7/\xbf\xf8\xf8\x07w\xfe#\x14\x08\x04\xfc" +
-	"Xx\x0b\xa5`\x11\xfdYP\xf4;\x04\xb4_\xfb\xe3" +
-	"u\x8bB\xdb\x1ex,\x07\xcd\xd4^\x0d\xbe\x8b\x12\x16" +
-	"\x0b\x00\xd2\xad \xa9\xbe\xff\xc3\xbb\xf6-\xfc\xd5\xa7[" +
-	"\xfckO*\xfeOZ\xbb\xb9\x98\x00\x8f/\xf9`e" +
-	"\xf3\x9c\xd0\x13\xbd\xb5\x05\x08\x17/\xde\x89\xd2\xfab\x01" +
-	"x\xbb\xf2\xab+O\x9d\x8fL\xdd\x01\xf2w\xb0\xcf\xa2" +
-	"?#\\\x9a-\xba\xaax\x0d\xa0=\xfa\x99W\xcet" +
-	"M\x9f\xb0\xd3\xbf\xe8\xe9\xe2\xb3\xb4\xe8e\xb6h\xd7\xdf" +
-	"\xab\x7fr\xe4\xc0\x8f\x09\xc0y\xda\x00\xeb\xb1\xa4\x0b\xa5" +
-	"\xe1%\xa4\xaa\xa2\xe4N@{\xcd\xd2\x13\xcf\xac\x93\xaf" +
-	"\xee\xeag{\xd5%\xdd(\xddQB\xdb\x93fM\xdc" +
-	"\x7f\xbe\xbenw\xee\xf68\xc2\x89\x84\x1b\xc7t\x8e)" +
-	"y\x06\xd0\x9e\xd2\xf3\xfc\xbe\x87?Y\xfb\x0f\x84\xe6r" +
-	"\x0fs\xaed\x05J\x1f\x96\x0c\x05\x90\xae3\xf4\xcf\xcf" +
-	"\\\xdb\xfe\xf0o\x9a\xf6\xe6\xea\xe6\x09\xfd\xeb\xd2\xa3(" +
-	"\xf5\x94\xd2\x9f\x9bKk\xc8:\xfc\xa5\xf7\x8a\x1fn\x9a" +
-	"\xb0\xaf?\xeb\x1c/;\x85\xd2\xe52\xda\xc9\xc52\xba" +
-	"\x07\xf7\xb98\x92\xb7w\xef>\xb6d\xea\x7f\xef\xb4\xe9" +
-	"\x1en\x95Ua}\xd9\x90EH\x8aC\x02'\x05E" +
-	"\x01\xc0\x1e\xf7\xd1\x83\xdb\x1ex:r\xa0?\xe5\xd7\xcb" +
-	"\x8fb\x06&\x15\x88\xa4\xfc\xd4\xbe\x1d\xd3\xbe\xba\xb2\xe6" +
-	"@\xee\xc6i\xfd\xfa\x1f\x88gQZH\xe8zY|" +
-	"\x80\x07\xb4\xcb\x97\xbc\xf1\xc3\x8f\xee\xf9\x8f\xe3~\xa3\x1d" +
-	"\xaf`^z\xa1\x82\xf4\xbd\xaf\xbc\xc45\x9fN\xbc\xea" +
-	"\x07\xdc\xaa(\xe6\x00\xa5\x8aJ\x06x\xef\x7fV\xb4\xa5" +
-	"&\xbc\xee\x07\xdcQ\xd9\xcd|\x8d\x01V\x96\x9c\x08\x07" +
-	"\x1b\xcc\x7f\xf2\x03\xd4\xca\xa3\x04H3\xc0\x8d\x8a\x97\x7f" +
-	"_5\xfd@/\xc0\xa3\x95l\x0f;\x18\xa0\xaa\xe9\xcc" +
-	"\x94\x906\xfb\x9fs.\x80\xd9\xe2t\xe5\x13(]\xad" +
-	"\xa4\x0b\xb8\\I\x96{\xf2\xf3\xedK\xf7n\x08\x9f\xef" +
-	"\xe3e\x1b\x86\xae@i\xebPB\xf6\x0c\xed\x04\xb4o" +
-	"\xde?\xfd\xbe\xea\xea\xf3\x17\xfa\xf5\x9fsC\xaf\xa1t" +
-	"\x9d\xa1?\x1e\xfa>\xa0\xbd\xe9O\xd7\xa4\xeeY6\xed" +
-	"\x9d\x1c4s\xca\x93\xc3\xdeB\xe9\xea0\xb6\x89a\xb4" +
-	"\xe3\x9b\xd3n\xbe\xbcez\xea\xdfsU\x17\xb0\x88\x1e" +
-	"\xde\x85R\xf5p\xfas\xf8\xf0E\xe4>\x0bS\xb3\xc5" +
-	"\xef\xb6\x0e\xb9\xe4\xbf\x81\x87\xaa\xfe\x88.yk\x15\xe9" +
-	"\x9b\xf8\xf3\xd9;\xee\x89KW\xfc\x80\x93U\x9b\x98\x99" +
-	"\x18\xe0\xcf\xa4W\xf6h\x1b\xae]\xede\xa6\xaak\x04" +
-	"\x10G\x10\xe0\xc8\x92\xfa\x96\x7f\xbb\xf2\xdd\xcf@\x1c\xcf" +
-	"y\xb1\x00X?iD7JsF\xd0\xde\x9bGP" +
-	"\xf0\x9d\xf9\xa4f\xd7kW\x7f\xfcy\xee\xde\x83\xa4s" +
-	"\xe1\x88M(%G\xb0D1\xe2N\x0e\xd0\xde\xb6\xea" +
-	"\xc9Gn\x8c\x12\xbf\xc8\x8d+v\xd4C\xd5\xef\xa2t" +
-	"\xa1\x9a]h\xf5\xabt\xd4\x177m\xfc\xdd\xb1\xc9\xb3" +
-	"\xbf\xf0o\xf4b\x0d\xcb\x12\xd7kh\xa3\x15\x7f\xb5\xfe" +
-	"R\xdd\x87Wz\x01\xc4\xdaS\x04\x18SK\x80\x83\xb8" +
-	"\xb3\xe4\xa7+>\xb8\xe1\x07\xcc\xa9eG\xfd\x19\x03\xdc" +
-	"\xe8y\xba\xfe\xbe\xd3\xcf\x7f\xd9O\xf6X_{\x0a\xa5" +
-	"\xcd\xb5\x94=\xba\xffen\xf2\x9d[/}\x95\xe3T" +
-	"\xcc\xf8\x1d\xb5O\xa0\xb4\xa1\x96\xee\xe4\xa1\xda50\xde" +
-	"\x8ek\x96jhJ\xa2pB\xca\xd0-}BT\xd7" +
-	"\x92\xba\xf6\xfd\xa8\x92\xd2R\xd3ff~\xa8k\xd5h" +
-	"\xa4C\x8b\xce\xd45K\x89k\xaa1\xbaE1\x04%" +
-	"i\xca\x01>\x00\x10@\x00\xb1l\x06\x80\\\xc4\xa3\x1c" +
-	"\xe6\xb0\xd3PW\xa5U\xd3\xc2r\xef\x0e\x01\xb1\x1c0" +
-	"\xaf\xe5\xa2\x86\xaaX\xea]JR5SJT5G" +
-	"\xb7\xaafZHX\xbd\x96\x9b\x0b \x97\xf2(\x0f\xe3" +
-	"\xd06T3\xa5k\xa6\x0a\x00X\xee\xd5\x93\xff\xcb\x92" +
-	"-\x8a\xa1\xf0\xf9\x1c\xd0\xadu\x03Xmf\xcej\xad" +
-	"\xa4\x8d7\xad\x16Dy\xa4\xbb\xe0\xdee\x00\xf2\x0b<" +
-	"\xca\x879\x14\x11\xc3H\xc2C\x8b\x01\xe4\x83<\xcao" +
-	"r(r\\\x189\x00\xf1\x1c!\xff\x95G\xf93\x0e" +
-	"E\x9e\x0f#\x0f ~L\xc2\x8fx\x8c\x14!\x87b" +
-	" \x10\xc6\x00\xa5R\x9c\x0b\x10\x09 \x8f\x91r\x92\x17" +
-	"\x14\x84\xb1\x00@*\xc3\xc9\x00\x91\"\x92\x87I^X" +
-	"\x18\xc6B\x00Id\xf8r\x92\x7f\x0f9\xb4\x93\xaa\xa5" +
-	"\xc4\x14K\x01\xe1'\x89\x18\x96\x01\x87e\x80\xb6\x96=" +
-	"\x0a\xf0\xaa\x89C\x00[x\xc4\x90\x97\xaf\x00Ih\xa7" +
-	"\xe3\xb1\xf9J*\x15\x07Aksa\xa5\xc0\xb1\x87m" +
-	"\xb7{\xb8L1\xd5\x16\xc5j'\x03\x93\xac\x14\xb0&" +
-	"\xa5\xc7\xe6\xc4\x9c_\xde\xbe\x00\x9c\x97\xcb\xbd@\xc8n" +
-	"`p\xb61S\xba\xa0\x99*\x19\xc7\xe7\x0d\x8b\xb3\xfe" +
-	"7\x96\xeb\xff\xf8\xe5^\x8f1\x80\xd5\x0dUO\xa9\xda" +
-	"<\xbd\xcd\x0b\xb5V\xb5\xc6L\xe7\xed\xfcn\xfb\x93\xe3" +
-	"\x8e\x05\xb7Y\xb4\xd5Y\x94\xce\x1a\xd23g\xcd\xebM" +
-	"\xf7\x9a\xfco\xcaE\xeeF\xc7\xd5\x01\xc8\xa3y\x94'" +
-	"r\xe8x\xf0x\x92\x8d\xe5Q\x9e\xc2a\xc8\xeaH\xa9" +
-	"9\x9e\x12\x02\x0c\xa5\x14\xab\xdd5m>\xf7\xa6X\x96" +
-	"\x12m\xef\x95\x9f\x94$\xe6\x11\xben9\x1b\xc0}\xcd" +
-	"l3\xf4tj\xbe\xa2)m\xaa\x01\xc0\x8e\xcc\xe2P" +
-	"\x9cAj\xc4\xe0\\\x80N\xb3\xc3\xb4\xd4d\xcc\x8e2" +
-	"\xf0r\x13\x00\xf2R\xde\xc4N\xd2\x9a1*\xe6m\x89" +
-	"\xbbU\xc3\x8c\xeb\x1a\xcb$&\xb2LR\xea\x9e\xbd\x99" +
-	"\xce\xde\xc8\xa3<\xcf3\xc3\x1cJ\x0f\x7f\xce\xa3\xbc\x80" +
-	"\x12\x09f\x12\x89L\x8e\xd5\xc2\xa3\x9c\xe0\xb0s\xb5j" +
-	",\xd3M\x15\x118D\xf8\xba\xd0\x1fP\xe0\x05ns" +
-	"\x82yz\xdb,#\x14_\xad\x1ar\x00\xfdU\x1d\xeb" +
-	"B\x0b:R\xaa\xff<u\xfd\x9c\x87d\xb3x\x94[" +
-	"|\xe7\x99?\xc3;\xa4\xe3k\xae\xe2~|\xad3\xa9" +
-	"\xac\x8d\xc4\xd7\xa9\x18\x04\x0e\x83y\xfa^D\xb5\x16\xc5" +
-	"\xb5\x98\xbe\x86\xde\xcc\x18\xc0bN\x11v7\xfc\x8b*" +
-	"\x00y-\x8f\xf2\xdfx\x1b^?\x19@\xbe\x97G\xf9" +
-	"A\xdf\x86\xef\x9f\x06 \xdf\xc7\xa3\xfc[\xca\xe4\x98\xc9" +
-	"\xe4\xbf&S=\xc8\xa3\xbc\x91\x129\xc7\x12\xb9\xb8\x81" +
-	"L\xf5\x08\x8f\xf2.\x0e\xf9\xb8\x9b\x08k\xd6\xc4cV" +
-	";\x0a\xc0\xa1\x00\xd8\xd0\xae\xc6\xdb\xda-\xe7\xe7\xb7a" +
-	"\xc2\xdb\xde\x84\xa5\x18\xd6\x8fb\x11=\xbaR\xb5Z\xdd" +
-	"\xbc\x94\x939\xeb\xbc@\xec?\xd0\xf9\xaf[\x82\xd75" +
-	"y\x01\xa2G\x8f\xc4\x9eu^S'\xf6\xfc\xd2\xe3\x08" +
-	"b\xcf\x01\xaf\x17\x14\xb7\xb6\xfa(\xdaV\xc3\xebx\xc5" +
-	"\xadG\xbd\x16E\xdcq\xca\xeb\x9c\xc5g\xcfz\xc9A" +
-	"\xdco\xf8\x98\xdb\xfeu\xbe\xbe}\x7f\x97\x8fr\x1e\xea" +
-	"\xf6\xe8\x95xd\xa7\xaf_;\xfe\x9c\x8f\x15\x9f<\xea" +
-	"\xeb\xe5O\xb7\xfa\x18\xf0\xe9S^\xd9\x10\xcfu\xfb\x08" +
-	"\xd2\x85\x9d>*v\xf19_\x93w\xb9\xcbv\xa2\x1f" +
-	"\x1a2\xee\xe7\x0ax\xc7\x08\x99\xb2\xe6&\xc8V\x07\xc8" +
-	"\xa2.\xbeZ\x054l'\xafA\x0d\xcbl\xb6\xf3N" +
-	"\x81\xf3\x92\xa3\xac9\xb7\x1ft\xbc\x1el\xe7\x11\xe7{" +
-	"\x96Me\xb6\x93\xda\xa0&\xb3\xb6\xfb\xbb!\xa3\xd7v" +
-	"\x0a\x11\xb6y\x0a\xfd2G\x91\x13q\xe8\x84\\\x88\xe9" +
-	"\xcb\x15\x9b5\x19\xb5NI\xe7{\xf5[\xa6\x05N\xf9" +
-	"B\x0f\xc3\xf5\xaa\xfb\xcc}m\x0f\xe6\xdbB\xd6\xd51" +
-	"\xeb\xeb\xce\x16r\xc4\xce\x16\x16\xa8k-\xfa\x87\xf3\x95" +
-	"T\xb3f\x19\x1d\x00r-_\x00\xe0\x92Lt\x88\x90" +
-	"x}\x06p\xe2U\x01=F\x81\x0e\x8f\x14/\xfc\x12" +
-	"8\xf1\x8c\x80\x9c;\xc6A\x874\x88\xc7\xbb\x81\x13\x8f" +
-	"\x08\xc8\xbb\xf3\x0at8\xb0\xb8\x97\xde\xdb-`\xc0\xa5" +
-	"S\xe8LR\xc4\x9eM\xc0\x89\x9b\x05,p\x191:" +
-	"\xacM\xdcp\x008\xf1!\x01\x0b\xdd\xa1\x0f:\xe3!" +
-	"q}\x17p\xe2/\x04\x14\\\x1e\x8c\x0e\xc3\x11W\x19" +
-	"\xc0\x89q\x81j\x08\xf9a#\xda\xd1\xac3a\xd6-" +
-	"\xa0\x11m\x87W\xa0\xe3,h4\xa2\xed\xd4r?\xd2" +
-	"p\xbd \x0b\xe5U\x82\x9a\xbd,>S\xd7\x1a2\xaf" +
-	"\xb8\xeb\xdd\xa5\xa0cP =f\xd6>P\xc3\x0c\xd4" +
-	"\x88\xfe\xfa:\x80\xd4\xe6%\xf9~\xba\xb2\xb1\xdc\xb7\xde" +
-	"\x8f\xe6DZ\xa6\xc07:KK\xcfb\x15@d\x17" +
-	"u\xe9/\xa2\xc7\x16\xa4\xbd\xb8\x18 \xf2\x02\xc9\x0f#" +
-	"\x87\x98\xe1\x0b\xd2!\xd6\xd4\x1f$\xf1\x09\xf4\x0a\x8dt" +
-	"\x9c\x91\x80\xc3$\x7f\x1d\xbdZ#\x9d\xc4V\x80\xc8\x09" +
-	"\x92\xbf\xc7H\x03\x9f!\x0d\x97q\x05@\xe4\x12\xc9o" +
-	"2\xd2\x10\xc8\x90\x86/\xd9\xb27\x18\x99\xe08\x14\x85" +
-	"\x820qYI\xe4H^\xce\x11\x99 yQa\x18" +
-	"\x8b\x00\xa4qL>\x96\xe4\xb3H\x1e\x14\xc2\x18\x04\x90" +
-	"\x9a\xb8e\x00\x91F\x92\xff\x94\xe4\xc5Ea,\x06\x90" +
-	"\xfe\x92\xc9\xff\x82\xe41\x92\x97\x04\xc3X\x02 )\x1c" +
-	"\x9dk)\xc9\xef%yiq\x18K\x01\xa4\x0en\x06" +
-	"@\xc4\"\xf9#$/\xc30\x96\x11\x03\xe6\x0c\x80\xc8" +
-	"oI\xfe\x18\xc9\x87\x94\x84q\x08\x80\xf4(\x93o$" +
-	"\xf9\x1e\x92\x87J\xc3\x18\x02\x90v3=\xdbI~\x8c" +
-	"\xebUv\xedei-\x96P[\x14\xe0}\x05\xcdR" +
-	"\x8dd\\S\x12\xe4\x04\xd9.\xaa\xc6\xb4bq\xcd\xed" +
-	"\xa9\xd4\xb5q\x8b\x11\x1b\xec\xc3yt=\xd9LO!" +
-	"\xa4X\xed}\x9e&\x9c\xbc\xcd\x1b>\xca\xe1\x9b~0" +
-	"T4\xa1*Z:5\x13\xf8d\xac\x0f\xe1J\xe8\xcb" +
-	"\x94D\x93\x01|_\xbe\x15\xd5\x93IE\x8b5\x81`" +
-	"\xf4}8\xf8&\xa2S\xd5V\xdf\xad\xf87\x9c\x1b\x11" +
-	"\xd1\xde%\x08C^\xdd\xce\xb4k\xb6\x12\x8b\xc5\xad\xb8" +
-	"\xaeA\x8d\x92\xf8Q\xccU\x15\xccl\xae3\xa1*+" +
-	"\xfb\x8a\x07\xc5!ZU3\x9d\xe0\xf3%^n{\x90" +
-	"C$\x84\xdb\xach\xfa;\xc7\x1c\xf2b\x02|3{" +
-	"q\x9b\x8d\x01\xb0\x97lJ\xce\x9f\"\xb9\xdd\xd6\x00&" +
-	"\x1c\xa6?]:\x07\xfa\xe6\xa5\xdcvg\xc0\xa3\x9b\xc1" +
-	"\x9a\xcd\xed\x04\x07\xc7\x97W\xa5\x05\xd5\xcce[U\x1e" +
-	";\x11\xfb\xa7[\\_\xba\xe5O&\xff\xcfL\x8b\xf5" +
-	"3!*\x8a\x8c\xa5\xb0\xad\xdc1\x8aQ\xd7\xf1\xf4\x1f" +
-	"'\x8e\xa1\xffx\xb1\xba\x0e\x00\x03b\xc5(\x00!\x9e" +
-	"\x8a\x0a\x9aj\x09\xa9x,\x946UCH[f^" +
-	"\xf6\xe9\xa7Y\xf4\xcd\x0a\xca\xddkS\xe82\x96f." +
-	"\xc3\xb9\xb58\xd1\xa1\x18\x8fr\xca\xc7\x91\x92$l\xe7" +
-	"Q\xb6\xa8t\xd5f8\xd2*z;\xc5\xa3|/\x97" +
-	"\xc9\xaa3\xf5\x183q\x008\x0c\x006\x98VLO" +
-	"[\xcee\xd2O\xd50\xdc\xbb\xb5\xe2I5\xf6\x93\xb4" +
-	"\xe5\xcb\xd4\x83\xab\xce\xe4[|\x9fa\xd1\x0a\x9f\xffE" +
-	"\xb3`\x08\x19-\xf1\x18\x16\x01\x87Ey:\x9e\xd30" +
-	"g{cZd\x98\xbb\xc8\xe3\xe4v\x1by\x94\xb7\xf8" +
-	"\xdcn\xf3b\x00\xf9\xefx\x94\xb7\xfb\xdcn\xab\x01 " +
-	"?\xc5\xa3\xbc\x87C\xccN\x0bww\x03\xc8{x\x94" +
-	"\x0fR\xdd\xe73\x1cs?9\xed\x8b<\xca\xc7\xa8\xe8" +
-	"\x07X\xd1\x17\x8f\xd0M\x1f\xe6Q~\xbb\xb7\xd3\x9a," +
-	"\xd6s* \xeb\xf0T\xd3\x84\x9a\xb8\xae\xf9\xc6u\xa6" +
-	"\xa5\xa7\x9a\x96[*\x1a\x11\xaa\x87\xcd:.\xff6\xe7" +
-	"\x0c\x83H\xba,mX\x98g\xdap\x89\xdd \x12\xef" +
-	"\xc0\x12\x94Ko\x07\x90\x12\xfb\x99\"\xb6(!#\xaf" +
-	"\x81\xbd\xcbl\x07p2\x87D\x1a\xdf_\xd0\x91B\xd5" +
-	"7\x10;\xcb\xb2JE\x1d\x80\xeb\xf7\x9c\xd1\x9a\xd6(" +
-	"\xde\xe6\x90\xe6\xe5D\xabB+L]\x1b\xd8\x98\xcb\x17" +
-	"gc\xdd68\xc8\xfaTw\x88\xedt\xc1\"R\xdb" +
-	"VJ\xe2a\xe8%\x12\xa9\x02G9\xb3\xed\x91\xac\x0d" +
-	"\xe62m\xf0p\x9c\x06\x10\x09\x93\xbc\x16\xbdp\x90\xaa" +
-	"\x99\xfa\x91$\x1f\x8b^DHc\x18\xbe\xd6\x99\x91\x8b" +
-	"\x85\x05\x996x\x1cR\x9b:\x96\xe4SH.\x14f" +
-	"\xda\xe0I\xacm\x9eH\xf2\xe9$/\x122m\xf0\x0f" +
-	"\x98\xfe\xa9$\x9fE\xf2`Q\xb6\x0df\xedz#\xc9" +
-	"\xe7!\x87v\xca\xd0\xa3\xaai\xce\x01t\xd3\x87C\xb4" +
-	"\x9c\x00\x13,\xa5\xcd\xf9\xbb\x81\xba\xb9\xb8\xe5kU\xe3" +
-	"\x89\xd8,\xc5\x02T]\x88\xa5\x18m\xaa\x071\xd2\xa6" +
-	"EW\x0d\x82O\xa7\x1dU\x8c6\xfdn\xd5\x80\x90\xd9" +
-	"G\xbc\xc0P}\xfazE\xab\x13\xc1\x83,\x16^\x89" +
-	"\xadu}\xf7\x0c\xe5\xba\xd73_A\x9cTwnq" +
-	"\xf6#\xc8%_\xad\xb8HN\xfe&\x8f\xf2\x17d\xdf" +
-	"\xc6L\xae\xbbN\xd1\xf6\x19\x8f\xf2M\xdf<\xedK\xca" +
-	"u7x\x8c\x04\xfc\x0c\x07\xe9\xea[]\x0fq\x08\xce" +
-	"p\xe6Q\xccC&\xa2\x8f\xe0\x8cG\"\x0e\xdf#\xf9" +
-	"T\xec\x9d\x1e\xc9\xe3\xf5\xb4\x15\x01^\x8d:\xe3\xc5\xce" +
-	"l\x9f\x9d\xdba\xf7\xc3\x1b\xfe\xc0]\xf7`\x1a\xb1\xbc" +
-	"\x1bLwh7\xe8\x063\x93\xbcsZ\xe6\xaf\xcf\xa9" +
-	"\xee m\x00\x0b\xf6\xfd\x06\xda\xaa\x9a\xa1\xfc?\xcc\xb8" +
-	"\x83\xc5\x01\xac\x993Y\xf6\xcdS\xf3J\x96\xce\xd0\x89" +
-	"\xcd\x9c\x04\xcb\xe8\xc8\xf983\xca\xfb8\xe36\x0c\xe3" +
-	"'{_g\x84\x95j\x87;W^\xad$\xd2n|" +
-	"\xffo\x00\x00\x00\xff\xff\xfe\x11AT"
+	return Conmon_followLogContainer_Results(p.Struct()), err
+}
+func (p Conmon_followLogContainer_Results_Future) Response() Conmon_FollowLogResponse_Future {
+	return Conmon_FollowLogResponse_Future{Future: p.Future.Field(0, nil)}
+}
+
+const schema_ffaaf7385bc4adad = "x\xda\xcd\\\x0dxT\xd5\x99\xbe\xe7N\xc2\xcd\x84\x84" +
+	"\xc9\xe5\x0e\x08\x11\x8cDP\x18A @\x85\x14\x0cI" +
+	"\xf8\x1d\x88f&Ak@\x97I\xe6&\x990\x99\x19" +
+	"\xe6GH*\x0b\xa6\xf2Tck%\xd5\xadd\xe5Y" +
+	"\xc5\x9f\x02\xd5\xd5\xb2\xda\xddR\xddU\xaaO)\xd5m" +
+	"\xcdn\xad\xf2T\x0b\x0a]\xc8R[\x91\xb6\x96\xad\xbd" +
+	"\xfb~\xf7\xce\xfd\x99\x9f@&t\x9fg}\xf8\xcc\xbd" +
+	"\xe7\xfd\xce9\xdf=?\xdf9\xe7\xfb\xbe3S\xf8\xbc" +
+	"\x8a\x82\xff,^\x9a7\xb7\xf8\xc6I\x1c\xdf\xb0\x8a\xe5" +
+	"\x8f\xe2\xaeWJ&\x1d\xfd\xe0\xddk\x9f\xbf\x87\x13g" +
+	"\xf1\xca\xfc'\xfe\xe9{\x0f~\xbc\xf5\x1f9\x8e\xcd\xf3" +
+	"\x14\x1f`R\xa0XH\xd2-\x1c'=Q,(\xe2" +
+	";\xdf\x9f4\xea+?\xcd\xe4\xffz\xf1 \x93\xf6\x83" +
+	"W\xa3\x1b\xc1\xffs\xf0\xc7NtE\x9f\xd9\xb3\xf2+" +
+	"\x9cx\x0d\xe3\xb8|&p\xdc\xbc\xd7\x8a?\xc4\xb3\xf4" +
+	"\x1e\x18\xdf+\xae\xc2\xd3\xc41\x82\xf2\xfb\xa7\x8e,\xf9" +
+	"\xd6\xae\xdf\xf6Z\x19\xf3\xc7\xa8\x8c\x93\xc7\x08 b\xf4" +
+	"\x81\xf1\x97\x0b]\xad\x8f\xdb\xd6>`e\xac\x1bs\x8c" +
+	"\x18\xe51\x82\xc8\xe3A\xecg\xca\xdfnt<\xfc\xcd" +
+	"\xf1\xebU6\xe5\x93y\xad\xef\xef>}\xe3?s\xf9" +
+	"\x02\xd8\xc5\x03L<\xc7$\x9e1i,\xa3|\xd1|" +
+	"\xa6<p}\xb5\xa7\xf0\x91'\x1f\xb2\x14+\xf5\xe4\x13" +
+	"z4\x9fI\xc7\xd4\xa7\xe8(\xa6\xbc\x18_\xf5\xc8g" +
+	"\xf7\xbe\xff\x10\xe7\xb9\x86Y\x0b\xd62\x8cb\xd2a\xd0" +
+	"\x00\xe8\xc3Q\x94\xa5\xbc\x80)3\x82GVO\xfa\xc5" +
+	"}\x0f[\x8b\xae( \xb4\xbb\x80I\xbd\xea\x93\xcb\xce" +
+	"\x94o\xbd\xfe\xcc\x9a\xff\xa8>\xf5w\x9c8\x1bIy" +
+	"\xc4Vi'\xb0\xc7\xce\xa4>\xf5i/\xd8~rU" +
+	"\xf7m\x8eg\xbe\xfah\xda\x97\xa9\xc5\x1e\x04\xe7\x05P" +
+	"a!\x93J\x0b)\xc7\xb1B\xa6|\xfb\xa9\xe3\x1b\xfb" +
+	"\xfex\xba?\xbd\xd3\xa4A\xb0U\x8cfR\x0d\xc8;" +
+	"\x9a\xd8\x07G3\xe5\x9fW\xad_\xbado\xf1\x9e\x0c" +
+	"\xf6\x0b`\xab,b\x92\x1b\xd4TD\xec\x1f\x161\xc5" +
+	";vg\xe3\xb7\xbc={\xac\x9fwNE+\x8b\xc1" +
+	"[\xac\xf2\x153\xe5\xe8o\x1f\x9a\xbe\xbe\xf0\xc1\xccb" +
+	"\xcf\x81\xadb\x0c\xa4\x00y\xc7\x10\xfb\xc11L\xd9y" +
+	"\xe6\xe6\xef\xad\xfb\xcao\x1f\xb7\x16{XE\x0b\x1d\xf8" +
+	"8\x07=\x1dr0e\xda{\xbf>\xe7)\xdd\xf7D" +
+	"F\xb1G\xc1VX\x02V\x90\xabD-\xb6\x84)\xbb" +
+	"\xd7\x9f\xde\xb4|\xb5coj\xeb\xa9\x8d}\x18\x8c\xbc" +
+	"\x88A\x01*\x17)C\x85\xc8\x94\xef\x1e\x9d\xe5\x0d." +
+	"\xfd\xc9\x93V9jT\xb4\x17|\xfd\x1a\xdfX\xa6t" +
+	"\x9f\x9e\xf0\xa4\xfb\x8e\x7f\x7f2C\x8e\x9a\xb1\xe8dP" +
+	"?\xe8\xc0X\xb5]$\xa6\x8c\xdf'\xfd\xc3\xaf\x83\xbf" +
+	"x\xc6Z\xac[R\x8b\x95\xc0\xab>U8\xc1w\xe1" +
+	"\xa3\xa7\xdeiX\xb8?\xebx\xabq2\xa9\x07\xd4\x07" +
+	"\xda\xeb\xa4,\x87\xc71e\xea\xf3?|\xbbw\xf1\xec" +
+	"\x03\xd6\xa2\x07\xc6\x11Z:\x1eM1^\xe5\x1b\xcf\x94" +
+	"\xdeo\xcb\xd7\xbdvh\x0d\xf1\xf1f\xc9\x90x\x00l" +
+	"c\xaf@#\x80*\xae \xf6\xfe+\x98r\xe8y\xcf" +
+	"\xc9\xff\xee\x7f&\xa5\xd8\x03*:\x08\xbe\x0b\x1a\xdf\x04" +
+	"\xa6\xdc\xfe\x87\xb2?\xec\x8a~\xfe\x9d,-|`\x02" +
+	"\xc6\x1c\xe8\x02\xa8p\xa2*\xd1D\xa6l\xd9x\xe4\xf9" +
+	"n\xcf\xa9g\xb3dpMdR\x07\xa8\x1b\xd4\xabf" +
+	"\xe8G\x06i\xd9\x9c\xef\xbf3\xcf\xf5\\z\x9b\xf0j" +
+	"\x1d`\x1d\x04]\x00\x15\x96R\x96\x81+\x99\xf2\xf9\xaf" +
+	"\xb6_\xf1\xc5\xd0\x9d\xcfY\x85\xff\xf0Ju\xe6M\xc2" +
+	"\xa0\x9e\xa4\xf2Mb\x7f\xfc\xcb+W\x9d*\xbc\xf3\x1f" +
+	"\xad\\*\xe6\x9a\x0c\xae\xc9\xea\x84\x9a\xcc\xcc\xee\x85\x00" +
+	"\xe3-\x02L\xa4\x0c\x83`-\xbf\x0a}X\x86\xfe." +
+	"\xa3,M\x05\xbc\xd2v\xebW_\xda\xf3\x9fg^L" +
+	"\x93Y\xfd\xca\x8e\x02^:\x00:\x04:Z\xc0#\xa5" +
+	"\xd9\xce+_~{p\xdf\x83\x0fT\xbf\x94\xfe\x956" +
+	"\xca\x12\xb5\xf3\x98\xec\xbct\x184`\xa7,{\x8bx" +
+	"\xc5v\xfcd\xe1\x83\xd5\xb3\xbf\x97U5\x14\xf1\xd2\x05" +
+	"Pa1/\x95\x16S\x8e\x83\xc5\x96n\x17'\xd9\x94" +
+	"\xe7\x9e{}\xfd\xc2?\x1ePh\x0c\x1c\x06\xdb9\x90" +
+	"K\xe21^yi\xefDT8\x91Wf\x9c\xbd\xef" +
+	"\x99\xaf~\xa7\xe1P\xb6*\x0e\x83\x87/\xe5\xa5\xb1\xa0" +
+	"\xf2R\xaa\xe2\xc3R^9\xfa\xbd\xfd\x95\x17>\xdar" +
+	"\x88\xbe\xc3Ra\xfe\x18UQ\x80\xb5\xf2J^r\x83" +
+	"\x9a\xae\xa4,\x153xeNb\xeb6\xd7\x98\x17\xa8" +
+	"\x12[\xca\xc8\xac\x99\xc1K\xdd\xa0^P?h\xac\x0b" +
+	"5\xb9x\xe56\xdf7\xff\xed\x89\x9e?\xbd\x92&\x95" +
+	": *\xc0\x13\x05\xf5\x80\xfa\\T\xc5\xb1\xeby\xe5" +
+	"o6\xbe\xf0\xf47?}\xe0\xdf\xb2\x8e\xa1\xc1\xeb\xf1" +
+	"\xe13!\x19\xc8=\x93\xb2\x94\xde\x00\xa9>\xfd\xfb\xb3" +
+	"\xce\x9f\x1e\xff\xa1\xa6\x9f\xb5Ov\xdd@h\xf4\x06\x14" +
+	"\xaf>\x95\xce\xe6\x95\x92\xf5?]r\xf6\xce_\xbfa" +
+	"\x1dk\xae\xd9*\xdfl\xf0\xa9O\xe5sx\xe5\xbf|" +
+	"?\xe0\x97\xbf\x15\xfcQ\xca\xba0\x87\xd0\x9e9\x10U" +
+	"}r\xcd\xe5\x95\xb3uo>809\xf2c+_" +
+	"\xe5\\\x95o.\xf8\xd4\xa7\x8a\x0a\x94w\xf2/\x1dm" +
+	"\x91\xd9o\xa6h\xaa\x0a\x95\xaf\x02|\xea\x93k\x1e\xaf" +
+	"l\x1a}\xc4i\xaf\x8a\xfd{Jy\xf3\x08\xed\x9e\x87" +
+	"\xa6U\x9f\xca\xe7\xf3\xcag\xe3\xfe\xf5[\xa5\x8b\x0f\xa5" +
+	"\xf0U\xccW\xf9\xe6\x83O}r-\xe0\x95\xd2\xea\xb7" +
+	"\xe7;B+\x7f\x96u\xa0V.@\x8f\x81zA\xfd" +
+	"\x0b(\xcb\xe0\x8d\xbc\xf2\xe4\xa7\xfb6\xbe\xb4\xcb\xf9N" +
+	"\x86\xea\xb9p#/U,\xe4\xa5\x1a\x90w\xa1Z\xc3" +
+	"\"^\xf9\xbbp\xcf\xcb\x13\xafy\xe9\x1d\xaa\xc1\x96Q" +
+	"\xc3\"\xd4\x00\xea\x05\xf5/\xa2,\xbdKxep\xee" +
+	"=\xfd3v\xbe\xfd^\x16\xa5\xd2\xbf\x84\x97\x8e\x81\x06" +
+	"A\x17\x96P\x86\xc2\x9bx\xe5\xcf;\x17\xef\x98<\xf9" +
+	"\x9d\xf7\xb2\x0e\x88\xd2\x9bx\xa9\x19\x14\x05\xf5\xdc\xa4N" +
+	"\xb7\xa5\xbc\xd2\x7f\xfd\x96\xc8\x9d\xcd\x95\x1fd\x9b\xd3\x07" +
+	"\x97\xa2pPa5\xa6[\xb5:\xdd\xaaye\xc7\xb3" +
+	"=\xdf\x1e\xf8\xf8\xd0\x07)\x8b\x9a\x8a\x16\xd6\x80\xaf\x86" +
+	"\x9e\x0e\xd5@\x98\xca?\xff\xeb\xe3\x8b#\xbfJ\x17&" +
+	"\x9f2\x1c\x05ka-\xd8A\xaeZ\xcar`\x05\xaf" +
+	"\xac\x8b\xac\x14\xaf\xf5\x8e9n-\xfa\xd0\x0a\xb5\xe8\x95" +
+	"\xe0]\xa9\x16\xbd\x92W\xbc\xbb\x9a~\xff\xed\xa6\xa5'" +
+	"\xb2\x09}\x14\x9ccWaJ\x81*VQ\x8e\xc3\xab" +
+	"0\xee\xbf\xbcr\xff\x9d\x01\xe9\xa3\x94\xf5DE\xc7\xae" +
+	"\x06\xefj\x95o5\xaf\xac\xb9\xee\xec\xc7o>\xbe\xe5" +
+	"\xa3\x8c\x15p\x00l\xa5n\x08\x0b\xaat\xab\xecn^" +
+	"\x19\xb3b\xc2\xa7\x7f\xd8\xff\xec\xa9Lv\xb0\x95\xae\x01" +
+	";\xa8r\x0d\xb1\xf7\xaf\xe1\x95/H?|!\xb4k" +
+	"\xf0T\xca\xf2\xa3\xa2\xe7\xc0\xc7\xafU\xf9\xd6\xf2\xca\xfb" +
+	"=\xa1\xba\x13\x9f\xdf\x7f&\x85OE\xcf\xad\x05_\x9d" +
+	"\xcaW\xc7+=\xe3\x0e\x9f\xdb}\xeb\xf4\xb3i*C" +
+	"\x1dR\x07\xea\xa0\xfc@\xfc\xcdh\x8f\x9bUEv\x0b" +
+	"\xaf\xfc\xe0\xcb\x9fLx\xe1\xd4\xc0oR6:\xb7\x10" +
+	"ZY\x0f=Q\xaf\x0e\xeez^\x99\xf2\xc1\xe7\xe7g" +
+	"~\xe3'\x1fg+\xf9\x028+=\xe0\x065y\xd4" +
+	"\xf1\xdd\xc0+\xa7\xaf\x94\xdf\x7f\xf7\x83\xff\xf9mFS" +
+	"T6`l\x83zA\xfd\x0d\xc4\xde\xd4\xc8+\xaf\xad" +
+	"\x9fW\xff\x8b\x8f\xae\xfd]\x06{G#\x16\x12\xd0!" +
+	"\xd0\xd1Fu!\xb9\x95W\xde\xfe\xb8\xec\xd9\x9f\x9cZ" +
+	"\xf3i\xfa`\xb2\xab\x0b\xc9\xad\xd0\xeb\xa0\xc3\xa0\x81[" +
+	")\x8bw\x03\xaf<\xb3\xf9\xc9\x87>+\x17\xcf\xa7\xeb" +
+	"lu\xfc5o@-\xa0C\xa0\xa3\x1bT\xfd\xe2\xe3" +
+	"\x95\x7f\xe9\x7f\xf8\x1b\xafW\xac<om\x9d>\x9f\xaa" +
+	"n}\x98k\xeaSO3\xafl\xb9Wq\xf2\x0b\xd7" +
+	"\x9f\xcf\xd6:}\xcd(\x11t\x0c4\xd8L9\xfa\xfc" +
+	"\xbc2\xeeo\xee9\xee:\xf3QJ\xc9{\xfdjk" +
+	"\xfb1\xc9\xd4\xa7~\x99W^f\x07Fo\xe88\xfd" +
+	"YJ\xcf\xcbj\xcf\xcb\xe8\xcbVu\xda\xb6B\xaf=" +
+	"\xf1\x9dy;\xde\xfa\xa7?eQ\x0d\x07[Q \xa8" +
+	"\xb0\x0dC\xb0M\xed\x9e6^y\xf0\xc2\x03?|\xe4" +
+	"\xcboe\xcbP\x09\xc6nP/\xa8_\xcdp\x00\x19" +
+	"\xfa\xfe\xc3\xdd\xf9\xc1\xe7?\xb8\x90m9:\x04\xce\x0b" +
+	"\xa0\xc2vT\xd1\xae\x8e\x9e\x00\xafl}\xe6\xa7\xafn" +
+	"\xfd\xb0L\xa1\x06\xcfK\xcf\xe2\x0e\xa0\x02P?\xe8@" +
+	"@\xd5p\x9d\x98\xf0E\xeb\x0b~Ur\\\xc9\x18\x05" +
+	"\xfd\x9dhA\xd0 \xe8B\xa7\xda\x8a!^\x09\x84\xe2" +
+	"r4\xe4\x0b\xe6\xcf\x8eD\xc3\xf1\xf0\xec\x96p\xa83" +
+	"\x1c\xba\xa1\xc5\x17\x09E*k\xb5\x97\xb5\xe1\xb6e\xd1" +
+	"\xc0]r\xf4\x86\x15Q\x9f\xa33\x10j\xabg\xac\x9e" +
+	"\xf1\x9e\"\x86b\xc4\xc9\x95\xe2d\x811qb\x8d8" +
+	"Q`\xbc8\xaeB\x1c'T\x85\xfc\x1d\xb1p\x08\\" +
+	"\xdb\xe9o\x83\xbc\x19\x8fe\xbeh\xd4\xd7\x85\x87\xa5\xcc" +
+	"\xa8x\xd4\xb0*n\x0ct\xca\xb1\xb8\xaf3\xd2\x10v" +
+	"$\xa2-rR\x80\x02U\x00\xd1\x8b\x0a!\x80\xfaW" +
+	"\xd9\xe2\x0b\x06k\x83\xe1\x16\x8em\x02\x8b\x82rP|" +
+	"(\xc0\xb1\x96\xe1W,o\x95[\x1a\xbaB-x\x8d" +
+	"\xfb\x02!9:\xb5\xde\x17\x15|\x9d1\xaa4\xcf\x96" +
+	"\x87s\x18\x1d(\x8bk\xc4b\xc1Sdc\x9eI<" +
+	"\xdb\x1e\x957' $XX\x899U8n)X" +
+	"Y\x09\xc7\x86YwKT\xf6\xc5\xe5\x9b}\xf8\xe0\x88" +
+	"\xafE\x8eM\xf5\xca\xb1\x84\x10\x8c\xa7\xd7\xed\x16E\xc1" +
+	"S\x82\xba\xaf\xe6\x99\x12\x05s8\x14\x93Q\x9dV\xbf" +
+	"q\x1c\xbc\xfc\xfa\xf1\xe9>\x9b\xfa\xe9\"\xab\x1a\xde\xd7" +
+	"\x1b\xc7l\xb3v\x91\x09K\xd9\\vlxB\xd4\xa6" +
+	"\x09\xe1\xa5\xd2m\xb18JA\xd7\xab\xff\xe7\xe7\xb2o" +
+	"\xa0jC\x9c\x97\x9a\xc5\xef\x0b\x9e\x7f\x818\xaf\xf3\xe0" +
+	"`N:\xc4\x8b\xaf5\x89o\x08\x9e\xd7\x91z\x12\xa9" +
+	"<\xefTG\xcc\x89f\xf1\x94\xe09ic^\x88+" +
+	"\xdalNfC\xea\xe7\xcd\x12c\x82\x97\xd9X\xc3$" +
+	"J\xcf\xcbs\xb2<2D0\xb74\x99\x09H\x042" +
+	"\x9d\x90\xfc|'\xcb\x072\x83U\x80\x04$\x02\x99O" +
+	"\xc8\xa8QN6\x0a\xc8\x02\xe4Y\x04d!!_\x02" +
+	"\xa2t\xcaq\x9f\xdf\x17\xf7q\xc2-A?5S1" +
+	"G\xc4\x89\x18\xa4\xa1\xe4\x87r6\x99\xda\x99\x8d\xe1X" +
+	"\xbd\x8d1\x87\xb9#J6$\x00%\x11\xf0\xd7\xf9\"" +
+	"\x91\x00'\x84\xda\xac\xdcE\x9c\xfa\xa8\xb4]\x02o\xf6" +
+	"\xc5\xe4z_\xbc=9V(\xb9\x88\xc4(\xe4\xb8\xb2" +
+	"H\xd8\xbf\xda\x9f\x9ej\x8a\xae\xe5H\x96Wb*4" +
+	"S\xba\xdc\xba\x19\x93+\x88I\x9e2\xcdh\xaci\x1d" +
+	"<\xec\xe1f\xa8\xc9\x8c\xe1\xd6\xcf\x86\xa5\xe1\x1a59" +
+	"\xbc\xea,\xb2\xc5d\xeb0\x13\xd9\x16O\x81!\xc5\x8c" +
+	"\x0aq\x86\xe0\x99\x0e)V\xc1R\x94\x1cd\xcb\xbd\xe2" +
+	"j\xc1\xb3\x0a\x89\x8d<+\x0b\xe2Kb)\xedd," +
+	"n\x96^\x0c\xfab\xf1\xdb\xa2\x818\xc7d\xe2\xb5s" +
+	"D96_T\xee\x0c\xdf%\x1bZR\xd5\x14A[" +
+	"<{\xf3]\\_\x18\xfb\xbf\x8c&\xfcp\xd8\xbaz" +
+	"m \x16\x97\xd1\x8b7P\x0b\x18jK\x17F\xa8\xb7" +
+	"\xe5\xe5Vd\xa6\x12\x80\xd0B(\xbd{R\xbe\xb2I" +
+	"\xff\xca9\xf8\xca!\xa6U\x89i>\xc9\x18\xb8Gs" +
+	"]\x99<\x099!\xd7\x87\x83\x81\x96.\x9a.V\xc1" +
+	"n\xd1\x17\xc8\x8a\xe4\x02\xd9D\x7fy\xedoY3\xd6" +
+	"(u\x85\xf2G\xc3\x11\xa8\x04\x99\xb3\xa9\xe3Y}\xbf" +
+	"Y\xde\xa2\xbf'\x05;\xc4r\\\xab\xeb\x03\x91*y" +
+	"\xad\xafY\x0e\x0e!\x95+)UEr\xd9.\xc5\xea" +
+	"\xe9\xa0&\xa3\x95:\xd6\x1e\x8eR\xed\xb6V\xbf)\xc3" +
+	"\xe0p\x87e,\xd1)\xa7\xcdkG\xd4\x97\xf3\xbc6" +
+	"\xce\xef\x19\x83\xf2p\xae\xdd\xb4\xbc3\x12\xefZK3" +
+	"3\xad\x93n\xd47\x11.\x8c\x1c$\x17\xbb \x8ec" +
+	"\x93,G\x00;\xa8/\xcc\xef?7\xdc\xef\x0fG\xe4" +
+	"P\xca\xf7{\xe52L\xcd\x11\xcdL\xc34\x99\xd1\x08" +
+	"{s\x1d\x12\x0d\xf1\xa8 \xfb:\x87\x18\x0f\xe5\xc9\xf1" +
+	"P\x99\x1c\x0f\x95\x18\x0f\x026S\xe0\xaa\x8a\xc5\xfd\xe1" +
+	"D<\xf9$G\xa3f\x9b\x1c\x18\x9e\x10^\xbdMh" +
+	"\x12;\xc2\x19\x938w\xed\x10\xf1%b\xe9\x83L\xdd" +
+	"\xa3\xe58\xc8\x0c\x93OF\xfb\xe6\xac\x0b\xea|[\x1b" +
+	"\x02\xddr]\x18\x93y(]P\x93leoR\x17" +
+	"\xd0\xa6y{ \xd4.c-\xa0\xd9\x9f\x08\x05\x03\x9d" +
+	"\x81\xb8\xcc1\x9ax\xdb\xb5\x17\x7f\xcez\xc0\xe7\xf7\x9b" +
+	"\xebB\x15-\xab97\x8cq`\xcdh\x98a\xf6\xb9" +
+	"\xa1\xb9\x87\xe8\xf3\x94u\xd5\xa5\xaf\xab\x0b\xcduu\x81" +
+	"K\\ x\xe6#q)\xcf\x1c\xf1\xae\x88\xbaTf" +
+	"\xee\x8a\x1c\xf8\x17\xc1\x8e&}\xe3\x92\x14w`x\xfd" +
+	"\xe8\x8b\xc7}-\xed\xa9{\x91N\x96k\xab\x196\x9a" +
+	"\x8cV\xeb\x1d^\xab\xd5\xb6E\xc3\x89H\x9d/\xe4k" +
+	"\x93\xa3\xdcP\xda\xaa&\xa9\xadHwl\x8fua\xe1" +
+	"\xed\xa4A\xa2\xb4\xa8\xb9[c\x9a\xfa\xc8M[\xa6\x8c" +
+	"\x19u/\x11Oj\xcb\xdc4\x96a\xea\x1f\xa9\xda\xae" +
+	"\xb6\x08\xe2\xd5K\x1fR_\xf4\x0d\xaf]\xab\xd5\xeeM" +
+	"\x16\xc7\xe4\xcb-\xeeV9\x1a\x0b\x84C\xea\xc1$\xc6" +
+	"\xe2\xa9\xc5=\x8f\xb1\xa17\xd6\xf2\x1aq\xb9\xe0Y\x86" +
+	"\xc6\xaa7Gv]\xb3\xe8\x11<\xd8\x8cx6\xd0\xb1" +
+	"\x84i\xc7\x92\xdb\xdd\xe2\x1d\x82g\x03R\xef\xc6\xb0\xc2" +
+	"\xc77\x87c\xea\x90g\x1c\xd1\xc5\xcf\x10\xb9o\xd2\x87" +
+	"\xb9\x9c\xb7\x86\x83\xc1\xf0\x96\xbf\xc2rn\xd8k3\xc6" +
+	"\xc5\xb9\x11o,.ca5l\xc1\x19\xe2t\x98\xe2" +
+	"\xe4]J\xf1;h\x90\x8a\xecG\x9e9\xb0\xa2\x99&" +
+	"\xb8\xdb]\x16K\xcc:\xb7\xc5\x96\xb7\xae\xc9b\xe3\\" +
+	"\xd7aq\xc3\xaek\xb68\xd2\xd7\xf5X|\x98\xeb*" +
+	"-~RO\x93\xc5\xab\xe8i\xb68\xeb=5\x16/" +
+	"m\x9d\xd7bz\xadkv4B}*\xdep\xdc\x17" +
+	"\xc7\xb0\xc57+\xcb\x12Q_s \x18\xe0l\xf1." +
+	"\xa5\xc6\xd7\xb2)\x82&\xe2\x1c\xb1DTV\xf4]-" +
+	"'`_\xab\xe8\xd6\x17\x06\xf3\x0bY_\xb8*\xec$" +
+	"\xb0\x91P\xf4]\x15m\xb0\x15}\xf5\xe3\x04\xac\x7f\xdb" +
+	"a*\"K\x91\x82m\xa8\xba\x0b\xe5XP\xa9\x0dw" +
+	"R\x1d1\x94\x1a\x0e\xe9\x87\xf8\xdb\x1c\x0d\x13\x98\xdeg" +
+	"\xd2n\xc1\x05\x12\x1a\x1e\x15pn\xde'\x183Fz" +
+	"\x1a\xc0\xd3\x00\x9e\"\xe0\x05\xc1\x9c5\xd2w\x85\x1a\x90" +
+	"\x80D /\x13b\xe3\xd5\x03\xbd\xf4}\xc1-\xbd\x02" +
+	"\xe4eB\x8e\x10\x92\xc7\xb4#\xfd\x1b@~\x0c\xe4\x08" +
+	"!\xef\x12\x92\x7f\xb5v\xa4\xff9\x90\xf7\x80\xbcK\xc8" +
+	"IBF\xd9\xb4#\xfd\x09\xa1I:\x05\xe4$!\xe7" +
+	"\x09\x11\x80\x90%\xee\x13\xa1C\xfa=\x90\xf3@\xbc\x05" +
+	"\x00\x0a`9(\x00\xf0\xb9\x00\x93B\x81\xe0-@\x8e" +
+	"\"\x02\xec\xa8\xdf\x0e\xc0^\xe0\x02\x09\x0d\x05\x84L%" +
+	"\xa4\xd0\xe9\xa4%K\x9aR\xd0!M\x032\x95\x90\xf9" +
+	"\x84\x8c\x1e\xe7d\xa3\x81\xcc-\xa8\x01\x09\x0ds\x08Y" +
+	"JH\x11\x8c\x0dX\xeb\xa4%\x05MR5\x90\xa5\x84" +
+	"\xac%\xa4x\xbc\x93\xf4\x82\xb4\xba\xa0Y\xaa\x03\xb2\x96" +
+	"\x90\x0d\x84\x8c\x99\xe2\x84\x0a\xe0\xa4\xdb\x91\xe7\x0e \x1b" +
+	"\x08i'\xc4\x81VsP\xbc\x04$\x08\x00i'$" +
+	"NHI\xb9\x13\xb3\x83\x93\x12\x05\x95 \x01\x89@v" +
+	"\x10\"\xa2\x05D \xdbP\xcf=@v\x10\xf25B" +
+	"\xc6\xa2\x09\xc6\x02\xf9zA\x8f\xb4\x0b\xc8C\x84<F" +
+	"\x88t\x05\xfc\xd2@\xf6\xa0\xb4=@\x1e#\xe4YB" +
+	"\x9c\x93\x9c\xcc\x09d?\xf2<\x07\xe4YB^&d" +
+	"\\\x81\x93\x8d\xa3\x1e-\xe8\x96^\x01\xf22!G\x08" +
+	"\x19?\xd9\xc9\xc6S\x8f\x16x\xa5\x1f\x039B\xc8\xbb" +
+	"\x84\\q\x8d\x93]A=\x0a\xe4= \xef\x12r\x92" +
+	"\x90\x09S\x9dl\x02\xf5h\x81[:\x05\xe4$!\xbf" +
+	"#d\xe2UN6\x11\xc8o\xf0=\x9f\x00\xf9\x1d\x10" +
+	"\xaf\x1d@\xa9\xdd\xc9J\xa9G\xd1l\xcc.x\xed\xd4" +
+	"\xa3\x04\\Y\xe8dWR\x8f\xda\xbdR\xb1]@\"" +
+	"\x90\x09\x84L\x1a\xedd\x93\x80\x8c\xb3\xf7J\x93\x81L" +
+	"\"d:!\x93\x8b\x9cl2\x90i\xf6\x0ei\x06\x90" +
+	"\xe9\x84\xcc'\xe4*\x18\x96\xae\xa2\xbe\xb6wK\x0b\x80" +
+	"\xcc'\xa4\x9e\x90\xb2iNV\x06\xa4\xce\xde,y\x80" +
+	"\xd4\x13\xb2\x81\x90\xab1\x0a\xae\xa6\x1e\xb5\xbb\xa5;\x80" +
+	"l \xa4\x9d\x90)\xa5N6\x05H\x00H'\x90 " +
+	"![\x09)\x1f\xe3d\xe5\x8cI\x09{\x0d\x08=J" +
+	"\xc8\x0eB\xaeq8\x19\x1c\x02\xd26 \xdb\x80\xdcM" +
+	"\xc8}\x84L-s\xb2\xa9(m'$\xb8\x1f\xc8}" +
+	"\x84<J\xc8\xb4+\x9dl\x1a\x90G\xec=\xd2n " +
+	"\x8f\x12\xf2\x14!\xd7\x0aNv-\x05\x09\xd9\x9b\xa4\xa7" +
+	"\x81<E\xc8\x0b\x84\\\x87\x1e\xbd\x8ef/\xda\xe0%" +
+	" /\x12\xf2*!\xd3\xd1\xd4\xd3\x81\xbc\x06\x09^\x03" +
+	"\xf2*!o\x122\xe3Z'\x9b\x01\xd9\xde\xc2\xf7\xbc" +
+	"\x0d\xe4g\x84\xfc\x92\x10W\x89\x93\xb9(z\x08\x12\xbc" +
+	"\x0f\xe4\x97\x84\x9c&\xe4z\xd1\xc9\xae\x07r\x0a\xc8\x19" +
+	" \xa7\x099O\xc8\xccUN6\x93f/d\xfb=" +
+	"\x90\xf3\x84\xe4\x15\x02\x99\x85>\x9d\x05\x84\x15vH\xf9" +
+	"\x85\x02\x12\x81\x94\x10r\xc3uNv\x03\x10\x11\xc88" +
+	" NB\xae&d6z{6\x85%\x01\x99\x02\xe4" +
+	"jBf\x122\x07\xbd=\x07\xc8\xacB\xb74\x17\xc8" +
+	"\x1cB\x16\x132\xb7\xd8\xc9\xe6\xd2\xfc-\x8cJ\xd5@" +
+	"\x96\x12\xb2\x96\x90\x0a\xf4O\x05\xf5va\x05\x08\xf3\x97" +
+	"\x90/\x112\x0f\xfd3\x8fz\xbb\x10\xf3\x17\xc8\x06B" +
+	"\xda\x09\x99\x8f6\x98\x8feo,/\x96\xf3b\x05/" +
+	"6\xf1\xe2\x82\xe9N\xb6\x00i=\xbc\xd8\xcf\x8b\x07x" +
+	"q\x80\x17\xbf 9\xd9\x17\x90v\x0e^,\x06O\x1d" +
+	"\xc8\x8d\xa5\xf3F4\x14\xc5gu\xe0\xbd\x07\xd4\x07:" +
+	"\x84\xf4\x85PL\x0b\xc9\xd7\x85\xf7\x0b\xa0B\x1e^@" +
+	"\x9e\x17\x17\xcdp\xb2E\xe4\x18\xc2{\x07\xa8\x1b\xb4\x17" +
+	"\xe9\x95.'\xab$g\x1e\xde\x8f\x81\x06Acm\xbc" +
+	"\xf8\xc5\xeb\x9d\xec\x8b\xe4+\xb6\xa1>P\x13\xa8\x07\xe9" +
+	"\x8b\xc7:\xd9b\xf2\xc1\xe0\xfd0h\x00t\x01\xe9K" +
+	"\xa0\xf6\x96\x90\xcf9\x0f~_\x90\x17\xd4\x9d\xc7\x8b7" +
+	"A\xfc\x9b\xc8\xb7\x83\xf7\x83\xa0\xc3\xa0A\xa4WAN" +
+	"\x8a\x06\x1b\x9b\x0f\x1f!\xa8\x12\xd4\x9c\xcf\x8bKQ\xce" +
+	"Rr\x8e\xe0}/\xe8 \xe8\x18\xd2\xab\xa1\"\xab\x91" +
+	"\xce\x8f\x82\xc7\x05\xe4\x02yG\xf1b\x0d\xd4S\x0dy" +
+	"\xb3\xf1\xde\x0f:\x00\x1a@z\xed\x04'\xab%_\x1d" +
+	"\xf1\x0b\xe0\x07y\x05^\\6\xd3\xc9\x96\x11?\xde\xfb" +
+	"@{AG\x91\xbe|\x96\x93-'o\x14\xde\xf9\x02" +
+	"\xb4\x01\xa8\xb2\x80\xb7\x1cc\x8c\xdd\xc2%\x8e1\xdb;" +
+	"\xb5\x95\xd5b'T\x90\xb4\"\x10\x94\x93\xfb|#9" +
+	"j\xae\xefZ\x1d\xc6&\xc4\xacCi\xd1\x97a\x8dI" +
+	"\xdf\\\xfa-\xfb\x01-\xb3\xb1i\xb1d\x8e\xc1-\xb2" +
+	"\x1a\x9b#\xce\x11\xbd\xcb\x17L\x13\x89\x0c,\x0dX\xdd" +
+	"Sdu\xf8\xa2C\xd8\xa1\xad[\x0e\xadFcgd" +
+	"\xd6\xb8\xbdU\xdbIh\x0c\xc6f\xc7\"\xd2f\xda\xac" +
+	",\x93#\x10\xbc\xdd*\xd0f\xeb&F\xcbnl\xb6" +
+	",\xd9#\xbe(\x0c\x09\xf2]\xd8\xc4\x04\xad\xed\x11\x94" +
+	"\xefB\x82/\xce9h+\x98\xde)U0\xb1\xcb!" +
+	"\x7f\xca\xee\\\xeb\xa6FN\x90\xb7\xc63\x8d\xe8\xc9\xe6" +
+	"a\xc4\x02\x86\x0c\xe3;L+\xb4\xa9\xd2\x045\xf6~" +
+	"\x16A\xe3\xe9\xdb0\x8d\xd5\xd83ZX\xd5q \xaf" +
+	"\x0eqU\x90=\xad\x9f\"\xe6\x8eL+\xc0\xd83Z" +
+	"\x0a\xd8\xe2\x8b\xb7\xb4\xd3\xc7\xb3v\xeb'\xc6h?\x1f" +
+	"j\x91\xd3\x86N\x8buc\xa7\x15j\xec=-\x856" +
+	"'Z[\xe5h\x03FX\xea`n\xa6\xca\xd0t\xa9" +
+	"\xb6pj\xb1\x1a\xca\xc1\xa2\xb2_3\xdd\xa5\x8eu9" +
+	"\x02\xfb\xf0m\x01\xce\x11\xf2\x87\xb7\xa4\"~_K\xbc" +
+	"\xde\x87\xaf\xa7\xae\xcb6\xf8p\xd6]\x15\x8e\xc5C\x9c" +
+	"\xa0\x19<\x8dOi\xa7T\xa4e\xf1\x8e(\xad\x98q" +
+	"\x9aqG\xc5\x0a8\"\x0cP$\xaf\x0b\xa8C!\x1f" +
+	"I\xf9\x8a\xfe\x1f\xf8Tp\xe5\x10\xa0>b\xb4-\xb3" +
+	"\xd6n\xc6\xd6\xde\xd2n\xfe@l\xd3\x8aD0\xc8\xbc" +
+	"r<\x1a@;Xj\xc7\xb9/\x16\x83\xa9`\x0d\x86" +
+	"oW\x86\xc4\xfa\x90\xe1\x1c\x915\x99\xf0v\x1a\x0aY" +
+	"\xd2\x15:\xb6\x05\xd0\x8af7\xe7\xab\x8d\xd3\x1aL\xc4" +
+	"\xda\x97\xdf\x85\x1eI\xdaR\xad\xadn\x01k\xba\xe2i" +
+	"\xa0l9)h\x1fj\x1ch,\x1f\xda\x0as#\xa9" +
+	"\x05\xceQ\x9fE\x0f*\xf1(\x8cI8|\xd1\x8c\xd4" +
+	"f\x98\xdek\x94)\x11Y\x11\x86V\xea\xf4\xc5\xb36" +
+	"Cw8\x94\xb5S[\x92gI\xae,zsr," +
+	"\xa4\xb8\xc4\x02\x9dh\xdd\x8c\\P\x1a\xf1\x80/\xd8\xc8" +
+	"\xd9|mV\x10\x0b\xa0fy\xaa\xd9\xde\x8a\x1ek\xf4" +
+	"\xb5i\xe7\x9c\xe4\xd8#\x13\x80i\x9dr+\xa1\xf0\x0a" +
+	"\xf5t\xcd%\x0d=*\x1f\xd3\xf8\x18\x9dG\xfb\xd0\x17" +
+	"\x90\xb0%\x10d2\xa9]Lf\x164Y\xed\x1a\xab" +
+	"\xea9r+\xf0\xbblJz\xf6.Rk\x87\xb2\x05" +
+	"\xb6F\x19\x87:\xce!\xc3\xaa\x9b\xbd\xb4\x0e\xc5\x0f\xff" +
+	"\x84\x1c\xadE\x9bvbU\xca.\x9e[ii\x97[" +
+	"6\xe1X>\xe4\x17x\x95N\x1c\xd0\xb1\xae\x84\xb8\xa4" +
+	"\xd5%\x83\xa5\xd7`a\xe4\x99\xa49{\xd1/\xb0\xf2" +
+	"\xd3\xd9\x14\x1fa\xe5\xb7~F\xb7\x92\x88\x04\xc3>\xff" +
+	"r\xa8B\x7f$\x8cc\xfdE[Fc\xaeIp\x0e" +
+	"|\xfbpX\xeb\xa3h\xc4\xd6\xc0\xd6a\xb0z\xb1f" +
+	"\xb6\x19\x07\xdf\xac\xac}I\xd6\xea\x16\x06\x7fW\x0cS" +
+	"s5\xf3_\x84\xff`\x92\xbfAfp\xdb\xc7\xab[" +
+	"Z`\x0e\xa1l\xc3\x10g\x19\xc4\x09\xca\xf1!\xba\xa4" +
+	"Y!\x85\x8e\xe1&s\x82\xd7\xb7%;S.^\x88" +
+	"\xfa\xa4\xb9~8N\xbc\xa8n\xb7\x81\x09I\xf1\x05\xb1" +
+	"*\xfa\xbb\xea\xb92*\xc1\xba\xe0\xe6fTl\x90\xb1" +
+	"V\xd0JA\x1aW3\xdb\xc5\xb9\xd4x\x822\xe6q" +
+	"\x1aRl+\x15\xb7\x09\x9e\xbb!\xc5}\xa6\xdd\xee\xfe" +
+	"\x0a\xf1~\xc1s\x1f\x12\x1f\xb6\xd8\xedvU\x8a\xbb\x04" +
+	"\xcfCH}\x8c\xac\x0fL\x0b'\xd8\xdd,\xee\x11<" +
+	"\x8f!u\x1fY\x1ex\xd5\xf2 >\xed\x16\xf7\x0b\x9e" +
+	"}H=\xc23[ \xc3\xd9^\xb6%\xe0\xd7T\x9f" +
+	"\xc0\x11\xb1\xaav9\xd0\xd6\x1e\xb7\xa4\xfc\x95\xcd\x80\xc3" +
+	"ts4\xc4\xa1\xf2V\xf8\x1bH+\xc4\x0d\xab,w" +
+	"\x91nt\xe9\x96\xc0\x09\xfcE\xed\xf3\xa5\xa6\x00\xb6\xa1" +
+	"\x04\xb0\x85Cs\xd9\x8d\xbc\xe7k\xcc\x12\xe8-\xd1\xfc" +
+	"6\xe2\xd8\xf0\xd6c\x86\x15\xe3\xed\x90\x19\xff$\x8d\x83" +
+	"\x1e2\"\xdb\xf1\x165\xc34\xf1v\xd8\x8c\xd7\x91&" +
+	"\xb2\xa3f\xd4\xa74\x85\x0d\x98&}i\x06\xf2\x19q" +
+	"\xe9x\xeb6\xa3\\\xf1\xd6k\xfa\xea\xa4Y\x98\xcbF" +
+	"\xa8\xb6\x04\x97\x89\x19_&-\xc0\xbc5\"d\xa4E" +
+	"\xa8\xddphHK \xa7\x11\xbb\x83\xb7\xa3\xa6\xafZ" +
+	"Z\x8e2\x8d@ki5\xca4\"\xbb\xa5:\x94i" +
+	"\x84AH\x1eHf\\I\xc1[\x8f\x19\x82\x80\xb7\x1a" +
+	"\xd3\xe7\x85|=f\x10:\xdezM\xa7+8{M" +
+	"#\xa9\xb4\x0e\xb5\x1b.!\xe9v\xb6\xd7\xb4\xf3Kw" +
+	"@\x16#rR\xf2\xa1\xe5\x8dx\x02I\xc6\xf7\x19\x96" +
+	"_)\x802\x8d\xc09\xa9\x13e\x1aQ\xdd\xd2fh" +
+	"\x1dc\x98\xe2\xadW\xd1\x8d\xec\\\x956_\x8d\x04\x9b" +
+	">\xfe\xb4\x18\x01\xc3\x1e\xec\xd5\x19u\xff\x01\x87\x1e\xd3" +
+	"=*\\\x99\xeaSQ\xf4<\xf9z&\xbd\xb0\xe5\xe9" +
+	"a_\xba\x9a\xe0\x14\x1d\xe2-X\xd2\x93\xa0\xe8\x9e\x05" +
+	"\xaeL\xab\xdbx\xaf\xd2\xcaUt\xe7'k3\x0b\xb4" +
+	"\xa6\xe9\x05\xe9*\x8a\xe9:\xca\xa1\x96\x97\x9e\x1c+\xd3" +
+	"\x8a\xd5\xe3#l)QR(]w\xbc1\x93\x87O" +
+	"\x09\xa2Pg\xaeb\xb2YDH\xcer\x96\x9c\xe6\xba" +
+	"\x08i\xc9\xba\x08z\xe0\x8c\xd1Az\xc2(\xbd\x1a5" +
+	".\x04\xbb+S\xffk2\xa6\xac\x07\x9a<\xde\xa4y" +
+	"?\xb5\x99\xcc4\xa3\xbd\x93\xee!\xa6\xf9\x876'\x04" +
+	"\xb5\xd5SS\x93\x1eG\x14\xa0\xc5\xc80\xdd\x9f\xa4K" +
+	"\xaa\x03y\xe9\x8e\xa6\x15\xba\xbf\xc3\x94BO\xb2J\xa1" +
+	"\x07\xbcp\x02\x06\x83B':\"V\xe7\x8b,\x0f\xc5" +
+	"\xa3z0\x08V\x95\xaf\xda<\x0bm0-\x1b\x91\xf8" +
+	"L\x0f\x9d\x96\xbe\xcb`\xbafB\xed\x0b\x8c\x11\xd13" +
+	"\xf4\x9b\x1e3\xcb\xf4P{\xe9i\xd6#\xed\x07\xdf>" +
+	"\xf0\x81\xe8\x19\xaa^\xbfE\xc7\xf4\x10Wi\x0f\xeb\x03" +
+	"\xafP\xfb\x14\xf8@\xf4\xccl\xc6\xcd\x1f\xa6_A\x90" +
+	"v\xa3\xbc=\xe0{\x0c| zfyF,5\xd3" +
+	"/YI\xbbX?x\x85\xdaG\xc1\x07\xa2g\x9c`" +
+	"\xf4\x1b\x0aL\x0f.\x97\xbe\xce\x0eI\x8f\x80\xefa\xf0" +
+	"\x81\xe8\x99\x8d2\xee\xe41\xfd\x16\x9ft?\xebE\x99" +
+	"B\xedC\xe0\x03\xd13\x13\x8c\x9b\x07L\x0f\xe9\x95v" +
+	"\xb2(x\x85\xda\xfb\xc0\x07\xa2gV`\\\xc6cz" +
+	"\xbc\xbc\xb4\x0d\xe5\xed\x04\xdf\xbd\xe0\x03\xd13\xb3\x1bw" +
+	"\xb3\x98\x1e\x9a-u\xa1]\xee\x01\xdf\x0e\xf0\x81\xe8\x99" +
+	"\x15\x1aW\xd4\xd8_^\xb9\x8a\xa3\xfb=R\x02\xdf\xbb" +
+	"\x0d|w\x83\xefn2\x82\x82o\xb4q)\x8b\xe9w" +
+	"\x9d\xa0\x9b:\xc0+\xd4\xc6\xc1\x17'3*\xf8\x8a\x8c" +
+	"\x18s\xa6_\xed\x83\xb6\xeb\x81\x8e\x13j\x83\xe0\x03\xd1" +
+	"3+6n\x1d1\xfd\x0a\x074f?x\x85\xdav" +
+	"\xf0\x81\xe8\x99\xdc\x7f1\xed4\xad\x86n\xb6$\x95\x16" +
+	"K\xaa\x1fN\x07\xf4XU\xa6k&\x16\xd5!\xdd\xa3" +
+	"\x9d\x91'j(\x9fd&\x9bld\x8a\xa5h\x1b\xe0" +
+	"UZ\xe6TAn\xf61]\xa3pF\xb1\xb1\xa4\x96" +
+	"\xe0\xcaT5\xa1'\xebQ~LW\x9df\x0e=\x88" +
+	"#\x8b\xf0Qc\xe6g\xca\xa8{\xaa9\xd5\xfbf\xe6" +
+	"H\x9d\xeaF-\xad\xc6\xf4\xcd(\xca\xd8\xbe\xe4\xb2\x93" +
+	"L\xdd\x09e\xee$\x87rC\xce\xb1\x86\x81\x0e{\x7f" +
+	"6\x90K\xb4\\\xda\xd2\xc5\xd2\"f\x1d\xf9p/\xeb" +
+	"\xbe\xb6\x04_\x0a\x82\xf1\x9f'\xe3?oF\xcdJ\xf7" +
+	"\xf0M\xd2N \xf7\x12\xf2\x10\x10\xa6\x05\xceJ\xbbx" +
+	"\xb7\xf4\x08\x80\x87\x09x\x9c7w\xbb\xd2\x1e\xbe\x02\x04" +
+	"o\x0e!\xfbxs\xc7+=\xcd{\xa5\xfd@\xf6\x11" +
+	"\xf2:!\xf96\xcd\xd7\xf6\x1a\xdf!\xbd\x01\xe4uB" +
+	"\x8e\x132*O\xf3\xb5\xbd\x0f\x01N\x009\x0e\xc4k" +
+	"#W[\xbe\xe6j\xfb\x1c\x00\xb3\x09^\x1b\x05\xe9\x12" +
+	"P0Js\xb5M\xb45I\x93m\xf0\xb2\x10\xb2\x90" +
+	"\x10\xbb\xa0\xf9\xda\x16\xd8\x9a\xa5E@\x16\x12\xd2HH" +
+	"a\x81\xe6k\xf3\x00Y\x07\xa4\x91\x90\x8d\x84\x8c\xb6k" +
+	"\xbe\xb6;ln\xc9\x07d#!;\x08)*\xd4|" +
+	"m\xdbl\xf0\x8b\x00\xb9\x9b\x90\xc7\x08)f\x9a\xafm" +
+	"\xb7-*\xed\x01\xf2\x18!\xcf\x122f\xb4\xe6k\xdb" +
+	"\x0f\xe49 \xcf\x12r\x84\x10G\x91\xe6k{\x03\xa5" +
+	"\xbd\x01\xe4uB\x8e\x13R\xc2k\xbe\xb6\xf7!\xc1\x09" +
+	" \xc7\x099K\x88X\xac\xf9\xda\xce@\xea\xdf\x009" +
+	"KHQ\x1e\xf9\xdal\x9a\xaf\xcd\x9e\xd7/\x89yB" +
+	"CI\x1e\xb5\x0e!R\x9e\xe6k\x9b\x98\xd7'M\x01" +
+	"r5!3\x09\x81\xa7M\xf5\xb5\xcd\xc8k\x92f\x01" +
+	"\x99I\xc8BB\xc6\xe5k\xbe\xb6\x05y\x1d\xd2\" " +
+	"\x0b\x09YF\xc8x\xa6\xf9\xda\xaa\xf3\x0eK\xab\x81\xac" +
+	"\"\xa41/\xeb9\x06\xc6\xbe\x90?\x88\xe3\xbcn\x95" +
+	"M5\xc8\xc8Q\xd8t}\xc1T;bY,\xee\x0f" +
+	"\x84\xacv\x1dyk \xaeZ4X6#^8\xdc" +
+	"\xb9\x9c\x188\x07X\xb21\x04\xf5]\xa0-\x9a\x1a\x00" +
+	"j^\x8c0#r[\x82\xb2/\x94\x88\xd4r65" +
+	"\x94&#\xac:\x18n\xf6\x05\xab\xa3\x9c-\xab5\x1b" +
+	"&\xd0N_\xc8_\xcd\x09\xd9\xad\xdd\x7f\xa53\xdbv" +
+	"9t\xd7\xad\xbe\xb4\xaf\xc9\xc2\x97\x0c\x0426\xbd\x9a" +
+	"\xcd\xcd8\x01Yln\xd0\xa5\x01\xd5eP\xe6\x0b\xae" +
+	"\xf0[\x0b\xb6k\xa2oG\xbbl\xca\x8aP\xfb6P" +
+	"\x97\xa5\x99\x83\x91\\G\x9f\x04\xe3j\xdcwIIa" +
+	"\x9e\x0e\xe0\xebe\xa6\xef\xd9\x93\x9d\xa5\x97\x16\x8e\xfae" +
+	"\xb2\x003=$\x88\xc5\xd2+#3j\xbaQ\x19\xe9" +
+	"48\x96s\x8e\xbb\xe4P<\xcdN\x0f5\xd9\x92\xe0" +
+	"\xa3Q \xa8\x95\xe2\xb0\xb1\xdaZ\xcd\xaaI\xdd\xdb=" +
+	"\xecH\x90\xb5\x81*-\xfa9u!X\x89\x85\x80\xf6" +
+	"~\xfa-Q\xa6_\xe7\xd7\x02N\xabK\x18\xfe\xe1\xc1" +
+	"A1\xd3\xd9\xd6\xa4\xbd\xc3\x8d\xafL\x86\xc6\xe8\xfb\xe1" +
+	"\x8b\x84\xda\x95\xea\xa1v\xf3-\x17%\x16\xb8\xc5E\x02" +
+	"6\xaa\xcc\xf3\xa5\xec\xf39\xf7\x85\xab?W\xcbP\xe6" +
+	"\x1d\x8f\xb4P\xaaRK(\x95!\xb9\xc7-\xae\x13<" +
+	"\x8dH\x8d\x1b\x0b\x95\xb8\xb9IL\x08\x9e8\x12w\\" +
+	"\xf6\xe7\x0c\xe5\xb7\xc8-X?#@\xfebw\x1d\\" +
+	"\x96 *}hd\xb9H\x90\xf3\xbd\x9a\xf4\x18\xc7\xcb" +
+	"\xb81`\xd8A2d)4\xaf\x95\x09\x17\x91%f" +
+	"5\xc8\xa5\x85]\xc68.\xc7\xe02\xc3\xd82\xd2\xe0" +
+	"\xb2,an\x97\x11\\f\xd882\xc4\x19f|a" +
+	"r\xfb?\xc2(T\xc3D6\xd2\x10\xcc\x98u\x8f\xab" +
+	"\xf7I\x8eB\x18\xb6\xaa\x91\x8e\xd6\x96\xd4-\xed\xe5\x8c" +
+	"V\xc3\xda7\xd2(zK\x00{6\x05\xfb_\xa8Z" +
+	"\x17\xc5W*\xfa\x04\xcfF\x88\x12\xb4\xa8\xa9\xcefq" +
+	"\xb3\xe0\x89h\xc1\x9d\xc6M\xb4.\xb7ne\x86\xe9\x98" +
+	"%/\xa2\xed.\x17w\x0b\x9eG\x91\xf8\xd4%\x94\xd7" +
+	"\xe5m&\x92\xd1\xfdi\xf6\xf4aF?\xaa\xd6#\x07" +
+	"\x9d\x00S[b\xbbG\xfb\xb2\x05\xe5\xe2\x02\x0aQ\x9e" +
+	"[.\xce\xa5H\xf7Y\xe5\xe2,X#\xb4Ho\x96" +
+	"'N+\x17\xa7\x09B B\xd72aB\xa11#" +
+	"D\xd4ou`=\xa0\x0d\x8b\x90\x88\xc7\xcc \xe6a" +
+	"\xf6S\x8a\xf5&\xbd\x9f\xd2\xaf\x0c\x96\x8a/\x09\x9e\x17" +
+	"\xd1\xce\xafZ\xaf\x0c\xba-W\x06\xf5\xf5\xe4D\x85x" +
+	"B\xf0\x1cG\xe2Y\x8b\x89\xffL\x85xF\xf0\x9cF" +
+	"\xeay:\xf0\xec\xd0L\xfc\x9f\x1c\x14\xff$x>\xa3" +
+	"h\"\xf5\xb2\xa0];\xed0Vi\xdc/,Q\xef" +
+	"\x0a\xf2\xdaa\xa7\x98\xb9a\xad\xc6\xb6\x9d\x90\xab\xd9e" +
+	"\xafWe\xb1@\xa8\xc5\xbaP\x95%B\xf0\x96Y7" +
+	"G\xb1M\x81\xc8\xea\x10\xf9\x12\x03~5\xba\xb3\x8c." +
+	"\xd7Z\xf7U\x97\x8cF\x08&\x17\xb3\xa4@\xa2i\xc8" +
+	"M2\x89\xb9\xde\x17\xb0\xeca.uGd\x98\xa1\xcb" +
+	"Y\x0c\xbaYKN\x99\xbdnQ\x16<~\xf4i\xc4" +
+	"\xf4\xfbtV\x8a\x9d\x82'\x88\xc4\xad\x16\xbfO\xa2\xd2" +
+	"\xb2\xc9\x10mWk\x83b\x9b[\xbcG\xf0\xec@\xea" +
+	"\xd7x\xed\x04Sk\xc6\x0c\xe4qD\xcc\xbcPc\x99" +
+	"\xba\xe6\xdd\x1a\xeb|&\xdf\xb5\xff\x16\xf2pr\x99s" +
+	"\xf5\xd8\x08m\x03Y\xef9\xa6\xa8\xd3\x0e\xab\x0f\xce\xf0" +
+	"\x91;\xa2\xf5\xda\xd8L\xdd&\x7f8\xc2\xeb\x9e\xe9w" +
+	"\x04sP\xe8\x86{c\xa4\xf7,t+}\xd2\xc6\x9b" +
+	"\xaa&n\x83\xd3\xca\x90dw\xa9E\x1d\x1bj\xe2\xe9" +
+	"&\xdd\x91\xf7\xa2E\x9f\xbf\x14\xb5\xdcB\xd6\xf5\xf9\x1b" +
+	"}\xe2[\x82\xe7M$\xbeKj\xc2\xa6\xa9\x89\x9f7" +
+	"\x8b\xef\x09\x9ew\x93W\x93\xf3\xf3T-!\x9ep'" +
+	"\xaf&\xab\xca#\x9b&\x88i\xc6\xb5\xec\x07k\xd5\x1a" +
+	"H\x918e\xd8<d\xb9\xca\x1b\x8b\x87#\xd5\xad8" +
+	"lE\xd5\x83\xdb\xf20k\xfd\xbf\xbb`0\xf6\xb26" +
+	"\x82\xda\x0d\x146\x92\xc1a\xf8\xdaF\xba\xf3\xf0\xa6\xde" +
+	"\xac\x1d\xce\x84\xa9\xb1x;\xb7k\xa6H\x7f\xe6a\xa1" +
+	"?\xb7\xdd\xdfe\xecx\x0c\xbf\xe8\xe5\xdfk\xd3\xe3\xf6" +
+	"\xd5\xc0\xc2K\xdc&u%o\x0f\xba\xd4\xdb\xa4a\xf5" +
+	"\xe4\xe2h\xeb\x0e\xa8\xd7)\xbb\xa1\xe1\xcc\x15} W" +
+	"9\xf4K\x0a\xb1D4\xfd\x02\x8fq1\xaa\xc2r\x8d" +
+	"\xd3\xb8c\x9bv\x8fsp\xc4\xf78Gv\xf1\xc5p" +
+	"\x12gt\xc5\xc0\xc8.z\x8f\xecP`xhG*" +
+	"F\xc6\xac\xb8\xe4\xdd\xa7K\x1f\xd8\x0d\xcd\xb9\xd9e\xd9" +
+	"\x1f_\xe6\x0e\xe8b\xd1\x06\x83#\xbd^\x8a\xb9\xe8\x18" +
+	"\xe1i\xd0\xf0\xb0g\xb4\xfc0\xafs\xa6\xdc\x88Kz" +
+	"0\xffJ\xcd^\xa97\xfb\xe5\x1bJ\xaa\xfc\xd1\xa4?" +
+	"&\x8b\x89\xf52.o\xeb7\x95\xe2]i\xda\xa7\xcd" +
+	"S\xa2~\x84v\xa6`\xe2\xb4&\xfa\xcb\x8b\xd3\xdc\xf4" +
+	"\xd7&N\xab\xc0\x19C\xd7B\x8a\x8c\xb2\xba`\xeb\xc3" +
+	"\xc2\xa9\xbe\x07\xb4x\xba\xa4\x11\xba\xccO\xa1\xcd\xa6\x92" +
+	"\xe8\xcb\xf5vucW$]+\xfd\xca3U\x95O" +
+	"\x1e@##\xb9\xd3\x85m$\x80@\xa5\x18 \xf9d" +
+	"7\xfd\xcd\x13\xe5\x1a\xec9Y\xbe\xe8s\xe1\xe4\xc8F" +
+	"\x89w\xb8\xc4;\xe0\x19\x15o\xaf\x14o\x87\xe7S\\" +
+	"\xe7B\xd7\x19\xbb0>\xea\xa5\x0d}\xa7\x16\x10\xd8J" +
+	"\x0e|\xd2q\xc9\xdf\xdb\xa9\x8au\xc5`\xfb\xa4\x0f\xec" +
+	"\x08'H\xfcd\x18\xe7\xf6\xa4\x89\x9axC\x09\xf5\xe4" +
+	"\xe7\x08\xc7\x83\xa4\x17\xab:1\xb3\xa3]\xaa\xaa\x96\x83" +
+	"\xadf#\xf4\xe7x\x192\xcb2\xa9\x86\xedL7\x9c" +
+	"Nv\xe6\xc5\x81Gh(\xa2\xc3\xce\x04f^\xf0\x1a" +
+	"\x07_\xf88\x00N\xfd\x14d\\\xf0\x9a\xc2\xcaAp" +
+	"^\x102\x93Y.x\xcd\xc2\x91j\x16\x90\x99\x84," +
+	"d\xe6\xe6JZ\x84j\x96\x00YL\xc8*fn\xb0" +
+	"\xa4\xd5\xc8\xb3\x1a\xc8*B\x1a\xd5sX\xbev\x0e[" +
+	"\xc7\x9a\xa5\xdb\x81|\x89\x10?!\xc2(\xcd\xeb$\xc3" +
+	"\xdf\x0b\x9flC;!qB\x0a\x04\xcd\xed\x94@=" +
+	"]@\xb6\x12r/!\xf6\x02\xcd\xed\xb4\x13\xa7:\xf8" +
+	"\xad\x1b\xee#\xe4a\xfa\x05\x18\xb4\x1fE\xf5\xad\xd6\xae" +
+	"v\x1b\xe1\xc3\xa6\xa37e\xe2\x09\xf1\xd4\xc8R5D" +
+	"\x9c\xba0\x10\xcf\xe2k\x09\x04\xfd\xcb|\xfa\x8f\x89\xa4" +
+	"d\x81!\xa7M\xce\xcc\x12M\xc4\xe2\xd4mF\xc8v" +
+	"jP,2\x85\x01s\x8e\xd8\x90pcT\xceR_" +
+	"\xba\xbe07\x90\xb9\x9d\x15\xb2\xc6\xe2d\x9e\xe7\x7f\x04" +
+	"\x8dk\xe8\xbd\xb7K\xc5\xb7\x05\xcf\xcf\xa0\xcb~i\x1e" +
+	"\xdd\xdeo\xb2\x9e\xdc\xf5\xa3\xdb\x99\x9a\xe4\xc9];\xa3" +
+	"\xdb\x96j\x03\x8a\xa1\xd3\xf2\xd1iy\xc6!]\xf7b" +
+	"\x16cp\x18\x87t\xf5\xe7\x81t/f\xca\xcf\x03-" +
+	"f\x16/\xe6\"\x0c\xe9\x94\x1f\x012\xdc\x98\xebX\xd4" +
+	"\x1cj\xedCl\xf4\xe3Zhk\x03g\x93[,;" +
+	"I\xcb<Nw2ew\xab\xfd\xbf\xf5=\xe5\xb6\xfb" +
+	"H\xb7\x06\x8el\x13d\x04\x08f,KM9\xf8z" +
+	"l!\xf9\"\xcb\xaf\xf5\xe7\x80\x8c\xe5W\xd5\xe1\xea\xf2" +
+	"\xbb\xd1\xb2\xfc\xfaj,\xb6B3\\\x9fE\xd2G\x83" +
+	"\x83b\xf53o\x06i\xc1\xffY'\xda\xd1\x91Xz" +
+	"\xf5k\xff#\xb9\xf7oD#f4\xed\xe0H\x7fv" +
+	"\xed2\xf6\\F\x0c\xe7H\xcf?i\x01\xc3\xd9\x03^" +
+	"-6\xa9\x039\x1b(/e\xe5\x1a\xa6\xc9S\x8f=" +
+	"SC\xcf\x84x\xb4\xeb\"\xbe\xbf\xf2\xac\xbe\xbf\x0a\xcb" +
+	"\xefl\x08\x9b2/\x84\x94a\x97\x94\x90\x87\xd8P\xf7" +
+	"\xe5\xf8\x1b[\xd9\xe2R\x866\xcb\x1aV\xd9\x0a\xf15" +
+	"\xc1\xf3*\x12\xdf\xb4\xa8\xf1\x1f\xbbu\xcb\xcaE\xcc\xb2" +
+	"\xc9\xc8\xebO*\xc4O\x04\xcf\xef\x90\xfag\xd2\xde\xaf" +
+	"j\xf6\x96?\x1d4\x8c\xb2E\xaa\xee.\xd6t\xb7\x1d" +
+	"\xdb\x04;4t\x81\xbeO\xc9\x16\xabm\xfc\xd4\x97q" +
+	"e\xe9\xff\x99\x956\xd9E\x07s\xdd\xcbz\xc3\xf12" +
+	"\xf5\"\xe1\x10\xa7}\xb78\x85\xf6\xb3\xdaO?\xf2\xda" +
+	"\xaf\xd9(\xf1h\"\xd4\x02\x15\xad}|\x15\x9c\xeb\xda" +
+	"}\x9a4\xb5\xa6\xca\xf4\xbff\x9c6\x80"
 
 func RegisterSchema(reg *schemas.Registry) {
 	reg.Register(&schemas.Schema{
 		String: schema_ffaaf7385bc4adad,
 		Nodes: []uint64{
+			0x81af26d8deca1c10,
+			0x81cf83061cb9d611,
 			0x83479da67279e173,
 			0x8aef91973dc8a4f5,
 			0x8b4c03a0662a38dc,
 			0x8b5b1693940f607e,
+			0x90a3950a51412b8b,
+			0x90dd84f8954874b4,
+			0x9488d71c49c86c29,
+			0x96e541d34ba6c497,
 			0x9887a60f577a1ecb,
+			0x9ae8f79260e0a4a7,
+			0x9d0da23d405b48b7,
+			0x9d82529754851252,
+			0x9d8e0a5b2890efca,
 			0xa0ef8355b64ee985,
+			0xa1a81a51f2e6d925,
 			0xa20f49456be85b99,
+			0xa3cb406c522dcab1,
+			0xa3ce5d4aa318e87a,
+			0xa6d76ce69f13a816,
 			0xa93853d6a4e3fa16,
 			0xaa2f3c8ad1c3af24,
 			0xaa4bbac12765a78a,
+			0xaaa69aebe451afba,
+			0xabfd7291f61ff659,
 			0xace5517aafc86077,
 			0xad2a33d6b9304413,
+			0xad5e6e3b177fdffd,
+			0xae5e0ae5001ebdfe,
 			0xae78ee8eb6b3a134,
+			0xb4e9d49db5875667,
 			0xb5418b8ea8ead17b,
 			0xb62f418e0ae4e003,
 			0xb737e899dd6633f1,
 			0xba53ab87a688ec29,
 			0xba77e3fa3aa9b6ca,
+			0xbab00e2a7d787530,
+			0xbdf982a1bf936157,
+			0xbf8bf393a5b0605f,
+			0xc3e0cf14ec9bf330,
 			0xc5e65eec3dcf5b10,
 			0xc76ccd4502bb61e7,
+			0xc9701dd28ecc4dec,
 			0xcc2f70676afee4e7,
 			0xce733f0914c80b6b,
 			0xceba3c1a97be15f8,
 			0xd0476e0f34d1411a,
 			0xd61491b560a8f3a3,
+			0xd6b52319bc826f96,
+			0xd9d185299a8131ea,
 			0xd9d61d1d803c85fc,
 			0xde3a625e70772b9a,
+			0xdebaeed2a782ac80,
 			0xdf703ca0befc3afc,
 			0xe00e522611477055,
+			0xe1405aa7f55a9152,
 			0xe313695ea9477b30,
+			0xe377a0cceeec274b,
+			0xe5aca9f6f318460e,
 			0xe5ea916eb0c31336,
+			0xe989fde14d6e82dd,
+			0xec285699f2c21582,
+			0xedd2e5b018f17bbb,
+			0xeecb8f2cf4fdde21,
+			0xeffbded8dd651be8,
 			0xf026e3d750335bc1,
 			0xf34be5cbac1feed1,
 			0xf41122f890a371a6,
 			0xf44732c48f949ab8,
+			0xf45b380214ff8477,
 			0xf4e3e92ae0815f15,
 			0xf8e86a5c0baa01bc,
 			0xf9b3cd8033aba1f8,
+			0xf9cd7b95c38bfa8e,
 			0xfabbfdde6d4ad392,
+			0xff1fe278c0cfa678,
+			0xffe010df085b0c55,
 		},
 		Compressed: true,
 	})
@@ -663,6 +663,36 @@ type CreateContainerConfig struct {
 
 	// LeakFDs can be used to keep file descriptors open as long as the container is running.
 	LeakFDs []RemoteFD
+
+	// LogStdin logs the data written to the container stdin, which may
+	// contain secrets.
+	LogStdin bool
+
+	// LogMetadata is the static container identity (e.g. pod name, namespace,
+	// image) added to JSON log entries.
+	LogMetadata map[string]string
+
+	// ValidateLogDrivers verifies the log driver paths and options before
+	// spawning the container.
+	ValidateLogDrivers bool
+
+	// OrderedLogDrivers writes the log drivers one after another, local files
+	// first, ignoring failures of the others.
+	OrderedLogDrivers bool
+
+	// LogMaxSize is the maximum log size in bytes of log drivers inheriting
+	// it, 0 means unlimited.
+	LogMaxSize uint64
+
+	// LogExitEvent writes a final `container_exit` event with the exit code
+	// into the logs. CRI logs contain it as full stdout record like
+	// `# conmon: {"event":"container_exit","exit_code":N}`, which consumers
+	// have to tell apart from the container output.
+	LogExitEvent bool
+
+	// MaxConcurrentLogWriters is the maximum amount of log drivers written at
+	// once, 0 means unlimited.
+	MaxConcurrentLogWriters uint32
 }
 
 // ContainerLogDriver specifies a selected logging mechanism.
@@ -674,8 +704,252 @@ type ContainerLogDriver struct {
 	Path string
 
 	// MaxSize is the maximum amount of bytes to be written before rotation.
-	// 0 translates to an unlimited size.
+	// 0 translates to an unlimited size, unless MaxSizeMode inherits the
+	// `CreateContainerConfig.LogMaxSize`.
 	MaxSize uint64
+
+	// MaxSizeText is the maximum log size like "10MiB" or "10MB", which takes
+	// precedence over MaxSize if not empty.
+	MaxSizeText string
+
+	// MaxSizeMode distinguishes an unlimited log size from one which is not
+	// configured by the driver.
+	MaxSizeMode LogDriverMaxSizeMode
+
+	// MaxFiles is the maximum amount of rotated log files to keep.
+	// 0 translates to an unlimited amount.
+	MaxFiles uint64
+
+	// Rotation is the strategy used when rotating the log file.
+	Rotation LogDriverRotation
+
+	// RotateInterval rotates the log file on the first write after this
+	// interval in milliseconds elapsed, 0 means disabled.
+	RotateInterval uint64
+
+	// BackupFormat is the format of the backup names of the rename rotation,
+	// containing either an "{index}" or a "{timestamp}" placeholder.
+	// Empty means numbered backups.
+	BackupFormat string
+
+	// Compress gzip compresses rotated log files.
+	//
+	// Deprecated: Use Compression instead, which takes precedence if set.
+	Compress bool
+
+	// Compression is the algorithm used for compressing rotated log files.
+	Compression LogDriverCompression
+
+	// Checksum writes the SHA-256 digest of every rotated log file into a
+	// ".sha256" sidecar.
+	Checksum bool
+
+	// Durability is the policy used for syncing written log lines to disk.
+	Durability LogDriverDurability
+
+	// SyncInterval is the sync interval in milliseconds of the
+	// LogDriverDurabilityInterval durability.
+	SyncInterval uint64
+
+	// MaxLineSize is the maximum line size in bytes before it gets split into
+	// multiple entries, 0 means unlimited.
+	MaxLineSize uint64
+
+	// MaxLineSizeText is the maximum line size like "16KiB", which takes
+	// precedence over MaxLineSize if not empty.
+	MaxLineSizeText string
+
+	// Args are the arguments passed to the program of the command log driver.
+	Args []string
+
+	// Backpressure is the strategy used if the command log driver does not
+	// keep up with the output.
+	Backpressure LogDriverBackpressure
+
+	// Framing is the framing of the entries written by the JSON log driver.
+	Framing LogDriverFraming
+
+	// QueueDepth is the maximum amount of writes queued in front of the log
+	// driver, 0 disables the queue.
+	QueueDepth uint64
+
+	// QueuePolicy is the strategy used if the queue in front of the log driver
+	// is full.
+	QueuePolicy LogDriverQueuePolicy
+
+	// ParseLevel detects a leading level token of the messages written by the
+	// JSON log driver.
+	ParseLevel bool
+
+	// LevelPattern is the regular expression used by ParseLevel, which has to
+	// capture a "level" group.
+	LevelPattern string
+
+	// Append appends to an existing log file of the JSON log driver instead of
+	// truncating it.
+	Append bool
+
+	// Stream is the output stream to be logged.
+	Stream LogDriverStream
+
+	// TimestampSource is the clock used for the log timestamps.
+	TimestampSource LogDriverTimestampSource
+
+	// Timezone is the IANA time zone of the JSON log driver timestamps, like
+	// "Europe/Berlin". Empty means UTC.
+	Timezone string
+
+	// PipeLabel is the label of the pipe field written by the JSON log driver.
+	PipeLabel LogDriverPipeLabel
+
+	// MessageKey, TimestampKey and PipeKey are the key names of the JSON log
+	// driver. Empty keeps the default ones.
+	MessageKey   string
+	TimestampKey string
+	PipeKey      string
+
+	// WatchPath reopens the log file if an external tool moved or deleted it.
+	WatchPath bool
+
+	// Sequence adds a "seq" field to the entries of the JSON log driver.
+	Sequence bool
+
+	// BufferSize is the capacity of the read and write buffers in bytes,
+	// 0 means the default of 8 KiB.
+	BufferSize uint64
+
+	// BatchSize is the amount of lines exported at once by the OTLP log
+	// driver, 0 means the default of 512.
+	BatchSize uint64
+
+	// MaxBufferedLines is the maximum amount of lines buffered by the OTLP log
+	// driver while the collector is unavailable, 0 means the default of 8192.
+	MaxBufferedLines uint64
+
+	// RepeatWindow collapses consecutive identical lines within this window in
+	// milliseconds into a single entry, 0 disables it.
+	RepeatWindow uint64
+
+	// RedactPatterns are regular expressions whose matches get replaced by
+	// "***" in every message.
+	RedactPatterns []string
+
+	// AddHostname adds the name of the node as "hostname" field to every entry
+	// of the JSON log driver.
+	AddHostname bool
+
+	// Hostname is the name added by AddHostname, empty means the hostname of
+	// the system.
+	Hostname string
+
+	// FileMode are the permission bits of the log files, 0 keeps the defaults.
+	FileMode uint32
+
+	// FileUID and FileGID are the owning user and group ID of the log files,
+	// nil keeps the current ones.
+	FileUID *int64
+	FileGID *int64
+
+	// DiskFullRetries are the retries of writes failing because the disk is
+	// full, 0 disables retrying.
+	DiskFullRetries uint32
+
+	// Optional only reports failures of the log driver as warning and
+	// disables it, instead of failing the container logging.
+	Optional bool
+
+	// FlushEveryLines and FlushEveryBytes flush the buffered lines after this
+	// amount of lines or bytes instead of after every write, 0 disables the
+	// threshold.
+	FlushEveryLines uint64
+	FlushEveryBytes uint64
+
+	// EmptyLines is the handling of empty lines.
+	EmptyLines LogDriverEmptyLines
+
+	// FallbackPath is the path to continue logging to if writing to Path
+	// fails. Empty disables the failover.
+	FallbackPath string
+
+	// TraceContext adds the "trace_id" and "span_id" of the active tracing
+	// span to every entry of the JSON log driver.
+	TraceContext bool
+
+	// ContainerName and Image are the name and image of the container added to
+	// the log entries or sidecar. Empty values are omitted.
+	ContainerName string
+	Image         string
+
+	// PartialTag and FullTag are the tags of the partial and full records of
+	// the CRI log driver. Empty means "P" and "F".
+	PartialTag string
+	FullTag    string
+
+	// NoFollow refuses to open the log files through a symbolic link.
+	NoFollow bool
+
+	// ReconcileInterval is the interval in milliseconds after which the
+	// accounted size of the log file gets reset to its actual size,
+	// 0 disables it.
+	ReconcileInterval uint64
+
+	// LinkPath is the path of a symbolic link pointing at the live log file of
+	// the CRI log driver. Empty disables it.
+	LinkPath string
+
+	// WriteTimeout is the timeout in milliseconds of every write to the log
+	// driver, 0 disables the timeout.
+	WriteTimeout uint64
+
+	// DockerCompat writes the entries of the JSON log driver exactly like the
+	// Docker "json-file" logging driver.
+	DockerCompat bool
+
+	// Multiline joins the continuation lines of the JSON log driver into the
+	// entry of their preceding line.
+	Multiline bool
+
+	// MultilinePattern is the regular expression detecting the continuation
+	// lines of Multiline.
+	MultilinePattern string
+
+	// MultilineTimeout writes a joined entry once this timeout in milliseconds
+	// elapsed since its first line, 0 means the default of 1 second.
+	MultilineTimeout uint64
+
+	// PreserveRaw keeps the messages of the JSON log driver exactly as written
+	// instead of trimming their surrounding whitespace.
+	PreserveRaw bool
+
+	// Upload uploads every rotated backup into an S3 compatible object store,
+	// disabled if nil.
+	Upload *LogUpload
+}
+
+// LogUpload specifies the S3 compatible object store receiving the rotated
+// log backups.
+type LogUpload struct {
+	// Endpoint is the HTTP or HTTPS endpoint of the object store, like
+	// "http://minio:9000".
+	Endpoint string
+
+	// Bucket is the bucket receiving the uploaded backups.
+	Bucket string
+
+	// Prefix is the prefix of the object keys, like "logs/".
+	Prefix string
+
+	// Region is the region used for signing the upload requests, defaults to
+	// "us-east-1" if empty.
+	Region string
+
+	// AccessKeyID and SecretAccessKey are the credentials for signing the
+	// upload requests, which are unsigned if empty.
+	AccessKeyID     string
+	SecretAccessKey string
+
+	// Delete deletes the local backup once it got uploaded.
+	Delete bool
 }
 
 // LogDriverType specifies available log drivers.
@@ -686,6 +960,13 @@ const (
 	// type.
 	LogDriverTypeContainerRuntimeInterface LogDriverType = iota
 	LogDriverTypeJSONLogger                LogDriverType = iota
+	LogDriverTypeSyslog                    LogDriverType = iota
+	LogDriverTypeJournald                  LogDriverType = iota
+	LogDriverTypeCommand                   LogDriverType = iota
+	LogDriverTypeNull                      LogDriverType = iota
+	LogDriverTypeOTLP                      LogDriverType = iota
+	LogDriverTypeMemory                    LogDriverType = iota
+	LogDriverTypeGELF                      LogDriverType = iota
 )
 
 // CreateContainerResponse is the response of the CreateContainer method.
@@ -766,6 +1047,18 @@ func (c *ConmonClient) CreateContainer(
 			return fmt.Errorf("convert file descriptor slice to slot list: %w", err)
 		}
 
+		req.SetLogStdin(cfg.LogStdin)
+
+		if err := stringStringMapToMapEntryList(cfg.LogMetadata, req.NewLogMetadata); err != nil {
+			return fmt.Errorf("convert log metadata string map to map entry list: %w", err)
+		}
+
+		req.SetValidateLogDrivers(cfg.ValidateLogDrivers)
+		req.SetOrderedLogDrivers(cfg.OrderedLogDrivers)
+		req.SetLogMaxSize(cfg.LogMaxSize)
+		req.SetLogExitEvent(cfg.LogExitEvent)
+		req.SetMaxConcurrentLogWriters(cfg.MaxConcurrentLogWriters)
+
 		return nil
 	})
 	defer free()
@@ -899,18 +1192,171 @@ func (c *ConmonClient) initLogDrivers(req *proto.Conmon_CreateContainerRequest,
 	if err != nil {
 		return fmt.Errorf("create log drivers: %w", err)
 	}
-	for i, logDriver := range logDrivers {
-		n := newLogDrivers.At(i)
-		if logDriver.Type == LogDriverTypeContainerRuntimeInterface {
-			n.SetType(proto.Conmon_LogDriver_Type_containerRuntimeInterface)
+	for i := range logDrivers {
+		if err := setLogDriver(newLogDrivers.At(i), &logDrivers[i]); err != nil {
+			return err
 		}
-		if logDriver.Type == LogDriverTypeJSONLogger {
-			n.SetType(proto.Conmon_LogDriver_Type_json)
+	}
+
+	return nil
+}
+
+func setLogDriver(n proto.Conmon_LogDriver, logDriver *ContainerLogDriver) error {
+	switch logDriver.Type {
+	case LogDriverTypeContainerRuntimeInterface:
+		n.SetType(proto.Conmon_LogDriver_Type_containerRuntimeInterface)
+	case LogDriverTypeJSONLogger:
+		n.SetType(proto.Conmon_LogDriver_Type_json)
+	case LogDriverTypeSyslog:
+		n.SetType(proto.Conmon_LogDriver_Type_syslog)
+	case LogDriverTypeJournald:
+		n.SetType(proto.Conmon_LogDriver_Type_journald)
+	case LogDriverTypeCommand:
+		n.SetType(proto.Conmon_LogDriver_Type_command)
+	case LogDriverTypeNull:
+		n.SetType(proto.Conmon_LogDriver_Type_null)
+	case LogDriverTypeOTLP:
+		n.SetType(proto.Conmon_LogDriver_Type_otlp)
+	case LogDriverTypeMemory:
+		n.SetType(proto.Conmon_LogDriver_Type_memory)
+	case LogDriverTypeGELF:
+		n.SetType(proto.Conmon_LogDriver_Type_gelf)
+	}
+	if err := n.SetPath(logDriver.Path); err != nil {
+		return fmt.Errorf("set log driver path: %w", err)
+	}
+	n.SetRequired(!logDriver.Optional)
+	if err := stringSliceToTextList(logDriver.Args, n.NewArgs); err != nil {
+		return fmt.Errorf("convert log driver args string slice to text list: %w", err)
+	}
+	n.SetBackpressure(proto.Conmon_LogDriver_Backpressure(logDriver.Backpressure))
+	n.SetQueueDepth(logDriver.QueueDepth)
+	n.SetQueuePolicy(proto.Conmon_LogDriver_QueuePolicy(logDriver.QueuePolicy))
+	n.SetBatchSize(logDriver.BatchSize)
+	n.SetMaxBufferedLines(logDriver.MaxBufferedLines)
+	n.SetWriteTimeout(logDriver.WriteTimeout)
+
+	if err := setLogDriverFile(n, logDriver); err != nil {
+		return err
+	}
+	if err := setLogDriverRotation(n, logDriver); err != nil {
+		return err
+	}
+
+	return setLogDriverEntries(n, logDriver)
+}
+
+// setLogDriverFile sets the options of the log file written by the driver.
+func setLogDriverFile(n proto.Conmon_LogDriver, logDriver *ContainerLogDriver) error {
+	n.SetDurability(proto.Conmon_LogDriver_Durability(logDriver.Durability))
+	n.SetSyncInterval(logDriver.SyncInterval)
+	n.SetAppend(logDriver.Append)
+	n.SetWatchPath(logDriver.WatchPath)
+	n.SetBufferSize(logDriver.BufferSize)
+	n.SetFileMode(logDriver.FileMode)
+	if logDriver.FileUID != nil {
+		n.SetFileUid(*logDriver.FileUID)
+	}
+	if logDriver.FileGID != nil {
+		n.SetFileGid(*logDriver.FileGID)
+	}
+	n.SetDiskFullRetries(logDriver.DiskFullRetries)
+	n.SetFlushEveryLines(logDriver.FlushEveryLines)
+	n.SetFlushEveryBytes(logDriver.FlushEveryBytes)
+	if err := n.SetFallbackPath(logDriver.FallbackPath); err != nil {
+		return fmt.Errorf("set log driver fallback path: %w", err)
+	}
+	n.SetNoFollow(logDriver.NoFollow)
+	n.SetReconcileInterval(logDriver.ReconcileInterval)
+	if err := n.SetLinkPath(logDriver.LinkPath); err != nil {
+		return fmt.Errorf("set log driver link path: %w", err)
+	}
+
+	return nil
+}
+
+// setLogDriverRotation sets the size limits, rotation and backup options.
+func setLogDriverRotation(n proto.Conmon_LogDriver, logDriver *ContainerLogDriver) error {
+	n.SetMaxSize(logDriver.MaxSize)
+	if err := n.SetMaxSizeText(logDriver.MaxSizeText); err != nil {
+		return fmt.Errorf("set log driver max size text: %w", err)
+	}
+	n.SetMaxSizeMode(proto.Conmon_LogDriver_MaxSizeMode(logDriver.MaxSizeMode))
+	n.SetMaxFiles(logDriver.MaxFiles)
+	n.SetRotation(proto.Conmon_LogDriver_Rotation(logDriver.Rotation))
+	n.SetRotateInterval(logDriver.RotateInterval)
+	if err := n.SetBackupFormat(logDriver.BackupFormat); err != nil {
+		return fmt.Errorf("set log driver backup format: %w", err)
+	}
+	n.SetCompress(logDriver.Compress)
+	n.SetCompression(proto.Conmon_LogDriver_Compression(logDriver.Compression))
+	n.SetChecksum(logDriver.Checksum)
+
+	if upload := logDriver.Upload; upload != nil {
+		for _, field := range []struct {
+			name  string
+			value string
+			set   func(string) error
+		}{
+			{"endpoint", upload.Endpoint, n.SetUploadEndpoint},
+			{"bucket", upload.Bucket, n.SetUploadBucket},
+			{"prefix", upload.Prefix, n.SetUploadPrefix},
+			{"region", upload.Region, n.SetUploadRegion},
+			{"access key ID", upload.AccessKeyID, n.SetUploadAccessKeyId},
+			{"secret access key", upload.SecretAccessKey, n.SetUploadSecretAccessKey},
+		} {
+			if err := field.set(field.value); err != nil {
+				return fmt.Errorf("set log driver upload %s: %w", field.name, err)
+			}
 		}
-		if err := n.SetPath(logDriver.Path); err != nil {
-			return fmt.Errorf("set log driver path: %w", err)
+		n.SetUploadDelete(upload.Delete)
+	}
+
+	return nil
+}
+
+// setLogDriverEntries sets the options of the written log entries.
+func setLogDriverEntries(n proto.Conmon_LogDriver, logDriver *ContainerLogDriver) error {
+	n.SetMaxLineSize(logDriver.MaxLineSize)
+	n.SetFraming(proto.Conmon_LogDriver_Framing(logDriver.Framing))
+	n.SetParseLevel(logDriver.ParseLevel)
+	n.SetStream(proto.Conmon_LogDriver_Stream(logDriver.Stream))
+	n.SetTimestampSource(proto.Conmon_LogDriver_TimestampSource(logDriver.TimestampSource))
+	n.SetPipeLabel(proto.Conmon_LogDriver_PipeLabel(logDriver.PipeLabel))
+	n.SetSequence(logDriver.Sequence)
+	n.SetRepeatWindow(logDriver.RepeatWindow)
+	if err := stringSliceToTextList(logDriver.RedactPatterns, n.NewRedactPatterns); err != nil {
+		return fmt.Errorf("convert log driver redact patterns string slice to text list: %w", err)
+	}
+	n.SetAddHostname(logDriver.AddHostname)
+	n.SetEmptyLines(proto.Conmon_LogDriver_EmptyLines(logDriver.EmptyLines))
+	n.SetTraceContext(logDriver.TraceContext)
+	n.SetDockerCompat(logDriver.DockerCompat)
+	n.SetMultiline(logDriver.Multiline)
+	n.SetMultilineTimeout(logDriver.MultilineTimeout)
+	n.SetPreserveRaw(logDriver.PreserveRaw)
+
+	for _, field := range []struct {
+		name  string
+		value string
+		set   func(string) error
+	}{
+		{"max line size text", logDriver.MaxLineSizeText, n.SetMaxLineSizeText},
+		{"level pattern", logDriver.LevelPattern, n.SetLevelPattern},
+		{"hostname", logDriver.Hostname, n.SetHostname},
+		{"message key", logDriver.MessageKey, n.SetMessageKey},
+		{"timestamp key", logDriver.TimestampKey, n.SetTimestampKey},
+		{"pipe key", logDriver.PipeKey, n.SetPipeKey},
+		{"timezone", logDriver.Timezone, n.SetTimezone},
+		{"container name", logDriver.ContainerName, n.SetContainerName},
+		{"image", logDriver.Image, n.SetImage},
+		{"partial tag", logDriver.PartialTag, n.SetPartialTag},
+		{"full tag", logDriver.FullTag, n.SetFullTag},
+		{"multiline pattern", logDriver.MultilinePattern, n.SetMultilinePattern},
+	} {
+		if err := field.set(field.value); err != nil {
+			return fmt.Errorf("set log driver %s: %w", field.name, err)
 		}
-		n.SetMaxSize(logDriver.MaxSize)
 	}
 
 	return nil
@@ -973,6 +1419,9 @@ func (c *ConmonClient) socket() string {
 type ReopenLogContainerConfig struct {
 	// ID is the container identifier.
 	ID string
+
+	// All reopens the logs of all running containers, which ignores the ID.
+	All bool
 }
 
 // ReopenLogContainer can be used to rotate all configured container log
@@ -1004,6 +1453,8 @@ func (c *ConmonClient) ReopenLogContainer(ctx context.Context, cfg *ReopenLogCon
 			return fmt.Errorf("set ID: %w", err)
 		}
 
+		req.SetAll(cfg.All)
+
 		return nil
 	})
 	defer free()
@@ -258,6 +258,30 @@ var _ = Describe("ConmonClient", func() {
 				logs := fileContents(logFile)
 				Expect(logs).NotTo(BeEmpty())
 			})
+
+			It(testName("should tail the logs", terminal), func() {
+				tr = newTestRunner()
+				tr.createRuntimeConfigWithProcessArgs(
+					terminal,
+					[]string{"/busybox", "sh", "-c", "echo hello && echo world"},
+					nil,
+				)
+				sut = tr.configGivenEnv()
+				tr.createContainer(sut, terminal)
+				tr.startContainer(sut)
+
+				Eventually(func() (string, error) {
+					resp, err := sut.TailLogContainer(context.Background(), &client.TailLogContainerConfig{
+						ID:    tr.ctrID,
+						Lines: 1,
+					})
+					if err != nil || len(resp.Lines) != 1 {
+						return "", err
+					}
+
+					return string(resp.Lines[0].Message), nil
+				}, time.Second*5).Should(HavePrefix("world"))
+			})
 		}
 	})
 
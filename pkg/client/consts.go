@@ -74,3 +74,172 @@ const (
 	// NamespaceUTS is the reference to the UTS namespace.
 	NamespaceUTS
 )
+
+// LogDriverRotation is the enum for all available log rotation strategies.
+type LogDriverRotation proto.Conmon_LogDriver_Rotation
+
+const (
+	// LogDriverRotationTruncate truncates the log file in place.
+	LogDriverRotationTruncate LogDriverRotation = LogDriverRotation(proto.Conmon_LogDriver_Rotation_truncate)
+
+	// LogDriverRotationRename renames the log file to a numbered backup.
+	LogDriverRotationRename LogDriverRotation = LogDriverRotation(proto.Conmon_LogDriver_Rotation_rename)
+
+	// LogDriverRotationTimestamp renames the log file to a backup suffixed
+	// with the UTC rotation time.
+	LogDriverRotationTimestamp LogDriverRotation = LogDriverRotation(proto.Conmon_LogDriver_Rotation_timestamp)
+)
+
+// LogDriverDurability is the enum for all available log sync policies.
+type LogDriverDurability proto.Conmon_LogDriver_Durability
+
+const (
+	// LogDriverDurabilityNone syncs the log file only when reopening it.
+	LogDriverDurabilityNone LogDriverDurability = LogDriverDurability(proto.Conmon_LogDriver_Durability_none)
+
+	// LogDriverDurabilityEveryWrite flushes and syncs the log file after every
+	// written line.
+	LogDriverDurabilityEveryWrite LogDriverDurability = LogDriverDurability(proto.Conmon_LogDriver_Durability_everyWrite)
+
+	// LogDriverDurabilityInterval syncs the log file periodically every
+	// `ContainerLogDriver.SyncInterval` milliseconds.
+	LogDriverDurabilityInterval LogDriverDurability = LogDriverDurability(proto.Conmon_LogDriver_Durability_interval)
+
+	// LogDriverDurabilityDsync opens the log file with `O_DSYNC`.
+	LogDriverDurabilityDsync LogDriverDurability = LogDriverDurability(proto.Conmon_LogDriver_Durability_dsync)
+)
+
+// LogDriverBackpressure is the enum for all available strategies of the
+// command log driver not keeping up with the output.
+type LogDriverBackpressure proto.Conmon_LogDriver_Backpressure
+
+const (
+	// LogDriverBackpressureBlock blocks the container output until the command
+	// accepted the line.
+	LogDriverBackpressureBlock LogDriverBackpressure = LogDriverBackpressure(proto.Conmon_LogDriver_Backpressure_block)
+
+	// LogDriverBackpressureDrop drops lines if the command does not keep up.
+	LogDriverBackpressureDrop LogDriverBackpressure = LogDriverBackpressure(proto.Conmon_LogDriver_Backpressure_drop)
+)
+
+// LogDriverQueuePolicy is the enum for all available strategies of a full log
+// driver queue.
+type LogDriverQueuePolicy proto.Conmon_LogDriver_QueuePolicy
+
+const (
+	// LogDriverQueuePolicyBlock blocks the container output until the log
+	// driver removed an entry from the queue.
+	LogDriverQueuePolicyBlock LogDriverQueuePolicy = LogDriverQueuePolicy(proto.Conmon_LogDriver_QueuePolicy_block)
+
+	// LogDriverQueuePolicyDropOldest drops the oldest queued entry in favor of
+	// the new one.
+	LogDriverQueuePolicyDropOldest LogDriverQueuePolicy = LogDriverQueuePolicy(
+		proto.Conmon_LogDriver_QueuePolicy_dropOldest,
+	)
+
+	// LogDriverQueuePolicyDropNewest drops the new entry.
+	LogDriverQueuePolicyDropNewest LogDriverQueuePolicy = LogDriverQueuePolicy(
+		proto.Conmon_LogDriver_QueuePolicy_dropNewest,
+	)
+)
+
+// LogDriverTimestampSource is the enum for all available log timestamp clocks.
+type LogDriverTimestampSource proto.Conmon_LogDriver_TimestampSource
+
+const (
+	// LogDriverTimestampSourceWallClock uses the system wall clock.
+	LogDriverTimestampSourceWallClock LogDriverTimestampSource = LogDriverTimestampSource(
+		proto.Conmon_LogDriver_TimestampSource_wallClock,
+	)
+
+	// LogDriverTimestampSourceMonotonic uses a wall clock anchored to the
+	// monotonic clock, which never jumps backwards.
+	LogDriverTimestampSourceMonotonic LogDriverTimestampSource = LogDriverTimestampSource(
+		proto.Conmon_LogDriver_TimestampSource_monotonic,
+	)
+)
+
+// LogDriverStream is the enum for all available logged output streams.
+type LogDriverStream proto.Conmon_LogDriver_Stream
+
+const (
+	// LogDriverStreamAll logs stdout and stderr.
+	LogDriverStreamAll LogDriverStream = LogDriverStream(proto.Conmon_LogDriver_Stream_all)
+
+	// LogDriverStreamStdout only logs stdout.
+	LogDriverStreamStdout LogDriverStream = LogDriverStream(proto.Conmon_LogDriver_Stream_stdout)
+
+	// LogDriverStreamStderr only logs stderr.
+	LogDriverStreamStderr LogDriverStream = LogDriverStream(proto.Conmon_LogDriver_Stream_stderr)
+)
+
+// LogDriverEmptyLines is the enum for all available handlings of empty lines.
+type LogDriverEmptyLines proto.Conmon_LogDriver_EmptyLines
+
+const (
+	// LogDriverEmptyLinesKeep writes empty lines as they are.
+	LogDriverEmptyLinesKeep LogDriverEmptyLines = LogDriverEmptyLines(proto.Conmon_LogDriver_EmptyLines_keep)
+
+	// LogDriverEmptyLinesDrop skips empty lines.
+	LogDriverEmptyLinesDrop LogDriverEmptyLines = LogDriverEmptyLines(proto.Conmon_LogDriver_EmptyLines_drop)
+)
+
+// LogDriverMaxSizeMode is the enum for all available log size limits.
+type LogDriverMaxSizeMode proto.Conmon_LogDriver_MaxSizeMode
+
+const (
+	// LogDriverMaxSizeModeInherit uses the max size of the log driver if not 0,
+	// otherwise `CreateContainerConfig.LogMaxSize`.
+	LogDriverMaxSizeModeInherit LogDriverMaxSizeMode = LogDriverMaxSizeMode(proto.Conmon_LogDriver_MaxSizeMode_inherit)
+
+	// LogDriverMaxSizeModeUnlimited does not limit the log size.
+	LogDriverMaxSizeModeUnlimited LogDriverMaxSizeMode = LogDriverMaxSizeMode(proto.Conmon_LogDriver_MaxSizeMode_unlimited)
+
+	// LogDriverMaxSizeModeLimited uses the max size of the log driver, which
+	// must not be 0.
+	LogDriverMaxSizeModeLimited LogDriverMaxSizeMode = LogDriverMaxSizeMode(proto.Conmon_LogDriver_MaxSizeMode_limited)
+)
+
+// LogDriverFraming is the enum for all available framings of the JSON logger.
+type LogDriverFraming proto.Conmon_LogDriver_Framing
+
+const (
+	// LogDriverFramingNDJSON writes newline delimited JSON.
+	LogDriverFramingNDJSON LogDriverFraming = LogDriverFraming(proto.Conmon_LogDriver_Framing_ndjson)
+
+	// LogDriverFramingJSONSeq writes JSON text sequences (RFC 7464).
+	LogDriverFramingJSONSeq LogDriverFraming = LogDriverFraming(proto.Conmon_LogDriver_Framing_jsonSeq)
+
+	// LogDriverFramingArray writes a single JSON array.
+	LogDriverFramingArray LogDriverFraming = LogDriverFraming(proto.Conmon_LogDriver_Framing_array)
+)
+
+// LogDriverPipeLabel is the enum for all available pipe labels of the JSON
+// logger.
+type LogDriverPipeLabel proto.Conmon_LogDriver_PipeLabel
+
+const (
+	// LogDriverPipeLabelName writes the full pipe name, like `stdout`.
+	LogDriverPipeLabelName LogDriverPipeLabel = LogDriverPipeLabel(proto.Conmon_LogDriver_PipeLabel_name)
+
+	// LogDriverPipeLabelShort writes the short pipe name, like `out`.
+	LogDriverPipeLabelShort LogDriverPipeLabel = LogDriverPipeLabel(proto.Conmon_LogDriver_PipeLabel_short)
+
+	// LogDriverPipeLabelFD writes the numeric file descriptor, like `1`.
+	LogDriverPipeLabelFD LogDriverPipeLabel = LogDriverPipeLabel(proto.Conmon_LogDriver_PipeLabel_fd)
+)
+
+// LogDriverCompression is the enum for all available compressions of rotated
+// log files.
+type LogDriverCompression proto.Conmon_LogDriver_Compression
+
+const (
+	// LogDriverCompressionNone keeps rotated log files uncompressed.
+	LogDriverCompressionNone LogDriverCompression = LogDriverCompression(proto.Conmon_LogDriver_Compression_none)
+
+	// LogDriverCompressionGzip gzip compresses rotated log files.
+	LogDriverCompressionGzip LogDriverCompression = LogDriverCompression(proto.Conmon_LogDriver_Compression_gzip)
+
+	// LogDriverCompressionZstd zstandard compresses rotated log files.
+	LogDriverCompressionZstd LogDriverCompression = LogDriverCompression(proto.Conmon_LogDriver_Compression_zstd)
+)
@@ -0,0 +1,529 @@
+package client
+
+import (
+	"context"
+	"fmt"
+	"time"
+
+	"github.com/containers/conmon-rs/internal/proto"
+)
+
+// LogLine is a single line of a container log.
+type LogLine struct {
+	// Timestamp is the timestamp of the line.
+	Timestamp string
+
+	// Pipe is the pipe label of the line.
+	Pipe string
+
+	// Message is the content of the line without trailing newline.
+	Message []byte
+}
+
+// TailLogContainerConfig is the configuration for calling the
+// TailLogContainer method.
+type TailLogContainerConfig struct {
+	// ID is the container identifier.
+	ID string
+
+	// Lines is the maximum amount of lines to be returned.
+	Lines uint64
+
+	// Since skips lines older than this time, the zero value means no limit.
+	Since time.Time
+
+	// Until skips lines from this time on, the zero value means no limit.
+	Until time.Time
+
+	// SkipInvalidTimestamps skips lines with an unparsable timestamp if a
+	// limit is set.
+	SkipInvalidTimestamps bool
+
+	// Stream only returns the lines of this stream of a combined log.
+	Stream LogDriverStream
+}
+
+// TailLogContainerResponse is the response of the TailLogContainer method.
+type TailLogContainerResponse struct {
+	// Lines are the last lines of the log, from oldest to newest.
+	Lines []*LogLine
+
+	// LastWrite is the time of the latest write of any log driver, the zero
+	// value if none wrote yet.
+	LastWrite time.Time
+}
+
+// TailLogContainer can be used to retrieve the last lines of a container log.
+func (c *ConmonClient) TailLogContainer(
+	ctx context.Context, cfg *TailLogContainerConfig,
+) (*TailLogContainerResponse, error) {
+	ctx, span := c.startSpan(ctx, "TailLogContainer")
+	if span != nil {
+		defer span.End()
+	}
+
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return nil, fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon(conn.Bootstrap(ctx))
+
+	future, free := client.TailLogContainer(ctx, func(p proto.Conmon_tailLogContainer_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+
+		if err := c.setMetadata(ctx, req); err != nil {
+			return err
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+
+		req.SetLines(cfg.Lines)
+		req.SetSince(timeToUnixNano(cfg.Since))
+		req.SetUntil(timeToUnixNano(cfg.Until))
+		req.SetSkipInvalidTimestamps(cfg.SkipInvalidTimestamps)
+		req.SetStream(proto.Conmon_LogDriver_Stream(cfg.Stream))
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return nil, fmt.Errorf("create result: %w", err)
+	}
+
+	response, err := result.Response()
+	if err != nil {
+		return nil, fmt.Errorf("set response: %w", err)
+	}
+
+	lines, err := response.Lines()
+	if err != nil {
+		return nil, fmt.Errorf("get lines: %w", err)
+	}
+
+	logLines := make([]*LogLine, 0, lines.Len())
+	for i := 0; i < lines.Len(); i++ {
+		logLine, err := logLineFromProto(lines.At(i))
+		if err != nil {
+			return nil, err
+		}
+
+		logLines = append(logLines, logLine)
+	}
+
+	return &TailLogContainerResponse{
+		Lines:     logLines,
+		LastWrite: unixNanoToTime(response.LastWrite()),
+	}, nil
+}
+
+// FollowLogContainerConfig is the configuration for calling the
+// FollowLogContainer method.
+type FollowLogContainerConfig struct {
+	// ID is the container identifier.
+	ID string
+
+	// Since skips lines older than this time, the zero value means no limit.
+	Since time.Time
+
+	// Until stops on the first line from this time on, the zero value means
+	// no limit.
+	Until time.Time
+
+	// SkipInvalidTimestamps skips lines with an unparsable timestamp if a
+	// limit is set.
+	SkipInvalidTimestamps bool
+
+	// Stream only follows the lines of this stream of a combined log.
+	Stream LogDriverStream
+
+	// OnLine is called for every followed line. Returning an error stops
+	// following the log.
+	OnLine func(*LogLine) error
+}
+
+// FollowLogContainer can be used to receive the lines of a container log as
+// they get written. It blocks until the container exited and all its output
+// got received, Until has been reached or the context got canceled.
+func (c *ConmonClient) FollowLogContainer(ctx context.Context, cfg *FollowLogContainerConfig) error {
+	ctx, span := c.startSpan(ctx, "FollowLogContainer")
+	if span != nil {
+		defer span.End()
+	}
+
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon(conn.Bootstrap(ctx))
+
+	future, free := client.FollowLogContainer(ctx, func(p proto.Conmon_followLogContainer_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+
+		if err := c.setMetadata(ctx, req); err != nil {
+			return err
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+
+		req.SetSince(timeToUnixNano(cfg.Since))
+		req.SetUntil(timeToUnixNano(cfg.Until))
+		req.SetSkipInvalidTimestamps(cfg.SkipInvalidTimestamps)
+		req.SetStream(proto.Conmon_LogDriver_Stream(cfg.Stream))
+
+		listener := proto.Conmon_LogListener_ServerToClient(&logListener{onLine: cfg.OnLine})
+		if err := req.SetListener(listener); err != nil {
+			return fmt.Errorf("set listener: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	if _, err := result.Response(); err != nil {
+		return fmt.Errorf("set response: %w", err)
+	}
+
+	return nil
+}
+
+// logListener is the local implementation of the LogListener receiving the
+// lines of FollowLogContainer.
+type logListener struct {
+	onLine func(*LogLine) error
+}
+
+func (l *logListener) Line(_ context.Context, call proto.Conmon_LogListener_line) error {
+	line, err := call.Args().Line()
+	if err != nil {
+		return fmt.Errorf("get line: %w", err)
+	}
+
+	logLine, err := logLineFromProto(line)
+	if err != nil {
+		return err
+	}
+
+	if l.onLine == nil {
+		return nil
+	}
+
+	return l.onLine(logLine)
+}
+
+// PauseLogContainerConfig is the configuration for calling the
+// PauseLogContainer method.
+type PauseLogContainerConfig struct {
+	// ID is the container identifier.
+	ID string
+
+	// BufferSize buffers up to this amount of bytes written while paused,
+	// 0 drops all of them.
+	BufferSize uint64
+}
+
+// PauseLogContainerResponse is the response of the PauseLogContainer method.
+type PauseLogContainerResponse struct {
+	// AlreadyPaused is true if the log has been paused before, which only
+	// replaced its buffer size.
+	AlreadyPaused bool
+}
+
+// PauseLogContainer can be used to pause writing the logs of a container
+// until ResumeLogContainer gets called.
+func (c *ConmonClient) PauseLogContainer(
+	ctx context.Context, cfg *PauseLogContainerConfig,
+) (*PauseLogContainerResponse, error) {
+	ctx, span := c.startSpan(ctx, "PauseLogContainer")
+	if span != nil {
+		defer span.End()
+	}
+
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return nil, fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon(conn.Bootstrap(ctx))
+
+	future, free := client.PauseLogContainer(ctx, func(p proto.Conmon_pauseLogContainer_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+
+		if err := c.setMetadata(ctx, req); err != nil {
+			return err
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+
+		req.SetBufferSize(cfg.BufferSize)
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return nil, fmt.Errorf("create result: %w", err)
+	}
+
+	response, err := result.Response()
+	if err != nil {
+		return nil, fmt.Errorf("set response: %w", err)
+	}
+
+	return &PauseLogContainerResponse{
+		AlreadyPaused: response.AlreadyPaused(),
+	}, nil
+}
+
+// ResumeLogContainerConfig is the configuration for calling the
+// ResumeLogContainer method.
+type ResumeLogContainerConfig struct {
+	// ID is the container identifier.
+	ID string
+}
+
+// ResumeLogContainer can be used to resume writing the logs of a paused
+// container, which writes the buffered output first.
+func (c *ConmonClient) ResumeLogContainer(ctx context.Context, cfg *ResumeLogContainerConfig) error {
+	ctx, span := c.startSpan(ctx, "ResumeLogContainer")
+	if span != nil {
+		defer span.End()
+	}
+
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon(conn.Bootstrap(ctx))
+
+	future, free := client.ResumeLogContainer(ctx, func(p proto.Conmon_resumeLogContainer_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+
+		if err := c.setMetadata(ctx, req); err != nil {
+			return err
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	if _, err := result.Response(); err != nil {
+		return fmt.Errorf("set response: %w", err)
+	}
+
+	return nil
+}
+
+// AddLogDriverConfig is the configuration for calling the AddLogDriver
+// method.
+type AddLogDriverConfig struct {
+	// ID is the container identifier.
+	ID string
+
+	// LogDriver is the log driver receiving all subsequent output of the
+	// container.
+	LogDriver ContainerLogDriver
+}
+
+// AddLogDriver can be used to add a log driver to a running container.
+func (c *ConmonClient) AddLogDriver(ctx context.Context, cfg *AddLogDriverConfig) error {
+	ctx, span := c.startSpan(ctx, "AddLogDriver")
+	if span != nil {
+		defer span.End()
+	}
+
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon(conn.Bootstrap(ctx))
+
+	future, free := client.AddLogDriver(ctx, func(p proto.Conmon_addLogDriver_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+
+		if err := c.setMetadata(ctx, req); err != nil {
+			return err
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+
+		driver, err := req.NewDriver()
+		if err != nil {
+			return fmt.Errorf("create log driver: %w", err)
+		}
+
+		return setLogDriver(driver, &cfg.LogDriver)
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return fmt.Errorf("create result: %w", err)
+	}
+
+	if _, err := result.Response(); err != nil {
+		return fmt.Errorf("set response: %w", err)
+	}
+
+	return nil
+}
+
+// RemoveLogDriverConfig is the configuration for calling the RemoveLogDriver
+// method.
+type RemoveLogDriverConfig struct {
+	// ID is the container identifier.
+	ID string
+
+	// Path removes the log drivers writing the file at this path.
+	Path string
+}
+
+// RemoveLogDriverResponse is the response of the RemoveLogDriver method.
+type RemoveLogDriverResponse struct {
+	// Removed is the amount of removed log drivers.
+	Removed uint64
+}
+
+// RemoveLogDriver can be used to remove the log drivers of a running
+// container writing a certain path.
+func (c *ConmonClient) RemoveLogDriver(
+	ctx context.Context, cfg *RemoveLogDriverConfig,
+) (*RemoveLogDriverResponse, error) {
+	ctx, span := c.startSpan(ctx, "RemoveLogDriver")
+	if span != nil {
+		defer span.End()
+	}
+
+	conn, err := c.newRPCConn()
+	if err != nil {
+		return nil, fmt.Errorf("create RPC connection: %w", err)
+	}
+	defer conn.Close()
+	client := proto.Conmon(conn.Bootstrap(ctx))
+
+	future, free := client.RemoveLogDriver(ctx, func(p proto.Conmon_removeLogDriver_Params) error {
+		req, err := p.NewRequest()
+		if err != nil {
+			return fmt.Errorf("create request: %w", err)
+		}
+
+		if err := c.setMetadata(ctx, req); err != nil {
+			return err
+		}
+
+		if err := req.SetId(cfg.ID); err != nil {
+			return fmt.Errorf("set ID: %w", err)
+		}
+
+		if err := req.SetPath(cfg.Path); err != nil {
+			return fmt.Errorf("set path: %w", err)
+		}
+
+		return nil
+	})
+	defer free()
+
+	result, err := future.Struct()
+	if err != nil {
+		return nil, fmt.Errorf("create result: %w", err)
+	}
+
+	response, err := result.Response()
+	if err != nil {
+		return nil, fmt.Errorf("set response: %w", err)
+	}
+
+	return &RemoveLogDriverResponse{
+		Removed: response.Removed(),
+	}, nil
+}
+
+func logLineFromProto(line proto.Conmon_LogLine) (*LogLine, error) {
+	timestamp, err := line.Timestamp()
+	if err != nil {
+		return nil, fmt.Errorf("get line timestamp: %w", err)
+	}
+
+	pipe, err := line.Pipe()
+	if err != nil {
+		return nil, fmt.Errorf("get line pipe: %w", err)
+	}
+
+	message, err := line.Message()
+	if err != nil {
+		return nil, fmt.Errorf("get line message: %w", err)
+	}
+
+	// The message bytes are only valid as long as the capnp message.
+	messageCopy := make([]byte, len(message))
+	copy(messageCopy, message)
+
+	return &LogLine{
+		Timestamp: timestamp,
+		Pipe:      pipe,
+		Message:   messageCopy,
+	}, nil
+}
+
+// timeToUnixNano converts the time to Unix nanoseconds, where the zero value
+// maps to 0.
+func timeToUnixNano(t time.Time) uint64 {
+	if t.IsZero() {
+		return 0
+	}
+
+	return uint64(t.UnixNano())
+}
+
+// unixNanoToTime converts Unix nanoseconds to a time, where 0 maps to the
+// zero value.
+func unixNanoToTime(nanos uint64) time.Time {
+	if nanos == 0 {
+		return time.Time{}
+	}
+
+	return time.Unix(0, int64(nanos))
+}